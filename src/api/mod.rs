@@ -53,13 +53,13 @@ mod session;
 
 
 
+mod objex;
+use objex::materials::material_routes;
 /*
 mod viewer;
 
 
-mod objex;
 use objex::objex::objex_routes;
-use objex::materials::material_routes;
 use objex::geospec::geospec_routes;
 use objex::templates::geometry_template_routes;
 */
@@ -69,8 +69,7 @@ use objex::templates::geometry_template_routes;
 
 
 // -------observers API 
-//mod observers;
-//use observers::*;
+mod observers;
 
 pub fn api_router(app_state: AppState) -> Router {
     // Time routes
@@ -96,7 +95,7 @@ pub fn api_router(app_state: AppState) -> Router {
     //let events_routes = events::events_routes();
     //let pages_routes = pages::pages_routes();
     //let viewer_routes = viewer::viewer_routes();
-    //let observer_routes = observers::observer_routes();
+    let observer_routes = observers::observer_routes();
 
     Router::new()
         .route("/ping", get(|| async { "pong" }))
@@ -112,7 +111,7 @@ pub fn api_router(app_state: AppState) -> Router {
         //.nest("/simulations", simulations_routes)
         //.nest("/entities", entities_routes)
         //.nest("/objex", objex_routes())
-        //.nest("/objex/materials", material_routes())
+        .nest("/objex/materials", material_routes())
         //.nest("/objex/geospec", geospec_routes())
        // .nest("/physics", physics_routes)
         
@@ -121,7 +120,7 @@ pub fn api_router(app_state: AppState) -> Router {
         
         //.nest("/pages", pages_routes)
         //.nest("/viewer", viewer_routes)
-      // .nest("/observers", observer_routes)
+        .nest("/observers", observer_routes)
         
         .with_state(app_state)
        // .layer(middleware::from_fn(identity_middleware)) // 👈 MOVE HERE
@@ -1,15 +1,17 @@
 use axum::{
     routing::{post, get},
-    extract::{State, Path},
+    extract::{State, Path, Query},
+    body::Bytes,
+    response::{IntoResponse, Response},
     Json, Router,
-    http::StatusCode,
+    http::{header, StatusCode},
 
 };
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
 use crate::shared::app_state::AppState;
-use crate::core::objex::geospec::{AuthoringShape, GeoSpec};
+use crate::core::objex::geospec::{AuthoringShape, GeoSpec, Inference, Mesh, MeshCheck, MeshFormat, PipeSize, PipeSystem, Profile, SectionProperties};
 
 /// ---------------------------------------------------------------------------
 /// Routes
@@ -18,6 +20,11 @@ use crate::core::objex::geospec::{AuthoringShape, GeoSpec};
 pub fn geospec_routes() -> Router<AppState> {
     Router::new()
         .route("/compile", post(compile_geospec))
+        .route("/section", post(section_properties))
+        .route("/infer", post(infer_parameters))
+        .route("/pipes/{system}", get(pipe_sizes))
+        .route("/import/{format}", post(import_mesh))
+        .route("/export/{format}", post(export_mesh))
         .route("/{id}", get(get_geospec))
         .route("/", get(list_geospecs))
 }
@@ -53,6 +60,75 @@ async fn compile_geospec(
     Json(GeoSpecRecord { id, spec })
 }
 
+/// Area, second moments and section moduli of a cross-section
+async fn section_properties(Json(profile): Json<Profile>) -> Json<SectionProperties> {
+    Json(profile.section())
+}
+
+/// Fill in a shape's missing parameters; reports measured vs inferred
+/// values and inconsistencies
+async fn infer_parameters(Json(input): Json<serde_json::Value>) -> Result<Json<Inference>, (StatusCode, String)> {
+    crate::core::objex::geospec::infer(&input).map(Json).map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+/// Catalog sizes of a pipe, tube or conduit system, smallest first
+async fn pipe_sizes(Path(system): Path<PipeSystem>) -> Json<Vec<PipeSize>> {
+    Json(system.sizes())
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeshImport {
+    pub record: GeoSpecRecord,
+    pub check: MeshCheck,
+    pub shape: AuthoringShape,
+}
+
+/// Import an OBJ / STL body as a mesh shape, compile and store it
+async fn import_mesh(
+    State(state): State<AppState>,
+    Path(format): Path<String>,
+    body: Bytes,
+) -> Result<Json<MeshImport>, (StatusCode, String)> {
+    let bad_request = |e: crate::core::objex::geospec::MeshError| (StatusCode::BAD_REQUEST, e.to_string());
+    let format: MeshFormat = format.parse().map_err(bad_request)?;
+    let mesh = Mesh::import(format, &body).map_err(bad_request)?;
+
+    let check = mesh.check();
+    let shape = AuthoringShape::Mesh(mesh);
+    let spec = shape.compile();
+    let id = Uuid::new_v4();
+    state.geospec_store.write().await.insert(id, spec.clone());
+
+    Ok(Json(MeshImport { record: GeoSpecRecord { id, spec }, check, shape }))
+}
+
+/// Segments around curved primitives when exporting.
+const DEFAULT_SEGMENTS: usize = 48;
+
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    pub segments: Option<usize>,
+}
+
+/// Triangulate a shape (primitives, extrusions, meshes) as OBJ / STL
+async fn export_mesh(
+    Path(format): Path<String>,
+    Query(params): Query<ExportParams>,
+    Json(shape): Json<AuthoringShape>,
+) -> Result<Response, (StatusCode, String)> {
+    let format: MeshFormat = format.parse().map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+    let mesh = Mesh::from_shape(&shape, params.segments.unwrap_or(DEFAULT_SEGMENTS)).ok_or((
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "shape has no triangle form".to_string(),
+    ))?;
+
+    let content_type = match format {
+        MeshFormat::Obj => "text/plain",
+        MeshFormat::StlAscii | MeshFormat::StlBinary => "model/stl",
+    };
+    Ok(([(header::CONTENT_TYPE, content_type)], mesh.export(format)).into_response())
+}
+
 /// Resolve GeoSpec by ID
 async fn get_geospec(
     State(state): State<AppState>,
@@ -1,5 +1,6 @@
 use axum::{
     extract::{Path, Query},
+    http::StatusCode,
     routing::get,
     Json, Router,
 };
@@ -13,10 +14,16 @@ use crate::core::objex::matcat::api::{
     get_grades,
     resolve_material,
     preview_material,
+    find_similar,
+    suggest_substitutes,
     IdName,
     ResolveResponse,
     PreviewResponse,
 };
+use crate::core::objex::matcat::MaterialMatch;
+
+/// Default number of results for similarity queries.
+const DEFAULT_K: usize = 10;
 
 #[derive(Debug, Deserialize)]
 pub struct ResolveQuery {
@@ -30,6 +37,43 @@ pub struct PreviewQuery {
     pub grade: Option<u16>,
 }
 
+/// `?variant=3&grade=1&focus=thermal&within=1,2&k=5`
+#[derive(Debug, Deserialize)]
+pub struct SimilarQuery {
+    pub variant: Option<u16>,
+    pub grade: Option<u16>,
+    #[serde(default)]
+    pub focus: String,
+    /// Comma-separated category ids.
+    #[serde(default)]
+    pub within: String,
+    pub k: Option<usize>,
+}
+
+/// `SimilarQuery` plus `require=tensile_strength>=400,cost<=5`.
+#[derive(Debug, Deserialize)]
+pub struct SubstituteQuery {
+    pub variant: Option<u16>,
+    pub grade: Option<u16>,
+    #[serde(default)]
+    pub require: String,
+    #[serde(default)]
+    pub focus: String,
+    #[serde(default)]
+    pub within: String,
+    pub k: Option<usize>,
+}
+
+fn split_list(s: &str) -> impl Iterator<Item = &str> {
+    s.split(',').map(str::trim).filter(|x| !x.is_empty())
+}
+
+fn parse_categories(within: &str) -> Result<Vec<u8>, (StatusCode, String)> {
+    split_list(within)
+        .map(|c| c.parse().map_err(|_| (StatusCode::BAD_REQUEST, format!("invalid category `{c}`"))))
+        .collect()
+}
+
 async fn list_categories() -> Json<Vec<IdName>> {
     Json(get_categories())
 }
@@ -60,6 +104,26 @@ async fn preview(
     Json(preview_material(category, q.variant, q.grade))
 }
 
+async fn similar(
+    Path(category): Path<u8>,
+    Query(q): Query<SimilarQuery>,
+) -> Result<Json<Vec<MaterialMatch>>, (StatusCode, String)> {
+    let within = parse_categories(&q.within)?;
+    find_similar(category, q.variant, q.grade, &q.focus, &within, q.k.unwrap_or(DEFAULT_K))
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+async fn substitutes(
+    Path(category): Path<u8>,
+    Query(q): Query<SubstituteQuery>,
+) -> Result<Json<Vec<MaterialMatch>>, (StatusCode, String)> {
+    let within = parse_categories(&q.within)?;
+    let require: Vec<String> = split_list(&q.require).map(str::to_string).collect();
+    suggest_substitutes(category, q.variant, q.grade, &require, &q.focus, &within, q.k.unwrap_or(DEFAULT_K))
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))
+}
 
 pub fn material_routes() -> Router<AppState> {
     Router::new()
@@ -71,4 +135,39 @@ pub fn material_routes() -> Router<AppState> {
         )
         .route("/resolve/{category}", get(resolve))
         .route("/preview/{category}", get(preview))
+        .route("/similar/{category}", get(similar))
+        .route("/substitutes/{category}", get(substitutes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similar_query(focus: &str, within: &str) -> SimilarQuery {
+        SimilarQuery { variant: Some(2), grade: Some(1), focus: focus.into(), within: within.into(), k: Some(3) }
+    }
+
+    #[tokio::test]
+    async fn similar_and_substitutes_report_bad_queries() {
+        let Json(found) = similar(Path(1), Query(similar_query("thermal", "1, 3"))).await.unwrap();
+        assert!(!found.is_empty() && found.len() <= 3);
+        assert!(found.iter().all(|m| [1, 3].contains(&m.id.category.0)));
+        assert!(found.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+        let bad = |(status, message): (StatusCode, String)| status == StatusCode::BAD_REQUEST && !message.is_empty();
+        assert!(bad(similar(Path(1), Query(similar_query("taste", ""))).await.unwrap_err()));
+        assert!(bad(similar(Path(1), Query(similar_query("", "steel"))).await.unwrap_err()));
+
+        let query = |require: &str| SubstituteQuery {
+            variant: Some(2),
+            grade: Some(1),
+            require: require.into(),
+            focus: String::new(),
+            within: String::new(),
+            k: None,
+        };
+        let Json(subs) = substitutes(Path(1), Query(query("density <= 5000"))).await.unwrap();
+        assert!(subs.len() <= DEFAULT_K);
+        assert!(bad(substitutes(Path(1), Query(query("density <= heavy"))).await.unwrap_err()));
+    }
 }
@@ -1,4 +1,7 @@
 pub mod materials;
+
+// Not yet ported off `supabasic` / the old `geospec_store`:
+/*
 pub mod templates;
 pub mod geospec;
 pub mod objex;
@@ -68,5 +71,4 @@ async fn create_objex_template(
 
     Json(created.into())
 }
-
-
+*/
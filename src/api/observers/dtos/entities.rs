@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use crate::core::id::EntityId;
+
+#[derive(Deserialize)]
+pub struct NearbyQuery {
+    pub radius_m: f64,
+    pub limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct NearestQuery {
+    pub k: usize,
+}
+
+/// Corners in any `UvoxId` syntax (hex, decimal degrees, DMS, geo URI)
+#[derive(Deserialize)]
+pub struct RegionQuery {
+    pub min: String,
+    pub max: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EntityDistance {
+    pub entity_id: EntityId,
+    pub distance_m: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ObserverEntitiesResponse {
+    pub observer_id: u64,
+    pub entities: Vec<EntityDistance>,
+}
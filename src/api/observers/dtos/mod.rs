@@ -1,31 +1,34 @@
 pub mod observer;
-pub mod frame;
-pub mod environment;
-pub mod sun;
-pub mod moon;
-
 pub use observer::*;
-pub use frame::*;
-pub use environment::*;
-pub use sun::*;
-pub use moon::*;
+pub mod entities;
+
+// Used only by the handlers not yet ported:
+//pub mod frame;
+//pub mod environment;
+//pub mod sun;
+//pub mod moon;
+
+//pub use frame::*;
+//pub use environment::*;
+//pub use sun::*;
+//pub use moon::*;
 
-pub mod camera;
-pub use camera::*;
+//pub mod camera;
+//pub use camera::*;
 
-pub mod horizon;
-pub use horizon::*;
+//pub mod horizon;
+//pub use horizon::*;
 
-pub mod camera_eclipse;
-pub use camera_eclipse::*;
+//pub mod camera_eclipse;
+//pub use camera_eclipse::*;
 
-pub mod atmosphere;
-pub use atmosphere::*;
+//pub mod atmosphere;
+//pub use atmosphere::*;
 
-pub mod surface_energy;
-pub use surface_energy::*;
+//pub mod surface_energy;
+//pub use surface_energy::*;
 
-pub mod pressure;
+//pub mod pressure;
 
-pub mod chemistry;
-pub mod chemistry_ocean;
\ No newline at end of file
+//pub mod chemistry;
+//pub mod chemistry_ocean;
@@ -0,0 +1,167 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::shared::app_state::AppState;
+use crate::api::observers::dtos::entities::*;
+use crate::core::id::EntityId;
+use crate::core::observer::{Observer, ObserverId};
+use crate::core::spatial::UvoxRegionId;
+use crate::core::uvoxid::UvoxId;
+use crate::engine::world::state::WorldState;
+
+/// The observer and its world's runtime state, whose spatial index
+/// answers the queries below. Loads the world if it isn't running yet.
+async fn observer_and_world(app: &AppState, id: u64) -> Result<(Observer, Arc<RwLock<WorldState>>), StatusCode> {
+    let observer = app.observers
+        .read().await
+        .get(&ObserverId(id))
+        .cloned()
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let world = app.world_engine
+        .load_world(observer.world)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    Ok((observer, world))
+}
+
+fn response(id: u64, found: Vec<(EntityId, f64)>) -> Json<ObserverEntitiesResponse> {
+    Json(ObserverEntitiesResponse {
+        observer_id: id,
+        entities: found
+            .into_iter()
+            .map(|(entity_id, distance_m)| EntityDistance { entity_id, distance_m })
+            .collect(),
+    })
+}
+
+/// ------------------------------------------------------------
+/// Entities within a radius of the observer, nearest first
+/// ------------------------------------------------------------
+pub async fn nearby_entities_handler(
+    State(app): State<AppState>,
+    Path(id): Path<u64>,
+    Query(q): Query<NearbyQuery>,
+) -> Result<Json<ObserverEntitiesResponse>, StatusCode> {
+    if !(q.radius_m.is_finite() && q.radius_m >= 0.0) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let (observer, world) = observer_and_world(&app, id).await?;
+
+    let mut found = observer.nearby(&world.read().await.spatial_index, q.radius_m);
+    if let Some(limit) = q.limit {
+        found.truncate(limit);
+    }
+    Ok(response(id, found))
+}
+
+/// ------------------------------------------------------------
+/// The k entities closest to the observer
+/// ------------------------------------------------------------
+pub async fn nearest_entities_handler(
+    State(app): State<AppState>,
+    Path(id): Path<u64>,
+    Query(q): Query<NearestQuery>,
+) -> Result<Json<ObserverEntitiesResponse>, StatusCode> {
+    let (observer, world) = observer_and_world(&app, id).await?;
+    let found = observer.nearest(&world.read().await.spatial_index, q.k);
+    Ok(response(id, found))
+}
+
+/// ------------------------------------------------------------
+/// Entities inside a lat/lon/r box, nearest to the observer first
+/// ------------------------------------------------------------
+pub async fn region_entities_handler(
+    State(app): State<AppState>,
+    Path(id): Path<u64>,
+    Query(q): Query<RegionQuery>,
+) -> Result<Json<ObserverEntitiesResponse>, StatusCode> {
+    let min: UvoxId = q.min.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+    let max: UvoxId = q.max.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+    let (observer, world) = observer_and_world(&app, id).await?;
+
+    let region = UvoxRegionId::new(min, max);
+    let found = observer.in_region(&world.read().await.spatial_index, &region);
+    Ok(response(id, found))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::components::position::Position;
+    use crate::core::id::WorldId;
+    use crate::core::tdt::SimTime;
+
+    fn at(s: &str) -> UvoxId {
+        s.parse().unwrap()
+    }
+
+    /// App state with world 1 running and holding `near` and `far`, and
+    /// observers 1 (in world 1) and 2 (in a world that doesn't exist).
+    async fn app_with_entities() -> (AppState, EntityId, EntityId) {
+        let app = AppState::from_env().unwrap();
+        let (near, far) = (EntityId::new(), EntityId::new());
+
+        let mut world = WorldState::flat();
+        world.entity_store.add_position(near, Position(at("0,0.001")));
+        world.entity_store.add_position(far, Position(at("0,0.01")));
+        world.refresh_spatial_index();
+        app.world_engine.worlds.write().await.insert(WorldId(1), Arc::new(RwLock::new(world)));
+
+        let mut observers = app.observers.write().await;
+        for (id, world) in [(1, WorldId(1)), (2, WorldId(-7))] {
+            observers.insert(ObserverId(id), Observer { id: ObserverId(id), world, uvox: at("0,0"), created_at: SimTime(0) });
+        }
+        drop(observers);
+        (app, near, far)
+    }
+
+    fn ids(Json(response): Json<ObserverEntitiesResponse>) -> Vec<EntityId> {
+        response.entities.iter().map(|e| e.entity_id).collect()
+    }
+
+    #[tokio::test]
+    async fn queries_run_against_the_observers_world() {
+        let (app, near, far) = app_with_entities().await;
+        let nearby = |radius_m, limit| {
+            nearby_entities_handler(State(app.clone()), Path(1), Query(NearbyQuery { radius_m, limit }))
+        };
+
+        let Json(within) = nearby(2_000.0, None).await.unwrap();
+        assert_eq!(within.observer_id, 1);
+        assert_eq!(within.entities.iter().map(|e| e.entity_id).collect::<Vec<_>>(), vec![near, far]);
+        assert!((within.entities[0].distance_m - 111.2).abs() < 1.0);
+        assert_eq!(ids(nearby(500.0, None).await.unwrap()), vec![near]);
+        assert_eq!(ids(nearby(2_000.0, Some(1)).await.unwrap()), vec![near]);
+
+        let nearest = nearest_entities_handler(State(app.clone()), Path(1), Query(NearestQuery { k: 1 }));
+        assert_eq!(ids(nearest.await.unwrap()), vec![near]);
+
+        let region = RegionQuery { min: "-0.001,0.005".into(), max: "0.001,0.02@100m".into() };
+        let found = region_entities_handler(State(app.clone()), Path(1), Query(region)).await;
+        assert_eq!(ids(found.unwrap()), vec![far]);
+    }
+
+    #[tokio::test]
+    async fn bad_requests_and_missing_observers_or_worlds() {
+        let (app, _, _) = app_with_entities().await;
+        let nearby = |id, radius_m| {
+            nearby_entities_handler(State(app.clone()), Path(id), Query(NearbyQuery { radius_m, limit: None }))
+        };
+
+        assert_eq!(nearby(1, f64::NAN).await.unwrap_err(), StatusCode::BAD_REQUEST);
+        assert_eq!(nearby(1, -1.0).await.unwrap_err(), StatusCode::BAD_REQUEST);
+        assert_eq!(nearby(99, 10.0).await.unwrap_err(), StatusCode::NOT_FOUND);
+        assert_eq!(nearby(2, 10.0).await.unwrap_err(), StatusCode::NOT_FOUND);
+
+        let region = RegionQuery { min: "north".into(), max: "0,0".into() };
+        let err = region_entities_handler(State(app.clone()), Path(1), Query(region)).await.unwrap_err();
+        assert_eq!(err, StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,50 +1,53 @@
 pub mod observer;
-pub mod frame;
-pub mod environment;
-pub mod sun;
-
 pub use observer::*;
-pub use frame::*;
-pub use environment::*;
-pub use sun::*;
-pub mod moon;
-pub use moon::*;
+pub mod entities;
+pub use entities::*;
+
+// Not yet ported off `supabasic` / `loader::load_world`:
+//pub mod frame;
+//pub mod environment;
+//pub mod sun;
 
-pub mod camera;
-pub use camera::*;
+//pub use frame::*;
+//pub use environment::*;
+//pub use sun::*;
+//pub mod moon;
+//pub use moon::*;
 
-pub mod horizon;
-pub use horizon::*;
+//pub mod camera;
+//pub use camera::*;
 
-pub mod camera_projected;
-pub use camera_projected::*;
+//pub mod horizon;
+//pub use horizon::*;
 
-pub mod camera_eclipse;
-pub use camera_eclipse::*;
+//pub mod camera_projected;
+//pub use camera_projected::*;
 
-pub mod camera_eclipse_timeline;
-pub use camera_eclipse_timeline::*;
+//pub mod camera_eclipse;
+//pub use camera_eclipse::*;
 
-pub mod atmosphere;
-pub use atmosphere::*;
+//pub mod camera_eclipse_timeline;
+//pub use camera_eclipse_timeline::*;
 
-pub mod surface_energy;
-pub use surface_energy::*;
+//pub mod atmosphere;
+//pub use atmosphere::*;
 
-pub mod atmosphere_sample;
-pub use atmosphere_sample::*;
+//pub mod surface_energy;
+//pub use surface_energy::*;
 
+//pub mod atmosphere_sample;
+//pub use atmosphere_sample::*;
 
-pub mod atmosphere_sample_sweep;
-pub use atmosphere_sample_sweep::*;
+//pub mod atmosphere_sample_sweep;
+//pub use atmosphere_sample_sweep::*;
 
-pub mod pressure_sample;
-pub use pressure_sample::*;
-pub mod pressure_sweep;
-pub use pressure_sweep::*;
+//pub mod pressure_sample;
+//pub use pressure_sample::*;
+//pub mod pressure_sweep;
+//pub use pressure_sweep::*;
 
-pub mod chemistry_atmosphere;
-pub use chemistry_atmosphere::*;
+//pub mod chemistry_atmosphere;
+//pub use chemistry_atmosphere::*;
 
-pub mod chemistry_ocean;
-pub mod land_height;
+//pub mod chemistry_ocean;
+//pub mod land_height;
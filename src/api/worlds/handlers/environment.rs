@@ -29,11 +29,11 @@ pub async fn sample_environment_handler(
     // 1️⃣ Parse UvoxId
     let uvox = match UvoxId::from_str(&q.uvox) {
         Ok(id) => id,
-        Err(_) => {
+        Err(e) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(serde_json::json!({
-                    "error": "invalid uvox id"
+                    "error": format!("invalid uvox id: {e}")
                 })),
             )
                 .into_response();
@@ -34,11 +34,11 @@ pub async fn world_relative_handler(
 
     let from_uvox = match UvoxId::from_str(&q.from_uvox) {
         Ok(v) => v,
-        Err(_) => {
+        Err(e) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(serde_json::json!({
-                    "error": "invalid uvox id"
+                    "error": format!("invalid uvox id: {e}")
                 })),
             )
                 .into_response();
@@ -1,109 +1,81 @@
-use chrono::{Utc};
-use omnivox::{
-    core::{
-        id::WorldId,
-        objex::core::material::MaterialLink,
-        objex::Objex,
-        tdt::sim_time::SimTime,
-        tdt::sim_duration::SimDuration,
-        uvoxid::UvoxId,
-    },
-    sim::{
-        entities::SimEntity,
-        simulations::simulation::Simulation,
-        systems::movement::MovementSystem,
-        world::state::{World, WorldState},
-        components::velocity::Velocity,
-        time::clock::SimClock,
-        UvoxQuat,
-    },
-};
-
-fn main() {
-    println!("--- Sim Runner Starting ---");
-
-    //
-    // 1) Create a World (NOT a DB world record)
-    //
-    let world_meta = World {
-        id: WorldId::from(0),
-        name: Some("Earth".into()),
-        description: Some("Local baseline world".into()),
-        world_epoch: Some(SimTime::from_ns(0)),
-    };
+//! Deterministic scenario runner.
+//!
+//! ```text
+//! sim_runner <scenario.json|scenario.toml> [--worlds DIR] [--out DIR] [--seed N]
+//! ```
+//!
+//! Loads the scenario's world from the JSON catalog (default
+//! `data/worlds`), runs it, and writes `timeline.json` and
+//! `snapshot.json` into the output directory (default `out`).
+//! The same scenario and seed always produce byte-identical files.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use omnivox::engine::scenario::{Scenario, run_scenario};
+use omnivox::engine::world::loader::WorldLoader;
+use omnivox::infra::inmemory::world_state::InMemoryWorldStateSource;
+use omnivox::infra::world_sources::catalog::json::JsonWorldCatalog;
+
+struct Args {
+    scenario: PathBuf,
+    worlds: PathBuf,
+    out: PathBuf,
+    seed: Option<u64>,
+}
 
-    //
-    // 2) Build runtime state
-    //
-    let mut world_state = WorldState::new(world_meta.clone());
+fn parse_args() -> Result<Args> {
+    let mut scenario = None;
+    let mut worlds = PathBuf::from("data/worlds");
+    let mut out = PathBuf::from("out");
+    let mut seed = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().with_context(|| format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--worlds" => worlds = value()?.into(),
+            "--out" => out = value()?.into(),
+            "--seed" => seed = Some(value()?.parse().context("--seed must be an integer")?),
+            flag if flag.starts_with("--") => bail!("unknown option {flag}"),
+            _ if scenario.is_none() => scenario = Some(PathBuf::from(arg)),
+            _ => bail!("unexpected argument {arg}"),
+        }
+    }
 
-    //
-    // 3) Spawn one entity
-    //
-    let eid = world_state.allocate_entity_id();
+    let scenario = scenario.context(
+        "usage: sim_runner <scenario.json|scenario.toml> [--worlds DIR] [--out DIR] [--seed N]",
+    )?;
+    Ok(Args { scenario, worlds, out, seed })
+}
 
-    let material = MaterialLink::vacuum();
-    let obj = Objex::sphere(material, 1.0);
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = parse_args()?;
 
-    let pos = UvoxId::earth(6371000000000, 0, 0);
+    let mut scenario = Scenario::load(&args.scenario)?;
+    if let Some(seed) = args.seed {
+        scenario.seed = seed;
+    }
 
-    let ent = SimEntity::spawn(
-        eid,
-        obj,
-        world_meta.id,
-        pos,
-        UvoxQuat::identity(),
-        SimTime::from_ns(0),
+    let loader = WorldLoader::new(
+        Arc::new(JsonWorldCatalog::from_dir(&args.worlds)?),
+        Arc::new(InMemoryWorldStateSource::default()),
     );
-
-    world_state.entities.insert(eid, ent);
-
-    //
-    // 4) Give the entity velocity so it moves
-    //
-    world_state
-        .components
-        .velocity_components
-        .insert(eid, Velocity::new(10.0, 0.0, 0.0));
-
-    //
-    // 5) Make a SimClock
-    //
-    let clock = SimClock::fixed_step(
-        SimTime::from_ns(0),
-        1_000_000_000, // 1 sec per tick
+    let world = loader
+        .load(scenario.world_id)
+        .await
+        .with_context(|| format!("loading world {:?}", scenario.world_id))?;
+
+    let run = run_scenario(world, &scenario)?;
+    run.write_to(&args.out)?;
+
+    println!(
+        "{} events, final time {} → {}",
+        run.timeline.len(),
+        run.snapshot.sim_time.format_rfc3339(),
+        args.out.display()
     );
-
-    //
-    // 6) Build Simulation instance
-    //
-    let mut sim = Simulation {
-        simulation_id: Default::default(),
-        world_id: world_meta.id,
-        sim_time: SimTime::from_ns(0),
-        clock,
-        world: world_state,
-        systems: vec![Box::new(MovementSystem {})],
-        timeline: vec![],
-    };
-
-    println!("World loaded: {:?}", world_meta.name);
-
-    //
-    // 7) Run 5 ticks
-    //
-    for step in 0..5 {
-        let evs = sim.tick();
-
-        println!(
-            "Tick {} → sim_time {:?}, events = {}",
-            step, sim.sim_time, evs.len()
-        );
-
-        for (id, ent) in &sim.world.entities {
-            println!("   {} -> {:?}", id, ent.position);
-        }
-    }
-
-    println!("--- Sim Runner Complete ---");
+    Ok(())
 }
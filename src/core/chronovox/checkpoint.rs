@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use crate::core::chronovox::projection::{apply_event, refresh_actives};
+use crate::core::chronovox::event::EventKey;
+use crate::core::chronovox::timeline::Timeline;
+use crate::core::entity::entity_store::EntityStore;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_time::SimTime;
+
+/// A folded store plus how far into the timeline it got.
+#[derive(Debug, Clone)]
+struct Checkpoint {
+    /// Last event applied to `store`.
+    last: Option<EventKey>,
+    store: EntityStore,
+}
+
+/// ---------------------------------------------------------------------------
+/// Snapshot checkpoints over a `Timeline`
+/// ---------------------------------------------------------------------------
+///
+/// A checkpoint keyed at `t` holds every event at or before `t` and none
+/// after, so `state_at` only replays the tail past the nearest checkpoint.
+/// Checkpoints are taken at most once per `interval` of sim time.
+///
+/// Appending later events keeps checkpoints valid; after inserting an
+/// event earlier in the timeline call `invalidate_from` with its time.
+#[derive(Debug, Clone)]
+pub struct TimelineCheckpoints {
+    interval: SimDuration,
+    checkpoints: BTreeMap<SimTime, Checkpoint>,
+}
+
+impl TimelineCheckpoints {
+    pub fn new(interval: SimDuration) -> Self {
+        Self { interval, checkpoints: BTreeMap::new() }
+    }
+
+    /// Walk `timeline` once, checkpointing every `interval`.
+    pub fn build(timeline: &Timeline, interval: SimDuration) -> Self {
+        let mut checkpoints = Self::new(interval);
+        checkpoints.extend(timeline);
+        checkpoints
+    }
+
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Times of the stored checkpoints, oldest first.
+    pub fn times(&self) -> impl Iterator<Item = SimTime> + '_ {
+        self.checkpoints.keys().copied()
+    }
+
+    /// Checkpoint any events appended since the last call.
+    pub fn extend(&mut self, timeline: &Timeline) {
+        let (mut last_at, mut last, mut store) = match self.checkpoints.last_key_value() {
+            Some((&t, c)) => (Some(t), c.last, c.store.clone()),
+            None => (None, None, EntityStore::new()),
+        };
+
+        for event in timeline.iter_after(last) {
+            // Only checkpoint between distinct instants, so a checkpoint
+            // never splits a group of simultaneous events.
+            if let Some(prev) = last {
+                let due = last_at.is_none_or(|at| event.t - at >= self.interval);
+                if event.t > prev.t && due {
+                    let mut snapshot = store.clone();
+                    refresh_actives(&mut snapshot, prev.t);
+                    self.checkpoints.insert(prev.t, Checkpoint { last, store: snapshot });
+                    last_at = Some(prev.t);
+                }
+            }
+
+            apply_event(&mut store, event);
+            last = Some(event.key());
+        }
+    }
+
+    /// Drop every checkpoint at or after `t`.
+    pub fn invalidate_from(&mut self, t: SimTime) {
+        self.checkpoints.split_off(&t);
+    }
+
+    /// Reconstruct the store as of `t`, starting from the nearest
+    /// checkpoint at or before it.
+    pub fn state_at(&self, timeline: &Timeline, t: SimTime) -> EntityStore {
+        match self.checkpoints.range(..=t).next_back() {
+            Some((_, checkpoint)) => {
+                timeline.playback_onto(checkpoint.store.clone(), checkpoint.last, t)
+            }
+            None => timeline.playback_until(t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::chronovox::{ChronoEvent, EventKind};
+    use crate::core::id::{EntityId, WorldId};
+
+    fn pipe_history(entity: EntityId) -> Timeline {
+        let t0 = SimTime::from_ns(0);
+        let mut events = vec![
+            ChronoEvent::new(entity, WorldId(1), t0, EventKind::Spawn).with_payload(json!({
+                "name": "pipe",
+                "position": { "r_um": 6_371_000_000_000i64, "lat_code": 0, "lon_code": 0 },
+            })),
+        ];
+
+        for month in 1..=120 {
+            let t = t0.add_days(30 * month);
+            events.push(ChronoEvent::new(entity, WorldId(1), t, EventKind::Move { dr: 0, dlat: 1_000, dlon: -500 }));
+            events.push(
+                ChronoEvent::new(entity, WorldId(1), t, EventKind::Degrade { rate: 0.01 }).with_payload(json!({
+                    "corrosion": 0.001 * month as f64,
+                    "fatigue": 0.002 * month as f64,
+                    "thermal": 0.0,
+                    "total_integrity": 1.0 - 0.003 * month as f64,
+                })),
+            );
+        }
+
+        events.push(ChronoEvent::new(entity, WorldId(1), t0.add_days(30 * 100), EventKind::Despawn));
+
+        let mut timeline = Timeline::new();
+        for event in events {
+            timeline.record(event);
+        }
+        timeline
+    }
+
+    fn as_json(store: &EntityStore) -> serde_json::Value {
+        serde_json::to_value(store).unwrap()
+    }
+
+    #[test]
+    fn playback_folds_events_up_to_cutoff() {
+        let pipe = EntityId::new();
+        let timeline = pipe_history(pipe);
+
+        let state = timeline.playback_until(SimTime::from_ns(0).add_days(30 * 10));
+        assert_eq!(state.positions[&pipe].0.lat_code.0, 10_000);
+        assert_eq!(state.positions[&pipe].0.lon_code.0, -5_000);
+        assert!((state.degradations[&pipe].total_integrity - 0.97).abs() < 1e-12);
+        assert!(state.is_active(&pipe));
+
+        let end = timeline.playback();
+        assert!(!end.is_active(&pipe));
+        assert_eq!(end.notes[&pipe].text, "pipe");
+    }
+
+    #[test]
+    fn checkpoints_match_full_replay() {
+        let pipe = EntityId::new();
+        let timeline = pipe_history(pipe);
+        let checkpoints = TimelineCheckpoints::build(&timeline, SimDuration::days(365));
+
+        assert!(checkpoints.len() >= 9);
+
+        for days in [0, 15, 30, 400, 1_825, 2_999, 3_000, 3_001, 9_999] {
+            let t = SimTime::from_ns(0).add_days(days);
+            assert_eq!(
+                as_json(&checkpoints.state_at(&timeline, t)),
+                as_json(&timeline.playback_until(t)),
+                "mismatch at day {days}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalidated_checkpoints_are_rebuilt() {
+        let pipe = EntityId::new();
+        let mut timeline = pipe_history(pipe);
+        let mut checkpoints = TimelineCheckpoints::build(&timeline, SimDuration::days(365));
+
+        let t = SimTime::from_ns(0).add_days(500);
+        timeline.record(ChronoEvent::new(pipe, WorldId(1), t, EventKind::Teleport { r_um: 1, lat_code: 2, lon_code: 3 }));
+        checkpoints.invalidate_from(t);
+        checkpoints.extend(&timeline);
+
+        let later = SimTime::from_ns(0).add_days(2_000);
+        assert_eq!(
+            as_json(&checkpoints.state_at(&timeline, later)),
+            as_json(&timeline.playback_until(later))
+        );
+    }
+}
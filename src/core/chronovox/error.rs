@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::core::chronovox::event::EventKey;
+
 #[derive(Debug, Error)]
 pub enum ChronovoxError {
     #[error("Storage error: {0}")]
@@ -19,6 +21,9 @@ pub enum ChronovoxError {
 
     #[error("Invalid event kind: {0}")]
     InvalidEventKind(String),
+
+    #[error("Conflicting event: {0} is already recorded with different content")]
+    ConflictingEvent(EventKey),
 }
 
 pub type Result<T> = std::result::Result<T, ChronovoxError>;
@@ -1,6 +1,7 @@
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
-//use uuid::Uuid;
+use std::fmt;
+use uuid::Uuid;
 use crate::core::id::{WorldId, EntityId};
 
 use crate::core::tdt::sim_time::SimTime;
@@ -22,6 +23,11 @@ pub struct ChronoEvent {
     #[serde(serialize_with = "serialize_simtime")]
     pub t: SimTime,
 
+    /// Order among events at the same instant; together with `t` and
+    /// `entity_id` it identifies the event (see `EventKey`)
+    #[serde(default)]
+    pub seq: u64,
+
     /// What happened
     pub kind: EventKind,
 
@@ -30,10 +36,33 @@ pub struct ChronoEvent {
     pub payload: Option<Value>,
 }
 
+/// ---------------------------------------------------------------------------
+/// EventKey — identity and total order of a ChronoEvent: (t, seq, entity)
+/// ---------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EventKey {
+    pub t: SimTime,
+    pub seq: u64,
+    pub entity_id: EntityId,
+}
+
+impl EventKey {
+    /// Lowest possible key at `t`, for range queries over an instant.
+    pub fn first_at(t: SimTime) -> Self {
+        Self { t, seq: 0, entity_id: EntityId(Uuid::nil()) }
+    }
+}
+
+impl fmt::Display for EventKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{} ({})", self.t.as_ns(), self.seq, self.entity_id)
+    }
+}
+
 /// ---------------------------------------------------------------------------
 /// Event categories
 /// ---------------------------------------------------------------------------
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventKind {
     // --- Lifecycle ---
     Spawn,
@@ -77,11 +106,33 @@ impl ChronoEvent {
                 entity_id,
                 world_id,
                 t,
+                seq: 0,
                 kind,
                 payload: None,
             }
         }
 
+    /// Set the same-instant sequence number fluently
+    #[inline]
+    pub fn with_seq(mut self, seq: u64) -> Self {
+        self.seq = seq;
+        self
+    }
+
+    /// Identity / ordering key
+    #[inline]
+    pub fn key(&self) -> EventKey {
+        EventKey { t: self.t, seq: self.seq, entity_id: self.entity_id }
+    }
+
+    /// Same key *and* same content (world, kind, payload).
+    pub fn is_same_event(&self, other: &Self) -> bool {
+        self.key() == other.key()
+            && self.world_id == other.world_id
+            && self.kind == other.kind
+            && self.payload == other.payload
+    }
+
     /// Add payload fluently
     #[inline]
     pub fn with_payload(mut self, payload: Value) -> Self {
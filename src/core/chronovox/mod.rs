@@ -1,7 +1,11 @@
 pub mod error;
 pub mod event;
 pub mod timeline;
+pub mod projection;
+pub mod checkpoint;
 
 pub use error::{ChronovoxError, Result};
-pub use event::{ChronoEvent, EventKind};
+pub use event::{ChronoEvent, EventKey, EventKind};
 pub use timeline::{Timeline};
+pub use projection::{apply_event, refresh_actives};
+pub use checkpoint::TimelineCheckpoints;
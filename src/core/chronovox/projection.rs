@@ -0,0 +1,142 @@
+use serde_json::Value;
+
+use crate::core::chronovox::event::{ChronoEvent, EventKey, EventKind};
+use crate::core::chronovox::timeline::Timeline;
+use crate::core::components::condition::{DegradationData, ThermalData};
+use crate::core::components::despawned_at::DespawnedAt;
+use crate::core::components::note::Note;
+use crate::core::components::position::Position;
+use crate::core::components::spawned_at::SpawnedAt;
+use crate::core::components::world_membership::WorldMembership;
+use crate::core::entity::entity_store::EntityStore;
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::uvoxid::{Delta, LatCode, LonCode, RUm, UvoxId};
+
+/// ---------------------------------------------------------------------------
+/// Reducer — folds one event into an `EntityStore`
+/// ---------------------------------------------------------------------------
+///
+/// - `Spawn`             → `SpawnedAt`, `WorldMembership`; payload
+///   `position` and `name` become `Position` / `Note`
+/// - `Despawn`           → `DespawnedAt`
+/// - `Move`              → applies the delta to `Position`
+/// - `Teleport`          → replaces `Position`
+/// - `Degrade`           → `DegradationData` from the payload
+/// - `TemperatureChange` → `ThermalData` from the payload, else
+///   `delta_c` on the existing reading
+///
+/// Every other kind is recorded history only and leaves the store as is.
+pub fn apply_event(store: &mut EntityStore, event: &ChronoEvent) {
+    let id = event.entity_id;
+    let payload = event.payload.as_ref();
+
+    match &event.kind {
+        EventKind::Spawn => {
+            store.despawned_ats.remove(&id);
+            store.add_spawned_at(id, SpawnedAt { time: event.t });
+            store.add_world_membership(id, WorldMembership { world_id: event.world_id });
+
+            if let Some(position) = field::<UvoxId>(payload, "position") {
+                store.add_position(id, Position(position));
+            }
+            if let Some(name) = field::<String>(payload, "name") {
+                store.add_note(id, Note { text: name });
+            }
+        }
+
+        EventKind::Despawn => {
+            store.add_despawned_at(id, DespawnedAt { time: event.t });
+        }
+
+        EventKind::Move { dr, dlat, dlon } => {
+            if let Some(position) = store.positions.get_mut(&id) {
+                position.0.apply_delta(Delta::new(*dr, *dlat, *dlon));
+            }
+        }
+
+        EventKind::Teleport { r_um, lat_code, lon_code } => {
+            let position = UvoxId::new(RUm(*r_um), LatCode(*lat_code), LonCode(*lon_code));
+            store.add_position(id, Position(position));
+        }
+
+        EventKind::Degrade { .. } => {
+            if let Some(data) = payload.and_then(parse::<DegradationData>) {
+                store.degradations.insert(id, data);
+            }
+        }
+
+        EventKind::TemperatureChange { delta_c } => {
+            match payload.and_then(parse::<ThermalData>) {
+                Some(data) => {
+                    store.thermals.insert(id, data);
+                }
+                None => {
+                    if let Some(thermal) = store.thermals.get_mut(&id) {
+                        thermal.temperature_c += delta_c;
+                    }
+                }
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Recompute the `Active` markers for time `t` from spawn/despawn times.
+pub fn refresh_actives(store: &mut EntityStore, t: SimTime) {
+    store.actives.clear();
+
+    let active: Vec<_> = store
+        .spawned_ats
+        .iter()
+        .filter(|(id, spawned)| {
+            spawned.time <= t
+                && store.despawned_ats.get(id).is_none_or(|d| d.time > t)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+
+    for id in active {
+        store.add_active(id);
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(value: &Value) -> Option<T> {
+    serde_json::from_value(value.clone()).ok()
+}
+
+fn field<T: serde::de::DeserializeOwned>(payload: Option<&Value>, key: &str) -> Option<T> {
+    payload.and_then(|p| p.get(key)).and_then(parse)
+}
+
+/// ---------------------------------------------------------------------------
+/// Playback
+/// ---------------------------------------------------------------------------
+impl Timeline {
+    /// Fold every event into a fresh store.
+    pub fn playback(&self) -> EntityStore {
+        let mut store = EntityStore::new();
+        for event in self.iter() {
+            apply_event(&mut store, event);
+        }
+        if let Some(last) = self.last() {
+            refresh_actives(&mut store, last.t);
+        }
+        store
+    }
+
+    /// State as of `t`: every event at or before `t`, applied in order.
+    pub fn playback_until(&self, t: SimTime) -> EntityStore {
+        self.playback_onto(EntityStore::new(), None, t)
+    }
+
+    /// Continue a fold from `store`, which already reflects every event
+    /// up to and including `after`.
+    pub fn playback_onto(&self, mut store: EntityStore, after: Option<EventKey>, t: SimTime) -> EntityStore {
+        for event in self.iter_after(after).take_while(|e| e.t <= t) {
+            apply_event(&mut store, event);
+        }
+        refresh_actives(&mut store, t);
+        store
+    }
+}
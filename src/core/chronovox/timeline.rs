@@ -1,34 +1,86 @@
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::ops::Bound;
 
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::ser::SerializeStruct;
 //use uuid::Uuid;
 
-use crate::core::chronovox::event::{ChronoEvent};
+use crate::core::chronovox::error::{ChronovoxError, Result};
+use crate::core::chronovox::event::{ChronoEvent, EventKey};
 use crate::core::id::EntityId;
+use crate::core::tdt::sim_time::SimTime;
 
 /// ---------------------------------------------------------------------------
-/// Timeline — a simple ordered log of ChronoEvents
+/// Timeline — an ordered, de-duplicated log of ChronoEvents
 /// ---------------------------------------------------------------------------
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Events are keyed by `EventKey` (t, seq, entity), so insertion is
+/// O(log n) and iteration is always in that total order. Appending an
+/// event that is already present is a no-op; appending a *different*
+/// event under an existing key is a `ConflictingEvent` error.
+#[derive(Debug, Clone, Default)]
 pub struct Timeline {
-    pub events: Vec<ChronoEvent>,
+    events: BTreeMap<EventKey, ChronoEvent>,
 }
 
 impl Timeline {
     /// Create an empty timeline
     pub fn new() -> Self {
-        Self { events: Vec::new() }
+        Self::default()
     }
 
-    /// Push without sorting (for bulk inserts)
-    pub fn push(&mut self, event: ChronoEvent) {
-        self.events.push(event);
+    /// Build from events in any order, dropping exact duplicates.
+    pub fn from_events(events: impl IntoIterator<Item = ChronoEvent>) -> Result<Self> {
+        let mut timeline = Self::new();
+        for event in events {
+            timeline.append(event)?;
+        }
+        Ok(timeline)
     }
 
-    /// Insert and maintain chronological ordering
-    pub fn insert(&mut self, event: ChronoEvent) {
-        self.events.push(event);
-        self.events.sort(); // relies on Ord for ChronoEvent
+    /// Insert under the event's own key.
+    /// Returns `false` if that exact event was already recorded.
+    pub fn append(&mut self, event: ChronoEvent) -> Result<bool> {
+        match self.events.entry(event.key()) {
+            Entry::Vacant(slot) => {
+                slot.insert(event);
+                Ok(true)
+            }
+            Entry::Occupied(existing) if existing.get().is_same_event(&event) => Ok(false),
+            Entry::Occupied(existing) => Err(ChronovoxError::ConflictingEvent(*existing.key())),
+        }
+    }
+
+    /// Append after everything already recorded at `event.t`, overwriting
+    /// its `seq`. For producers that don't number their own events.
+    pub fn record(&mut self, mut event: ChronoEvent) -> EventKey {
+        event.seq = self
+            .at(event.t)
+            .next_back()
+            .map_or(0, |last| last.seq + 1);
+
+        let key = event.key();
+        self.events.insert(key, event);
+        key
+    }
+
+    /// Append every event from `other`; returns how many were new.
+    pub fn merge(&mut self, other: &Timeline) -> Result<usize> {
+        let mut added = 0;
+        for event in other.iter() {
+            added += self.append(event.clone())? as usize;
+        }
+        Ok(added)
+    }
+
+    pub fn get(&self, key: &EventKey) -> Option<&ChronoEvent> {
+        self.events.get(key)
+    }
+
+    pub fn remove(&mut self, key: &EventKey) -> Option<ChronoEvent> {
+        self.events.remove(key)
     }
 
     pub fn len(&self) -> usize {
@@ -39,36 +91,95 @@ impl Timeline {
         self.events.is_empty()
     }
 
+    pub fn first(&self) -> Option<&ChronoEvent> {
+        self.events.values().next()
+    }
+
+    pub fn last(&self) -> Option<&ChronoEvent> {
+        self.events.values().next_back()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &ChronoEvent> {
+        self.events.values()
+    }
+
     pub fn iter_chronological(&self) -> impl Iterator<Item = &ChronoEvent> {
-        self.events.iter()
+        self.iter()
+    }
+
+    /// Events strictly after `key` (all events for `None`).
+    pub fn iter_after(&self, key: Option<EventKey>) -> impl Iterator<Item = &ChronoEvent> {
+        let start = key.map_or(Bound::Unbounded, Bound::Excluded);
+        self.events.range((start, Bound::Unbounded)).map(|(_, e)| e)
+    }
+
+    /// Events at exactly `t`, in sequence order.
+    pub fn at(&self, t: SimTime) -> impl DoubleEndedIterator<Item = &ChronoEvent> {
+        self.events
+            .range(EventKey::first_at(t)..EventKey::first_at(t.add_ns(1)))
+            .map(|(_, e)| e)
+    }
+
+    /// First event strictly after the instant `t`.
+    pub fn next_after(&self, t: SimTime) -> Option<&ChronoEvent> {
+        self.events
+            .range(EventKey::first_at(t.add_ns(1))..)
+            .map(|(_, e)| e)
+            .next()
     }
 
     /// Query events inside a time range (ns)
     pub fn query_time_range(&self, start_ns: i128, end_ns: i128) -> Vec<&ChronoEvent> {
+        if start_ns > end_ns {
+            return Vec::new();
+        }
         self.events
-            .iter()
-            .filter(|e| {
-                let t = e.t.as_ns();
-                t >= start_ns && t <= end_ns
-            })
+            .range(EventKey::first_at(SimTime(start_ns))..EventKey::first_at(SimTime(end_ns).add_ns(1)))
+            .map(|(_, e)| e)
             .collect()
     }
 
     /// Query events for a specific entity
     pub fn query_entity(&self, entity_id: EntityId) -> Vec<&ChronoEvent> {
-        self.events
-            .iter()
+        self.iter()
             .filter(|e| e.entity_id == entity_id)
             .collect()
     }
 }
 
 /// ---------------------------------------------------------------------------
-/// Sorting ChronoEvents chronologically
+/// Serde — on disk a timeline is `{ "events": [...] }` in key order
+/// ---------------------------------------------------------------------------
+impl Serialize for Timeline {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let events: Vec<&ChronoEvent> = self.iter().collect();
+        let mut state = serializer.serialize_struct("Timeline", 1)?;
+        state.serialize_field("events", &events)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Timeline {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct TimelineFile {
+            events: Vec<ChronoEvent>,
+        }
+
+        let file = TimelineFile::deserialize(deserializer)?;
+        Timeline::from_events(file.events).map_err(serde::de::Error::custom)
+    }
+}
+
+/// ---------------------------------------------------------------------------
+/// Ordering ChronoEvents: by `EventKey` (t, seq, entity)
 /// ---------------------------------------------------------------------------
+///
+/// Equality is identity: two events are equal when their keys match.
+/// Use `ChronoEvent::is_same_event` to compare content as well.
 impl PartialEq for ChronoEvent {
     fn eq(&self, other: &Self) -> bool {
-        self.t.as_ns() == other.t.as_ns()
+        self.key() == other.key()
     }
 }
 
@@ -76,29 +187,109 @@ impl Eq for ChronoEvent {}
 
 impl PartialOrd for ChronoEvent {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.t.as_ns().cmp(&other.t.as_ns()))
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for ChronoEvent {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.t.as_ns().cmp(&other.t.as_ns())
+        self.key().cmp(&other.key())
     }
 }
 
 /// Iterators
 impl IntoIterator for Timeline {
     type Item = ChronoEvent;
-    type IntoIter = std::vec::IntoIter<ChronoEvent>;
+    type IntoIter = std::collections::btree_map::IntoValues<EventKey, ChronoEvent>;
     fn into_iter(self) -> Self::IntoIter {
-        self.events.into_iter()
+        self.events.into_values()
     }
 }
 
 impl<'a> IntoIterator for &'a Timeline {
     type Item = &'a ChronoEvent;
-    type IntoIter = std::slice::Iter<'a, ChronoEvent>;
+    type IntoIter = std::collections::btree_map::Values<'a, EventKey, ChronoEvent>;
     fn into_iter(self) -> Self::IntoIter {
-        self.events.iter()
+        self.events.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::chronovox::EventKind;
+    use crate::core::id::WorldId;
+
+    fn event(entity: EntityId, ns: i128, kind: EventKind) -> ChronoEvent {
+        ChronoEvent::new(entity, WorldId(1), SimTime::from_ns(ns), kind)
+    }
+
+    #[test]
+    fn same_instant_orders_by_seq_then_entity() {
+        let (a, b) = {
+            let mut ids = [EntityId::new(), EntityId::new()];
+            ids.sort();
+            (ids[0], ids[1])
+        };
+
+        let mut timeline = Timeline::new();
+        timeline.append(event(b, 5, EventKind::Spawn).with_seq(1)).unwrap();
+        timeline.append(event(b, 5, EventKind::Spawn)).unwrap();
+        timeline.append(event(a, 5, EventKind::Spawn)).unwrap();
+        timeline.append(event(a, 1, EventKind::Despawn).with_seq(9)).unwrap();
+
+        let order: Vec<_> = timeline.iter().map(|e| (e.t.as_ns(), e.seq, e.entity_id)).collect();
+        assert_eq!(order, vec![(1, 9, a), (5, 0, a), (5, 0, b), (5, 1, b)]);
+    }
+
+    #[test]
+    fn append_is_idempotent_and_rejects_conflicts() {
+        let pipe = EntityId::new();
+        let mut timeline = Timeline::new();
+
+        assert!(timeline.append(event(pipe, 10, EventKind::Degrade { rate: 0.1 })).unwrap());
+        assert!(!timeline.append(event(pipe, 10, EventKind::Degrade { rate: 0.1 })).unwrap());
+        assert!(matches!(
+            timeline.append(event(pipe, 10, EventKind::Degrade { rate: 0.2 })),
+            Err(ChronovoxError::ConflictingEvent(_))
+        ));
+        assert_eq!(timeline.len(), 1);
+    }
+
+    #[test]
+    fn record_numbers_events_within_an_instant() {
+        let pipe = EntityId::new();
+        let mut timeline = Timeline::new();
+
+        let first = timeline.record(event(pipe, 10, EventKind::Spawn));
+        let second = timeline.record(event(pipe, 10, EventKind::Degrade { rate: 0.1 }));
+        let other = timeline.record(event(pipe, 11, EventKind::Despawn));
+
+        assert_eq!((first.seq, second.seq, other.seq), (0, 1, 0));
+        assert_eq!(timeline.at(SimTime::from_ns(10)).count(), 2);
+        assert_eq!(timeline.query_time_range(10, 11).len(), 3);
+    }
+
+    #[test]
+    fn merge_and_round_trip_are_reproducible() {
+        let pipe = EntityId::new();
+        let mut a = Timeline::new();
+        a.record(event(pipe, 1, EventKind::Spawn));
+        a.record(event(pipe, 2, EventKind::Degrade { rate: 0.5 }));
+
+        let mut b = Timeline::new();
+        b.record(event(pipe, 2, EventKind::Degrade { rate: 0.5 }));
+        b.record(event(pipe, 3, EventKind::Despawn));
+
+        let mut ab = a.clone();
+        assert_eq!(ab.merge(&b).unwrap(), 1);
+        let mut ba = b.clone();
+        assert_eq!(ba.merge(&a).unwrap(), 1);
+
+        let json = serde_json::to_string(&ab).unwrap();
+        assert_eq!(json, serde_json::to_string(&ba).unwrap());
+
+        let back: Timeline = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
     }
 }
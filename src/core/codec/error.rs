@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CodecError {
+    #[error("input ended early")]
+    UnexpectedEof,
+
+    #[error("unsupported codec version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("unknown layout byte {0:#04x}")]
+    UnknownLayout(u8),
+
+    #[error("expected type tag {expected}, found {found}")]
+    TagMismatch { expected: u8, found: u8 },
+
+    #[error("expected a {expected} frame")]
+    FrameMismatch { expected: &'static str },
+
+    #[error("unknown {what} tag {tag}")]
+    UnknownTag { what: &'static str, tag: u8 },
+
+    #[error("varint is too long")]
+    VarintOverflow,
+
+    #[error("{0} unread bytes after the value")]
+    TrailingBytes(usize),
+
+    #[error("invalid UTF-8 string")]
+    InvalidUtf8,
+
+    #[error("invalid payload: {0}")]
+    Payload(String),
+
+    #[error("invalid text encoding: {0}")]
+    Text(String),
+}
+
+pub type Result<T> = std::result::Result<T, CodecError>;
@@ -0,0 +1,619 @@
+use serde_json::Value;
+
+use crate::core::chronovox::{ChronoEvent, EventKind};
+use crate::core::codec::error::{CodecError, Result};
+use crate::core::codec::wire::{Layout, Reader, Writer};
+use crate::core::codec::{tag, BinaryCodec};
+use crate::core::id::{EntityId, WorldId};
+use crate::core::spatial::UvoxRegionId;
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::uvoxid::{DLat, DLon, DRUm, Delta, LatCode, LonCode, RUm, UvoxId};
+
+// Sequence differences wrap, so any pair of values round-trips.
+
+// ------------------------------------------------------------
+// SimTime
+// ------------------------------------------------------------
+
+impl BinaryCodec for SimTime {
+    const TAG: u8 = tag::SIM_TIME;
+
+    fn encode_body(&self, w: &mut Writer) {
+        w.i128(self.0);
+    }
+
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self> {
+        Ok(SimTime(r.i128()?))
+    }
+
+    fn encode_after(&self, prev: &Self, w: &mut Writer) {
+        w.i128(self.0.wrapping_sub(prev.0));
+    }
+
+    fn decode_after(prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        Ok(SimTime(prev.0.wrapping_add(r.i128()?)))
+    }
+}
+
+// ------------------------------------------------------------
+// UvoxId — raw codes, no clamping or wrapping on the way back
+// ------------------------------------------------------------
+
+fn uvox_parts(id: &UvoxId) -> [i64; 3] {
+    [id.r_um.0, id.lat_code.0, id.lon_code.0]
+}
+
+fn uvox_from([r, lat, lon]: [i64; 3]) -> UvoxId {
+    UvoxId { r_um: RUm(r), lat_code: LatCode(lat), lon_code: LonCode(lon) }
+}
+
+fn write_triple(w: &mut Writer, v: [i64; 3], base: [i64; 3]) {
+    for i in 0..3 {
+        w.i64(v[i].wrapping_sub(base[i]));
+    }
+}
+
+fn read_triple(r: &mut Reader<'_>, base: [i64; 3]) -> Result<[i64; 3]> {
+    Ok([
+        base[0].wrapping_add(r.i64()?),
+        base[1].wrapping_add(r.i64()?),
+        base[2].wrapping_add(r.i64()?),
+    ])
+}
+
+impl BinaryCodec for UvoxId {
+    const TAG: u8 = tag::UVOX_ID;
+
+    fn encode_body(&self, w: &mut Writer) {
+        write_triple(w, uvox_parts(self), [0; 3]);
+    }
+
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self> {
+        Ok(uvox_from(read_triple(r, [0; 3])?))
+    }
+
+    fn encode_after(&self, prev: &Self, w: &mut Writer) {
+        write_triple(w, uvox_parts(self), uvox_parts(prev));
+    }
+
+    fn decode_after(prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        Ok(uvox_from(read_triple(r, uvox_parts(prev))?))
+    }
+}
+
+// ------------------------------------------------------------
+// Delta
+// ------------------------------------------------------------
+
+fn delta_parts(d: &Delta) -> [i64; 3] {
+    [d.dr.0, d.dlat.0, d.dlon.0]
+}
+
+fn delta_from([dr, dlat, dlon]: [i64; 3]) -> Delta {
+    Delta::typed(DRUm(dr), DLat(dlat), DLon(dlon))
+}
+
+impl BinaryCodec for Delta {
+    const TAG: u8 = tag::DELTA;
+
+    fn encode_body(&self, w: &mut Writer) {
+        write_triple(w, delta_parts(self), [0; 3]);
+    }
+
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self> {
+        Ok(delta_from(read_triple(r, [0; 3])?))
+    }
+
+    fn encode_after(&self, prev: &Self, w: &mut Writer) {
+        write_triple(w, delta_parts(self), delta_parts(prev));
+    }
+
+    fn decode_after(prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        Ok(delta_from(read_triple(r, delta_parts(prev))?))
+    }
+}
+
+// ------------------------------------------------------------
+// UvoxRegionId — compact layout stores `max` relative to `min`
+// ------------------------------------------------------------
+
+fn write_region(w: &mut Writer, region: &UvoxRegionId, base: [i64; 3]) {
+    let min = uvox_parts(&region.min);
+    write_triple(w, min, base);
+    match w.layout() {
+        Layout::Fixed => write_triple(w, uvox_parts(&region.max), [0; 3]),
+        Layout::Compact => write_triple(w, uvox_parts(&region.max), min),
+    }
+}
+
+fn read_region(r: &mut Reader<'_>, base: [i64; 3]) -> Result<UvoxRegionId> {
+    let min = read_triple(r, base)?;
+    let max = match r.layout() {
+        Layout::Fixed => read_triple(r, [0; 3])?,
+        Layout::Compact => read_triple(r, min)?,
+    };
+    Ok(UvoxRegionId { min: uvox_from(min), max: uvox_from(max) })
+}
+
+impl BinaryCodec for UvoxRegionId {
+    const TAG: u8 = tag::UVOX_REGION_ID;
+
+    fn encode_body(&self, w: &mut Writer) {
+        write_region(w, self, [0; 3]);
+    }
+
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self> {
+        read_region(r, [0; 3])
+    }
+
+    fn encode_after(&self, prev: &Self, w: &mut Writer) {
+        write_region(w, self, uvox_parts(&prev.min));
+    }
+
+    fn decode_after(prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        read_region(r, uvox_parts(&prev.min))
+    }
+}
+
+// ------------------------------------------------------------
+// ChronoEvent
+// ------------------------------------------------------------
+//
+// Body: entity, world, t, seq, kind tag + fields, payload (a binary
+// JSON value tree; version 1 frames carry JSON text instead).
+// In a compact sequence the entity is a one-byte "same as before" flag
+// when it repeats, and `t` is a difference.
+
+const NEW_ENTITY: u8 = 0;
+const SAME_ENTITY: u8 = 1;
+
+fn write_kind(w: &mut Writer, kind: &EventKind) {
+    match kind {
+        EventKind::Spawn => w.u8(0),
+        EventKind::Despawn => w.u8(1),
+        EventKind::Move { dr, dlat, dlon } => {
+            w.u8(2);
+            write_triple(w, [*dr, *dlat, *dlon], [0; 3]);
+        }
+        EventKind::Accelerate { ar, alat, alon } => {
+            w.u8(3);
+            w.f64(*ar);
+            w.f64(*alat);
+            w.f64(*alon);
+        }
+        EventKind::Teleport { r_um, lat_code, lon_code } => {
+            w.u8(4);
+            write_triple(w, [*r_um, *lat_code, *lon_code], [0; 3]);
+        }
+        EventKind::TemperatureChange { delta_c } => {
+            w.u8(5);
+            w.f64(*delta_c);
+        }
+        EventKind::PressureChange { delta_pa } => {
+            w.u8(6);
+            w.f64(*delta_pa);
+        }
+        EventKind::Radiation { dose } => {
+            w.u8(7);
+            w.f64(*dose);
+        }
+        EventKind::Shock { g } => {
+            w.u8(8);
+            w.f64(*g);
+        }
+        EventKind::Degrade { rate } => {
+            w.u8(9);
+            w.f64(*rate);
+        }
+        EventKind::Leak { severity } => {
+            w.u8(10);
+            w.f64(*severity);
+        }
+        EventKind::Fracture { plane } => {
+            w.u8(11);
+            w.str(plane);
+        }
+        EventKind::Bond { with } => {
+            w.u8(12);
+            w.uuid(&with.0);
+        }
+        EventKind::Unbond { from } => {
+            w.u8(13);
+            w.uuid(&from.0);
+        }
+        EventKind::Transfer { to, what, amount } => {
+            w.u8(14);
+            w.uuid(&to.0);
+            w.str(what);
+            w.f64(*amount);
+        }
+        EventKind::Custom(label) => {
+            w.u8(15);
+            w.str(label);
+        }
+    }
+}
+
+fn read_kind(r: &mut Reader<'_>) -> Result<EventKind> {
+    Ok(match r.u8()? {
+        0 => EventKind::Spawn,
+        1 => EventKind::Despawn,
+        2 => {
+            let [dr, dlat, dlon] = read_triple(r, [0; 3])?;
+            EventKind::Move { dr, dlat, dlon }
+        }
+        3 => EventKind::Accelerate { ar: r.f64()?, alat: r.f64()?, alon: r.f64()? },
+        4 => {
+            let [r_um, lat_code, lon_code] = read_triple(r, [0; 3])?;
+            EventKind::Teleport { r_um, lat_code, lon_code }
+        }
+        5 => EventKind::TemperatureChange { delta_c: r.f64()? },
+        6 => EventKind::PressureChange { delta_pa: r.f64()? },
+        7 => EventKind::Radiation { dose: r.f64()? },
+        8 => EventKind::Shock { g: r.f64()? },
+        9 => EventKind::Degrade { rate: r.f64()? },
+        10 => EventKind::Leak { severity: r.f64()? },
+        11 => EventKind::Fracture { plane: r.str()? },
+        12 => EventKind::Bond { with: EntityId(r.uuid()?) },
+        13 => EventKind::Unbond { from: EntityId(r.uuid()?) },
+        14 => EventKind::Transfer { to: EntityId(r.uuid()?), what: r.str()?, amount: r.f64()? },
+        15 => EventKind::Custom(r.str()?),
+        tag => return Err(CodecError::UnknownTag { what: "event kind", tag }),
+    })
+}
+
+// Payload value tags
+const NO_PAYLOAD: u8 = 0;
+const JSON_TEXT: u8 = 1;
+const VALUE_TREE: u8 = 2;
+
+const NULL: u8 = 0;
+const FALSE: u8 = 1;
+const TRUE: u8 = 2;
+const INT: u8 = 3;
+const UINT: u8 = 4;
+const FLOAT: u8 = 5;
+const STRING: u8 = 6;
+const ARRAY: u8 = 7;
+const OBJECT: u8 = 8;
+
+/// Deepest payload nesting `read_value` follows.
+const MAX_PAYLOAD_DEPTH: usize = 128;
+
+fn write_payload(w: &mut Writer, payload: &Option<Value>) {
+    match payload {
+        None => w.u8(NO_PAYLOAD),
+        Some(value) => {
+            w.u8(VALUE_TREE);
+            write_value(w, value);
+        }
+    }
+}
+
+/// Numbers keep their JSON kind; floats are raw bits.
+fn write_value(w: &mut Writer, value: &Value) {
+    match value {
+        Value::Null => w.u8(NULL),
+        Value::Bool(false) => w.u8(FALSE),
+        Value::Bool(true) => w.u8(TRUE),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                w.u8(INT);
+                w.i64(i);
+            } else if let Some(u) = n.as_u64() {
+                w.u8(UINT);
+                w.u64(u);
+            } else {
+                w.u8(FLOAT);
+                w.f64(n.as_f64().expect("a JSON number is i64, u64 or f64"));
+            }
+        }
+        Value::String(text) => {
+            w.u8(STRING);
+            w.str(text);
+        }
+        Value::Array(items) => {
+            w.u8(ARRAY);
+            w.varint(items.len() as u128);
+            for item in items {
+                write_value(w, item);
+            }
+        }
+        Value::Object(fields) => {
+            w.u8(OBJECT);
+            w.varint(fields.len() as u128);
+            for (key, item) in fields {
+                w.str(key);
+                write_value(w, item);
+            }
+        }
+    }
+}
+
+fn read_payload(r: &mut Reader<'_>) -> Result<Option<Value>> {
+    match r.u8()? {
+        NO_PAYLOAD => Ok(None),
+        JSON_TEXT => serde_json::from_str(&r.str()?)
+            .map(Some)
+            .map_err(|e| CodecError::Payload(e.to_string())),
+        VALUE_TREE => read_value(r, 0).map(Some),
+        tag => Err(CodecError::UnknownTag { what: "payload", tag }),
+    }
+}
+
+fn read_value(r: &mut Reader<'_>, depth: usize) -> Result<Value> {
+    if depth > MAX_PAYLOAD_DEPTH {
+        return Err(CodecError::Payload(format!("nested more than {MAX_PAYLOAD_DEPTH} deep")));
+    }
+    // Every element takes at least a byte, so longer counts are truncated
+    let count = |r: &mut Reader<'_>| {
+        usize::try_from(r.varint()?).ok().filter(|&n| n <= r.remaining()).ok_or(CodecError::UnexpectedEof)
+    };
+    Ok(match r.u8()? {
+        NULL => Value::Null,
+        FALSE => Value::Bool(false),
+        TRUE => Value::Bool(true),
+        INT => r.i64()?.into(),
+        UINT => r.u64()?.into(),
+        FLOAT => {
+            let v = r.f64()?;
+            serde_json::Number::from_f64(v)
+                .map(Value::Number)
+                .ok_or_else(|| CodecError::Payload(format!("{v} is not a JSON number")))?
+        }
+        STRING => Value::String(r.str()?),
+        ARRAY => {
+            let n = count(r)?;
+            (0..n).map(|_| read_value(r, depth + 1)).collect::<Result<Vec<_>>>()?.into()
+        }
+        OBJECT => {
+            let n = count(r)?;
+            let mut fields = serde_json::Map::new();
+            for _ in 0..n {
+                let key = r.str()?;
+                fields.insert(key, read_value(r, depth + 1)?);
+            }
+            Value::Object(fields)
+        }
+        tag => return Err(CodecError::UnknownTag { what: "payload value", tag }),
+    })
+}
+
+fn write_event_rest(w: &mut Writer, event: &ChronoEvent) {
+    w.u64(event.seq);
+    write_kind(w, &event.kind);
+    write_payload(w, &event.payload);
+}
+
+fn read_event_rest(
+    r: &mut Reader<'_>,
+    entity_id: EntityId,
+    world_id: WorldId,
+    t: SimTime,
+) -> Result<ChronoEvent> {
+    Ok(ChronoEvent {
+        entity_id,
+        world_id,
+        t,
+        seq: r.u64()?,
+        kind: read_kind(r)?,
+        payload: read_payload(r)?,
+    })
+}
+
+impl BinaryCodec for ChronoEvent {
+    const TAG: u8 = tag::CHRONO_EVENT;
+
+    fn encode_body(&self, w: &mut Writer) {
+        w.uuid(&self.entity_id.0);
+        w.i64(self.world_id.0);
+        self.t.encode_body(w);
+        write_event_rest(w, self);
+    }
+
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self> {
+        let entity_id = EntityId(r.uuid()?);
+        let world_id = WorldId(r.i64()?);
+        let t = SimTime::decode_body(r)?;
+        read_event_rest(r, entity_id, world_id, t)
+    }
+
+    fn encode_after(&self, prev: &Self, w: &mut Writer) {
+        if self.entity_id == prev.entity_id {
+            w.u8(SAME_ENTITY);
+        } else {
+            w.u8(NEW_ENTITY);
+            w.uuid(&self.entity_id.0);
+        }
+        w.i64(self.world_id.0.wrapping_sub(prev.world_id.0));
+        self.t.encode_after(&prev.t, w);
+        write_event_rest(w, self);
+    }
+
+    fn decode_after(prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        let entity_id = match r.u8()? {
+            SAME_ENTITY => prev.entity_id,
+            NEW_ENTITY => EntityId(r.uuid()?),
+            tag => return Err(CodecError::UnknownTag { what: "entity flag", tag }),
+        };
+        let world_id = WorldId(prev.world_id.0.wrapping_add(r.i64()?));
+        let t = SimTime::decode_after(&prev.t, r)?;
+        read_event_rest(r, entity_id, world_id, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::core::codec::*;
+    use crate::core::uvoxid::EARTH_RADIUS_UM;
+    use super::*;
+
+    fn walk(n: usize) -> Vec<UvoxId> {
+        let mut pos = UvoxId::new(RUm(EARTH_RADIUS_UM), LatCode::from_degrees(27.7), LonCode::from_degrees(179.99));
+        (0..n)
+            .map(|i| {
+                pos += Delta::new((i % 7) as i64 * 1_000, 35_000 + (i % 3) as i64, -12_000);
+                pos
+            })
+            .collect()
+    }
+
+    fn events() -> Vec<ChronoEvent> {
+        let (pipe, tank) = (EntityId::new(), EntityId::new());
+        let t0 = SimTime::from_ns(1_700_000_000_000_000_000);
+        vec![
+            ChronoEvent::new(pipe, WorldId(3), t0, EventKind::Spawn)
+                .with_payload(json!({"name": "pipe", "position": {"r_um": 1, "lat_code": -2, "lon_code": 3}})),
+            ChronoEvent::new(pipe, WorldId(3), t0.add_days(30), EventKind::Degrade { rate: 0.012_345_678_9 }),
+            ChronoEvent::new(pipe, WorldId(3), t0.add_days(30), EventKind::Move { dr: -5, dlat: i64::MIN, dlon: i64::MAX })
+                .with_seq(1),
+            ChronoEvent::new(tank, WorldId(3), t0.add_days(31), EventKind::Transfer {
+                to: pipe,
+                what: "water ∆".into(),
+                amount: -0.0,
+            }),
+            ChronoEvent::new(tank, WorldId(-1), SimTime(i128::MIN), EventKind::Custom("Replace".into())),
+        ]
+    }
+
+    #[test]
+    fn single_values_round_trip_in_both_layouts() {
+        let region = UvoxRegionId::new(walk(1)[0], walk(50)[49]);
+        for layout in [Layout::Fixed, Layout::Compact] {
+            for id in walk(20) {
+                assert_eq!(UvoxId::from_bytes(&id.to_bytes(layout)), Ok(id));
+            }
+            let delta = Delta::new(-1, i64::MIN, i64::MAX);
+            assert_eq!(Delta::from_bytes(&delta.to_bytes(layout)), Ok(delta));
+            assert_eq!(UvoxRegionId::from_base64(&region.to_base64(layout)), Ok(region));
+            assert_eq!(SimTime::from_base32(&SimTime(i128::MAX).to_base32(layout)), Ok(SimTime(i128::MAX)));
+
+            for event in events() {
+                let back = ChronoEvent::from_bytes(&event.to_bytes(layout)).unwrap();
+                assert!(back.is_same_event(&event), "{layout:?}: {event:?}");
+            }
+        }
+
+        assert_eq!(walk(1)[0].to_bytes(Layout::Fixed).len(), 3 + 24);
+    }
+
+    #[test]
+    fn compact_sequences_are_small_and_exact() {
+        let path = walk(10_000);
+        let fixed = encode_seq(&path, Layout::Fixed);
+        let compact = encode_seq(&path, Layout::Compact);
+        assert_eq!(decode_seq::<UvoxId>(&fixed).unwrap(), path);
+        assert_eq!(decode_seq::<UvoxId>(&compact).unwrap(), path);
+        assert!(compact.len() * 3 < fixed.len(), "{} vs {}", compact.len(), fixed.len());
+
+        let log = events();
+        for layout in [Layout::Fixed, Layout::Compact] {
+            let back: Vec<ChronoEvent> = decode_seq(&encode_seq(&log, layout)).unwrap();
+            assert!(back.iter().zip(&log).all(|(a, b)| a.is_same_event(b)));
+        }
+
+        let text = to_base32(&compact).to_ascii_lowercase();
+        assert_eq!(decode_seq::<UvoxId>(&from_base32(&text).unwrap()).unwrap(), path);
+    }
+
+    #[test]
+    fn payload_and_kind_floats_round_trip_exactly() {
+        let pipe = EntityId::new();
+        let t0 = SimTime::from_ns(0);
+        let log = vec![
+            ChronoEvent::new(pipe, WorldId(1), t0, EventKind::Degrade { rate: 97.82034458884425 })
+                .with_payload(json!({
+                    "temperature_c": 97.82034458884425,
+                    "readings": [0.1 + 0.2, 1.0 / 3.0, 5e-324, f64::MAX, -2.2250738585072014e-308],
+                    "count": u64::MAX,
+                    "offset": i64::MIN,
+                    "nested": {"ok": true, "none": null, "label": "Δt"}
+                })),
+            ChronoEvent::new(pipe, WorldId(1), t0.add_days(1), EventKind::Accelerate {
+                ar: std::f64::consts::PI,
+                alat: -1e-310,
+                alon: f64::NAN,
+            }),
+            ChronoEvent::new(pipe, WorldId(1), t0.add_days(2), EventKind::TemperatureChange { delta_c: 0.1 + 0.7 }),
+        ];
+
+        for layout in [Layout::Fixed, Layout::Compact] {
+            for event in &log {
+                let back = ChronoEvent::from_bytes(&event.to_bytes(layout)).unwrap();
+                assert_eq!(back.payload, event.payload);
+                if let (EventKind::Accelerate { alon, .. }, EventKind::Accelerate { alon: back, .. }) = (&event.kind, &back.kind) {
+                    assert_eq!(alon.to_bits(), back.to_bits());
+                } else {
+                    assert!(back.is_same_event(event), "{layout:?}: {event:?}");
+                }
+            }
+            let back: Vec<ChronoEvent> = decode_seq(&encode_seq(&log, layout)).unwrap();
+            assert!(back.iter().zip(&log).all(|(a, b)| a.payload == b.payload));
+        }
+    }
+
+    #[test]
+    fn version_one_json_payloads_still_decode() {
+        let event = ChronoEvent::new(EntityId::new(), WorldId(1), SimTime::from_ns(0), EventKind::Spawn);
+        let payload = json!({"temperature_c": 97.82034458884425});
+
+        // Version 1 body: the same, with the payload as JSON text
+        let mut bytes = event.to_bytes(Layout::Fixed);
+        bytes[0] = 1;
+        assert_eq!(bytes.pop(), Some(NO_PAYLOAD));
+        let mut w = Writer::new(Layout::Fixed);
+        w.u8(JSON_TEXT);
+        w.str(&payload.to_string());
+        bytes.extend(w.into_bytes());
+
+        let back = ChronoEvent::from_bytes(&bytes).unwrap();
+        assert!(back.is_same_event(&event.with_payload(payload)));
+    }
+
+    #[test]
+    fn malformed_payloads_are_rejected() {
+        let mut deep = json!(1);
+        for _ in 0..=MAX_PAYLOAD_DEPTH {
+            deep = json!([deep]);
+        }
+        let event = ChronoEvent::new(EntityId::new(), WorldId(1), SimTime::from_ns(0), EventKind::Spawn);
+        let bytes = event.clone().with_payload(deep).to_bytes(Layout::Compact);
+        assert!(matches!(ChronoEvent::from_bytes(&bytes), Err(CodecError::Payload(_))));
+
+        // An array claiming more items than there are bytes
+        let mut huge = event.to_bytes(Layout::Compact);
+        huge.pop();
+        huge.extend([VALUE_TREE, ARRAY, 0xff, 0xff, 0x7f]);
+        assert_eq!(ChronoEvent::from_bytes(&huge), Err(CodecError::UnexpectedEof));
+
+        let mut unknown = event.to_bytes(Layout::Compact);
+        unknown.pop();
+        unknown.extend([VALUE_TREE, 42]);
+        assert_eq!(ChronoEvent::from_bytes(&unknown), Err(CodecError::UnknownTag { what: "payload value", tag: 42 }));
+    }
+
+    #[test]
+    fn malformed_frames_are_rejected() {
+        let bytes = walk(1)[0].to_bytes(Layout::Compact);
+
+        assert_eq!(UvoxId::from_bytes(&bytes[..bytes.len() - 1]), Err(CodecError::UnexpectedEof));
+        assert_eq!(SimTime::from_bytes(&bytes), Err(CodecError::TagMismatch { expected: tag::SIM_TIME, found: tag::UVOX_ID }));
+        assert_eq!(decode_seq::<UvoxId>(&bytes), Err(CodecError::FrameMismatch { expected: "sequence" }));
+
+        let mut future = bytes.clone();
+        future[0] = CODEC_VERSION + 1;
+        assert_eq!(UvoxId::from_bytes(&future), Err(CodecError::UnsupportedVersion(CODEC_VERSION + 1)));
+
+        let mut padded = bytes.clone();
+        padded.push(0);
+        assert_eq!(UvoxId::from_bytes(&padded), Err(CodecError::TrailingBytes(1)));
+
+        let mut bad_layout = bytes;
+        bad_layout[2] = 7;
+        assert_eq!(UvoxId::from_bytes(&bad_layout), Err(CodecError::UnknownLayout(7)));
+
+        assert!(matches!(UvoxId::from_base64("not base64!"), Err(CodecError::Text(_))));
+    }
+}
@@ -0,0 +1,189 @@
+//! ---------------------------------------------------------------------------
+//! Codec — versioned binary and text encodings for core value types
+//! ---------------------------------------------------------------------------
+//!
+//! Every frame starts with three bytes: codec version, type tag and a
+//! flags byte (layout in the low bit, `SEQUENCE` for a run of records).
+//! `Layout::Fixed` keeps every integer full width; `Layout::Compact`
+//! uses zigzag varints and, inside sequences, stores each record as a
+//! difference from the previous one, which is what makes long position
+//! and event logs small. Both decode to exactly the values encoded.
+//!
+//! Frames can be carried as text with URL-safe base64 or base32 (no
+//! padding).
+
+pub mod error;
+pub mod wire;
+mod impls;
+
+pub use error::{CodecError, Result};
+pub use wire::{Layout, Reader, Writer};
+
+use data_encoding::{BASE32_NOPAD, BASE64URL_NOPAD};
+
+/// Bumped whenever an existing type's body changes shape.
+///
+/// Version 2 stores `ChronoEvent` payloads as a binary value tree
+/// instead of JSON text; version 1 frames still decode.
+pub const CODEC_VERSION: u8 = 2;
+
+/// Oldest version `decode` accepts.
+const MIN_CODEC_VERSION: u8 = 1;
+
+const SEQUENCE: u8 = 0x80;
+
+/// Type tags
+pub mod tag {
+    pub const UVOX_ID: u8 = 1;
+    pub const DELTA: u8 = 2;
+    pub const UVOX_REGION_ID: u8 = 3;
+    pub const SIM_TIME: u8 = 4;
+    pub const CHRONO_EVENT: u8 = 5;
+}
+
+/// A value with a binary body. `encode_after` / `decode_after` let a
+/// type write itself relative to the previous record of a compact
+/// sequence; by default records are written whole.
+pub trait BinaryCodec: Sized {
+    const TAG: u8;
+
+    fn encode_body(&self, w: &mut Writer);
+    fn decode_body(r: &mut Reader<'_>) -> Result<Self>;
+
+    fn encode_after(&self, _prev: &Self, w: &mut Writer) {
+        self.encode_body(w)
+    }
+
+    fn decode_after(_prev: &Self, r: &mut Reader<'_>) -> Result<Self> {
+        Self::decode_body(r)
+    }
+
+    fn to_bytes(&self, layout: Layout) -> Vec<u8> {
+        encode(self, layout)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        decode(bytes)
+    }
+
+    fn to_base64(&self, layout: Layout) -> String {
+        to_base64(&encode(self, layout))
+    }
+
+    fn from_base64(text: &str) -> Result<Self> {
+        decode(&from_base64(text)?)
+    }
+
+    fn to_base32(&self, layout: Layout) -> String {
+        to_base32(&encode(self, layout))
+    }
+
+    fn from_base32(text: &str) -> Result<Self> {
+        decode(&from_base32(text)?)
+    }
+}
+
+// ------------------------------------------------------------
+// Frames
+// ------------------------------------------------------------
+
+fn header(tag: u8, layout: Layout, flags: u8) -> Writer {
+    let mut w = Writer::new(layout);
+    w.u8(CODEC_VERSION);
+    w.u8(tag);
+    w.u8(layout as u8 | flags);
+    w
+}
+
+fn open<T: BinaryCodec>(bytes: &[u8], sequence: bool) -> Result<Reader<'_>> {
+    let [version, tag, flags] = match bytes {
+        [a, b, c, ..] => [*a, *b, *c],
+        _ => return Err(CodecError::UnexpectedEof),
+    };
+    if !(MIN_CODEC_VERSION..=CODEC_VERSION).contains(&version) {
+        return Err(CodecError::UnsupportedVersion(version));
+    }
+    if tag != T::TAG {
+        return Err(CodecError::TagMismatch { expected: T::TAG, found: tag });
+    }
+    if (flags & SEQUENCE != 0) != sequence {
+        let expected = if sequence { "sequence" } else { "single value" };
+        return Err(CodecError::FrameMismatch { expected });
+    }
+
+    Ok(Reader::new(&bytes[3..], Layout::from_byte(flags & !SEQUENCE)?))
+}
+
+/// One value in its own frame.
+pub fn encode<T: BinaryCodec>(value: &T, layout: Layout) -> Vec<u8> {
+    let mut w = header(T::TAG, layout, 0);
+    value.encode_body(&mut w);
+    w.into_bytes()
+}
+
+pub fn decode<T: BinaryCodec>(bytes: &[u8]) -> Result<T> {
+    let mut r = open::<T>(bytes, false)?;
+    let value = T::decode_body(&mut r)?;
+    r.finish()?;
+    Ok(value)
+}
+
+/// A run of values in one frame: a count, then the records. In the
+/// compact layout each record after the first is written relative to
+/// the one before.
+pub fn encode_seq<T: BinaryCodec>(values: &[T], layout: Layout) -> Vec<u8> {
+    let mut w = header(T::TAG, layout, SEQUENCE);
+    w.varint(values.len() as u128);
+
+    let mut prev: Option<&T> = None;
+    for value in values {
+        match (layout, prev) {
+            (Layout::Compact, Some(prev)) => value.encode_after(prev, &mut w),
+            _ => value.encode_body(&mut w),
+        }
+        prev = Some(value);
+    }
+    w.into_bytes()
+}
+
+pub fn decode_seq<T: BinaryCodec>(bytes: &[u8]) -> Result<Vec<T>> {
+    let mut r = open::<T>(bytes, true)?;
+    let count = usize::try_from(r.varint()?).map_err(|_| CodecError::UnexpectedEof)?;
+
+    // Every record is at least one byte; don't trust a huge count.
+    let mut values: Vec<T> = Vec::with_capacity(count.min(r.remaining()));
+    for _ in 0..count {
+        let value = match (r.layout(), values.last()) {
+            (Layout::Compact, Some(prev)) => T::decode_after(prev, &mut r)?,
+            _ => T::decode_body(&mut r)?,
+        };
+        values.push(value);
+    }
+    r.finish()?;
+    Ok(values)
+}
+
+// ------------------------------------------------------------
+// Text
+// ------------------------------------------------------------
+
+pub fn to_base64(bytes: &[u8]) -> String {
+    BASE64URL_NOPAD.encode(bytes)
+}
+
+pub fn from_base64(text: &str) -> Result<Vec<u8>> {
+    BASE64URL_NOPAD
+        .decode(text.as_bytes())
+        .map_err(|e| CodecError::Text(e.to_string()))
+}
+
+pub fn to_base32(bytes: &[u8]) -> String {
+    BASE32_NOPAD.encode(bytes)
+}
+
+/// Case-insensitive, since base32 text is often lowercased in URLs.
+pub fn from_base32(text: &str) -> Result<Vec<u8>> {
+    BASE32_NOPAD
+        .decode(text.to_ascii_uppercase().as_bytes())
+        .map_err(|e| CodecError::Text(e.to_string()))
+}
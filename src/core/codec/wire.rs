@@ -0,0 +1,211 @@
+use uuid::Uuid;
+
+use crate::core::codec::error::{CodecError, Result};
+
+/// How integers are laid out in a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Layout {
+    /// Little-endian, full width. Every record of a type is the same size.
+    Fixed = 0,
+    /// Zigzag LEB128 varints; sequences store each record as a
+    /// difference from the one before.
+    Compact = 1,
+}
+
+impl Layout {
+    pub(crate) fn from_byte(b: u8) -> Result<Self> {
+        match b {
+            0 => Ok(Layout::Fixed),
+            1 => Ok(Layout::Compact),
+            other => Err(CodecError::UnknownLayout(other)),
+        }
+    }
+}
+
+fn zigzag(v: i128) -> u128 {
+    ((v << 1) ^ (v >> 127)) as u128
+}
+
+fn unzigzag(v: u128) -> i128 {
+    ((v >> 1) as i128) ^ -((v & 1) as i128)
+}
+
+// ------------------------------------------------------------
+// Writer
+// ------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct Writer {
+    buf: Vec<u8>,
+    layout: Layout,
+}
+
+impl Writer {
+    pub fn new(layout: Layout) -> Self {
+        Self { buf: Vec::new(), layout }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    pub fn u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    /// Unsigned LEB128; lengths and counts use it in every layout.
+    pub fn varint(&mut self, mut v: u128) {
+        loop {
+            let byte = (v & 0x7f) as u8;
+            v >>= 7;
+            if v == 0 {
+                self.buf.push(byte);
+                return;
+            }
+            self.buf.push(byte | 0x80);
+        }
+    }
+
+    pub fn u64(&mut self, v: u64) {
+        match self.layout {
+            Layout::Fixed => self.buf.extend_from_slice(&v.to_le_bytes()),
+            Layout::Compact => self.varint(v as u128),
+        }
+    }
+
+    pub fn i64(&mut self, v: i64) {
+        match self.layout {
+            Layout::Fixed => self.buf.extend_from_slice(&v.to_le_bytes()),
+            Layout::Compact => self.varint(zigzag(v as i128)),
+        }
+    }
+
+    pub fn i128(&mut self, v: i128) {
+        match self.layout {
+            Layout::Fixed => self.buf.extend_from_slice(&v.to_le_bytes()),
+            Layout::Compact => self.varint(zigzag(v)),
+        }
+    }
+
+    /// Raw IEEE-754 bits, so every value (including NaN payloads and
+    /// -0.0) comes back identical.
+    pub fn f64(&mut self, v: f64) {
+        self.buf.extend_from_slice(&v.to_bits().to_le_bytes());
+    }
+
+    pub fn uuid(&mut self, v: &Uuid) {
+        self.buf.extend_from_slice(v.as_bytes());
+    }
+
+    pub fn str(&mut self, v: &str) {
+        self.varint(v.len() as u128);
+        self.buf.extend_from_slice(v.as_bytes());
+    }
+}
+
+// ------------------------------------------------------------
+// Reader
+// ------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    layout: Layout,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8], layout: Layout) -> Self {
+        Self { bytes, pos: 0, layout }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    pub fn finish(&self) -> Result<()> {
+        match self.remaining() {
+            0 => Ok(()),
+            n => Err(CodecError::TrailingBytes(n)),
+        }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let end = self.pos.checked_add(N).filter(|&end| end <= self.bytes.len());
+        let end = end.ok_or(CodecError::UnexpectedEof)?;
+        let mut out = [0u8; N];
+        out.copy_from_slice(&self.bytes[self.pos..end]);
+        self.pos = end;
+        Ok(out)
+    }
+
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    pub fn varint(&mut self) -> Result<u128> {
+        let mut value = 0u128;
+        for shift in (0..128).step_by(7) {
+            let byte = self.u8()?;
+            let bits = (byte & 0x7f) as u128;
+            if shift == 126 && bits > 0b11 {
+                return Err(CodecError::VarintOverflow);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(CodecError::VarintOverflow)
+    }
+
+    pub fn u64(&mut self) -> Result<u64> {
+        match self.layout {
+            Layout::Fixed => Ok(u64::from_le_bytes(self.take()?)),
+            Layout::Compact => u64::try_from(self.varint()?).map_err(|_| CodecError::VarintOverflow),
+        }
+    }
+
+    pub fn i64(&mut self) -> Result<i64> {
+        match self.layout {
+            Layout::Fixed => Ok(i64::from_le_bytes(self.take()?)),
+            Layout::Compact => {
+                i64::try_from(unzigzag(self.varint()?)).map_err(|_| CodecError::VarintOverflow)
+            }
+        }
+    }
+
+    pub fn i128(&mut self) -> Result<i128> {
+        match self.layout {
+            Layout::Fixed => Ok(i128::from_le_bytes(self.take()?)),
+            Layout::Compact => Ok(unzigzag(self.varint()?)),
+        }
+    }
+
+    pub fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(u64::from_le_bytes(self.take()?)))
+    }
+
+    pub fn uuid(&mut self) -> Result<Uuid> {
+        Ok(Uuid::from_bytes(self.take()?))
+    }
+
+    pub fn str(&mut self) -> Result<String> {
+        let len = usize::try_from(self.varint()?).map_err(|_| CodecError::UnexpectedEof)?;
+        if len > self.remaining() {
+            return Err(CodecError::UnexpectedEof);
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        String::from_utf8(bytes.to_vec()).map_err(|_| CodecError::InvalidUtf8)
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CorrosionData {
+    pub surface_area: f64,       // m², to localize corrosion loss
+    pub thickness_loss: f64,     // m, material lost over time
+    pub rate: f64,               // m/year (corrosion rate)
+    pub environment_factor: f32, // humidity, salinity, acidity, etc.
+    pub severity: f64,           // 0.0–1.0, normalized damage
+}
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DegradationData {
+    pub corrosion: f64,        // 0.0–1.0, from corrosion severity
+    pub fatigue: f64,          // 0.0–1.0, consumed service life
+    pub thermal: f64,          // 0.0–1.0, worst overheating seen
+    pub total_integrity: f64,  // 1.0 = as new, 0.0 = failed
+}
@@ -0,0 +1,6 @@
+pub mod corrosion;
+pub mod thermal;
+pub mod degradation;
+pub use corrosion::CorrosionData;
+pub use thermal::{Sunlight, ThermalData, ThermalExposure};
+pub use degradation::DegradationData;
@@ -0,0 +1,23 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ThermalData {
+    pub temperature_c: f64,                  // instantaneous temp
+    pub heat_capacity_j_per_kg_k: f64,
+    pub absorptivity: f64,
+    pub mass_kg: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ThermalExposure {
+    pub total_energy_j: f64,                 // accumulated absorbed energy
+    pub average_temperature_c: f64,          // rolling mean
+    pub cycles: u64,                         // number of integration steps
+}
+
+/// Solar irradiance reaching an entity, kept up to date by whatever
+/// tracks the sun. No component means no sunlight.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Sunlight {
+    pub irradiance_w_m2: f64,                // W/m² on the exposed surface
+}
@@ -0,0 +1,7 @@
+use serde::{Serialize, Deserialize};
+
+use crate::core::objex::geospec::mesh::Mesh;
+
+/// Triangle-mesh geometry, in the entity's own frame (meters).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeshGeometry(pub Mesh);
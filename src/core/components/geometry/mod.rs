@@ -3,8 +3,10 @@ pub mod length;
 pub mod radius;
 pub mod thickness;
 pub mod width;
+pub mod mesh;
 pub use height::Height;
 pub use length::Length;
 pub use radius::Radius;
 pub use thickness::Thickness;
-pub use width::Width;
\ No newline at end of file
+pub use width::Width;
+pub use mesh::MeshGeometry;
@@ -0,0 +1,8 @@
+use crate::core::objex::matcat::layered::LayeredMaterial;
+
+use serde::{Serialize, Deserialize};
+
+/// Layer stack an entity is made of, outermost first. Its `Material` is
+/// the outermost layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layers(pub LayeredMaterial);
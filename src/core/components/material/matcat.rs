@@ -0,0 +1,7 @@
+use crate::core::objex::matcat::materials::MatCatId;
+
+use serde::{Serialize, Deserialize};
+
+/// Catalog identity of the material an entity is made of.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Material(pub MatCatId);
@@ -2,7 +2,11 @@ pub mod density;
 pub mod conductivity;
 pub mod hardness;
 pub mod viscosity;
+pub mod matcat;
+pub mod layers;
 pub use density::Density;
 pub use conductivity::Conductivity;
 pub use hardness::Hardness;
 pub use viscosity::Viscosity;
+pub use matcat::Material;
+pub use layers::Layers;
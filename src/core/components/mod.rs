@@ -7,4 +7,6 @@ pub use spatial::*;
 pub mod time;
 pub use time::*;
 pub mod geometry;
-pub use geometry::*;
\ No newline at end of file
+pub use geometry::*;
+pub mod condition;
+pub use condition::*;
\ No newline at end of file
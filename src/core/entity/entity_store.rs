@@ -12,13 +12,22 @@ use crate::core::components::{time::Time,
 use crate::core::components::material::{Density, 
                                             Hardness, 
                                             Viscosity, 
-                                            Conductivity
+                                            Conductivity,
+                                            Material,
+                                            Layers,
                                         };
+use crate::core::components::condition::{CorrosionData,
+                                             ThermalData,
+                                             ThermalExposure,
+                                             Sunlight,
+                                             DegradationData,
+                                         };
 use crate::core::components::geometry::{Length, 
                                             Radius,     
                                             Thickness, 
                                             Width, 
-                                            Height
+                                            Height,
+                                            MeshGeometry,
                                         };
 
 use serde::{Serialize, Deserialize};
@@ -41,6 +50,24 @@ pub struct EntityStore {
     pub spawned_ats: HashMap<EntityId, SpawnedAt>,
     pub despawned_ats: HashMap<EntityId, DespawnedAt>,
     pub actives: HashMap<EntityId, Active>,
+
+    // --- Material identity + simulated condition (optional in old snapshots) ---
+    #[serde(default)]
+    pub materials: HashMap<EntityId, Material>,
+    #[serde(default)]
+    pub layered_materials: HashMap<EntityId, Layers>,
+    #[serde(default)]
+    pub corrosions: HashMap<EntityId, CorrosionData>,
+    #[serde(default)]
+    pub thermals: HashMap<EntityId, ThermalData>,
+    #[serde(default)]
+    pub thermal_exposures: HashMap<EntityId, ThermalExposure>,
+    #[serde(default)]
+    pub sunlights: HashMap<EntityId, Sunlight>,
+    #[serde(default)]
+    pub degradations: HashMap<EntityId, DegradationData>,
+    #[serde(default)]
+    pub meshes: HashMap<EntityId, MeshGeometry>,
 }
 
 impl EntityStore {
@@ -62,6 +89,14 @@ impl EntityStore {
             spawned_ats: HashMap::new(),
             despawned_ats: HashMap::new(),
             actives: HashMap::new(),
+            materials: HashMap::new(),
+            layered_materials: HashMap::new(),
+            corrosions: HashMap::new(),
+            thermals: HashMap::new(),
+            thermal_exposures: HashMap::new(),
+            sunlights: HashMap::new(),
+            degradations: HashMap::new(),
+            meshes: HashMap::new(),
         }
     }
 
@@ -124,4 +159,16 @@ impl EntityStore {
     pub fn add_height(&mut self, entity: EntityId, height: Height) {
         self.heights.insert(entity, height);
     }
+    pub fn add_material(&mut self, entity: EntityId, material: Material) {
+        self.materials.insert(entity, material);
+    }
+    pub fn add_mesh(&mut self, entity: EntityId, mesh: MeshGeometry) {
+        self.meshes.insert(entity, mesh);
+    }
+    pub fn add_layers(&mut self, entity: EntityId, layers: Layers) {
+        self.layered_materials.insert(entity, layers);
+    }
+    pub fn add_sunlight(&mut self, entity: EntityId, sunlight: Sunlight) {
+        self.sunlights.insert(entity, sunlight);
+    }
 }
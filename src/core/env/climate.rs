@@ -0,0 +1,31 @@
+use crate::core::env::fields::{Field, FieldSample};
+use crate::core::uvoxid::UvoxId;
+use crate::core::tdt::SimDuration;
+use crate::core::world::world_env_descriptor::ClimateModel;
+
+/// Surface climate: relative humidity and chloride deposition, uniform
+/// over the world. Drives the humidity/chloride material curves and the
+/// corrosion system.
+pub struct ClimateField {
+    pub relative_humidity: f64, // 0–1
+    pub chloride: f64,          // mg/(m²·d)
+}
+
+impl ClimateField {
+    pub fn from_model(model: &ClimateModel) -> Self {
+        Self {
+            relative_humidity: model.relative_humidity.clamp(0.0, 1.0),
+            chloride: model.chloride.max(0.0),
+        }
+    }
+}
+
+impl Field for ClimateField {
+    fn sample(&self, _id: &UvoxId, _time: SimDuration) -> FieldSample {
+        FieldSample {
+            relative_humidity: self.relative_humidity,
+            chloride: self.chloride,
+            ..Default::default()
+        }
+    }
+}
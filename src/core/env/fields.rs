@@ -32,6 +32,8 @@ pub struct FieldSample {
     pub wind_radial: f64,      // m/s
     pub resistance: f64,       // drag-ish scalar
     pub land_height_m: f64,
+    pub relative_humidity: f64, // 0–1
+    pub chloride: f64,          // chloride deposition, mg/(m²·d)
 }
 
 impl Default for FieldSample {
@@ -45,6 +47,8 @@ impl Default for FieldSample {
             wind_radial: 0.0,
             resistance: 0.0,
             land_height_m: 0.0,
+            relative_humidity: 0.0,
+            chloride: 0.0,
         }
     }
 }
@@ -61,5 +65,7 @@ impl FieldSample {
         self.gravity_radial += other.gravity_radial;
         self.wind_radial += other.wind_radial;
         self.resistance += other.resistance;
+        self.relative_humidity += other.relative_humidity;
+        self.chloride += other.chloride;
     }
 }
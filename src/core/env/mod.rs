@@ -13,6 +13,7 @@
 
 
 pub mod atmosphere;
+pub mod climate;
 pub mod fields;
 pub mod gravity;
 pub mod medium;
@@ -26,6 +27,7 @@ pub mod derived_env;
 
 pub use gravity::*;
 pub use atmosphere::*;
+pub use climate::ClimateField;
 pub use medium::*;
 pub use pressure::*;
 pub use resistance::*;
@@ -12,6 +12,8 @@ pub mod observer;
 pub mod math;
 pub mod components;
 pub mod property;
+pub mod objex;     // objects: geometry (geospec) + materials (matcat)
+pub mod codec;     // compact binary / text encodings
 pub use uvoxid::{*};
 pub use chronovox::{*};
 pub use tdt::{*};
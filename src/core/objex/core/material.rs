@@ -59,11 +59,11 @@ impl MaterialLink {
 impl MaterialLink {
     pub fn new(name: MaterialName) -> Self {
         let matcat_id = MatCatId::from_name(&name)
-            .unwrap_or_else(|| MatCatId { 
-                category: CategoryId(0), 
-                variant: Some(VariantId(0)), 
-                grade: Some(GradeId(0)    
-            ) });
+            .unwrap_or(MatCatId {
+                category: CategoryId(0),
+                variant: Some(VariantId(0)),
+                grade: Some(GradeId(0)),
+            });
 
         // Infer kind automatically
         let kind = match name {
@@ -2,10 +2,10 @@
 pub mod composite;
 pub mod types;
 pub mod material;
-pub mod api;
+//pub mod api; // needs AppState.objex_store
 
 //pub use object::*;
 pub use composite::*;
 pub use types::*;
 pub use material::*;
-pub use api::*;
\ No newline at end of file
+//pub use api::*;
\ No newline at end of file
@@ -1,7 +1,11 @@
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
-use crate::core::objex::matcat::materials::MatCatId;
+use crate::core::objex::matcat::layered::LayeredMaterial;
+use crate::core::env::FieldSample;
+use crate::core::objex::matcat::curves::props_at;
+use crate::core::objex::matcat::materials::{props_for, MatCatId};
+use crate::core::objex::matcat::properties::MatProps;
 
 /// Canonical physical object definition.
 /// Geometry is referenced, not embedded.
@@ -12,8 +16,13 @@ pub struct Objex {
     /// Reference to canonical geometry
     pub geospec_id: Uuid,
 
-    /// Material identity (deterministic, not UUID)
+    /// Material identity (deterministic, not UUID).
+    /// For a layered objex, the outermost layer.
     pub matcat: MatCatId,
+
+    /// Layer stack used in place of the single `matcat`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layers: Option<LayeredMaterial>,
 }
 
 impl Objex {
@@ -23,6 +32,7 @@ impl Objex {
             id: Uuid::new_v4(),
             geospec_id,
             matcat,
+            layers: None,
         }
     }
 
@@ -32,7 +42,33 @@ impl Objex {
             id,
             geospec_id,
             matcat,
+            layers: None,
+        }
+    }
+
+    /// Make this objex out of a layer stack; `matcat` follows the
+    /// outermost layer.
+    pub fn with_layers(mut self, layers: LayeredMaterial) -> Self {
+        self.matcat = layers.outermost();
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Effective material properties: the laminate's when layered,
+    /// otherwise the catalog entry's.
+    pub fn props(&self) -> MatProps {
+        match &self.layers {
+            Some(layers) => layers.effective_props(),
+            None => props_for(&self.matcat),
         }
     }
-}
 
+    /// `props` under the conditions in `env` (temperature, humidity,
+    /// chloride).
+    pub fn props_at(&self, env: &FieldSample) -> MatProps {
+        match &self.layers {
+            Some(layers) => layers.effective_props_at(env),
+            None => props_at(&self.matcat, env),
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use thiserror::Error;
-use crate::supabasic;
+//use crate::supabasic;
 
 #[derive(Debug, Error)]
 pub enum ObjexError {
-    #[error("Supabase error: {0}")]
-    Supabase(#[from] supabasic::SupabasicError),
+    //#[error("Supabase error: {0}")]
+    //Supabase(#[from] supabasic::SupabasicError),
 
     #[error("Serde JSON error: {0}")]
     SerdeJson(#[from] serde_json::Error),
@@ -2,6 +2,9 @@ use serde::{Serialize, Deserialize};
 use crate::core::objex::geospec::primitives::*;
 use crate::core::objex::geospec::{Bounds, GeoSpec, Volume, SurfaceArea};
 use crate::core::objex::geospec::profile::*;
+use crate::core::objex::geospec::csg::{self, CsgOptions, Placed};
+use crate::core::objex::geospec::mesh::Mesh;
+use crate::core::objex::geospec::sweep::SweepPath;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -13,12 +16,20 @@ pub enum AuthoringShape {
     Box(BoxShape),
     Cylinder(Cylinder),
     Cone(Cone),
+    /// Indexed triangles; solid when watertight.
+    Mesh(Mesh),
+    /// Children may overlap; shared volume counts once.
     Union {
-    children: Vec<AuthoringShape>,
+        children: Vec<Placed>,
     },
+    /// `outer` with `inner` removed.
     Difference {
-        outer: Box<AuthoringShape>,
-        inner: Box<AuthoringShape>,
+        outer: Box<Placed>,
+        inner: Box<Placed>,
+    },
+    /// Volume common to all children.
+    Intersection {
+        children: Vec<Placed>,
     },
     // new
     Extrude {
@@ -26,76 +37,42 @@ pub enum AuthoringShape {
         length: f64,
         capped: bool,
     },
-
+    /// `profile` carried along `path`; `capped` closes both ends.
+    Sweep {
+        profile: Profile,
+        path: SweepPath,
+        capped: bool,
+    },
 
 }
 
 
 impl AuthoringShape {
     pub fn compile(&self) -> GeoSpec {
+        self.compile_with(&CsgOptions::default())
+    }
+
+    /// Compile with explicit settings for the numeric fallback.
+    pub fn compile_with(&self, options: &CsgOptions) -> GeoSpec {
+        let m = csg::measure(self, options);
         GeoSpec {
             bounds: Bounds::from_shape(self),
-            volume: self.volume(),
-            surface_area: self.surface_area(),
+            volume: m.volume,
+            surface_area: m.surface_area,
+            volume_error: m.volume_error,
+            surface_area_error: m.surface_area_error,
         }
     }
 }
 
 impl SurfaceArea for AuthoringShape {
     fn surface_area(&self) -> f64 {
-        match self {
-            AuthoringShape::Point(_) => 0.0,
-            AuthoringShape::Line(_) => 0.0,
-            AuthoringShape::Plane(p) => p.surface_area(),
-            AuthoringShape::Sphere(s) => s.surface_area(),
-            AuthoringShape::Box(b) => b.surface_area(),
-            AuthoringShape::Cylinder(c) => c.surface_area(),
-            AuthoringShape::Cone(c) => c.surface_area(),
-            AuthoringShape::Union { children } => {
-                children.iter().map(|c| c.surface_area()).sum()
-            }
-
-            AuthoringShape::Difference { outer, inner } => {
-                // Outer surface + inner exposed surface
-                outer.surface_area() + inner.surface_area()
-            }
-            AuthoringShape::Extrude { profile, length, capped } => {
-                let side = profile.perimeter() * length;
-                let caps = if *capped {
-                    2.0 * profile.area()
-                } else {
-                    0.0
-                };
-                side + caps
-            }
-
-
-        }
+        csg::measure(self, &CsgOptions::default()).surface_area
     }
 }
 
 impl Volume for AuthoringShape {
     fn volume(&self) -> f64 {
-        match self {
-            AuthoringShape::Sphere(s) => s.volume(),
-            AuthoringShape::Box(b) => b.volume(),
-            AuthoringShape::Cylinder(c) => c.volume(),
-            AuthoringShape::Cone(c) => c.volume(),
-            AuthoringShape::Union { children } => {
-                children.iter().map(|c| c.volume()).sum()
-            }
-
-            AuthoringShape::Difference { outer, inner } => {
-                outer.volume() - inner.volume()
-            }
-            AuthoringShape::Extrude { profile, length, capped } => {
-                profile.area() * length
-            }
-
-            _ => 0.0,
-        }
+        csg::measure(self, &CsgOptions::default()).volume
     }
 }
-
-
-
@@ -0,0 +1,1362 @@
+//! Constructive solid geometry for `AuthoringShape`.
+//!
+//! Every primitive is centred on its local origin. A box spans `length`
+//! along x, `width` along y and `height` along z. Cylinders, cones (base
+//! at -h/2, apex at +h/2) and extrusions (profile in the xy plane) run
+//! along z; sweeps follow their path as given. A `Placement` puts a child in its parent's frame: rotate
+//! about the child's origin, then offset.
+//!
+//! Volume and area are exact for single solids, disjoint or strictly
+//! nested children, sphere pairs, axis-aligned box pairs, n-way box
+//! intersections and through-holes (box minus cylinder). Anything else
+//! is estimated on a deterministic grid and reported with an error
+//! bound. Points, lines, planes and zero-volume solids have no interior
+//! and are ignored inside boolean nodes.
+
+use std::f64::consts::PI;
+
+use glam::{DMat3, DQuat, DVec3};
+use serde::{Deserialize, Serialize};
+
+use crate::core::objex::geospec::authoring::AuthoringShape;
+use crate::core::objex::geospec::profile::{Profile, ProfileArea, ProfilePerimeter};
+use crate::core::objex::geospec::sweep::{self, Piece, Station};
+use crate::core::objex::geospec::traits::{SurfaceArea, Volume};
+use crate::core::objex::geospec::Bounds;
+
+/// Touching / coplanar tolerance, relative to the size of the node.
+const REL_EPS: f64 = 1e-9;
+
+/// Offset used to probe either side of a surface sample, relative to
+/// the size of the node.
+const PROBE_EPS: f64 = 1e-7;
+
+// ----------------------------------------------------------------
+// Placement
+// ----------------------------------------------------------------
+
+/// Rotation as a quaternion; normalized on use.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Orientation {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Orientation {
+    pub fn identity() -> Self {
+        Self { w: 1.0, x: 0.0, y: 0.0, z: 0.0 }
+    }
+
+    /// Right-handed rotation of `degrees` about `axis`.
+    pub fn from_axis_angle(axis: [f64; 3], degrees: f64) -> Self {
+        let axis = DVec3::from(axis).normalize_or_zero();
+        if axis == DVec3::ZERO {
+            return Self::identity();
+        }
+        let q = DQuat::from_axis_angle(axis, degrees.to_radians());
+        Self { w: q.w, x: q.x, y: q.y, z: q.z }
+    }
+
+    pub(crate) fn quat(&self) -> DQuat {
+        let q = DQuat::from_xyzw(self.x, self.y, self.z, self.w);
+        if q.length_squared() > 0.0 { q.normalize() } else { DQuat::IDENTITY }
+    }
+}
+
+/// Where a child sits in its parent's frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Placement {
+    #[serde(default)]
+    pub offset: [f64; 3],
+    #[serde(default)]
+    pub rotation: Orientation,
+}
+
+impl Placement {
+    pub fn at(x: f64, y: f64, z: f64) -> Self {
+        Self { offset: [x, y, z], ..Self::default() }
+    }
+
+    pub fn rotated(mut self, axis: [f64; 3], degrees: f64) -> Self {
+        self.rotation = Orientation::from_axis_angle(axis, degrees);
+        self
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn from_frame(at: DVec3, rot: DQuat) -> Self {
+        Self { offset: at.to_array(), rotation: Orientation { w: rot.w, x: rot.x, y: rot.y, z: rot.z } }
+    }
+
+    /// `(offset, rotation)` as glam types.
+    pub(crate) fn frame(&self) -> (DVec3, DQuat) {
+        (DVec3::from(self.offset), self.rotation.quat())
+    }
+
+    fn xf(&self) -> Xf {
+        Xf { rot: DMat3::from_quat(self.rotation.quat()), off: DVec3::from(self.offset) }
+    }
+}
+
+/// A child of a boolean node. Serializes as the shape itself plus an
+/// optional `placement`, so unplaced children read as plain shapes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Placed {
+    #[serde(flatten)]
+    pub shape: AuthoringShape,
+    #[serde(default, skip_serializing_if = "Placement::is_identity")]
+    pub placement: Placement,
+}
+
+impl Placed {
+    pub fn new(shape: AuthoringShape) -> Self {
+        Self { shape, placement: Placement::default() }
+    }
+
+    pub fn at(mut self, x: f64, y: f64, z: f64) -> Self {
+        self.placement.offset = [x, y, z];
+        self
+    }
+
+    pub fn rotated(mut self, axis: [f64; 3], degrees: f64) -> Self {
+        self.placement = self.placement.rotated(axis, degrees);
+        self
+    }
+
+    fn xf(&self) -> Xf {
+        self.placement.xf()
+    }
+}
+
+impl From<AuthoringShape> for Placed {
+    fn from(shape: AuthoringShape) -> Self {
+        Self::new(shape)
+    }
+}
+
+/// Rigid transform: `rot * p + off`.
+#[derive(Debug, Clone, Copy)]
+struct Xf {
+    rot: DMat3,
+    off: DVec3,
+}
+
+impl Xf {
+    const IDENTITY: Xf = Xf { rot: DMat3::IDENTITY, off: DVec3::ZERO };
+
+    fn apply(&self, p: DVec3) -> DVec3 {
+        self.rot * p + self.off
+    }
+
+    /// Parent-frame point into this frame.
+    fn local(&self, p: DVec3) -> DVec3 {
+        self.rot.transpose() * (p - self.off)
+    }
+
+    fn then(&self, child: &Xf) -> Xf {
+        Xf { rot: self.rot * child.rot, off: self.apply(child.off) }
+    }
+
+    fn inverse(&self) -> Xf {
+        let rot = self.rot.transpose();
+        Xf { rot, off: -(rot * self.off) }
+    }
+}
+
+// ----------------------------------------------------------------
+// Measures
+// ----------------------------------------------------------------
+
+/// Volume and surface area with absolute error bounds (zero when exact).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Measure {
+    pub volume: f64,
+    pub volume_error: f64,
+    pub surface_area: f64,
+    pub surface_area_error: f64,
+}
+
+impl Measure {
+    fn exact(volume: f64, surface_area: f64) -> Self {
+        Self { volume, surface_area, ..Self::default() }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.volume_error == 0.0 && self.surface_area_error == 0.0
+    }
+
+    fn plus(self, other: Measure) -> Self {
+        Self {
+            volume: self.volume + other.volume,
+            volume_error: self.volume_error + other.volume_error,
+            surface_area: self.surface_area + other.surface_area,
+            surface_area_error: self.surface_area_error + other.surface_area_error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CsgOptions {
+    /// Grid cells along the longest side for the volume estimate, and
+    /// samples per side of each surface patch for the area estimate.
+    pub resolution: usize,
+}
+
+impl Default for CsgOptions {
+    fn default() -> Self {
+        Self { resolution: 64 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Union,
+    Intersection,
+    Difference,
+}
+
+pub fn measure(shape: &AuthoringShape, options: &CsgOptions) -> Measure {
+    match shape {
+        AuthoringShape::Union { children } => union_measure(shape, children, options),
+        AuthoringShape::Intersection { children } => intersection_measure(shape, children, options),
+        AuthoringShape::Difference { outer, inner } => difference_measure(shape, outer, inner, options),
+        leaf => leaf_measure(leaf),
+    }
+}
+
+fn leaf_measure(shape: &AuthoringShape) -> Measure {
+    match shape {
+        AuthoringShape::Sphere(s) => Measure::exact(s.volume(), s.surface_area()),
+        AuthoringShape::Box(b) => Measure::exact(b.volume(), b.surface_area()),
+        AuthoringShape::Cylinder(c) => Measure::exact(c.volume(), c.surface_area()),
+        AuthoringShape::Cone(c) => Measure::exact(c.volume(), c.surface_area()),
+        AuthoringShape::Plane(p) => Measure::exact(0.0, p.surface_area()),
+        AuthoringShape::Mesh(m) => Measure::exact(m.volume(), m.surface_area()),
+        AuthoringShape::Extrude { profile, length, capped } => {
+            let caps = if *capped { 2.0 * profile.area() } else { 0.0 };
+            Measure::exact(profile.area() * length, profile.perimeter() * length + caps)
+        }
+        AuthoringShape::Sweep { profile, path, capped } => {
+            let caps = if *capped { 2.0 * profile.area() } else { 0.0 };
+            Measure::exact(path.swept_volume(profile), path.swept_area(profile) + caps)
+        }
+        _ => Measure::default(),
+    }
+}
+
+/// Solid children that are not empty.
+fn solids(children: &[Placed]) -> Vec<&Placed> {
+    children
+        .iter()
+        .filter(|c| is_solid(&c.shape) && !is_void(&c.shape) && placed_extent(c).is_some())
+        .collect()
+}
+
+fn union_measure(shape: &AuthoringShape, children: &[Placed], options: &CsgOptions) -> Measure {
+    let solids = solids(children);
+    let eps = node_eps(&solids);
+
+    // Children strictly inside another child add nothing
+    let kept: Vec<&Placed> = solids
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| !solids.iter().enumerate().any(|(j, o)| *i != j && contained(c, o, eps)))
+        .map(|(_, c)| *c)
+        .collect();
+
+    match kept.as_slice() {
+        [] => Measure::default(),
+        [only] => measure(&only.shape, options),
+        _ if pairwise_separated(&kept, eps) => {
+            kept.iter().fold(Measure::default(), |m, c| m.plus(measure(&c.shape, options)))
+        }
+        [a, b] => pair(Op::Union, a, b, eps).unwrap_or_else(|| numeric(shape, options)),
+        _ => numeric(shape, options),
+    }
+}
+
+fn intersection_measure(shape: &AuthoringShape, children: &[Placed], options: &CsgOptions) -> Measure {
+    let solid: Vec<&Placed> = children.iter().filter(|c| is_solid(&c.shape)).collect();
+    if solid.is_empty() || solid.iter().any(|c| is_void(&c.shape)) || extent(shape, &Xf::IDENTITY).is_none() {
+        return Measure::default();
+    }
+    let eps = node_eps(&solid);
+
+    if let [only] = solid.as_slice() {
+        return measure(&only.shape, options);
+    }
+    for (i, a) in solid.iter().enumerate() {
+        if solid[i + 1..].iter().any(|b| separated(a, b, eps)) {
+            return Measure::default();
+        }
+    }
+
+    // All axis-aligned boxes: the intersection is a box
+    let boxes: Option<Vec<Aabb>> = solid.iter().map(|c| aligned_box(c)).collect();
+    if let Some(boxes) = boxes {
+        // Boxes that only touch share a face, not a solid
+        let common = boxes.iter().skip(1).try_fold(boxes[0], |acc, b| acc.intersect(b));
+        return common
+            .filter(|b| b.size().min_element() > eps)
+            .map(|b| Measure::exact(b.volume(), b.area()))
+            .unwrap_or_default();
+    }
+
+    // A child inside all the others is the intersection
+    for (i, c) in solid.iter().enumerate() {
+        if solid.iter().enumerate().all(|(j, o)| i == j || contained(c, o, eps)) {
+            return measure(&c.shape, options);
+        }
+    }
+
+    match solid.as_slice() {
+        [a, b] => pair(Op::Intersection, a, b, eps).unwrap_or_else(|| numeric(shape, options)),
+        _ => numeric(shape, options),
+    }
+}
+
+fn difference_measure(shape: &AuthoringShape, outer: &Placed, inner: &Placed, options: &CsgOptions) -> Measure {
+    if !is_solid(&outer.shape) || is_void(&outer.shape) || placed_extent(outer).is_none() {
+        return Measure::default();
+    }
+    if !is_solid(&inner.shape) || is_void(&inner.shape) || placed_extent(inner).is_none() {
+        return measure(&outer.shape, options);
+    }
+
+    let eps = node_eps(&[outer, inner]);
+    if separated(outer, inner, eps) {
+        return measure(&outer.shape, options);
+    }
+    if contained(inner, outer, eps) {
+        // A sealed cavity: its walls add to the area
+        let (o, i) = (measure(&outer.shape, options), measure(&inner.shape, options));
+        return Measure {
+            volume: o.volume - i.volume,
+            volume_error: o.volume_error + i.volume_error,
+            surface_area: o.surface_area + i.surface_area,
+            surface_area_error: o.surface_area_error + i.surface_area_error,
+        };
+    }
+    if contained(outer, inner, eps) {
+        return Measure::default();
+    }
+
+    pair(Op::Difference, outer, inner, eps).unwrap_or_else(|| numeric(shape, options))
+}
+
+// ----------------------------------------------------------------
+// Analytic pairs
+// ----------------------------------------------------------------
+
+fn pair(op: Op, a: &Placed, b: &Placed, eps: f64) -> Option<Measure> {
+    match (&a.shape, &b.shape) {
+        (AuthoringShape::Sphere(sa), AuthoringShape::Sphere(sb)) => Some(sphere_pair(
+            op,
+            (DVec3::from(a.placement.offset), sa.radius),
+            (DVec3::from(b.placement.offset), sb.radius),
+        )),
+        (AuthoringShape::Box(_), AuthoringShape::Box(_)) => {
+            Some(box_pair(op, &aligned_box(a)?, &aligned_box(b)?, eps))
+        }
+        (AuthoringShape::Box(_), AuthoringShape::Cylinder(_)) if op == Op::Difference => through_hole(a, b, eps),
+        _ => None,
+    }
+}
+
+fn sphere_pair(op: Op, (ca, ra): (DVec3, f64), (cb, rb): (DVec3, f64)) -> Measure {
+    let volume = |r: f64| 4.0 / 3.0 * PI * r.powi(3);
+    let area = |r: f64| 4.0 * PI * r * r;
+    let (va, aa, vb, ab) = (volume(ra), area(ra), volume(rb), area(rb));
+    let d = ca.distance(cb);
+
+    if d >= ra + rb {
+        return match op {
+            Op::Union => Measure::exact(va + vb, aa + ab),
+            Op::Intersection => Measure::default(),
+            Op::Difference => Measure::exact(va, aa),
+        };
+    }
+    if d <= (ra - rb).abs() {
+        let a_inside = ra <= rb;
+        return match (op, a_inside) {
+            (Op::Union, true) => Measure::exact(vb, ab),
+            (Op::Union, false) => Measure::exact(va, aa),
+            (Op::Intersection, true) => Measure::exact(va, aa),
+            (Op::Intersection, false) => Measure::exact(vb, ab),
+            (Op::Difference, true) => Measure::default(),
+            (Op::Difference, false) => Measure::exact(va - vb, aa + ab),
+        };
+    }
+
+    // Lens: each sphere contributes the cap beyond the shared plane
+    let x = (d * d + ra * ra - rb * rb) / (2.0 * d);
+    let (ha, hb) = (ra - x, rb - (d - x));
+    let cap_volume = |r: f64, h: f64| PI * h * h * (3.0 * r - h) / 3.0;
+    let cap_area = |r: f64, h: f64| 2.0 * PI * r * h;
+    let lens = cap_volume(ra, ha) + cap_volume(rb, hb);
+    let (cap_a, cap_b) = (cap_area(ra, ha), cap_area(rb, hb));
+
+    match op {
+        Op::Union => Measure::exact(va + vb - lens, aa - cap_a + ab - cap_b),
+        Op::Intersection => Measure::exact(lens, cap_a + cap_b),
+        Op::Difference => Measure::exact(va - lens, aa - cap_a + cap_b),
+    }
+}
+
+fn box_pair(op: Op, a: &Aabb, b: &Aabb, eps: f64) -> Measure {
+    let size = a.max.min(b.max) - a.min.max(b.min);
+    let disjoint = match op {
+        Op::Union => Measure::exact(a.volume() + b.volume(), a.area() + b.area()),
+        Op::Intersection => Measure::default(),
+        Op::Difference => Measure::exact(a.volume(), a.area()),
+    };
+    if size.min_element() < -eps {
+        return disjoint;
+    }
+
+    let thin: Vec<usize> = (0..3).filter(|&k| size[k] <= eps).collect();
+    if !thin.is_empty() {
+        // Touching: a shared face is interior to a union
+        let contact = match thin.as_slice() {
+            [k] => size[(k + 1) % 3] * size[(k + 2) % 3],
+            _ => 0.0,
+        };
+        return match op {
+            Op::Union => Measure::exact(disjoint.volume, disjoint.surface_area - 2.0 * contact),
+            _ => disjoint,
+        };
+    }
+
+    let common = Aabb { min: a.min.max(b.min), max: a.max.min(b.max) };
+    match op {
+        Op::Union => Measure::exact(a.volume() + b.volume() - common.volume(), a.area() + b.area() - common.area()),
+        Op::Intersection => Measure::exact(common.volume(), common.area()),
+        Op::Difference => {
+            // Each face of the common box lies on a's surface (removed)
+            // or only on b's (newly exposed).
+            let mut area = a.area();
+            for k in 0..3 {
+                let face = size[(k + 1) % 3] * size[(k + 2) % 3];
+                for (c, ca, cb) in [(common.min[k], a.min[k], b.min[k]), (common.max[k], a.max[k], b.max[k])] {
+                    if (c - ca).abs() <= eps {
+                        area -= face;
+                    } else if (c - cb).abs() <= eps {
+                        area += face;
+                    }
+                }
+            }
+            Measure::exact(a.volume() - common.volume(), area)
+        }
+    }
+}
+
+/// A box minus a cylinder that runs right through it along one of the
+/// box's axes, clear of its sides.
+fn through_hole(block: &Placed, drill: &Placed, eps: f64) -> Option<Measure> {
+    let (AuthoringShape::Box(b), AuthoringShape::Cylinder(c)) = (&block.shape, &drill.shape) else {
+        return None;
+    };
+    let rel = block.xf().inverse().then(&drill.xf());
+    let axis = rel.rot * DVec3::Z;
+    let half = DVec3::new(b.length, b.width, b.height) / 2.0;
+
+    let k = (0..3).find(|&k| (axis[k].abs() - 1.0).abs() < 1e-9)?;
+    for i in (0..3).filter(|&i| i != k) {
+        if rel.off[i].abs() + c.radius >= half[i] - eps {
+            return None;
+        }
+    }
+    let (lo, hi) = (rel.off[k] - c.height / 2.0, rel.off[k] + c.height / 2.0);
+    if lo > -half[k] + eps || hi < half[k] - eps {
+        return None;
+    }
+
+    let depth = 2.0 * half[k];
+    let disc = PI * c.radius * c.radius;
+    Some(Measure::exact(
+        b.volume() - disc * depth,
+        b.surface_area() - 2.0 * disc + 2.0 * PI * c.radius * depth,
+    ))
+}
+
+// ----------------------------------------------------------------
+// Classification
+// ----------------------------------------------------------------
+
+fn is_solid(shape: &AuthoringShape) -> bool {
+    if let AuthoringShape::Mesh(m) = shape {
+        return m.is_watertight();
+    }
+    !matches!(shape, AuthoringShape::Point(_) | AuthoringShape::Line(_) | AuthoringShape::Plane(_))
+}
+
+/// A solid primitive with no volume (zero radius, a flat box, ...).
+fn is_void(shape: &AuthoringShape) -> bool {
+    let boolean = matches!(
+        shape,
+        AuthoringShape::Union { .. } | AuthoringShape::Intersection { .. } | AuthoringShape::Difference { .. }
+    );
+    !boolean && leaf_measure(shape).volume <= 0.0
+}
+
+/// Point membership, `p` in the shape's own frame.
+fn contains(shape: &AuthoringShape, p: DVec3) -> bool {
+    match shape {
+        AuthoringShape::Sphere(s) => p.length_squared() <= s.radius * s.radius,
+        AuthoringShape::Box(b) => {
+            p.x.abs() <= b.length / 2.0 && p.y.abs() <= b.width / 2.0 && p.z.abs() <= b.height / 2.0
+        }
+        AuthoringShape::Cylinder(c) => {
+            p.x * p.x + p.y * p.y <= c.radius * c.radius && p.z.abs() <= c.height / 2.0
+        }
+        AuthoringShape::Cone(c) => {
+            let radius_at = c.radius * (c.height / 2.0 - p.z) / c.height;
+            p.z.abs() <= c.height / 2.0 && (p.x * p.x + p.y * p.y).sqrt() <= radius_at
+        }
+        AuthoringShape::Extrude { profile, length, .. } => {
+            p.z.abs() <= length / 2.0 && profile.contains(p.x, p.y)
+        }
+        AuthoringShape::Sweep { profile, path, .. } => sweep::contains(profile, path, p),
+        AuthoringShape::Mesh(m) => m.contains(p),
+        AuthoringShape::Point(_) | AuthoringShape::Line(_) | AuthoringShape::Plane(_) => false,
+        AuthoringShape::Union { children } => children.iter().any(|c| placed_contains(c, p)),
+        AuthoringShape::Intersection { children } => {
+            let mut solid = children.iter().filter(|c| is_solid(&c.shape)).peekable();
+            solid.peek().is_some() && solid.all(|c| placed_contains(c, p))
+        }
+        AuthoringShape::Difference { outer, inner } => {
+            placed_contains(outer, p) && !placed_contains(inner, p)
+        }
+    }
+}
+
+fn placed_contains(child: &Placed, p: DVec3) -> bool {
+    contains(&child.shape, child.xf().local(p))
+}
+
+/// Distance from `p` (own frame) to the surface of a convex primitive,
+/// positive inside. `None` for shapes that are not convex primitives.
+fn depth(shape: &AuthoringShape, p: DVec3) -> Option<f64> {
+    let rho = (p.x * p.x + p.y * p.y).sqrt();
+    Some(match shape {
+        AuthoringShape::Sphere(s) => s.radius - p.length(),
+        AuthoringShape::Box(b) => (b.length / 2.0 - p.x.abs())
+            .min(b.width / 2.0 - p.y.abs())
+            .min(b.height / 2.0 - p.z.abs()),
+        AuthoringShape::Cylinder(c) => (c.radius - rho).min(c.height / 2.0 - p.z.abs()),
+        AuthoringShape::Cone(c) => {
+            let slant = (c.radius * c.radius + c.height * c.height).sqrt();
+            let radius_at = c.radius * (c.height / 2.0 - p.z) / c.height;
+            (p.z + c.height / 2.0).min((radius_at - rho) * c.height / slant)
+        }
+        AuthoringShape::Extrude { profile, length, .. } => {
+            let section = match profile {
+                Profile::Circle { outer_radius, inner_radius: None } => outer_radius - rho,
+                Profile::Rect { width, height, wall: None } => {
+                    (width / 2.0 - p.x.abs()).min(height / 2.0 - p.y.abs())
+                }
+                _ => return None,
+            };
+            section.min(length / 2.0 - p.z.abs())
+        }
+        _ => return None,
+    })
+}
+
+/// Whether `inner` lies strictly inside `outer` (a convex primitive).
+fn contained(inner: &Placed, outer: &Placed, eps: f64) -> bool {
+    let frame = outer.xf();
+    if let AuthoringShape::Sphere(s) = &inner.shape {
+        let centre = frame.local(DVec3::from(inner.placement.offset));
+        return depth(&outer.shape, centre).is_some_and(|d| d > s.radius + eps);
+    }
+
+    let hull = match local_box(&inner.shape) {
+        Some(b) => b.corners().map(|c| inner.xf().apply(c)),
+        None => match placed_extent(inner) {
+            Some(b) => b.corners(),
+            None => return false,
+        },
+    };
+    hull.iter().all(|p| depth(&outer.shape, frame.local(*p)).is_some_and(|d| d > eps))
+}
+
+fn separated(a: &Placed, b: &Placed, eps: f64) -> bool {
+    let boxes = match (placed_extent(a), placed_extent(b)) {
+        (Some(ea), Some(eb)) => ea.separated(&eb, eps),
+        _ => true,
+    };
+    let spheres = {
+        let (ca, cb) = (DVec3::from(a.placement.offset), DVec3::from(b.placement.offset));
+        let (ra, rb) = (farthest(&a.shape, &Xf::IDENTITY), farthest(&b.shape, &Xf::IDENTITY));
+        ca.distance(cb) > ra + rb + eps
+    };
+    boxes || spheres
+}
+
+fn pairwise_separated(children: &[&Placed], eps: f64) -> bool {
+    children
+        .iter()
+        .enumerate()
+        .all(|(i, a)| children[i + 1..].iter().all(|b| separated(a, b, eps)))
+}
+
+/// World box of a box child whose rotation only permutes / flips axes.
+fn aligned_box(child: &Placed) -> Option<Aabb> {
+    let AuthoringShape::Box(_) = child.shape else {
+        return None;
+    };
+    let rot = child.xf().rot;
+    let aligned = [rot.x_axis, rot.y_axis, rot.z_axis]
+        .iter()
+        .flat_map(|col| col.to_array())
+        .all(|v| v.abs() < 1e-9 || (v.abs() - 1.0).abs() < 1e-9);
+    if aligned { placed_extent(child) } else { None }
+}
+
+fn node_eps(children: &[&Placed]) -> f64 {
+    let diagonal = children
+        .iter()
+        .filter_map(|c| placed_extent(c))
+        .reduce(|a, b| a.union(&b))
+        .map(|b| b.size().length())
+        .unwrap_or(0.0);
+    REL_EPS * diagonal
+}
+
+// ----------------------------------------------------------------
+// Extents
+// ----------------------------------------------------------------
+
+/// Axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Aabb {
+    pub min: DVec3,
+    pub max: DVec3,
+}
+
+impl Aabb {
+    fn around(points: impl IntoIterator<Item = DVec3>) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|p| Aabb { min: p, max: p })
+            .reduce(|a, b| a.union(&b))
+    }
+
+    fn union(&self, other: &Aabb) -> Aabb {
+        Aabb { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    fn intersect(&self, other: &Aabb) -> Option<Aabb> {
+        let b = Aabb { min: self.min.max(other.min), max: self.max.min(other.max) };
+        (b.min.cmple(b.max).all()).then_some(b)
+    }
+
+    fn separated(&self, other: &Aabb, eps: f64) -> bool {
+        (0..3).any(|k| self.max[k] < other.min[k] - eps || other.max[k] < self.min[k] - eps)
+    }
+
+    fn size(&self) -> DVec3 {
+        self.max - self.min
+    }
+
+    fn volume(&self) -> f64 {
+        let s = self.size();
+        s.x * s.y * s.z
+    }
+
+    fn area(&self) -> f64 {
+        let s = self.size();
+        2.0 * (s.x * s.y + s.y * s.z + s.z * s.x)
+    }
+
+    fn corners(&self) -> [DVec3; 8] {
+        std::array::from_fn(|i| {
+            DVec3::new(
+                if i & 1 == 0 { self.min.x } else { self.max.x },
+                if i & 2 == 0 { self.min.y } else { self.max.y },
+                if i & 4 == 0 { self.min.z } else { self.max.z },
+            )
+        })
+    }
+}
+
+/// Bounding box of a primitive in its own frame.
+fn local_box(shape: &AuthoringShape) -> Option<Aabb> {
+    let half = match shape {
+        AuthoringShape::Sphere(s) => DVec3::splat(s.radius),
+        AuthoringShape::Box(b) => DVec3::new(b.length, b.width, b.height) / 2.0,
+        AuthoringShape::Cylinder(c) => DVec3::new(c.radius, c.radius, c.height / 2.0),
+        AuthoringShape::Cone(c) => DVec3::new(c.radius, c.radius, c.height / 2.0),
+        AuthoringShape::Extrude { profile, length, .. } => {
+            let (hx, hy) = profile.half_extents();
+            DVec3::new(hx, hy, length / 2.0)
+        }
+        AuthoringShape::Mesh(m) => {
+            let (min, max) = m.bounds()?;
+            return Some(Aabb { min: DVec3::from(min), max: DVec3::from(max) });
+        }
+        AuthoringShape::Sweep { .. } => return extent(shape, &Xf::IDENTITY),
+        _ => return None,
+    };
+    Some(Aabb { min: -half, max: half })
+}
+
+/// Box of a disc (centre, axis, radius).
+fn disc_box(centre: DVec3, axis: DVec3, radius: f64) -> Aabb {
+    let reach = DVec3::ONE - axis * axis;
+    let reach = DVec3::new(reach.x.max(0.0).sqrt(), reach.y.max(0.0).sqrt(), reach.z.max(0.0).sqrt()) * radius;
+    Aabb { min: centre - reach, max: centre + reach }
+}
+
+fn placed_extent(child: &Placed) -> Option<Aabb> {
+    extent(&child.shape, &child.xf())
+}
+
+/// Bounding box of the shape after `xf`; `None` when empty.
+fn extent(shape: &AuthoringShape, xf: &Xf) -> Option<Aabb> {
+    let axis = xf.rot * DVec3::Z;
+    match shape {
+        AuthoringShape::Point(_) => Aabb::around([xf.off]),
+        AuthoringShape::Line(l) => {
+            Aabb::around([-0.5, 0.5].map(|s| xf.apply(DVec3::new(s * l.length, 0.0, 0.0))))
+        }
+        AuthoringShape::Plane(p) => Aabb::around(
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                .map(|(sx, sy)| xf.apply(DVec3::new(sx * p.width / 2.0, sy * p.height / 2.0, 0.0))),
+        ),
+        AuthoringShape::Sphere(s) => Some(Aabb { min: xf.off - s.radius, max: xf.off + s.radius }),
+        AuthoringShape::Box(_) => Aabb::around(local_box(shape)?.corners().map(|c| xf.apply(c))),
+        AuthoringShape::Mesh(m) => Aabb::around(m.vertices().iter().map(|v| xf.apply(DVec3::from(*v)))),
+        AuthoringShape::Cylinder(c) => {
+            let half = axis * c.height / 2.0;
+            Some(disc_box(xf.off - half, axis, c.radius).union(&disc_box(xf.off + half, axis, c.radius)))
+        }
+        AuthoringShape::Cone(c) => {
+            let half = axis * c.height / 2.0;
+            let apex = xf.off + half;
+            Some(disc_box(xf.off - half, axis, c.radius).union(&Aabb { min: apex, max: apex }))
+        }
+        AuthoringShape::Extrude { profile, length, .. } => {
+            let half = axis * length / 2.0;
+            if let Profile::Circle { outer_radius, .. } = profile {
+                return Some(
+                    disc_box(xf.off - half, axis, *outer_radius).union(&disc_box(xf.off + half, axis, *outer_radius)),
+                );
+            }
+            Aabb::around(prism_vertices(profile, *length).map(|v| xf.apply(v)))
+        }
+        AuthoringShape::Sweep { profile, path, .. } => {
+            let (points, pad) = sweep::hull(profile, path);
+            let b = Aabb::around(points.into_iter().map(|v| xf.apply(v)))?;
+            Some(Aabb { min: b.min - pad, max: b.max + pad })
+        }
+        AuthoringShape::Union { children } => children
+            .iter()
+            .filter(|c| is_solid(&c.shape))
+            .filter_map(|c| extent(&c.shape, &xf.then(&c.xf())))
+            .reduce(|a, b| a.union(&b)),
+        AuthoringShape::Intersection { children } => {
+            let mut boxes = children
+                .iter()
+                .filter(|c| is_solid(&c.shape))
+                .map(|c| extent(&c.shape, &xf.then(&c.xf())));
+            let first = boxes.next()??;
+            boxes.try_fold(first, |acc, b| acc.intersect(&b?))
+        }
+        AuthoringShape::Difference { outer, .. } => {
+            if is_solid(&outer.shape) { extent(&outer.shape, &xf.then(&outer.xf())) } else { None }
+        }
+    }
+}
+
+/// Outline vertices of a prism at both ends.
+fn prism_vertices(profile: &Profile, length: f64) -> impl Iterator<Item = DVec3> {
+    let outer = profile.outlines().into_iter().next().unwrap_or_default();
+    [-length / 2.0, length / 2.0]
+        .into_iter()
+        .flat_map(move |z| outer.clone().into_iter().map(move |[x, y]| DVec3::new(x, y, z)))
+}
+
+/// Farthest distance from the origin of `xf`'s target frame.
+fn farthest(shape: &AuthoringShape, xf: &Xf) -> f64 {
+    let axis = xf.rot * DVec3::Z;
+    // Farthest point of a circle (centre, radius) square to `axis`
+    let rim = |centre: DVec3, radius: f64| {
+        let across = (centre - axis * centre.dot(axis)).length();
+        (centre.length_squared() + radius * radius + 2.0 * radius * across).sqrt()
+    };
+    let farthest_of = |points: &mut dyn Iterator<Item = DVec3>| points.map(|p| p.length()).fold(0.0, f64::max);
+
+    match shape {
+        AuthoringShape::Sphere(s) => xf.off.length() + s.radius,
+        AuthoringShape::Cylinder(c) => {
+            let half = axis * c.height / 2.0;
+            rim(xf.off - half, c.radius).max(rim(xf.off + half, c.radius))
+        }
+        AuthoringShape::Cone(c) => {
+            let half = axis * c.height / 2.0;
+            rim(xf.off - half, c.radius).max((xf.off + half).length())
+        }
+        AuthoringShape::Extrude { profile: Profile::Circle { outer_radius, .. }, length, .. } => {
+            let half = axis * length / 2.0;
+            rim(xf.off - half, *outer_radius).max(rim(xf.off + half, *outer_radius))
+        }
+        AuthoringShape::Extrude { profile, length, .. } => {
+            farthest_of(&mut prism_vertices(profile, *length).map(|v| xf.apply(v)))
+        }
+        AuthoringShape::Sweep { profile, path, .. } => {
+            let (points, pad) = sweep::hull(profile, path);
+            farthest_of(&mut points.into_iter().map(|v| xf.apply(v))) + pad
+        }
+        AuthoringShape::Box(_) => farthest_of(&mut local_box(shape).into_iter().flat_map(|b| b.corners()).map(|c| xf.apply(c))),
+        AuthoringShape::Mesh(m) => farthest_of(&mut m.vertices().iter().map(|v| xf.apply(DVec3::from(*v)))),
+        AuthoringShape::Point(_) | AuthoringShape::Line(_) | AuthoringShape::Plane(_) => {
+            farthest_of(&mut extent(shape, xf).into_iter().flat_map(|b| b.corners()))
+        }
+        AuthoringShape::Union { children } => children
+            .iter()
+            .filter(|c| is_solid(&c.shape))
+            .map(|c| farthest(&c.shape, &xf.then(&c.xf())))
+            .fold(0.0, f64::max),
+        AuthoringShape::Intersection { children } => {
+            let Some(bounds) = extent(shape, xf) else {
+                return 0.0;
+            };
+            children
+                .iter()
+                .filter(|c| is_solid(&c.shape))
+                .map(|c| farthest(&c.shape, &xf.then(&c.xf())))
+                .fold(farthest_of(&mut bounds.corners().into_iter()), f64::min)
+        }
+        AuthoringShape::Difference { outer, .. } => {
+            if is_solid(&outer.shape) { farthest(&outer.shape, &xf.then(&outer.xf())) } else { 0.0 }
+        }
+    }
+}
+
+/// Enclosing sphere about the shape's origin, and its axis-aligned box.
+pub(crate) fn bounds(shape: &AuthoringShape) -> Bounds {
+    let (min, max) = extent(shape, &Xf::IDENTITY)
+        .map(|b| (b.min.to_array(), b.max.to_array()))
+        .unwrap_or_default();
+    Bounds { radius: farthest(shape, &Xf::IDENTITY), min, max }
+}
+
+// ----------------------------------------------------------------
+// Numeric fallback
+// ----------------------------------------------------------------
+
+/// A surface sample: point, outward normal and the area it stands for.
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    p: DVec3,
+    n: DVec3,
+    area: f64,
+}
+
+/// A `nu` x `nv` grid of samples (row-major); `wrap` joins the last
+/// column to the first.
+struct Patch {
+    nu: usize,
+    nv: usize,
+    wrap: bool,
+    samples: Vec<Sample>,
+}
+
+impl Patch {
+    fn grid(n: usize, wrap: bool, f: impl Fn(f64, f64) -> Sample) -> Self {
+        let samples = (0..n * n)
+            .map(|i| f(((i / n) as f64 + 0.5) / n as f64, ((i % n) as f64 + 0.5) / n as f64))
+            .collect();
+        Self { nu: n, nv: n, wrap, samples }
+    }
+
+    fn neighbours(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        let (u, v) = (i / self.nv, i % self.nv);
+        let up = (u > 0).then(|| i - self.nv);
+        let down = (u + 1 < self.nu).then(|| i + self.nv);
+        let left = if v > 0 { Some(i - 1) } else { self.wrap.then(|| i + self.nv - 1) };
+        let right = if v + 1 < self.nv { Some(i + 1) } else { self.wrap.then(|| i + 1 - self.nv) };
+        [up, down, left, right].into_iter().flatten()
+    }
+}
+
+/// Surface patches of a primitive in its own frame, `n` samples a side.
+fn patches(shape: &AuthoringShape, n: usize) -> Vec<Patch> {
+    let cells = (n * n) as f64;
+    let disc = |radius: f64, z: f64, normal: f64| {
+        Patch::grid(n, true, move |u, v| {
+            let (rho, phi) = (radius * u.sqrt(), v * 2.0 * PI);
+            Sample {
+                p: DVec3::new(rho * phi.cos(), rho * phi.sin(), z),
+                n: DVec3::Z * normal,
+                area: PI * radius * radius / cells,
+            }
+        })
+    };
+
+    match shape {
+        AuthoringShape::Sphere(s) => {
+            let r = s.radius;
+            vec![Patch::grid(n, true, |u, v| {
+                // Equal-area bands (Archimedes)
+                let z = r * (2.0 * u - 1.0);
+                let (rho, phi) = ((r * r - z * z).max(0.0).sqrt(), v * 2.0 * PI);
+                let p = DVec3::new(rho * phi.cos(), rho * phi.sin(), z);
+                Sample { p, n: p / r, area: 4.0 * PI * r * r / cells }
+            })]
+        }
+        AuthoringShape::Box(b) => {
+            let half = DVec3::new(b.length, b.width, b.height) / 2.0;
+            let mut faces = Vec::new();
+            for k in 0..3 {
+                let (i, j) = ((k + 1) % 3, (k + 2) % 3);
+                for sign in [-1.0, 1.0] {
+                    faces.push(Patch::grid(n, false, |u, v| {
+                        let mut p = DVec3::ZERO;
+                        p[k] = sign * half[k];
+                        p[i] = (2.0 * u - 1.0) * half[i];
+                        p[j] = (2.0 * v - 1.0) * half[j];
+                        let mut normal = DVec3::ZERO;
+                        normal[k] = sign;
+                        Sample { p, n: normal, area: 4.0 * half[i] * half[j] / cells }
+                    }));
+                }
+            }
+            faces
+        }
+        AuthoringShape::Cylinder(c) => {
+            let (r, h) = (c.radius, c.height);
+            vec![
+                Patch::grid(n, true, |u, v| {
+                    let phi = v * 2.0 * PI;
+                    let normal = DVec3::new(phi.cos(), phi.sin(), 0.0);
+                    Sample {
+                        p: normal * r + DVec3::Z * (u - 0.5) * h,
+                        n: normal,
+                        area: 2.0 * PI * r * h / cells,
+                    }
+                }),
+                disc(r, -h / 2.0, -1.0),
+                disc(r, h / 2.0, 1.0),
+            ]
+        }
+        AuthoringShape::Cone(c) => {
+            let (r, h) = (c.radius, c.height);
+            let slant = (r * r + h * h).sqrt();
+            vec![
+                Patch::grid(n, true, |u, v| {
+                    // Equal-area rings down from the apex
+                    let (t, phi) = (u.sqrt(), v * 2.0 * PI);
+                    Sample {
+                        p: DVec3::new(r * t * phi.cos(), r * t * phi.sin(), h / 2.0 - h * t),
+                        n: DVec3::new(h * phi.cos(), h * phi.sin(), r) / slant,
+                        area: PI * r * slant / cells,
+                    }
+                }),
+                disc(r, -h / 2.0, -1.0),
+            ]
+        }
+        AuthoringShape::Extrude { profile, length, capped } => {
+            let mut out: Vec<Patch> = profile
+                .outlines()
+                .iter()
+                .map(|outline| {
+                    let walk = Outline::new(outline);
+                    Patch::grid(n, true, |u, v| {
+                        let (x, y, normal) = walk.at(v);
+                        Sample {
+                            p: DVec3::new(x, y, (u - 0.5) * length),
+                            n: normal,
+                            area: walk.perimeter * length / cells,
+                        }
+                    })
+                })
+                .collect();
+
+            if *capped {
+                for sign in [-1.0, 1.0] {
+                    let end = Station { at: DVec3::Z * sign * length / 2.0, rot: DQuat::IDENTITY };
+                    out.push(section_cap(profile, n, end, sign));
+                }
+            }
+            out
+        }
+        AuthoringShape::Sweep { profile, path, capped } => {
+            let mut out = Vec::new();
+            for piece in path.pieces() {
+                for outline in profile.outlines() {
+                    let walk = Outline::new(&outline);
+                    out.push(Patch::grid(n, true, |u, v| {
+                        let (x, y, normal) = walk.at(v);
+                        let (from, to) = piece.fibre(x, y);
+                        let s = from + u * (to - from);
+                        let rot = match piece {
+                            Piece::Straight { start, .. } => start.rot,
+                            Piece::Bend { .. } => piece.station(s).rot,
+                        };
+                        Sample {
+                            p: piece.point(x, y, s),
+                            n: rot * normal,
+                            area: walk.perimeter * (to - from) * piece.stretch(x, y) / cells,
+                        }
+                    }));
+                }
+            }
+            if *capped {
+                let (start, end) = path.ends();
+                out.push(section_cap(profile, n, start, -1.0));
+                out.push(section_cap(profile, n, end, 1.0));
+            }
+            out
+        }
+        AuthoringShape::Mesh(m) => {
+            // Keep the total sample count near a single n x n patch
+            let triangles = m.triangles().len().max(1);
+            let per_side = ((n as f64 / (triangles as f64).sqrt()).ceil() as usize).clamp(2, n);
+            (0..m.triangles().len())
+                .map(|t| {
+                    let [a, b, c] = m.corners(t);
+                    let (normal, area) = (m.face_normal(t), (b - a).cross(c - a).length() / 2.0);
+                    Patch::grid(per_side, false, |u, v| {
+                        // Area-preserving square-to-triangle map
+                        let su = u.sqrt();
+                        Sample {
+                            p: a * (1.0 - su) + b * (su * (1.0 - v)) + c * (su * v),
+                            n: normal,
+                            area: area / (per_side * per_side) as f64,
+                        }
+                    })
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// An end face: the section at `station`, facing forward (`sign` 1) or
+/// back (-1).
+fn section_cap(profile: &Profile, n: usize, station: Station, sign: f64) -> Patch {
+    let (hx, hy) = profile.half_extents();
+    let cells = (n * n) as f64;
+    let mut cap = Patch::grid(n, false, |u, v| {
+        let (x, y) = ((2.0 * u - 1.0) * hx, (2.0 * v - 1.0) * hy);
+        let inside = profile.contains(x, y);
+        Sample {
+            p: station.place(x, y),
+            n: station.forward() * sign,
+            area: if inside { 4.0 * hx * hy / cells } else { 0.0 },
+        }
+    });
+    // Match the exact section area
+    let sampled: f64 = cap.samples.iter().map(|s| s.area).sum();
+    if sampled > 0.0 {
+        let scale = profile.area() / sampled;
+        cap.samples.iter_mut().for_each(|s| s.area *= scale);
+    }
+    cap
+}
+
+/// Arc-length walk around a closed outline.
+struct Outline<'a> {
+    points: &'a [[f64; 2]],
+    cumulative: Vec<f64>,
+    perimeter: f64,
+}
+
+impl<'a> Outline<'a> {
+    fn new(points: &'a [[f64; 2]]) -> Self {
+        let mut cumulative = Vec::with_capacity(points.len() + 1);
+        let mut total = 0.0;
+        cumulative.push(0.0);
+        for (i, a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            total += ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt();
+            cumulative.push(total);
+        }
+        Self { points, cumulative, perimeter: total }
+    }
+
+    /// Point and outward normal at fraction `t` of the perimeter.
+    fn at(&self, t: f64) -> (f64, f64, DVec3) {
+        let s = t * self.perimeter;
+        let i = self.cumulative.partition_point(|&c| c <= s).clamp(1, self.points.len()) - 1;
+        let (a, b) = (self.points[i], self.points[(i + 1) % self.points.len()]);
+        let length = self.cumulative[i + 1] - self.cumulative[i];
+        let f = if length > 0.0 { (s - self.cumulative[i]) / length } else { 0.0 };
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        let normal = DVec3::new(dy, -dx, 0.0).normalize_or_zero();
+        (a[0] + f * dx, a[1] + f * dy, normal)
+    }
+}
+
+fn collect_leaves<'a>(shape: &'a AuthoringShape, xf: Xf, out: &mut Vec<(&'a AuthoringShape, Xf)>) {
+    match shape {
+        AuthoringShape::Union { children } | AuthoringShape::Intersection { children } => {
+            for c in children.iter().filter(|c| is_solid(&c.shape)) {
+                collect_leaves(&c.shape, xf.then(&c.xf()), out);
+            }
+        }
+        AuthoringShape::Difference { outer, inner } => {
+            for c in [outer, inner].into_iter().filter(|c| is_solid(&c.shape)) {
+                collect_leaves(&c.shape, xf.then(&c.xf()), out);
+            }
+        }
+        leaf if is_solid(leaf) => out.push((leaf, xf)),
+        _ => {}
+    }
+}
+
+/// Grid estimate for shapes with no closed form.
+///
+/// Volume: membership at the corners of a regular grid over the
+/// bounding box; a cell counts by its fraction of inside corners, and
+/// cells with mixed corners bound the error. Area: every primitive's
+/// surface is sampled on equal-area patches and a sample counts when
+/// the whole shape changes across it (probed just inside and outside);
+/// samples whose neighbours disagree bound the error. Both assume no
+/// feature is thinner than a cell.
+fn numeric(shape: &AuthoringShape, options: &CsgOptions) -> Measure {
+    let Some(bounds) = extent(shape, &Xf::IDENTITY) else {
+        return Measure::default();
+    };
+    let size = bounds.size();
+    let longest = size.max_element();
+    if longest <= 0.0 {
+        return Measure::default();
+    }
+    let n = options.resolution.max(4);
+
+    // Volume
+    let h = longest / n as f64;
+    let counts = size.to_array().map(|s| ((s / h).ceil() as usize).max(1));
+    let [cx, cy, cz] = counts;
+    let corner = |i: usize, j: usize, k: usize| i + (cx + 1) * (j + (cy + 1) * k);
+    let mut inside = vec![false; (cx + 1) * (cy + 1) * (cz + 1)];
+    for k in 0..=cz {
+        for j in 0..=cy {
+            for i in 0..=cx {
+                let p = bounds.min + DVec3::new(i as f64, j as f64, k as f64) * h;
+                inside[corner(i, j, k)] = contains(shape, p);
+            }
+        }
+    }
+
+    let cell = h * h * h;
+    let (mut volume, mut volume_error) = (0.0, 0.0);
+    for k in 0..cz {
+        for j in 0..cy {
+            for i in 0..cx {
+                let hits = (0..8)
+                    .filter(|c| inside[corner(i + (c & 1), j + ((c >> 1) & 1), k + ((c >> 2) & 1))])
+                    .count();
+                volume += cell * hits as f64 / 8.0;
+                if hits > 0 && hits < 8 {
+                    volume_error += cell * hits.max(8 - hits) as f64 / 8.0;
+                }
+            }
+        }
+    }
+
+    // Surface area
+    let probe = PROBE_EPS * size.length();
+    let mut leaves = Vec::new();
+    collect_leaves(shape, Xf::IDENTITY, &mut leaves);
+
+    let (mut area, mut area_error) = (0.0, 0.0);
+    for (index, (leaf, xf)) in leaves.iter().enumerate() {
+        for patch in patches(leaf, n) {
+            let kept: Vec<Option<bool>> = patch
+                .samples
+                .iter()
+                .map(|s| {
+                    if s.area <= 0.0 {
+                        return None;
+                    }
+                    let (p, normal) = (xf.apply(s.p), (xf.rot * s.n).normalize_or_zero());
+                    let (below, above) = (p - normal * probe, p + normal * probe);
+                    let boundary = contains(shape, below) != contains(shape, above);
+                    // A face shared with an earlier primitive counts once
+                    let shared = leaves[..index]
+                        .iter()
+                        .any(|(other, oxf)| contains(other, oxf.local(below)) != contains(other, oxf.local(above)));
+                    Some(boundary && !shared)
+                })
+                .collect();
+
+            for (i, (sample, status)) in patch.samples.iter().zip(&kept).enumerate() {
+                let Some(status) = status else { continue };
+                if *status {
+                    area += sample.area;
+                }
+                if patch.neighbours(i).any(|j| kept[j].is_some_and(|other| other != *status)) {
+                    area_error += sample.area;
+                }
+            }
+        }
+    }
+
+    Measure { volume, volume_error, surface_area: area, surface_area_error: area_error }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::geospec::primitives::{BoxShape, Cylinder, Point, Sphere};
+
+    fn sphere(radius: f64) -> AuthoringShape {
+        AuthoringShape::Sphere(Sphere { radius })
+    }
+
+    fn cube(side: f64) -> AuthoringShape {
+        AuthoringShape::Box(BoxShape { length: side, width: side, height: side })
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn analytic_booleans_handle_overlap_and_placement() {
+        let opts = CsgOptions::default();
+
+        // Unit cubes overlapping by half: a 1.5 x 1 x 1 box
+        let union = AuthoringShape::Union {
+            children: vec![Placed::new(cube(1.0)), Placed::new(cube(1.0)).at(0.5, 0.0, 0.0)],
+        };
+        let m = measure(&union, &opts);
+        assert!(m.is_exact() && close(m.volume, 1.5) && close(m.surface_area, 8.0));
+
+        let inter = AuthoringShape::Intersection {
+            children: vec![Placed::new(cube(1.0)), Placed::new(cube(1.0)).at(0.5, 0.0, 0.0)],
+        };
+        let m = measure(&inter, &opts);
+        assert!(close(m.volume, 0.5) && close(m.surface_area, 4.0));
+
+        // A quarter turn keeps a box axis-aligned
+        let diff = AuthoringShape::Difference {
+            outer: Box::new(cube(1.0).into()),
+            inner: Box::new(Placed::new(cube(1.0)).at(0.5, 0.0, 0.0).rotated([0.0, 0.0, 1.0], 90.0)),
+        };
+        let m = measure(&diff, &opts);
+        assert!(m.is_exact() && close(m.volume, 0.5) && close(m.surface_area, 4.0));
+
+        // Spheres of radius 1, centres 1 apart
+        let lens_cap = PI * 0.5f64.powi(2) * (3.0 - 0.5) / 3.0;
+        let spheres = AuthoringShape::Union {
+            children: vec![Placed::new(sphere(1.0)), Placed::new(sphere(1.0)).at(1.0, 0.0, 0.0)],
+        };
+        let m = measure(&spheres, &opts);
+        assert!(close(m.volume, 8.0 / 3.0 * PI - 2.0 * lens_cap));
+        assert!(close(m.surface_area, 8.0 * PI - 2.0 * PI));
+
+        // Drilled through along x after a rotation
+        let drilled = AuthoringShape::Difference {
+            outer: Box::new(AuthoringShape::Box(BoxShape { length: 2.0, width: 1.0, height: 1.0 }).into()),
+            inner: Box::new(
+                Placed::new(AuthoringShape::Cylinder(Cylinder { radius: 0.25, height: 3.0 }))
+                    .rotated([0.0, 1.0, 0.0], 90.0),
+            ),
+        };
+        let m = measure(&drilled, &opts);
+        let disc = PI * 0.0625;
+        assert!(m.is_exact() && close(m.volume, 2.0 - disc * 2.0));
+        assert!(close(m.surface_area, 10.0 - 2.0 * disc + 2.0 * PI * 0.25 * 2.0));
+
+        // Sealed cavity; disjoint children
+        let cavity = AuthoringShape::Difference { outer: Box::new(cube(2.0).into()), inner: Box::new(sphere(0.5).into()) };
+        let m = measure(&cavity, &opts);
+        assert!(close(m.volume, 8.0 - PI / 6.0) && close(m.surface_area, 24.0 + PI));
+
+        let apart = AuthoringShape::Union {
+            children: vec![Placed::new(sphere(1.0)).at(-3.0, 0.0, 0.0), Placed::new(sphere(1.0)).at(3.0, 0.0, 0.0)],
+        };
+        let b = bounds(&apart);
+        assert!(close(b.radius, 4.0));
+        assert_eq!((b.min, b.max), ([-4.0, -1.0, -1.0], [4.0, 1.0, 1.0]));
+        assert!(close(measure(&apart, &opts).volume, 8.0 / 3.0 * PI));
+
+        // Plain children still parse
+        let parsed: AuthoringShape = serde_json::from_str(
+            r#"{"type":"union","children":[{"type":"sphere","radius":1.0},
+                {"type":"box","length":1,"width":1,"height":1,"placement":{"offset":[5,0,0]}}]}"#,
+        )
+        .unwrap();
+        let AuthoringShape::Union { children } = &parsed else { panic!("not a union") };
+        assert_eq!(children[1].placement.offset, [5.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn numeric_estimate_brackets_exact_value() {
+        let spheres = AuthoringShape::Union {
+            children: vec![Placed::new(sphere(1.0)), Placed::new(sphere(1.0)).at(1.0, 0.0, 0.0)],
+        };
+        let exact = measure(&spheres, &CsgOptions::default());
+        let estimate = numeric(&spheres, &CsgOptions::default());
+
+        assert!(!estimate.is_exact());
+        assert!((estimate.volume - exact.volume).abs() <= estimate.volume_error);
+        assert!((estimate.surface_area - exact.surface_area).abs() <= estimate.surface_area_error);
+        assert!(estimate.volume_error < 0.2 * exact.volume);
+        assert!(estimate.surface_area_error < 0.2 * exact.surface_area);
+
+        // Deterministic
+        assert_eq!(estimate, numeric(&spheres, &CsgOptions::default()));
+    }
+
+    #[test]
+    fn degenerate_children_are_handled_exactly() {
+        let opts = CsgOptions::default();
+        let exact = |shape: &AuthoringShape, volume: f64, area: f64| {
+            let m = measure(shape, &opts);
+            assert!(m.is_exact(), "{shape:?} estimated: {m:?}");
+            assert!(close(m.volume, volume) && close(m.surface_area, area), "{shape:?}: {m:?}");
+        };
+        let union = |children: Vec<Placed>| AuthoringShape::Union { children };
+        let intersection = |children: Vec<Placed>| AuthoringShape::Intersection { children };
+        let difference = |outer: Placed, inner: Placed| AuthoringShape::Difference {
+            outer: Box::new(outer),
+            inner: Box::new(inner),
+        };
+
+        // Zero-size children add and remove nothing, wherever they sit
+        for at in [0.0, 0.5, 5.0] {
+            exact(&union(vec![cube(1.0).into(), Placed::new(cube(0.0)).at(at, 0.0, 0.0)]), 1.0, 6.0);
+            exact(&difference(cube(1.0).into(), Placed::new(sphere(0.0)).at(at, 0.0, 0.0)), 1.0, 6.0);
+        }
+        exact(&union(vec![cube(0.0).into(), sphere(0.0).into()]), 0.0, 0.0);
+        exact(&intersection(vec![cube(1.0).into(), cube(0.0).into()]), 0.0, 0.0);
+        exact(&difference(cube(0.0).into(), cube(1.0).into()), 0.0, 0.0);
+
+        // Coincident children: union and intersection are either one,
+        // the difference is empty
+        for shape in [cube(1.0), sphere(1.0)] {
+            let m = measure(&shape, &opts);
+            let twins = || vec![Placed::new(shape.clone()), Placed::new(shape.clone())];
+            exact(&union(twins()), m.volume, m.surface_area);
+            exact(&intersection(twins()), m.volume, m.surface_area);
+            exact(&difference(shape.clone().into(), shape.clone().into()), 0.0, 0.0);
+        }
+
+        // Face-to-face boxes merge; edge-to-edge ones only touch
+        exact(&union(vec![Placed::new(cube(1.0)).at(-0.5, 0.0, 0.0), Placed::new(cube(1.0)).at(0.5, 0.0, 0.0)]), 2.0, 10.0);
+        exact(&union(vec![Placed::new(cube(1.0)).at(-0.5, -0.5, 0.0), Placed::new(cube(1.0)).at(0.5, 0.5, 0.0)]), 2.0, 12.0);
+        exact(&intersection(vec![Placed::new(cube(1.0)).at(-0.5, 0.0, 0.0), Placed::new(cube(1.0)).at(0.5, 0.0, 0.0)]), 0.0, 0.0);
+
+        // No solid children at all
+        exact(&union(vec![]), 0.0, 0.0);
+        exact(&intersection(vec![]), 0.0, 0.0);
+        exact(&union(vec![AuthoringShape::Point(Point).into()]), 0.0, 0.0);
+    }
+}
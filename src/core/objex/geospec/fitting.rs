@@ -0,0 +1,612 @@
+//! Pipe fittings, and runs built from them one segment at a time.
+//!
+//! A fitting is laid out from its inlet: the inlet face is centred on
+//! the origin and flow leaves along +z. Shapes are the fitting's walls;
+//! the bore is empty. Bodies are butt-weld style, with the pipe's own OD
+//! and wall, except the coupling, which is a sleeve. Each fitting owns
+//! its stretch of the flow path, so internal volumes and wetted areas
+//! add up along a run without double counting.
+//!
+//! Material volume, internal volume and wetted area are exact. The tee
+//! evaluates its branch junction by quadrature.
+
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use glam::{DQuat, DVec3};
+use serde::{Deserialize, Serialize};
+
+use crate::core::objex::geospec::authoring::AuthoringShape;
+use crate::core::objex::geospec::csg::{Placed, Placement};
+use crate::core::objex::geospec::mesh::Mesh;
+use crate::core::objex::geospec::pipe::{PipeError, PipeSize};
+use crate::core::objex::geospec::primitives::Cylinder;
+use crate::core::objex::geospec::profile::Profile;
+use crate::core::objex::geospec::sweep::SweepPath;
+use crate::core::objex::matcat::materials::MatCatId;
+
+/// Segments around a reducer's mesh.
+const REDUCER_SEGMENTS: usize = 96;
+
+/// Dimensions are checked by the constructors and on load; a fitting
+/// built by hand is checked by `validate` (and by `shape`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", try_from = "FittingSpec")]
+pub enum Fitting {
+    /// Straight length of pipe.
+    Pipe { size: PipeSize, length: f64 },
+    /// Bend of `degrees` at centreline `radius`, curving toward +x.
+    Elbow { size: PipeSize, degrees: f64, radius: f64 },
+    /// Sleeve over a joint: the pipe's bore, one wall proud of its
+    /// outside, one OD long.
+    Coupling { size: PipeSize },
+    /// Concentric cone from `from` to `to`, as long as both ODs together.
+    Reducer { from: PipeSize, to: PipeSize },
+    /// Run straight through, with a branch toward +x at the middle.
+    /// Every end is one run OD from the centre.
+    Tee { run: PipeSize, branch: PipeSize },
+}
+
+/// Serialized form, validated on load.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum FittingSpec {
+    Pipe { size: PipeSize, length: f64 },
+    Elbow { size: PipeSize, degrees: f64, radius: f64 },
+    Coupling { size: PipeSize },
+    Reducer { from: PipeSize, to: PipeSize },
+    Tee { run: PipeSize, branch: PipeSize },
+}
+
+impl TryFrom<FittingSpec> for Fitting {
+    type Error = PipeError;
+
+    fn try_from(spec: FittingSpec) -> Result<Self, Self::Error> {
+        let fitting = match spec {
+            FittingSpec::Pipe { size, length } => Fitting::Pipe { size, length },
+            FittingSpec::Elbow { size, degrees, radius } => Fitting::Elbow { size, degrees, radius },
+            FittingSpec::Coupling { size } => Fitting::Coupling { size },
+            FittingSpec::Reducer { from, to } => Fitting::Reducer { from, to },
+            FittingSpec::Tee { run, branch } => Fitting::Tee { run, branch },
+        };
+        fitting.validate()?;
+        Ok(fitting)
+    }
+}
+
+impl Fitting {
+    pub fn pipe(size: PipeSize, length: f64) -> Result<Self, PipeError> {
+        let pipe = Fitting::Pipe { size, length };
+        pipe.validate()?;
+        Ok(pipe)
+    }
+
+    /// Elbow at the size's standard bend radius.
+    pub fn elbow(size: PipeSize, degrees: f64) -> Result<Self, PipeError> {
+        let elbow = Fitting::Elbow { size, degrees, radius: size.bend_radius() };
+        elbow.validate()?;
+        Ok(elbow)
+    }
+
+    pub fn coupling(size: PipeSize) -> Self {
+        Fitting::Coupling { size }
+    }
+
+    pub fn reducer(from: PipeSize, to: PipeSize) -> Self {
+        Fitting::Reducer { from, to }
+    }
+
+    /// The branch may be smaller than the run but not larger, inside or
+    /// out.
+    pub fn tee(run: PipeSize, branch: PipeSize) -> Result<Self, PipeError> {
+        let tee = Fitting::Tee { run, branch };
+        tee.validate()?;
+        Ok(tee)
+    }
+
+    /// Positive, finite lengths; elbows above 0° and below 180°, bent
+    /// wider than the pipe; tee branches no larger than their run.
+    pub fn validate(&self) -> Result<(), PipeError> {
+        match self {
+            Fitting::Pipe { length, .. } if !(length.is_finite() && *length > 0.0) => Err(PipeError::Length(*length)),
+            Fitting::Elbow { degrees, .. } if !(*degrees > 0.0 && *degrees < 180.0) => Err(PipeError::Angle(*degrees)),
+            Fitting::Elbow { size, radius, .. } if !(radius.is_finite() && *radius > size.outer_diameter() / 2.0) => {
+                Err(PipeError::BendRadius { radius: *radius, min: size.outer_diameter() / 2.0 })
+            }
+            Fitting::Tee { run, branch }
+                if branch.outer_diameter() > run.outer_diameter() || branch.inner_diameter() > run.inner_diameter() =>
+            {
+                Err(PipeError::BranchTooLarge { run: run.nominal().to_string(), branch: branch.nominal().to_string() })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn inlet(&self) -> PipeSize {
+        match self {
+            Fitting::Pipe { size, .. } | Fitting::Elbow { size, .. } | Fitting::Coupling { size } => *size,
+            Fitting::Reducer { from, .. } => *from,
+            Fitting::Tee { run, .. } => *run,
+        }
+    }
+
+    pub fn material(&self) -> MatCatId {
+        self.inlet().material()
+    }
+
+    /// Size and frame (relative to the inlet) where flow leaves; a tee's
+    /// run outlet.
+    pub fn outlet(&self) -> (PipeSize, Placement) {
+        match self {
+            Fitting::Pipe { size, length } => (*size, Placement::at(0.0, 0.0, *length)),
+            Fitting::Elbow { size, degrees, radius } => {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                (*size, Placement::at(radius * (1.0 - cos), 0.0, radius * sin).rotated([0.0, 1.0, 0.0], *degrees))
+            }
+            Fitting::Coupling { size } => (*size, Placement::at(0.0, 0.0, size.outer_diameter())),
+            Fitting::Reducer { to, .. } => (*to, Placement::at(0.0, 0.0, self.reducer_length())),
+            Fitting::Tee { run, .. } => (*run, Placement::at(0.0, 0.0, 2.0 * run.outer_diameter())),
+        }
+    }
+
+    /// A tee's branch outlet, relative to the inlet.
+    pub fn branch(&self) -> Option<(PipeSize, Placement)> {
+        let Fitting::Tee { run, branch } = self else {
+            return None;
+        };
+        let c = run.outer_diameter();
+        Some((*branch, Placement::at(c, 0.0, c).rotated([0.0, 1.0, 0.0], 90.0)))
+    }
+
+    /// Length of the flow path through the fitting (a tee's run).
+    pub fn length(&self) -> f64 {
+        match self {
+            Fitting::Pipe { length, .. } => *length,
+            Fitting::Elbow { degrees, radius, .. } => radius * degrees.to_radians(),
+            Fitting::Coupling { size } => size.outer_diameter(),
+            Fitting::Reducer { .. } => self.reducer_length(),
+            Fitting::Tee { run, .. } => 2.0 * run.outer_diameter(),
+        }
+    }
+
+    /// The fitting's walls; fails for a fitting `validate` rejects.
+    pub fn shape(&self) -> Result<AuthoringShape, PipeError> {
+        self.validate()?;
+        let straight = |profile: Profile, length: f64| -> Result<AuthoringShape, PipeError> {
+            Ok(AuthoringShape::Sweep { profile, path: SweepPath::straight(length)?, capped: true })
+        };
+        Ok(match self {
+            Fitting::Pipe { size, length } => straight(size.profile(), *length)?,
+            Fitting::Elbow { size, degrees, radius } => AuthoringShape::Sweep {
+                profile: size.profile(),
+                path: SweepPath::arc(*radius, *degrees)?,
+                capped: true,
+            },
+            Fitting::Coupling { size } => {
+                let sleeve = Profile::Circle {
+                    outer_radius: size.outer_diameter() / 2.0 + size.wall(),
+                    inner_radius: Some(size.inner_diameter() / 2.0),
+                };
+                straight(sleeve, size.outer_diameter())?
+            }
+            Fitting::Reducer { from, to } => {
+                let length = self.reducer_length();
+                let outline = [
+                    [from.inner_diameter() / 2.0, 0.0],
+                    [from.outer_diameter() / 2.0, 0.0],
+                    [to.outer_diameter() / 2.0, length],
+                    [to.inner_diameter() / 2.0, length],
+                ];
+                let mesh = Mesh::revolve(&outline, REDUCER_SEGMENTS)
+                    .ok_or_else(|| PipeError::Reducer { from: from.nominal().to_string(), to: to.nominal().to_string() })?;
+                AuthoringShape::Mesh(mesh)
+            }
+            Fitting::Tee { run, branch } => {
+                let c = run.outer_diameter();
+                // Run along z through (0, 0, c), branch along x from the run's axis
+                let tube = |d_run: f64, d_branch: f64| AuthoringShape::Union {
+                    children: vec![
+                        Placed::new(AuthoringShape::Cylinder(Cylinder { radius: d_run / 2.0, height: 2.0 * c }))
+                            .at(0.0, 0.0, c),
+                        Placed::new(AuthoringShape::Cylinder(Cylinder { radius: d_branch / 2.0, height: c }))
+                            .rotated([0.0, 1.0, 0.0], 90.0)
+                            .at(c / 2.0, 0.0, c),
+                    ],
+                };
+                AuthoringShape::Difference {
+                    outer: Box::new(tube(run.outer_diameter(), branch.outer_diameter()).into()),
+                    inner: Box::new(tube(run.inner_diameter(), branch.inner_diameter()).into()),
+                }
+            }
+        })
+    }
+
+    /// Volume of the walls, m³.
+    pub fn material_volume(&self) -> f64 {
+        match self {
+            Fitting::Reducer { from, to } => {
+                let length = self.reducer_length();
+                frustum(from.outer_diameter() / 2.0, to.outer_diameter() / 2.0, length)
+                    - frustum(from.inner_diameter() / 2.0, to.inner_diameter() / 2.0, length)
+            }
+            Fitting::Tee { run, branch } => {
+                tee_bore(run.outer_diameter() / 2.0, branch.outer_diameter() / 2.0, run.outer_diameter())
+                    - self.internal_volume()
+            }
+            Fitting::Coupling { size } => {
+                let outer = size.outer_diameter() / 2.0 + size.wall();
+                (PI * outer * outer - size.bore_area()) * self.length()
+            }
+            Fitting::Pipe { size, .. } | Fitting::Elbow { size, .. } => {
+                PI * (size.outer_diameter() / 2.0).powi(2) * self.length() - self.internal_volume()
+            }
+        }
+    }
+
+    /// Volume of the bore, m³.
+    pub fn internal_volume(&self) -> f64 {
+        match self {
+            Fitting::Reducer { from, to } => {
+                frustum(from.inner_diameter() / 2.0, to.inner_diameter() / 2.0, self.reducer_length())
+            }
+            Fitting::Tee { run, branch } => {
+                tee_bore(run.inner_diameter() / 2.0, branch.inner_diameter() / 2.0, run.outer_diameter())
+            }
+            _ => self.inlet().bore_area() * self.length(),
+        }
+    }
+
+    /// Area of the bore's wall, m².
+    pub fn wetted_area(&self) -> f64 {
+        match self {
+            Fitting::Reducer { from, to } => {
+                let (r0, r1) = (from.inner_diameter() / 2.0, to.inner_diameter() / 2.0);
+                PI * (r0 + r1) * ((r0 - r1).powi(2) + self.reducer_length().powi(2)).sqrt()
+            }
+            Fitting::Tee { run, branch } => {
+                let (r1, r2, c) = (run.inner_diameter() / 2.0, branch.inner_diameter() / 2.0, run.outer_diameter());
+                // Run wall less the branch opening; branch wall from the
+                // run's bore out to its end
+                let opening = simpson(
+                    |t| {
+                        let d = (r1 * r1 - (r2 * t.sin()).powi(2)).sqrt();
+                        if d > 0.0 { 2.0 * r1 * (r2 * t.cos()).powi(2) / d } else { 0.0 }
+                    },
+                    -FRAC_PI_2,
+                    FRAC_PI_2,
+                );
+                let branch_wall =
+                    TAU * r2 * c - r2 * simpson(|t| (r1 * r1 - (r2 * t.cos()).powi(2)).max(0.0).sqrt(), 0.0, TAU);
+                TAU * r1 * 2.0 * c - opening + branch_wall
+            }
+            _ => PI * self.inlet().inner_diameter() * self.length(),
+        }
+    }
+
+    fn reducer_length(&self) -> f64 {
+        match self {
+            Fitting::Reducer { from, to } => from.outer_diameter() + to.outer_diameter(),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Volume of a cone frustum.
+fn frustum(r0: f64, r1: f64, length: f64) -> f64 {
+    PI * length / 3.0 * (r0 * r0 + r0 * r1 + r1 * r1)
+}
+
+/// Volume of a tee's solid: a run cylinder (radius `r1`, length `2c`)
+/// and a branch (radius `r2` <= `r1`) from its axis out to `c`.
+fn tee_bore(r1: f64, r2: f64, c: f64) -> f64 {
+    // Part of the branch inside the run, y = r2 sin t across the branch
+    let shared = simpson(
+        |t| 2.0 * (r2 * t.cos()).powi(2) * (r1 * r1 - (r2 * t.sin()).powi(2)).max(0.0).sqrt(),
+        -FRAC_PI_2,
+        FRAC_PI_2,
+    );
+    PI * r1 * r1 * 2.0 * c + PI * r2 * r2 * c - shared
+}
+
+/// Composite Simpson's rule over [a, b].
+fn simpson(f: impl Fn(f64) -> f64, a: f64, b: f64) -> f64 {
+    const N: usize = 512;
+    let h = (b - a) / N as f64;
+    let inner: f64 = (1..N).map(|i| f(a + i as f64 * h) * if i % 2 == 1 { 4.0 } else { 2.0 }).sum();
+    (f(a) + inner + f(b)) * h / 3.0
+}
+
+// ----------------------------------------------------------------
+// Runs
+// ----------------------------------------------------------------
+
+/// Fittings laid end to end from an inlet.
+///
+/// Each step places a fitting on the current outlet and moves the
+/// outlet to the fitting's far end. Consecutive pipe and elbows of one
+/// size become a single sweep in `shape()`, so a plain run measures
+/// exactly.
+#[derive(Debug, Clone)]
+pub struct PipeRun {
+    size: PipeSize,
+    at: DVec3,
+    rot: DQuat,
+    fittings: Vec<(Fitting, Placement)>,
+    branches: Vec<(PipeSize, Placement)>,
+}
+
+impl PipeRun {
+    /// A run starting at the origin, heading along +z.
+    pub fn new(size: PipeSize) -> Self {
+        Self::starting_at(size, Placement::default())
+    }
+
+    /// A run starting at `inlet`, e.g. a tee's branch.
+    pub fn starting_at(size: PipeSize, inlet: Placement) -> Self {
+        let (at, rot) = inlet.frame();
+        Self { size, at, rot, fittings: Vec::new(), branches: Vec::new() }
+    }
+
+    pub fn pipe(self, length: f64) -> Result<Self, PipeError> {
+        let pipe = Fitting::pipe(self.size, length)?;
+        Ok(self.then(pipe, 0.0))
+    }
+
+    /// Turn `degrees`, toward the run's x axis turned `roll` degrees
+    /// about the direction of flow.
+    pub fn elbow(self, degrees: f64, roll: f64) -> Result<Self, PipeError> {
+        let elbow = Fitting::elbow(self.size, degrees)?;
+        Ok(self.then(elbow, roll))
+    }
+
+    pub fn coupling(self) -> Self {
+        let coupling = Fitting::coupling(self.size);
+        self.then(coupling, 0.0)
+    }
+
+    pub fn reducer(self, to: PipeSize) -> Self {
+        let reducer = Fitting::reducer(self.size, to);
+        self.then(reducer, 0.0)
+    }
+
+    /// Tee whose branch leaves toward the run's x axis turned `roll`
+    /// degrees; the run carries straight on. See `branches`.
+    pub fn tee(self, branch: PipeSize, roll: f64) -> Result<Self, PipeError> {
+        let tee = Fitting::tee(self.size, branch)?;
+        Ok(self.then(tee, roll))
+    }
+
+    fn then(mut self, fitting: Fitting, roll: f64) -> Self {
+        let rot = self.rot * DQuat::from_rotation_z(roll.to_radians());
+        let inlet = Placement::from_frame(self.at, rot);
+        let place = |local: Placement| {
+            let (offset, turn) = local.frame();
+            Placement::from_frame(self.at + rot * offset, rot * turn)
+        };
+
+        if let Some((size, port)) = fitting.branch() {
+            self.branches.push((size, place(port)));
+        }
+        let (size, outlet) = fitting.outlet();
+        let (at, next) = place(outlet).frame();
+        // Undo the roll so straight fittings keep the run's frame
+        self.rot = if roll == 0.0 { next } else { next * DQuat::from_rotation_z(-roll.to_radians()) };
+        self.at = at;
+        self.size = size;
+        self.fittings.push((fitting, inlet));
+        self
+    }
+
+    /// Size at the current outlet.
+    pub fn size(&self) -> PipeSize {
+        self.size
+    }
+
+    /// Where the next fitting goes.
+    pub fn outlet(&self) -> Placement {
+        Placement::from_frame(self.at, self.rot)
+    }
+
+    /// Each fitting with its inlet placement.
+    pub fn fittings(&self) -> &[(Fitting, Placement)] {
+        &self.fittings
+    }
+
+    /// Tee branch outlets, to start further runs from.
+    pub fn branches(&self) -> &[(PipeSize, Placement)] {
+        &self.branches
+    }
+
+    /// Flow path length along the run, m.
+    pub fn length(&self) -> f64 {
+        self.fittings.iter().map(|(f, _)| f.length()).sum()
+    }
+
+    pub fn material_volume(&self) -> f64 {
+        self.fittings.iter().map(|(f, _)| f.material_volume()).sum()
+    }
+
+    pub fn internal_volume(&self) -> f64 {
+        self.fittings.iter().map(|(f, _)| f.internal_volume()).sum()
+    }
+
+    pub fn wetted_area(&self) -> f64 {
+        self.fittings.iter().map(|(f, _)| f.wetted_area()).sum()
+    }
+
+    /// The whole run in the run's frame.
+    pub fn shape(&self) -> Result<AuthoringShape, PipeError> {
+        // (size, path points so far, end of the stretch)
+        let mut stretch: Option<(PipeSize, Vec<DVec3>, DVec3)> = None;
+        let mut children = Vec::new();
+        let flush = |stretch: &mut Option<(PipeSize, Vec<DVec3>, DVec3)>,
+                     children: &mut Vec<Placed>|
+         -> Result<(), PipeError> {
+            if let Some((size, mut points, end)) = stretch.take() {
+                points.push(end);
+                let points = points.iter().map(|p| p.to_array()).collect();
+                let path = SweepPath::new(points, size.bend_radius())?;
+                children.push(Placed::new(AuthoringShape::Sweep { profile: size.profile(), path, capped: true }));
+            }
+            Ok(())
+        };
+
+        for (fitting, inlet) in &self.fittings {
+            let (at, rot) = inlet.frame();
+            let corners: Vec<DVec3> = match fitting {
+                Fitting::Pipe { .. } => Vec::new(),
+                Fitting::Elbow { size, degrees, radius } if *radius == size.bend_radius() => {
+                    let arc = SweepPath::arc(*radius, *degrees)?;
+                    let points = arc.points();
+                    points[1..points.len() - 1].iter().map(|p| at + rot * DVec3::from(*p)).collect()
+                }
+                _ => {
+                    flush(&mut stretch, &mut children)?;
+                    children.push(Placed { shape: fitting.shape()?, placement: *inlet });
+                    continue;
+                }
+            };
+
+            if stretch.as_ref().is_some_and(|(size, ..)| *size != fitting.inlet()) {
+                flush(&mut stretch, &mut children)?;
+            }
+            let (_, points, end) = stretch.get_or_insert_with(|| (fitting.inlet(), vec![at], at));
+            points.extend(corners);
+            let (offset, _) = fitting.outlet().1.frame();
+            *end = at + rot * offset;
+        }
+        flush(&mut stretch, &mut children)?;
+
+        Ok(match children.len() {
+            1 if children[0].placement.is_identity() => children.remove(0).shape,
+            _ => AuthoringShape::Union { children },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::geospec::pipe::PipeSystem;
+    use crate::core::objex::geospec::traits::{SurfaceArea, Volume};
+
+    fn close(a: f64, b: f64, rel: f64) -> bool {
+        (a - b).abs() <= rel * a.abs().max(b.abs())
+    }
+
+    #[test]
+    fn catalog_fittings_and_runs_measure_consistently() {
+        let two = PipeSystem::NpsSch40.size("2").unwrap();
+        assert!(close(two.outer_diameter(), 2.375 * 0.0254, 1e-12));
+        assert!(close(two.inner_diameter(), 2.067 * 0.0254, 1e-12));
+        assert!(close(PipeSystem::CopperL.size("1/2\"").unwrap().wall(), 0.040 * 0.0254, 1e-12));
+        assert!(close(PipeSystem::Pex.size("1").unwrap().wall(), 0.125 * 0.0254, 1e-9));
+        assert!(PipeSystem::CopperM.size("1/4").is_err());
+        assert_eq!(PipeSystem::Emt.material(), MatCatId::steel_lowcarbon());
+        let json = serde_json::to_string(&two).unwrap();
+        assert_eq!(serde_json::from_str::<PipeSize>(&json).unwrap(), two);
+
+        // Straight fittings match their sweeps exactly
+        for fitting in [Fitting::pipe(two, 1.5).unwrap(), Fitting::elbow(two, 90.0).unwrap(), Fitting::coupling(two)] {
+            let spec = fitting.shape().unwrap().compile();
+            assert!(close(spec.volume, fitting.material_volume(), 1e-9));
+            let ends = 2.0 * (PI * (fitting.inlet().outer_diameter() / 2.0).powi(2) - fitting.inlet().bore_area());
+            assert!(spec.surface_area > fitting.wetted_area() + ends);
+        }
+
+        // A reducer's mesh converges on its frustums
+        let one = PipeSystem::NpsSch40.size("1").unwrap();
+        let reducer = Fitting::reducer(two, one);
+        assert!(close(reducer.shape().unwrap().volume(), reducer.material_volume(), 2e-3));
+
+        // Equal tee: the branch takes half a Steinmetz solid out of the run
+        let tee = Fitting::tee(two, two).unwrap();
+        let (r, c) = (two.inner_diameter() / 2.0, two.outer_diameter());
+        assert!(close(tee.internal_volume(), PI * r * r * 3.0 * c - 8.0 / 3.0 * r.powi(3), 1e-9));
+        let spec = tee.shape().unwrap().compile();
+        assert!((spec.volume - tee.material_volume()).abs() <= spec.volume_error);
+        assert!(Fitting::tee(one, two).is_err());
+
+        // Pipe and elbows of one size become a single exact sweep
+        let run = PipeRun::new(two)
+            .pipe(2.0)
+            .unwrap()
+            .elbow(90.0, 0.0)
+            .unwrap()
+            .pipe(1.0)
+            .unwrap()
+            .elbow(45.0, 90.0)
+            .unwrap()
+            .elbow(45.0, 90.0)
+            .unwrap()
+            .pipe(0.5)
+            .unwrap();
+        let shape = run.shape().unwrap();
+        assert!(matches!(shape, AuthoringShape::Sweep { .. }));
+        assert!(close(shape.volume(), run.material_volume(), 1e-9));
+        let AuthoringShape::Sweep { path, .. } = &shape else { unreachable!() };
+        assert!(close(path.length(), run.length(), 1e-9));
+        // Ends up heading along +y, 2 x 45° rolled a quarter turn
+        let (_, rot) = run.outlet().frame();
+        assert!((rot * DVec3::Z - DVec3::Y).length() < 1e-9);
+        assert!(close(run.internal_volume(), two.bore_area() * run.length(), 1e-12));
+
+        // A tee splits the run and leaves a branch port on the far side
+        let run = PipeRun::new(two).pipe(1.0).unwrap().tee(one, 180.0).unwrap().pipe(1.0).unwrap();
+        let (size, port) = run.branches()[0];
+        let (at, rot) = port.frame();
+        assert_eq!(size, one);
+        assert!((at - DVec3::new(-c, 0.0, 1.0 + c)).length() < 1e-12);
+        assert!((rot * DVec3::Z + DVec3::X).length() < 1e-12);
+        assert!((run.outlet().frame().0 - DVec3::new(0.0, 0.0, 2.0 + 2.0 * c)).length() < 1e-12);
+        assert!(matches!(run.shape().unwrap(), AuthoringShape::Union { children } if children.len() == 3));
+        assert!(close(run.wetted_area(), run.fittings().iter().map(|(f, _)| f.wetted_area()).sum(), 1e-12));
+        assert!(run.wetted_area() > 0.0 && run.shape().unwrap().surface_area() > run.wetted_area());
+    }
+
+    #[test]
+    fn invalid_fittings_are_rejected_on_load_and_by_shape() {
+        let two = PipeSystem::NpsSch40.size("2").unwrap();
+        let one = PipeSystem::NpsSch40.size("1").unwrap();
+        let size = r#"{"system":"nps_sch40","nominal":"2"}"#;
+        let load = |json: String| serde_json::from_str::<Fitting>(&json);
+
+        for json in [
+            format!(r#"{{"type":"pipe","size":{size},"length":0.0}}"#),
+            format!(r#"{{"type":"pipe","size":{size},"length":-1.0}}"#),
+            format!(r#"{{"type":"elbow","size":{size},"degrees":180.0,"radius":0.1}}"#),
+            format!(r#"{{"type":"elbow","size":{size},"degrees":0.0,"radius":0.1}}"#),
+            format!(r#"{{"type":"elbow","size":{size},"degrees":90.0,"radius":0.0}}"#),
+            format!(r#"{{"type":"elbow","size":{size},"degrees":90.0,"radius":-0.1}}"#),
+            format!(r#"{{"type":"elbow","size":{size},"degrees":90.0,"radius":0.01}}"#),
+            format!(r#"{{"type":"tee","run":{{"system":"nps_sch40","nominal":"1"}},"branch":{size}}}"#),
+        ] {
+            assert!(load(json.clone()).is_err(), "{json}");
+        }
+
+        // Valid fittings round-trip
+        for fitting in [Fitting::pipe(two, 1.0).unwrap(), Fitting::elbow(two, 45.0).unwrap(), Fitting::tee(two, one).unwrap()] {
+            let json = serde_json::to_string(&fitting).unwrap();
+            assert_eq!(load(json).unwrap(), fitting);
+        }
+
+        // Built by hand: `shape` reports rather than panics
+        let hand = [
+            (Fitting::Pipe { size: two, length: f64::NAN }, PipeError::Length(f64::NAN)),
+            (Fitting::Elbow { size: two, degrees: 200.0, radius: 0.1 }, PipeError::Angle(200.0)),
+        ];
+        for (fitting, expected) in hand {
+            assert_eq!(fitting.shape().unwrap_err().to_string(), expected.to_string());
+        }
+        let tight = Fitting::Elbow { size: two, degrees: 90.0, radius: 0.0 };
+        assert!(matches!(tight.shape(), Err(PipeError::BendRadius { radius: 0.0, .. })));
+        assert_eq!(Fitting::elbow(two, 180.0), Err(PipeError::Angle(180.0)));
+        assert_eq!(Fitting::pipe(two, f64::INFINITY), Err(PipeError::Length(f64::INFINITY)));
+
+        // An elbow at a non-standard radius stands alone in a run
+        let mut run = PipeRun::new(two).pipe(1.0).unwrap();
+        run.fittings.push((Fitting::Elbow { size: two, degrees: 90.0, radius: 0.5 }, run.outlet()));
+        assert!(matches!(run.shape().unwrap(), AuthoringShape::Union { children } if children.len() == 2));
+        run.fittings.push((Fitting::Elbow { size: two, degrees: 90.0, radius: -1.0 }, run.outlet()));
+        assert!(matches!(run.shape(), Err(PipeError::BendRadius { .. })));
+    }
+}
@@ -1,41 +1,680 @@
-use serde_json::Value;
+//! Inferring shape parameters from partial descriptions.
+//!
+//! Each shape type has a set of quantities (dimensions, volume, surface
+//! area, mass, density) tied together by relations of the form
+//! `output = f(inputs)`, with `f` increasing in every input. Given any
+//! sufficient subset, `infer` fills in the rest:
+//!
+//! 1. Propagation: a relation with exactly one unknown is solved for it,
+//!    in closed form for the output and by bisection for an input.
+//! 2. Shooting: if propagation stalls with two unknowns and two spare
+//!    relations (a cylinder from volume and surface area), one dimension
+//!    is scanned and the leftover relation's residual is root-found:
+//!    sign changes by bisection, tangent (double) roots by minimising
+//!    the residual. Roots that agree within the tolerance are one root;
+//!    more than one is reported as ambiguous rather than guessed, none
+//!    as no solution.
+//! 3. Checking: every relation whose quantities are all known is
+//!    evaluated, and over-constrained inputs that disagree by more than
+//!    the relative tolerance are reported as conflicts.
+//!
+//! Every value records whether it was measured (given), taken from a
+//! material's density, or inferred and through which relation.
+
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
 use crate::core::objex::geospec::shape::Shape;
 use crate::core::objex::geospec::traits::Dimensions;
 use crate::core::objex::geospec::primitives::*;
+use crate::core::objex::geospec::mesh::Mesh;
+use crate::core::objex::matcat::{MatCatId, props_for};
+
+use Quantity::*;
+
+/// Relative disagreement tolerated between over-constrained values.
+pub const DEFAULT_TOLERANCE: f64 = 1e-3;
+
+/// Points per decade, over twelve decades, when shooting.
+const SCAN_PER_DECADE: usize = 100;
+
+/// Golden-section step, 1/φ.
+const GOLDEN: f64 = 0.618_033_988_749_894_8;
 
 pub fn infer_from_json(input: &Value) -> Option<Value> {
     match input.get("type")?.as_str()? {
-        "sphere" => Some(Shape::Sphere(Sphere {
-            radius: input.get("radius")?.as_f64()?,
-        }).as_json()),
-
-        "box" => Some(Shape::Box(BoxShape {
-            length: input.get("length")?.as_f64()?,
-            width: input.get("width")?.as_f64()?,
-            height: input.get("height")?.as_f64()?,
-        }).as_json()),
-
-        "cylinder" => Some(Shape::Cylinder(Cylinder {
-            radius: input.get("radius")?.as_f64()?,
-            height: input.get("height")?.as_f64()?,
-        }).as_json()),
-
-        "cone" => Some(Shape::Cone(Cone {
-            radius: input.get("radius")?.as_f64()?,
-            height: input.get("height")?.as_f64()?,
-        }).as_json()),
-
-        "plane" => Some(Shape::Plane(Plane {
-            width: input.get("width")?.as_f64()?,
-            height: input.get("height")?.as_f64()?,
-        }).as_json()),
-
-        "line" => Some(Shape::Line(Line {
-            length: input.get("length")?.as_f64()?,
-        }).as_json()),
-
-        "point" => Some(Shape::Point(Point).as_json()),
-
-        _ => None,
+        "mesh" => Some(Shape::Mesh(Mesh::deserialize(input).ok()?).as_json()),
+        _ => infer(input).ok()?.to_json(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InferenceError {
+    #[error("input must be a JSON object")]
+    NotAnObject,
+
+    #[error("input needs a string `type`")]
+    MissingType,
+
+    #[error("cannot infer parameters of a `{0}`")]
+    UnknownType(String),
+
+    #[error("`{quantity:?}` must be a positive number, got {value}")]
+    BadValue { quantity: Quantity, value: Value },
+
+    #[error("unknown material `{0}`")]
+    UnknownMaterial(String),
+
+    #[error("tolerance must be positive, got {0}")]
+    Tolerance(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Quantity {
+    Radius,
+    Diameter,
+    Length,
+    Width,
+    Height,
+    SlantHeight,
+    Volume,
+    SurfaceArea,
+    Mass,
+    Density,
+}
+
+impl Quantity {
+    /// JSON key.
+    pub fn key(self) -> &'static str {
+        match self {
+            Quantity::Radius => "radius",
+            Quantity::Diameter => "diameter",
+            Quantity::Length => "length",
+            Quantity::Width => "width",
+            Quantity::Height => "height",
+            Quantity::SlantHeight => "slant_height",
+            Quantity::Volume => "volume",
+            Quantity::SurfaceArea => "surface_area",
+            Quantity::Mass => "mass",
+            Quantity::Density => "density",
+        }
+    }
+
+    /// Power of length, for picking a scan scale; 0 for mass and density.
+    fn length_power(self) -> i32 {
+        match self {
+            Quantity::Volume => 3,
+            Quantity::SurfaceArea => 2,
+            Quantity::Mass | Quantity::Density => 0,
+            _ => 1,
+        }
+    }
+}
+
+/// Where a value came from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum ValueSource {
+    Measured,
+    /// Density of the named material.
+    Material { material: MatCatId },
+    /// Solved from the relation.
+    Inferred { relation: &'static str },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InferredValue {
+    pub value: f64,
+    #[serde(flatten)]
+    pub source: ValueSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InferenceIssue {
+    /// Known values break the relation by `residual` (relative).
+    Conflict { relation: &'static str, residual: f64 },
+    /// No positive `quantity` satisfies the relation.
+    NoSolution { relation: &'static str, quantity: Quantity },
+    /// Several values of `quantity` satisfy every relation.
+    Ambiguous { quantity: Quantity, candidates: Vec<f64> },
+}
+
+/// Result of `infer`: every quantity it could determine, what it could
+/// not, and anything inconsistent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Inference {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub values: BTreeMap<Quantity, InferredValue>,
+    /// Dimensions still unknown.
+    pub missing: Vec<Quantity>,
+    pub issues: Vec<InferenceIssue>,
+    pub tolerance: f64,
+}
+
+/// Infer a shape's parameters from a partial JSON description: `type`,
+/// any of its quantities by key, optionally a `material` (name, code or
+/// id) standing in for density, and a relative `tolerance`.
+pub fn infer(input: &Value) -> Result<Inference, InferenceError> {
+    let object = input.as_object().ok_or(InferenceError::NotAnObject)?;
+    let kind = object.get("type").and_then(Value::as_str).ok_or(InferenceError::MissingType)?;
+    let model = Model::of(kind).ok_or_else(|| InferenceError::UnknownType(kind.to_string()))?;
+
+    let tolerance = match object.get("tolerance") {
+        None => DEFAULT_TOLERANCE,
+        Some(t) => match t.as_f64() {
+            Some(t) if t.is_finite() && t > 0.0 => t,
+            _ => return Err(InferenceError::Tolerance(t.as_f64().unwrap_or(f64::NAN))),
+        },
+    };
+
+    let mut values = BTreeMap::new();
+    for &quantity in model.quantities {
+        let Some(raw) = object.get(quantity.key()) else {
+            continue;
+        };
+        match raw.as_f64() {
+            Some(value) if value.is_finite() && value > 0.0 => {
+                values.insert(quantity, InferredValue { value, source: ValueSource::Measured });
+            }
+            _ => return Err(InferenceError::BadValue { quantity, value: raw.clone() }),
+        }
+    }
+    if let Some(material) = object.get("material")
+        && model.quantities.contains(&Quantity::Density)
+        && !values.contains_key(&Quantity::Density)
+    {
+        let material = material_id(material)?;
+        let density = props_for(&material).density as f64;
+        values.insert(Quantity::Density, InferredValue { value: density, source: ValueSource::Material { material } });
+    }
+
+    let mut issues = Vec::new();
+    if let Err(issue) = model.propagate(&mut values) {
+        issues.push(issue);
+    } else if let Some(issue) = model.shoot(&mut values, tolerance) {
+        issues.push(issue);
+    }
+    issues.extend(model.conflicts(&values, tolerance));
+
+    let missing = model.dimensions.iter().copied().filter(|q| !values.contains_key(q)).collect();
+    Ok(Inference { kind: model.kind, values, missing, issues, tolerance })
+}
+
+fn material_id(material: &Value) -> Result<MatCatId, InferenceError> {
+    match material.as_str() {
+        Some(name) => MatCatId::from_common_name(name)
+            .or_else(|| MatCatId::parse_code(name))
+            .ok_or_else(|| InferenceError::UnknownMaterial(name.to_string())),
+        None => MatCatId::deserialize(material).map_err(|_| InferenceError::UnknownMaterial(material.to_string())),
+    }
+}
+
+impl Inference {
+    pub fn value(&self, quantity: Quantity) -> Option<f64> {
+        self.values.get(&quantity).map(|v| v.value)
+    }
+
+    /// Every dimension known.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Quantities that were given.
+    pub fn measured(&self) -> impl Iterator<Item = Quantity> + '_ {
+        self.values.iter().filter(|(_, v)| v.source == ValueSource::Measured).map(|(q, _)| *q)
+    }
+
+    /// Quantities that were not given.
+    pub fn inferred(&self) -> impl Iterator<Item = Quantity> + '_ {
+        self.values.iter().filter(|(_, v)| v.source != ValueSource::Measured).map(|(q, _)| *q)
+    }
+
+    /// The shape, once its dimensions are known.
+    pub fn shape(&self) -> Option<Shape> {
+        let get = |q| self.value(q);
+        Some(match self.kind {
+            "point" => Shape::Point(Point),
+            "line" => Shape::Line(Line { length: get(Quantity::Length)? }),
+            "plane" => Shape::Plane(Plane { width: get(Quantity::Width)?, height: get(Quantity::Height)? }),
+            "sphere" => Shape::Sphere(Sphere { radius: get(Quantity::Radius)? }),
+            "box" => Shape::Box(BoxShape {
+                length: get(Quantity::Length)?,
+                width: get(Quantity::Width)?,
+                height: get(Quantity::Height)?,
+            }),
+            "cylinder" => Shape::Cylinder(Cylinder { radius: get(Quantity::Radius)?, height: get(Quantity::Height)? }),
+            "cone" => Shape::Cone(Cone { radius: get(Quantity::Radius)?, height: get(Quantity::Height)? }),
+            _ => return None,
+        })
+    }
+
+    /// `type` and every known quantity, if complete and consistent.
+    pub fn to_json(&self) -> Option<Value> {
+        if !self.is_complete() || !self.is_consistent() {
+            return None;
+        }
+        let mut out = Map::new();
+        out.insert("type".into(), self.kind.into());
+        for (quantity, value) in &self.values {
+            out.insert(quantity.key().into(), value.value.into());
+        }
+        Some(Value::Object(out))
+    }
+}
+
+// ----------------------------------------------------------------
+// Models
+// ----------------------------------------------------------------
+
+/// `params[0] = f(params[1..])`, with `f` increasing in each input.
+struct Relation {
+    name: &'static str,
+    params: &'static [Quantity],
+    f: fn(&[f64]) -> f64,
+}
+
+impl Relation {
+    fn values(&self, values: &BTreeMap<Quantity, InferredValue>) -> Vec<Option<f64>> {
+        self.params.iter().map(|q| values.get(q).map(|v| v.value)).collect()
+    }
+
+    /// Solve for the one missing parameter, at `unknown`.
+    fn solve(&self, known: &[Option<f64>], unknown: usize) -> Option<f64> {
+        let mut inputs: Vec<f64> = known[1..].iter().map(|v| v.unwrap_or(0.0)).collect();
+        if unknown == 0 {
+            return Some((self.f)(&inputs));
+        }
+        let target = known[0]?;
+        let mut excess = |x: f64| {
+            inputs[unknown - 1] = x;
+            (self.f)(&inputs) - target
+        };
+
+        // Bracket geometrically, then bisect in log space
+        let (mut lo, mut hi) = (1.0, 1.0);
+        while excess(hi) < 0.0 {
+            hi *= 2.0;
+            if hi > 1e300 {
+                return None;
+            }
+        }
+        while excess(lo) > 0.0 {
+            lo /= 2.0;
+            if lo < 1e-300 {
+                return None;
+            }
+        }
+        for _ in 0..200 {
+            let mid = (lo * hi).sqrt();
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if excess(mid) < 0.0 { lo = mid } else { hi = mid }
+        }
+        Some((lo * hi).sqrt())
+    }
+
+    /// Relative residual, signed; `None` unless every parameter is known.
+    fn residual(&self, values: &BTreeMap<Quantity, InferredValue>) -> Option<f64> {
+        let known: Option<Vec<f64>> = self.values(values).into_iter().collect();
+        let known = known?;
+        Some((known[0] - (self.f)(&known[1..])) / known[0])
+    }
+}
+
+struct Model {
+    kind: &'static str,
+    /// Quantities that define the shape.
+    dimensions: &'static [Quantity],
+    quantities: &'static [Quantity],
+    relations: &'static [Relation],
+}
+
+const MASS: Relation = Relation { name: "m = ρV", params: &[Mass, Density, Volume], f: |v| v[0] * v[1] };
+const DIAMETER: Relation = Relation { name: "d = 2r", params: &[Diameter, Radius], f: |v| 2.0 * v[0] };
+
+static SPHERE: &[Relation] = &[
+    DIAMETER,
+    Relation { name: "V = 4/3 πr³", params: &[Volume, Radius], f: |v| 4.0 / 3.0 * PI * v[0].powi(3) },
+    Relation { name: "A = 4πr²", params: &[SurfaceArea, Radius], f: |v| 4.0 * PI * v[0].powi(2) },
+    MASS,
+];
+
+static BOX: &[Relation] = &[
+    Relation { name: "V = lwh", params: &[Volume, Length, Width, Height], f: |v| v[0] * v[1] * v[2] },
+    Relation {
+        name: "A = 2(lw + lh + wh)",
+        params: &[SurfaceArea, Length, Width, Height],
+        f: |v| 2.0 * (v[0] * v[1] + v[0] * v[2] + v[1] * v[2]),
+    },
+    MASS,
+];
+
+static CYLINDER: &[Relation] = &[
+    DIAMETER,
+    Relation { name: "V = πr²h", params: &[Volume, Radius, Height], f: |v| PI * v[0].powi(2) * v[1] },
+    Relation {
+        name: "A = 2πr(r + h)",
+        params: &[SurfaceArea, Radius, Height],
+        f: |v| 2.0 * PI * v[0] * (v[0] + v[1]),
+    },
+    MASS,
+];
+
+static CONE: &[Relation] = &[
+    DIAMETER,
+    Relation { name: "s = √(r² + h²)", params: &[SlantHeight, Radius, Height], f: |v| v[0].hypot(v[1]) },
+    Relation { name: "V = πr²h/3", params: &[Volume, Radius, Height], f: |v| PI * v[0].powi(2) * v[1] / 3.0 },
+    Relation {
+        name: "A = πr(r + s)",
+        params: &[SurfaceArea, Radius, SlantHeight],
+        f: |v| PI * v[0] * (v[0] + v[1]),
+    },
+    MASS,
+];
+
+static PLANE: &[Relation] =
+    &[Relation { name: "A = wh", params: &[SurfaceArea, Width, Height], f: |v| v[0] * v[1] }];
+
+impl Model {
+    fn of(kind: &str) -> Option<Self> {
+        let (kind, dimensions, quantities, relations): (_, &[Quantity], &[Quantity], _) = match kind {
+            "point" => ("point", &[], &[], &[] as &[Relation]),
+            "line" => ("line", &[Length], &[Length], &[]),
+            "plane" => ("plane", &[Width, Height], &[Width, Height, SurfaceArea], PLANE),
+            "sphere" => ("sphere", &[Radius], &[Radius, Diameter, Volume, SurfaceArea, Mass, Density], SPHERE),
+            "box" => (
+                "box",
+                &[Length, Width, Height],
+                &[Length, Width, Height, Volume, SurfaceArea, Mass, Density],
+                BOX,
+            ),
+            "cylinder" => (
+                "cylinder",
+                &[Radius, Height],
+                &[Radius, Diameter, Height, Volume, SurfaceArea, Mass, Density],
+                CYLINDER,
+            ),
+            "cone" => (
+                "cone",
+                &[Radius, Height],
+                &[Radius, Diameter, Height, SlantHeight, Volume, SurfaceArea, Mass, Density],
+                CONE,
+            ),
+            _ => return None,
+        };
+        Some(Model { kind, dimensions, quantities, relations })
+    }
+
+    /// Solve relations with one unknown until none is left. Returns the
+    /// relations used, in order.
+    fn propagate(&self, values: &mut BTreeMap<Quantity, InferredValue>) -> Result<Vec<usize>, InferenceIssue> {
+        let mut used = Vec::new();
+        loop {
+            let mut progress = false;
+            for (i, relation) in self.relations.iter().enumerate() {
+                let known = relation.values(values);
+                let mut unknown = known.iter().enumerate().filter(|(_, v)| v.is_none()).map(|(j, _)| j);
+                let (Some(j), None) = (unknown.next(), unknown.next()) else {
+                    continue;
+                };
+                let quantity = relation.params[j];
+                let value = relation
+                    .solve(&known, j)
+                    .filter(|v| v.is_finite() && *v > 0.0)
+                    .ok_or(InferenceIssue::NoSolution { relation: relation.name, quantity })?;
+                values.insert(quantity, InferredValue { value, source: ValueSource::Inferred { relation: relation.name } });
+                used.push(i);
+                progress = true;
+            }
+            if !progress {
+                return Ok(used);
+            }
+        }
+    }
+
+    /// When propagation leaves dimensions unknown, guess one, propagate,
+    /// and root-find the first relation left over. Adopts a unique root;
+    /// roots whose residuals stay within `tolerance` between them count
+    /// as one.
+    fn shoot(&self, values: &mut BTreeMap<Quantity, InferredValue>, tolerance: f64) -> Option<InferenceIssue> {
+        let scale = values
+            .iter()
+            .find(|(q, _)| q.length_power() > 0)
+            .map(|(q, v)| v.value.powf(1.0 / q.length_power() as f64))?;
+
+        for &quantity in self.dimensions.iter().filter(|q| !values.contains_key(q)) {
+            // Relative residual of the spare relation, and which one it is
+            let trial = |x: f64| -> Option<(usize, f64)> {
+                let mut values = values.clone();
+                values.insert(quantity, InferredValue { value: x, source: ValueSource::Measured });
+                let used = self.propagate(&mut values).ok()?;
+                if self.dimensions.iter().any(|q| !values.contains_key(q)) {
+                    return None;
+                }
+                self.relations
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !used.contains(i))
+                    .find_map(|(i, r)| Some((i, r.residual(&values)?)))
+            };
+
+            let steps = 12 * SCAN_PER_DECADE;
+            let xs: Vec<f64> = (0..=steps).map(|k| scale * 10f64.powf(-6.0 + k as f64 / SCAN_PER_DECADE as f64)).collect();
+            let gs: Vec<_> = xs.iter().map(|&x| trial(x)).collect();
+            if gs.iter().all(Option::is_none) {
+                // Infeasible if some relation rejects every trial value;
+                // otherwise this quantity just doesn't settle the rest
+                let mut failures = xs.iter().map(|&x| {
+                    let mut values = values.clone();
+                    values.insert(quantity, InferredValue { value: x, source: ValueSource::Measured });
+                    self.propagate(&mut values).err()
+                });
+                if let Some(Some(InferenceIssue::NoSolution { relation, .. })) = failures.next()
+                    && failures.all(|f| f.is_some())
+                {
+                    return Some(InferenceIssue::NoSolution { relation, quantity });
+                }
+                continue;
+            }
+
+            let mut roots: Vec<(f64, usize)> = Vec::new();
+            // Sign changes
+            for k in 0..steps {
+                let (Some((i, g0)), Some((j, g1))) = (gs[k], gs[k + 1]) else {
+                    continue;
+                };
+                if i != j || g0.signum() == g1.signum() {
+                    continue;
+                }
+                let (mut lo, mut hi, mut g_lo) = (xs[k], xs[k + 1], g0);
+                for _ in 0..200 {
+                    let mid = (lo * hi).sqrt();
+                    if mid <= lo || mid >= hi {
+                        break;
+                    }
+                    match trial(mid) {
+                        Some((_, g)) if g.signum() == g_lo.signum() => (lo, g_lo) = (mid, g),
+                        _ => hi = mid,
+                    }
+                }
+                roots.push(((lo * hi).sqrt(), i));
+            }
+
+            // Tangent roots: a local minimum of |g| that reaches zero
+            // (within tolerance) without crossing it
+            for k in 1..steps {
+                let (Some((i, g0)), Some((j, g1)), Some((l, g2))) = (gs[k - 1], gs[k], gs[k + 1]) else {
+                    continue;
+                };
+                let dip = g1.abs() <= g0.abs() && g1.abs() <= g2.abs();
+                if i != j || j != l || !dip || g0.signum() != g1.signum() || g1.signum() != g2.signum() {
+                    continue;
+                }
+                let size = |t: f64| trial(t.exp()).filter(|(r, _)| *r == i).map_or(f64::INFINITY, |(_, g)| g.abs());
+                let (mut lo, mut hi) = (xs[k - 1].ln(), xs[k + 1].ln());
+                for _ in 0..100 {
+                    let (a, b) = (hi - (hi - lo) * GOLDEN, lo + (hi - lo) * GOLDEN);
+                    if size(a) < size(b) {
+                        hi = b;
+                    } else {
+                        lo = a;
+                    }
+                }
+                let t = (lo + hi) / 2.0;
+                if size(t) <= tolerance {
+                    roots.push((t.exp(), i));
+                }
+            }
+
+            // Merge neighbouring roots the relation cannot tell apart
+            roots.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut distinct: Vec<(f64, f64, usize)> = Vec::new();
+            for (x, i) in roots {
+                if let Some((_, last, j)) = distinct.last_mut()
+                    && *j == i
+                    && trial((*last * x).sqrt()).is_some_and(|(r, g)| r == i && g.abs() <= tolerance)
+                {
+                    *last = x;
+                    continue;
+                }
+                distinct.push((x, x, i));
+            }
+            let roots: Vec<(f64, usize)> = distinct.into_iter().map(|(first, last, i)| ((first * last).sqrt(), i)).collect();
+
+            return match roots.as_slice() {
+                [] => {
+                    let (spare, _) = gs.iter().flatten().next()?;
+                    Some(InferenceIssue::NoSolution { relation: self.relations[*spare].name, quantity })
+                }
+                &[(x, i)] => {
+                    let relation = self.relations[i].name;
+                    values.insert(quantity, InferredValue { value: x, source: ValueSource::Inferred { relation } });
+                    self.propagate(values).err()
+                }
+                _ => Some(InferenceIssue::Ambiguous { quantity, candidates: roots.iter().map(|r| r.0).collect() }),
+            };
+        }
+        None
+    }
+
+    fn conflicts(&self, values: &BTreeMap<Quantity, InferredValue>, tolerance: f64) -> Vec<InferenceIssue> {
+        self.relations
+            .iter()
+            .filter_map(|r| {
+                let residual = r.residual(values)?;
+                (residual.abs() > tolerance).then_some(InferenceIssue::Conflict { relation: r.name, residual })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * a.abs().max(b.abs())
+    }
+
+    #[test]
+    fn infers_from_sufficient_subsets_and_flags_conflicts() {
+        // Cylinder from volume and height
+        let cyl = infer(&json!({ "type": "cylinder", "volume": PI * 8.0, "height": 2.0 })).unwrap();
+        assert!(cyl.is_complete() && cyl.is_consistent());
+        assert!(close(cyl.value(Radius).unwrap(), 2.0));
+        assert!(close(cyl.value(Diameter).unwrap(), 4.0));
+        assert!(close(cyl.value(SurfaceArea).unwrap(), 2.0 * PI * 2.0 * 4.0));
+        assert_eq!(cyl.measured().collect::<Vec<_>>(), vec![Height, Volume]);
+        assert_eq!(cyl.values[&Radius].source, ValueSource::Inferred { relation: "V = πr²h" });
+
+        // Box from mass, a material's density and two dimensions
+        let steel = props_for(&MatCatId::steel_lowcarbon()).density as f64;
+        let bx = infer(&json!({
+            "type": "box", "mass": steel * 0.06, "material": "steel", "length": 0.5, "width": 0.4
+        }))
+        .unwrap();
+        assert!(close(bx.value(Height).unwrap(), 0.3));
+        assert!(matches!(bx.values[&Density].source, ValueSource::Material { .. }));
+        assert!(matches!(bx.shape(), Some(Shape::Box(b)) if close(b.height, 0.3)));
+
+        // Cone from surface area and diameter, through the slant height
+        let (r, h) = (3.0_f64, 4.0_f64);
+        let cone = infer(&json!({ "type": "cone", "surface_area": PI * r * (r + 5.0), "diameter": 2.0 * r })).unwrap();
+        assert!(close(cone.value(Height).unwrap(), h));
+
+        // Two unknowns: a unique root is adopted, several are reported
+        let ball = infer(&json!({ "type": "sphere", "surface_area": 4.0 * PI * 9.0 })).unwrap();
+        assert!(close(ball.value(Volume).unwrap(), 36.0 * PI));
+        let twin = infer(&json!({ "type": "cylinder", "volume": PI * 4.0 * 6.0, "surface_area": 2.0 * PI * 2.0 * 8.0 }))
+            .unwrap();
+        let [InferenceIssue::Ambiguous { candidates, .. }] = twin.issues.as_slice() else { panic!("{:?}", twin.issues) };
+        assert!(candidates.len() == 2 && close(candidates[0], 2.0) && close(candidates[1], 13f64.sqrt() - 1.0));
+        assert!(!twin.is_complete() && twin.to_json().is_none());
+        let plane = infer(&json!({ "type": "plane", "surface_area": 6.0, "width": 2.0 })).unwrap();
+        assert!(close(plane.to_json().unwrap()["height"].as_f64().unwrap(), 3.0));
+
+        // Over-constrained: agreement within tolerance passes, otherwise flagged
+        let fine = infer(&json!({ "type": "sphere", "radius": 1.0, "volume": 4.0 / 3.0 * PI * 1.0005 })).unwrap();
+        assert!(fine.is_consistent());
+        let bad = infer(&json!({ "type": "sphere", "radius": 1.0, "volume": 5.0, "tolerance": 1e-2 })).unwrap();
+        assert!(matches!(bad.issues.as_slice(), [InferenceIssue::Conflict { relation: "V = 4/3 πr³", .. }]));
+        assert!(infer_from_json(&json!({ "type": "sphere", "radius": 1.0, "volume": 5.0 })).is_none());
+        let short = infer(&json!({ "type": "cone", "radius": 1.0, "surface_area": 1.0 })).unwrap();
+        assert!(matches!(short.issues.as_slice(), [InferenceIssue::NoSolution { .. }]));
+
+        assert!(matches!(infer(&json!({ "type": "box", "width": -1.0 })), Err(InferenceError::BadValue { .. })));
+        assert!(matches!(infer(&json!({ "type": "torus" })), Err(InferenceError::UnknownType(_))));
+        assert!(infer(&json!({ "type": "box", "length": 1.0 })).unwrap().missing == vec![Width, Height]);
+    }
+
+    #[test]
+    fn shooting_merges_tangent_roots_and_reports_no_solution() {
+        let near = |a: f64, b: f64| (a - b).abs() <= 1e-4 * b;
+
+        // Unit cube: width and height touch the same double root
+        let cube = infer(&json!({ "type": "box", "length": 1, "surface_area": 6, "volume": 1 })).unwrap();
+        assert!(cube.is_complete() && cube.is_consistent(), "{:?}", cube.issues);
+        assert!(near(cube.value(Width).unwrap(), 1.0) && near(cube.value(Height).unwrap(), 1.0));
+
+        // Cylinder at its least surface area for the volume: r = h/2
+        let r = (0.5 / PI).cbrt();
+        let can = infer(&json!({ "type": "cylinder", "volume": 1.0, "surface_area": 6.0 * PI * r * r })).unwrap();
+        assert!(can.is_complete() && can.is_consistent(), "{:?}", can.issues);
+        assert!(near(can.value(Radius).unwrap(), r) && near(can.value(Height).unwrap(), 2.0 * r));
+
+        // Less area than any cylinder of that volume
+        let none = infer(&json!({ "type": "cylinder", "surface_area": 1, "volume": 1 })).unwrap();
+        assert!(matches!(none.issues.as_slice(), [InferenceIssue::NoSolution { quantity: Radius, .. }]), "{:?}", none.issues);
+        assert_eq!(none.missing, vec![Radius, Height]);
+        assert!(!none.is_complete() && none.to_json().is_none());
+
+        // A looser tolerance cannot tell close twins apart
+        let twin = json!({ "type": "cylinder", "volume": PI * 4.0 * 6.0, "surface_area": 2.0 * PI * 2.0 * 8.0 });
+        let mut loose = twin.clone();
+        loose["tolerance"] = json!(0.05);
+        assert!(matches!(infer(&twin).unwrap().issues.as_slice(), [InferenceIssue::Ambiguous { .. }]));
+        assert!(infer(&loose).unwrap().is_complete());
+
+        // No trial value gets through propagation at all
+        let cone = infer(&json!({ "type": "cone", "volume": 1e300, "slant_height": 1e-300 })).unwrap();
+        assert!(matches!(cone.issues.as_slice(), [InferenceIssue::NoSolution { .. }]), "{:?}", cone.issues);
+        assert!(!cone.is_complete());
+
+        assert_eq!(infer(&json!([1, 2])), Err(InferenceError::NotAnObject));
+        assert_eq!(infer(&json!({ "radius": 1.0 })), Err(InferenceError::MissingType));
+        assert_eq!(infer(&json!({ "type": 3 })), Err(InferenceError::MissingType));
     }
 }
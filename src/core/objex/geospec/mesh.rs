@@ -0,0 +1,618 @@
+//! Triangle meshes.
+//!
+//! A `Mesh` is an indexed triangle list in the shape's own frame (no
+//! recentring). A watertight mesh — every edge shared by exactly two
+//! triangles running opposite ways — is a solid: it has a volume and
+//! takes part in CSG. An open mesh is a sheet, like a `Plane`.
+
+use std::collections::HashMap;
+
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::objex::geospec::authoring::AuthoringShape;
+use crate::core::objex::geospec::profile::Profile;
+use crate::core::objex::geospec::sweep::{Piece, SweepPath};
+use crate::core::objex::geospec::traits::{SurfaceArea, Volume};
+
+#[derive(Debug, Error)]
+pub enum MeshError {
+    #[error("triangle {triangle} refers to vertex {index}, but the mesh has {vertices} vertices")]
+    IndexOutOfRange { triangle: usize, index: i64, vertices: usize },
+
+    #[error("vertex {vertex} is not finite")]
+    NonFinite { vertex: usize },
+
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("binary STL is truncated: expected {expected} bytes, found {found}")]
+    Truncated { expected: usize, found: usize },
+
+    #[error("unsupported mesh format `{0}`")]
+    Format(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "MeshData", into = "MeshData")]
+pub struct Mesh {
+    vertices: Vec<[f64; 3]>,
+    triangles: Vec<[u32; 3]>,
+    /// Cached `check().is_watertight()`
+    closed: bool,
+}
+
+/// Serialized form, validated on load.
+#[derive(Serialize, Deserialize)]
+struct MeshData {
+    vertices: Vec<[f64; 3]>,
+    triangles: Vec<[u32; 3]>,
+}
+
+impl TryFrom<MeshData> for Mesh {
+    type Error = MeshError;
+
+    fn try_from(data: MeshData) -> Result<Self, Self::Error> {
+        Mesh::new(data.vertices, data.triangles)
+    }
+}
+
+impl From<Mesh> for MeshData {
+    fn from(mesh: Mesh) -> Self {
+        Self { vertices: mesh.vertices, triangles: mesh.triangles }
+    }
+}
+
+/// Edge-level report on a mesh's topology.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MeshCheck {
+    pub triangles: usize,
+    /// Edges used by a single triangle (holes)
+    pub boundary_edges: usize,
+    /// Edges used by more than two triangles
+    pub non_manifold_edges: usize,
+    /// Edges whose two triangles run the same way (flipped faces)
+    pub misoriented_edges: usize,
+    /// Triangles with a repeated vertex or zero area
+    pub degenerate_triangles: usize,
+}
+
+impl MeshCheck {
+    pub fn is_watertight(&self) -> bool {
+        self.triangles > 0
+            && self.boundary_edges == 0
+            && self.non_manifold_edges == 0
+            && self.misoriented_edges == 0
+    }
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<[f64; 3]>, triangles: Vec<[u32; 3]>) -> Result<Self, MeshError> {
+        if let Some(vertex) = vertices.iter().position(|v| !v.iter().all(|c| c.is_finite())) {
+            return Err(MeshError::NonFinite { vertex });
+        }
+        for (t, tri) in triangles.iter().enumerate() {
+            if let Some(&index) = tri.iter().find(|&&i| i as usize >= vertices.len()) {
+                return Err(MeshError::IndexOutOfRange { triangle: t, index: index as i64, vertices: vertices.len() });
+            }
+        }
+        let mut mesh = Self { vertices, triangles, closed: false };
+        mesh.closed = mesh.check().is_watertight();
+        Ok(mesh)
+    }
+
+    pub fn vertices(&self) -> &[[f64; 3]] {
+        &self.vertices
+    }
+
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        &self.triangles
+    }
+
+    pub fn is_watertight(&self) -> bool {
+        self.closed
+    }
+
+    pub fn check(&self) -> MeshCheck {
+        // (low, high) -> (uses, uses running low -> high)
+        let mut edges: HashMap<(u32, u32), (usize, usize)> = HashMap::new();
+        let mut degenerate = 0;
+
+        for (t, tri) in self.triangles.iter().enumerate() {
+            let [a, b, c] = *tri;
+            if a == b || b == c || c == a {
+                degenerate += 1;
+                continue;
+            }
+            if self.triangle_area(t) == 0.0 {
+                degenerate += 1;
+            }
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                let entry = edges.entry((from.min(to), from.max(to))).or_default();
+                entry.0 += 1;
+                entry.1 += usize::from(from < to);
+            }
+        }
+
+        let mut check = MeshCheck { triangles: self.triangles.len(), degenerate_triangles: degenerate, ..Default::default() };
+        for (uses, forward) in edges.into_values() {
+            match uses {
+                1 => check.boundary_edges += 1,
+                2 if forward != 1 => check.misoriented_edges += 1,
+                2 => {}
+                _ => check.non_manifold_edges += 1,
+            }
+        }
+        check
+    }
+
+    /// Volume enclosed by the triangles, positive when they face
+    /// outwards (counter-clockwise seen from outside).
+    pub fn signed_volume(&self) -> f64 {
+        (0..self.triangles.len())
+            .map(|t| {
+                let [a, b, c] = self.corners(t);
+                a.dot(b.cross(c))
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+
+    /// Axis-aligned bounds (min, max); `None` for an empty mesh.
+    pub fn bounds(&self) -> Option<([f64; 3], [f64; 3])> {
+        let mut points = self.vertices.iter().map(|v| DVec3::from(*v));
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p)));
+        Some((min.to_array(), max.to_array()))
+    }
+
+    /// Farthest vertex from the origin.
+    pub fn bounding_radius(&self) -> f64 {
+        self.vertices.iter().map(|v| DVec3::from(*v).length()).fold(0.0, f64::max)
+    }
+
+    /// Merge vertices closer than `tolerance` (bit-identical ones at 0)
+    /// and drop the triangles that collapse.
+    pub fn welded(&self, tolerance: f64) -> Mesh {
+        let key = |v: &[f64; 3]| -> [i64; 3] {
+            if tolerance > 0.0 {
+                v.map(|x| (x / tolerance).round() as i64)
+            } else {
+                v.map(|x| (x + 0.0).to_bits() as i64)
+            }
+        };
+
+        let mut index: HashMap<[i64; 3], u32> = HashMap::new();
+        let mut vertices = Vec::new();
+        let remap: Vec<u32> = self
+            .vertices
+            .iter()
+            .map(|v| {
+                *index.entry(key(v)).or_insert_with(|| {
+                    vertices.push(*v);
+                    (vertices.len() - 1) as u32
+                })
+            })
+            .collect();
+
+        let triangles = self
+            .triangles
+            .iter()
+            .map(|t| t.map(|i| remap[i as usize]))
+            .filter(|[a, b, c]| a != b && b != c && c != a)
+            .collect();
+
+        Mesh::new(vertices, triangles).expect("welding keeps indices in range")
+    }
+
+    pub(crate) fn corners(&self, t: usize) -> [DVec3; 3] {
+        self.triangles[t].map(|i| DVec3::from(self.vertices[i as usize]))
+    }
+
+    /// Unit normal from the winding; zero for a degenerate triangle.
+    pub(crate) fn face_normal(&self, t: usize) -> DVec3 {
+        let [a, b, c] = self.corners(t);
+        (b - a).cross(c - a).normalize_or_zero()
+    }
+
+    fn triangle_area(&self, t: usize) -> f64 {
+        let [a, b, c] = self.corners(t);
+        (b - a).cross(c - a).length() / 2.0
+    }
+
+    /// Point membership by ray parity along +x. Open meshes contain
+    /// nothing.
+    pub(crate) fn contains(&self, p: DVec3) -> bool {
+        if !self.closed {
+            return false;
+        }
+        // Nudge the ray off edges and vertices it would graze
+        let scale = self.bounding_radius().max(1.0);
+        let (y, z) = (p.y + 0.577_215_664_9e-9 * scale, p.z + 0.314_159_265_3e-9 * scale);
+
+        let mut crossings = 0;
+        for t in 0..self.triangles.len() {
+            let [a, b, c] = self.corners(t);
+            if (y < a.y && y < b.y && y < c.y) || (y > a.y && y > b.y && y > c.y) {
+                continue;
+            }
+            if (z < a.z && z < b.z && z < c.z) || (z > a.z && z > b.z && z > c.z) {
+                continue;
+            }
+            // Barycentric coordinates of (y, z) in the projected triangle
+            let det = (b.y - a.y) * (c.z - a.z) - (c.y - a.y) * (b.z - a.z);
+            if det == 0.0 {
+                continue;
+            }
+            let u = ((y - a.y) * (c.z - a.z) - (c.y - a.y) * (z - a.z)) / det;
+            let v = ((b.y - a.y) * (z - a.z) - (y - a.y) * (b.z - a.z)) / det;
+            if u < 0.0 || v < 0.0 || u + v > 1.0 {
+                continue;
+            }
+            let x = a.x + u * (b.x - a.x) + v * (c.x - a.x);
+            if x > p.x {
+                crossings += 1;
+            }
+        }
+        crossings % 2 == 1
+    }
+
+    /// Solid of revolution about z. `outline` is a closed polygon in the
+    /// (r, z) half-plane, r > 0, running counter-clockwise.
+    pub fn revolve(outline: &[[f64; 2]], segments: usize) -> Option<Mesh> {
+        let mut builder = Builder::default();
+        let rings: Vec<Vec<u32>> = outline.iter().map(|[r, z]| builder.circle(*r, *z, segments.max(3))).collect();
+        for (i, ring) in rings.iter().enumerate() {
+            builder.band(ring, &rings[(i + 1) % rings.len()]);
+        }
+        Mesh::new(builder.vertices, builder.triangles).ok()
+    }
+
+    /// Triangulate a primitive: `segments` around circles (at least 3).
+    /// `None` for points, lines and boolean nodes.
+    pub fn from_shape(shape: &AuthoringShape, segments: usize) -> Option<Mesh> {
+        let n = segments.max(3);
+        let mut builder = Builder::default();
+
+        match shape {
+            AuthoringShape::Plane(p) => {
+                let (hx, hy) = (p.width / 2.0, p.height / 2.0);
+                let quad = [[-hx, -hy], [hx, -hy], [hx, hy], [-hx, hy]].map(|[x, y]| builder.vertex([x, y, 0.0]));
+                builder.quad(quad);
+            }
+            AuthoringShape::Box(b) => {
+                let h = [b.length / 2.0, b.width / 2.0, b.height / 2.0];
+                let v: Vec<u32> = (0..8)
+                    .map(|i| {
+                        builder.vertex([
+                            if i & 1 == 0 { -h[0] } else { h[0] },
+                            if i & 2 == 0 { -h[1] } else { h[1] },
+                            if i & 4 == 0 { -h[2] } else { h[2] },
+                        ])
+                    })
+                    .collect();
+                for [a, b, c, d] in [[0, 2, 3, 1], [4, 5, 7, 6], [0, 1, 5, 4], [2, 6, 7, 3], [0, 4, 6, 2], [1, 3, 7, 5]] {
+                    builder.quad([v[a], v[b], v[c], v[d]]);
+                }
+            }
+            AuthoringShape::Sphere(s) => {
+                let rings = n.div_ceil(2).max(2);
+                let south = builder.vertex([0.0, 0.0, -s.radius]);
+                let north = builder.vertex([0.0, 0.0, s.radius]);
+                let ring: Vec<Vec<u32>> = (1..rings)
+                    .map(|i| {
+                        let theta = std::f64::consts::PI * i as f64 / rings as f64;
+                        let (rho, z) = (s.radius * theta.sin(), -s.radius * theta.cos());
+                        builder.circle(rho, z, n)
+                    })
+                    .collect();
+                builder.cap(&ring[0], south, false);
+                for pair in ring.windows(2) {
+                    builder.band(&pair[0], &pair[1]);
+                }
+                builder.cap(&ring[ring.len() - 1], north, true);
+            }
+            AuthoringShape::Cylinder(c) => {
+                let bottom = builder.circle(c.radius, -c.height / 2.0, n);
+                let top = builder.circle(c.radius, c.height / 2.0, n);
+                builder.band(&bottom, &top);
+                let (lo, hi) = (builder.vertex([0.0, 0.0, -c.height / 2.0]), builder.vertex([0.0, 0.0, c.height / 2.0]));
+                builder.cap(&bottom, lo, false);
+                builder.cap(&top, hi, true);
+            }
+            AuthoringShape::Cone(c) => {
+                let base = builder.circle(c.radius, -c.height / 2.0, n);
+                let centre = builder.vertex([0.0, 0.0, -c.height / 2.0]);
+                let apex = builder.vertex([0.0, 0.0, c.height / 2.0]);
+                builder.cap(&base, centre, false);
+                builder.cap(&base, apex, true);
+            }
+            AuthoringShape::Extrude { profile, length, capped } => {
+                builder.prism(profile, *length, *capped)?;
+            }
+            AuthoringShape::Sweep { profile, path, capped } => {
+                builder.sweep(profile, path, *capped, n)?;
+            }
+            AuthoringShape::Mesh(m) => return Some(m.clone()),
+            _ => return None,
+        }
+
+        Mesh::new(builder.vertices, builder.triangles).ok()
+    }
+}
+
+impl SurfaceArea for Mesh {
+    fn surface_area(&self) -> f64 {
+        (0..self.triangles.len()).map(|t| self.triangle_area(t)).sum()
+    }
+}
+
+impl Volume for Mesh {
+    /// Enclosed volume; zero for an open mesh.
+    fn volume(&self) -> f64 {
+        if self.closed { self.signed_volume().abs() } else { 0.0 }
+    }
+}
+
+/// Places a section point (x, y) in the shape's frame.
+type Section = Box<dyn Fn(f64, f64) -> [f64; 3]>;
+
+/// Accumulates vertices and outward-wound triangles.
+#[derive(Default)]
+struct Builder {
+    vertices: Vec<[f64; 3]>,
+    triangles: Vec<[u32; 3]>,
+}
+
+impl Builder {
+    fn vertex(&mut self, v: [f64; 3]) -> u32 {
+        self.vertices.push(v);
+        (self.vertices.len() - 1) as u32
+    }
+
+    /// Counter-clockwise quad (seen from outside).
+    fn quad(&mut self, [a, b, c, d]: [u32; 4]) {
+        self.triangles.push([a, b, c]);
+        self.triangles.push([a, c, d]);
+    }
+
+    /// Counter-clockwise ring (seen from +z) at height `z`.
+    fn circle(&mut self, radius: f64, z: f64, n: usize) -> Vec<u32> {
+        (0..n)
+            .map(|i| {
+                let t = std::f64::consts::TAU * i as f64 / n as f64;
+                self.vertex([radius * t.cos(), radius * t.sin(), z])
+            })
+            .collect()
+    }
+
+    /// Side wall between two matching rings, `lower` below `upper`.
+    fn band(&mut self, lower: &[u32], upper: &[u32]) {
+        for i in 0..lower.len() {
+            let j = (i + 1) % lower.len();
+            self.quad([lower[i], lower[j], upper[j], upper[i]]);
+        }
+    }
+
+    /// Fan from a ring to `tip`, facing up (`true`) or down.
+    fn cap(&mut self, ring: &[u32], tip: u32, up: bool) {
+        for i in 0..ring.len() {
+            let j = (i + 1) % ring.len();
+            self.triangles.push(if up { [ring[i], ring[j], tip] } else { [ring[j], ring[i], tip] });
+        }
+    }
+
+    fn prism(&mut self, profile: &Profile, length: f64, capped: bool) -> Option<()> {
+        let (z0, z1) = (-length / 2.0, length / 2.0);
+        let ends: [Section; 2] = [Box::new(move |x, y| [x, y, z0]), Box::new(move |x, y| [x, y, z1])];
+        self.tube(&profile.outlines(), &ends, capped)
+    }
+
+    /// Sections at every piece end and `n` per turn through bends.
+    fn sweep(&mut self, profile: &Profile, path: &SweepPath, capped: bool, n: usize) -> Option<()> {
+        let pieces = path.pieces();
+        let first = *pieces.first()?;
+        let mut sections: Vec<Section> =
+            vec![Box::new(move |x, y| first.point(x, y, first.fibre(x, y).0).to_array())];
+        for piece in pieces {
+            match piece {
+                Piece::Straight { .. } => {
+                    sections.push(Box::new(move |x, y| piece.point(x, y, piece.fibre(x, y).1).to_array()));
+                }
+                Piece::Bend { angle, .. } => {
+                    let steps = ((angle / std::f64::consts::TAU * n as f64).ceil() as usize).max(1);
+                    for k in 1..=steps {
+                        let s = angle * k as f64 / steps as f64;
+                        sections.push(Box::new(move |x, y| piece.point(x, y, s).to_array()));
+                    }
+                }
+            }
+        }
+        self.tube(&profile.outlines(), &sections, capped)
+    }
+
+    /// Outlines carried through successive sections (first to last
+    /// running along +z of the profile), walled between neighbours and
+    /// capped at both ends.
+    fn tube(&mut self, outlines: &[Vec<[f64; 2]>], sections: &[Section], capped: bool) -> Option<()> {
+        let mut rings = Vec::new();
+
+        for outline in outlines {
+            let placed: Vec<Vec<u32>> = sections
+                .iter()
+                .map(|place| outline.iter().map(|[x, y]| self.vertex(place(*x, *y))).collect())
+                .collect();
+            // Outlines run so that (dy, -dx) points out of the solid
+            for pair in placed.windows(2) {
+                self.band(&pair[0], &pair[1]);
+            }
+            rings.push((placed.first()?.clone(), placed.last()?.clone()));
+        }
+        if !capped {
+            return Some(());
+        }
+
+        match (outlines, rings.as_slice()) {
+            ([outer], [(lower, upper)]) => {
+                for [a, b, c] in ear_clip(outer)? {
+                    self.triangles.push([upper[a], upper[b], upper[c]]);
+                    self.triangles.push([lower[c], lower[b], lower[a]]);
+                }
+            }
+            ([outer, hole], [(outer_lo, outer_hi), (hole_lo, hole_hi)]) if outer.len() == hole.len() => {
+                // Concentric outlines: stitch the ring between them. The
+                // hole runs clockwise; walk it backwards from the vertex
+                // nearest the outer start.
+                let n = outer.len();
+                let start = (0..n)
+                    .min_by(|&i, &j| {
+                        let d = |k: usize| (hole[k][0] - outer[0][0]).powi(2) + (hole[k][1] - outer[0][1]).powi(2);
+                        d(i).total_cmp(&d(j))
+                    })
+                    .unwrap_or(0);
+                let matched = |k: usize| (start + n - k % n) % n;
+                for i in 0..n {
+                    let j = (i + 1) % n;
+                    let (hi_i, hi_j) = (hole_hi[matched(i)], hole_hi[matched(j)]);
+                    let (lo_i, lo_j) = (hole_lo[matched(i)], hole_lo[matched(j)]);
+                    self.quad([outer_hi[i], outer_hi[j], hi_j, hi_i]);
+                    self.quad([outer_lo[j], outer_lo[i], lo_i, lo_j]);
+                }
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+}
+
+/// Triangulate a simple counter-clockwise polygon (indices into it).
+fn ear_clip(polygon: &[[f64; 2]]) -> Option<Vec<[usize; 3]>> {
+    let cross = |o: [f64; 2], a: [f64; 2], b: [f64; 2]| (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0]);
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    let mut out = Vec::with_capacity(polygon.len().saturating_sub(2));
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = (remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]);
+            let (pa, pb, pc) = (polygon[a], polygon[b], polygon[c]);
+            cross(pa, pb, pc) > 0.0
+                && remaining.iter().filter(|&&k| k != a && k != b && k != c).all(|&k| {
+                    let p = polygon[k];
+                    cross(pa, pb, p) < 0.0 || cross(pb, pc, p) < 0.0 || cross(pc, pa, p) < 0.0
+                })
+        })?;
+        out.push([remaining[(ear + n - 1) % n], remaining[ear], remaining[(ear + 1) % n]]);
+        remaining.remove(ear);
+    }
+    if let [a, b, c] = remaining[..] {
+        out.push([a, b, c]);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::geospec::csg::Placed;
+    use crate::core::objex::geospec::primitives::{BoxShape, Cylinder, Sphere};
+    use crate::core::objex::geospec::profile::ProfileArea;
+
+    #[test]
+    fn tessellated_solids_are_watertight_and_measure_correctly() {
+        let cube = Mesh::from_shape(&AuthoringShape::Box(BoxShape { length: 2.0, width: 1.0, height: 1.0 }), 0).unwrap();
+        assert!(cube.is_watertight());
+        assert!((cube.signed_volume() - 2.0).abs() < 1e-12);
+        assert!((cube.surface_area() - 10.0).abs() < 1e-12);
+        assert_eq!(cube.bounds(), Some(([-1.0, -0.5, -0.5], [1.0, 0.5, 0.5])));
+
+        // A regular n-gon prism
+        let n = 64;
+        let cyl = Mesh::from_shape(&AuthoringShape::Cylinder(Cylinder { radius: 1.0, height: 2.0 }), n).unwrap();
+        let polygon = n as f64 / 2.0 * (std::f64::consts::TAU / n as f64).sin();
+        assert!(cyl.is_watertight());
+        assert!((cyl.volume() - polygon * 2.0).abs() < 1e-9);
+
+        for profile in [
+            Profile::IBeam { flange_width: 0.2, flange_thickness: 0.02, web_height: 0.3, web_thickness: 0.01 },
+            Profile::Rect { width: 0.1, height: 0.2, wall: Some(0.01) },
+            Profile::Circle { outer_radius: 0.1, inner_radius: Some(0.08) },
+        ] {
+            let area = profile.area();
+            let beam = Mesh::from_shape(&AuthoringShape::Extrude { profile, length: 3.0, capped: true }, n).unwrap();
+            assert!(beam.is_watertight(), "{:?}", beam.check());
+            assert!((beam.signed_volume() - area * 3.0).abs() < 1e-3 * area * 3.0);
+        }
+
+        // Holes and flips are reported
+        let mut open = cube.clone();
+        open.triangles.pop();
+        let check = Mesh::new(open.vertices.clone(), open.triangles.clone()).unwrap().check();
+        assert_eq!(check.boundary_edges, 3);
+        assert_eq!(Mesh::new(open.vertices, open.triangles).unwrap().volume(), 0.0);
+
+        let mut flipped = cube.triangles.clone();
+        flipped[0].swap(1, 2);
+        assert_eq!(Mesh::new(cube.vertices.clone(), flipped).unwrap().check().misoriented_edges, 3);
+
+        // Meshes are CSG leaves: a sphere cut from a mesh block
+        let cut = AuthoringShape::Difference {
+            outer: Box::new(AuthoringShape::Mesh(cube.clone()).into()),
+            inner: Box::new(Placed::new(AuthoringShape::Sphere(Sphere { radius: 0.25 })).at(1.0, 0.0, 0.0)),
+        }
+        .compile();
+        let half_ball = 2.0 / 3.0 * std::f64::consts::PI * 0.25f64.powi(3);
+        assert!((cut.volume - (2.0 - half_ball)).abs() <= cut.volume_error);
+        assert_eq!(cut.bounds.max, [1.0, 0.5, 0.5]);
+
+        assert!(cube.contains(DVec3::new(0.9, 0.4, -0.4)));
+        assert!(!cube.contains(DVec3::new(1.1, 0.0, 0.0)));
+        assert!(matches!(
+            Mesh::new(vec![[0.0; 3]], vec![[0, 0, 1]]),
+            Err(MeshError::IndexOutOfRange { triangle: 0, index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn empty_degenerate_and_invalid_meshes() {
+        let empty = Mesh::new(vec![], vec![]).unwrap();
+        assert!(!empty.is_watertight());
+        assert_eq!((empty.bounds(), empty.volume(), empty.bounding_radius()), (None, 0.0, 0.0));
+
+        // Loading validates indices like `new`
+        let bad = r#"{"vertices":[[0,0,0],[1,0,0],[0,1,0]],"triangles":[[0,1,3]]}"#;
+        let err = serde_json::from_str::<Mesh>(bad).unwrap_err();
+        assert!(err.to_string().contains("vertex 3"), "{err}");
+        let tri = |corner: [f64; 3]| Mesh::new(vec![[0.0; 3], corner, [0.0, 1.0, 0.0]], vec![[0, 1, 2]]);
+        for c in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(tri([1.0, c, 0.0]), Err(MeshError::NonFinite { vertex: 1 })));
+        }
+
+        // Repeated and collinear corners are degenerate; a fin is non-manifold
+        let v = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]];
+        let check = Mesh::new(v.clone(), vec![[0, 0, 1], [0, 1, 2]]).unwrap().check();
+        assert_eq!(check.degenerate_triangles, 2);
+        let fin = Mesh::new(v, vec![[0, 1, 3], [1, 0, 4], [0, 1, 5]]).unwrap().check();
+        assert_eq!(fin.non_manifold_edges, 1);
+        assert!(!fin.is_watertight());
+
+        // Welding within tolerance closes a cube split into loose faces
+        let cube = Mesh::from_shape(&AuthoringShape::Box(BoxShape { length: 1.0, width: 1.0, height: 1.0 }), 0).unwrap();
+        let loose: Vec<[f64; 3]> = (0..cube.triangles.len())
+            .flat_map(|t| cube.corners(t).map(|c| (c + DVec3::splat(1e-7 * t as f64)).to_array()))
+            .collect();
+        let triangles = (0..loose.len() as u32 / 3).map(|t| [3 * t, 3 * t + 1, 3 * t + 2]).collect();
+        let loose = Mesh::new(loose, triangles).unwrap();
+        assert!(!loose.is_watertight() && !loose.welded(0.0).is_watertight());
+        let welded = loose.welded(1e-4);
+        assert!(welded.is_watertight());
+        assert_eq!((welded.vertices.len(), welded.triangles.len()), (8, 12));
+
+        // Welding everything together collapses every triangle
+        assert!(loose.welded(10.0).triangles.is_empty());
+    }
+}
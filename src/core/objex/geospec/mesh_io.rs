@@ -0,0 +1,368 @@
+//! OBJ and STL (ASCII and binary) reading and writing for `Mesh`.
+//!
+//! OBJ keeps its vertex indexing; polygons are fanned into triangles and
+//! texture/normal references are ignored. STL stores loose triangles, so
+//! imports weld bit-identical vertices back together. STL is single
+//! precision; values are rounded to `f32` on export.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::objex::geospec::mesh::{Mesh, MeshError};
+
+const STL_HEADER: usize = 80;
+const STL_FACET: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshFormat {
+    Obj,
+    /// Read: ASCII or binary, detected. Write: ASCII.
+    StlAscii,
+    /// Read: ASCII or binary, detected. Write: binary.
+    StlBinary,
+}
+
+impl FromStr for MeshFormat {
+    type Err = MeshError;
+
+    /// `obj`, `stl` (binary) or `stl-ascii`; case-insensitive, so file
+    /// extensions work too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "obj" => Ok(Self::Obj),
+            "stl" | "stl-binary" => Ok(Self::StlBinary),
+            "stl-ascii" => Ok(Self::StlAscii),
+            _ => Err(MeshError::Format(s.to_string())),
+        }
+    }
+}
+
+impl MeshFormat {
+    fn of_path(path: &Path) -> Result<Self, MeshError> {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .ok_or_else(|| MeshError::Format(path.display().to_string()))?
+            .parse()
+    }
+}
+
+impl Mesh {
+    pub fn import(format: MeshFormat, bytes: &[u8]) -> Result<Mesh, MeshError> {
+        match format {
+            MeshFormat::Obj => {
+                let text = std::str::from_utf8(bytes)
+                    .map_err(|e| MeshError::Parse { line: 0, message: e.to_string() })?;
+                Mesh::from_obj(text)
+            }
+            MeshFormat::StlAscii | MeshFormat::StlBinary => Mesh::from_stl(bytes),
+        }
+    }
+
+    pub fn export(&self, format: MeshFormat) -> Vec<u8> {
+        match format {
+            MeshFormat::Obj => self.to_obj().into_bytes(),
+            MeshFormat::StlAscii => self.to_stl_ascii("mesh").into_bytes(),
+            MeshFormat::StlBinary => self.to_stl_binary(),
+        }
+    }
+
+    /// Load by extension (`.obj`, `.stl`).
+    pub fn load(path: impl AsRef<Path>) -> Result<Mesh, MeshError> {
+        let path = path.as_ref();
+        Mesh::import(MeshFormat::of_path(path)?, &std::fs::read(path)?)
+    }
+
+    /// Save by extension (`.obj`, `.stl` as binary).
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), MeshError> {
+        let path = path.as_ref();
+        std::fs::write(path, self.export(MeshFormat::of_path(path)?))?;
+        Ok(())
+    }
+
+    // ----------------------------------------------------------------
+    // OBJ
+    // ----------------------------------------------------------------
+
+    pub fn from_obj(text: &str) -> Result<Mesh, MeshError> {
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+
+        for (n, raw) in text.lines().enumerate() {
+            let line = n + 1;
+            let parse_error = |message: String| MeshError::Parse { line, message };
+            let mut tokens = raw.split('#').next().unwrap_or("").split_whitespace();
+
+            match tokens.next() {
+                Some("v") => {
+                    let coords = tokens
+                        .take(3)
+                        .map(|t| t.parse::<f64>().map_err(|e| parse_error(format!("`{t}`: {e}"))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let [x, y, z] = coords[..] else {
+                        return Err(parse_error("a vertex needs three coordinates".into()));
+                    };
+                    vertices.push([x, y, z]);
+                }
+                Some("f") => {
+                    let corners = tokens
+                        .map(|t| {
+                            let index: i64 = t
+                                .split('/')
+                                .next()
+                                .unwrap_or("")
+                                .parse()
+                                .map_err(|e| parse_error(format!("`{t}`: {e}")))?;
+                            // 1-based, or negative counting back from the last vertex
+                            let resolved = if index < 0 { vertices.len() as i64 + index } else { index - 1 };
+                            if index == 0 || resolved < 0 || resolved >= vertices.len() as i64 {
+                                return Err(MeshError::IndexOutOfRange {
+                                    triangle: triangles.len(),
+                                    index,
+                                    vertices: vertices.len(),
+                                });
+                            }
+                            Ok(resolved as u32)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    if corners.len() < 3 {
+                        return Err(parse_error("a face needs at least three vertices".into()));
+                    }
+                    for i in 1..corners.len() - 1 {
+                        triangles.push([corners[0], corners[i], corners[i + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Mesh::new(vertices, triangles)
+    }
+
+    pub fn to_obj(&self) -> String {
+        let mut out = format!("# {} vertices, {} triangles\n", self.vertices().len(), self.triangles().len());
+        for [x, y, z] in self.vertices() {
+            let _ = writeln!(out, "v {x} {y} {z}");
+        }
+        for [a, b, c] in self.triangles() {
+            let _ = writeln!(out, "f {} {} {}", a + 1, b + 1, c + 1);
+        }
+        out
+    }
+
+    // ----------------------------------------------------------------
+    // STL
+    // ----------------------------------------------------------------
+
+    /// Read ASCII or binary STL. A binary file is recognised by its
+    /// size matching the facet count in its header, since binary
+    /// headers may also begin with `solid`.
+    pub fn from_stl(bytes: &[u8]) -> Result<Mesh, MeshError> {
+        if bytes.len() >= STL_HEADER + 4 {
+            let count = u32::from_le_bytes(bytes[STL_HEADER..STL_HEADER + 4].try_into().unwrap()) as usize;
+            let expected = STL_HEADER + 4 + count * STL_FACET;
+            if bytes.len() == expected {
+                return Self::from_stl_binary(&bytes[STL_HEADER + 4..]);
+            }
+            if !bytes.trim_ascii_start().starts_with(b"solid") {
+                return Err(MeshError::Truncated { expected, found: bytes.len() });
+            }
+        }
+        if !bytes.trim_ascii_start().starts_with(b"solid") {
+            return Err(MeshError::Format("stl".into()));
+        }
+        let text = std::str::from_utf8(bytes).map_err(|e| MeshError::Parse { line: 0, message: e.to_string() })?;
+        Self::from_stl_ascii(text)
+    }
+
+    fn from_stl_binary(facets: &[u8]) -> Result<Mesh, MeshError> {
+        let float = |b: &[u8]| f32::from_le_bytes(b.try_into().unwrap()) as f64;
+        let mut vertices = Vec::with_capacity(facets.len() / STL_FACET * 3);
+        for facet in facets.chunks_exact(STL_FACET) {
+            // 12 bytes of normal, three vertices, 2 bytes of attributes
+            for corner in facet[12..48].chunks_exact(12) {
+                vertices.push([float(&corner[0..4]), float(&corner[4..8]), float(&corner[8..12])]);
+            }
+        }
+        Self::from_loose_triangles(vertices)
+    }
+
+    fn from_stl_ascii(text: &str) -> Result<Mesh, MeshError> {
+        let mut vertices = Vec::new();
+        let mut in_facet = 0;
+
+        for (n, raw) in text.lines().enumerate() {
+            let line = n + 1;
+            let mut tokens = raw.split_whitespace();
+            match tokens.next() {
+                Some("vertex") => {
+                    let coords = tokens
+                        .map(|t| {
+                            t.parse::<f64>()
+                                .map_err(|e| MeshError::Parse { line, message: format!("`{t}`: {e}") })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let [x, y, z] = coords[..] else {
+                        return Err(MeshError::Parse { line, message: "a vertex needs three coordinates".into() });
+                    };
+                    vertices.push([x, y, z]);
+                    in_facet += 1;
+                }
+                Some("endloop") => {
+                    if in_facet != 3 {
+                        return Err(MeshError::Parse { line, message: format!("facet has {in_facet} vertices, not 3") });
+                    }
+                    in_facet = 0;
+                }
+                _ => {}
+            }
+        }
+        if vertices.len() % 3 != 0 {
+            let line = text.lines().count();
+            return Err(MeshError::Parse { line, message: format!("last facet has {in_facet} vertices, not 3") });
+        }
+
+        Self::from_loose_triangles(vertices)
+    }
+
+    fn from_loose_triangles(vertices: Vec<[f64; 3]>) -> Result<Mesh, MeshError> {
+        let triangles = (0..vertices.len() as u32 / 3).map(|t| [3 * t, 3 * t + 1, 3 * t + 2]).collect();
+        Ok(Mesh::new(vertices, triangles)?.welded(0.0))
+    }
+
+    pub fn to_stl_ascii(&self, name: &str) -> String {
+        let mut out = format!("solid {name}\n");
+        for t in 0..self.triangles().len() {
+            let n = self.face_normal(t);
+            let _ = writeln!(out, "  facet normal {} {} {}", n.x as f32, n.y as f32, n.z as f32);
+            out.push_str("    outer loop\n");
+            for v in self.corners(t) {
+                let _ = writeln!(out, "      vertex {} {} {}", v.x as f32, v.y as f32, v.z as f32);
+            }
+            out.push_str("    endloop\n  endfacet\n");
+        }
+        let _ = writeln!(out, "endsolid {name}");
+        out
+    }
+
+    pub fn to_stl_binary(&self) -> Vec<u8> {
+        let mut out = vec![0u8; STL_HEADER];
+        let header = b"omnivox binary STL";
+        out[..header.len()].copy_from_slice(header);
+        out.extend_from_slice(&(self.triangles().len() as u32).to_le_bytes());
+
+        for t in 0..self.triangles().len() {
+            let n = self.face_normal(t);
+            for v in std::iter::once(n).chain(self.corners(t)) {
+                for c in v.to_array() {
+                    out.extend_from_slice(&(c as f32).to_le_bytes());
+                }
+            }
+            out.extend_from_slice(&0u16.to_le_bytes());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::geospec::authoring::AuthoringShape;
+    use crate::core::objex::geospec::primitives::BoxShape;
+    use crate::core::objex::geospec::traits::SurfaceArea;
+
+    #[test]
+    fn formats_round_trip() {
+        // Quads, slashes, comments and a negative index
+        let obj = "# unit cube\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv 0 0 1\nv 1 0 1\nv 1 1 1\nv 0 1 1\n\
+                   f 1 4 3 2\nf 5 6 7 8\nf 1//1 2//1 6//1 5//1\nf 2 3 7 6\nf 3/1 4/1 8/1 7/1\nf -4 -1 -5 -8\n";
+        let cube = Mesh::from_obj(obj).unwrap();
+        assert_eq!(cube.triangles().len(), 12);
+        assert!(cube.is_watertight());
+        assert!((cube.signed_volume() - 1.0).abs() < 1e-12);
+
+        assert_eq!(Mesh::from_obj(&cube.to_obj()).unwrap(), cube);
+
+        let shape = AuthoringShape::Box(BoxShape { length: 0.5, width: 0.25, height: 2.0 });
+        let block = Mesh::from_shape(&shape, 0).unwrap();
+        for format in [MeshFormat::StlBinary, MeshFormat::StlAscii] {
+            let back = Mesh::import(format, &block.export(format)).unwrap();
+            assert_eq!(back.vertices().len(), 8);
+            assert!(back.is_watertight());
+            assert!((back.surface_area() - block.surface_area()).abs() < 1e-6);
+        }
+
+        assert!(matches!(Mesh::from_stl(&block.to_stl_binary()[..120]), Err(MeshError::Truncated { .. })));
+        assert!(matches!(Mesh::from_obj("v 0 0 0\nf 1 2 3\n"), Err(MeshError::IndexOutOfRange { index: 2, .. })));
+        assert!(matches!("ply".parse::<MeshFormat>(), Err(MeshError::Format(_))));
+    }
+
+    #[test]
+    fn malformed_obj_is_rejected_with_its_position() {
+        let triangle = "v 0 0 0\nv 1 0 0\nv 0 1 0\n";
+        let obj = |faces: &str| Mesh::from_obj(&format!("{triangle}{faces}"));
+
+        for (face, bad) in [("f 0 1 2", 0), ("f 1 2 4", 4), ("f -4 1 2", -4), ("f 1 2 3\nf 1 2 9", 9)] {
+            match obj(face) {
+                Err(MeshError::IndexOutOfRange { index, vertices: 3, .. }) => assert_eq!(index, bad, "{face}"),
+                other => panic!("{face}: {other:?}"),
+            }
+        }
+        assert!(matches!(obj("f 1 2 3\nf 1 2 9"), Err(MeshError::IndexOutOfRange { triangle: 1, .. })));
+        // A face may not refer to a vertex defined after it
+        assert!(matches!(Mesh::from_obj("v 0 0 0\nf 1 2 3\nv 1 0 0\nv 0 1 0\n"), Err(MeshError::IndexOutOfRange { .. })));
+
+        assert!(matches!(obj("f 1 2"), Err(MeshError::Parse { line: 4, .. })));
+        assert!(matches!(obj("f 1 2 x"), Err(MeshError::Parse { line: 4, .. })));
+        assert!(matches!(obj("f 1 2 99999999999999999999"), Err(MeshError::Parse { line: 4, .. })));
+        assert!(matches!(Mesh::from_obj("v 0 0\n"), Err(MeshError::Parse { line: 1, .. })));
+        assert!(matches!(Mesh::from_obj("v 0 zero 0\n"), Err(MeshError::Parse { line: 1, .. })));
+        let nan = Mesh::from_obj("v nan 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n");
+        assert!(matches!(nan, Err(MeshError::NonFinite { vertex: 0 })), "{nan:?}");
+        assert!(matches!(Mesh::from_obj("v 0 0 0\nv 1 inf 0\n"), Err(MeshError::NonFinite { vertex: 1 })));
+        assert_eq!(obj("").unwrap().triangles().len(), 0);
+    }
+
+    #[test]
+    fn malformed_stl_is_rejected() {
+        let block = Mesh::from_shape(&AuthoringShape::Box(BoxShape { length: 1.0, width: 1.0, height: 1.0 }), 0).unwrap();
+        let binary = block.to_stl_binary();
+        assert_eq!(binary.len(), STL_HEADER + 4 + 12 * STL_FACET);
+
+        // One facet short, one byte short, one byte over, header only
+        for len in [binary.len() - STL_FACET, binary.len() - 1, STL_HEADER + 4] {
+            assert_eq!(
+                Mesh::from_stl(&binary[..len]).unwrap_err().to_string(),
+                MeshError::Truncated { expected: binary.len(), found: len }.to_string()
+            );
+        }
+        let mut long = binary.clone();
+        long.push(0);
+        assert!(matches!(Mesh::from_stl(&long), Err(MeshError::Truncated { found, .. }) if found == binary.len() + 1));
+        assert!(matches!(Mesh::from_stl(&binary[..40]), Err(MeshError::Format(_))));
+        assert!(matches!(Mesh::from_stl(b""), Err(MeshError::Format(_))));
+        let mut nan = binary.clone();
+        nan[STL_HEADER + 4 + 12..STL_HEADER + 4 + 16].copy_from_slice(&f32::NAN.to_le_bytes());
+        assert!(matches!(Mesh::from_stl(&nan), Err(MeshError::NonFinite { vertex: 0 })));
+
+        // ASCII facets need exactly three vertices of three coordinates
+        let facet = |vertices: &str| format!("solid t\nfacet normal 0 0 1\nouter loop\n{vertices}endloop\nendfacet\nendsolid t\n");
+        let ok = facet("vertex 0 0 0\nvertex 1 0 0\nvertex 0 1 0\n");
+        assert_eq!(Mesh::from_stl(ok.as_bytes()).unwrap().triangles().len(), 1);
+        assert!(matches!(
+            Mesh::from_stl(facet("vertex 0 0 0\nvertex 1 0 0\n").as_bytes()),
+            Err(MeshError::Parse { line: 6, .. })
+        ));
+        assert!(matches!(
+            Mesh::from_stl(facet("vertex 0 0 0\nvertex 1 0 0\nvertex 0 1 0\nvertex 1 1 0\n").as_bytes()),
+            Err(MeshError::Parse { line: 8, .. })
+        ));
+        assert!(matches!(
+            Mesh::from_stl(facet("vertex 0 0 0\nvertex 1 0\nvertex 0 1 0\n").as_bytes()),
+            Err(MeshError::Parse { line: 5, .. })
+        ));
+        // Cut off mid-facet, with no endloop to check against
+        let cut = "solid t\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\n";
+        assert!(matches!(Mesh::from_stl(cut.as_bytes()), Err(MeshError::Parse { line: 5, .. })));
+    }
+}
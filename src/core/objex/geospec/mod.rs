@@ -5,6 +5,12 @@ pub mod authoring;
 pub mod inference;
 pub mod api;
 pub mod profile;
+pub mod csg;
+pub mod mesh;
+pub mod mesh_io;
+pub mod sweep;
+pub mod pipe;
+pub mod fitting;
 
 pub use primitives::*;
 pub use traits::*;
@@ -13,6 +19,12 @@ pub use authoring::*;
 pub use inference::*;
 pub use api::*;
 pub use profile::*;
+pub use csg::{CsgOptions, Measure, Orientation, Placed, Placement};
+pub use mesh::{Mesh, MeshCheck, MeshError};
+pub use mesh_io::MeshFormat;
+pub use sweep::{PathError, SweepPath};
+pub use pipe::{PipeError, PipeSize, PipeSystem};
+pub use fitting::{Fitting, PipeRun};
 pub mod store;
 pub use store::GeoSpecStore;
 
@@ -21,7 +33,13 @@ use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Bounds {
+    /// Enclosing sphere about the shape's origin
     pub radius: f64,
+    /// Axis-aligned box
+    #[serde(default)]
+    pub min: [f64; 3],
+    #[serde(default)]
+    pub max: [f64; 3],
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,86 +47,15 @@ pub struct GeoSpec {
     pub bounds: Bounds,
     pub volume: f64,
     pub surface_area: f64,
+    /// Absolute error bounds; zero when computed in closed form
+    #[serde(default)]
+    pub volume_error: f64,
+    #[serde(default)]
+    pub surface_area_error: f64,
 }
 
 impl Bounds {
     pub fn from_shape(shape: &AuthoringShape) -> Self {
-        match shape {
-            AuthoringShape::Point(_) => Bounds { radius: 0.0 },
-
-            AuthoringShape::Line(line) => Bounds {
-                radius: line.length / 2.0,
-            },
-
-            AuthoringShape::Plane(plane) => {
-                let diag = (plane.width.powi(2) + plane.height.powi(2)).sqrt();
-                Bounds { radius: diag / 2.0 }
-            }
-
-            AuthoringShape::Sphere(s) => Bounds { radius: s.radius },
-
-            AuthoringShape::Box(b) => {
-                let diag = (b.length.powi(2)
-                    + b.width.powi(2)
-                    + b.height.powi(2))
-                    .sqrt();
-                Bounds { radius: diag / 2.0 }
-            }
-
-            AuthoringShape::Cylinder(c) => {
-                let diag = (4.0 * c.radius.powi(2) + c.height.powi(2)).sqrt();
-                Bounds { radius: diag / 2.0 }
-            }
-
-            AuthoringShape::Cone(c) => {
-                let diag = (4.0 * c.radius.powi(2) + c.height.powi(2)).sqrt();
-                Bounds { radius: diag / 2.0 }
-            }
-
-            // ───────────── NEW: EXTRUDE ─────────────
-
-            AuthoringShape::Extrude { profile, length, capped } => {
-                use crate::core::objex::geospec::profile::Profile;
-
-                let r_profile = match profile {
-                    Profile::Circle { outer_radius, .. } => *outer_radius,
-
-                    Profile::Rect { width, height, .. } => {
-                        ((width / 2.0).powi(2) + (height / 2.0).powi(2)).sqrt()
-                    }
-
-                    Profile::IBeam {
-                        flange_width,
-                        web_height,
-                        flange_thickness,
-                        ..
-                    } => {
-                        let h = web_height + 2.0 * flange_thickness;
-                        ((flange_width / 2.0).powi(2) + (h / 2.0).powi(2)).sqrt()
-                    }
-                };
-
-                let radius = (r_profile.powi(2) + (length / 2.0).powi(2)).sqrt();
-
-                Bounds { radius }
-            }
-
-            // ───────────── COMPOSITES ─────────────
-
-            AuthoringShape::Union { children } => {
-                let radius = children
-                    .iter()
-                    .map(|c| Bounds::from_shape(c).radius)
-                    .fold(0.0, f64::max);
-
-                Bounds { radius }
-            }
-
-            AuthoringShape::Difference { outer, .. } => {
-                Bounds::from_shape(outer)
-            }
-        }
+        csg::bounds(shape)
     }
 }
-
-
@@ -0,0 +1,329 @@
+//! Standard pipe, tube and conduit sizes.
+//!
+//! Outside diameters and walls as published, converted to metres:
+//!
+//! - `NpsSch40`, `NpsSch80`: steel pipe, ASME B36.10M, 1/8" to 12"
+//! - `CopperK`, `CopperL`, `CopperM`: copper tube size (CTS), ASTM B88;
+//!   OD is nominal + 1/8"
+//! - `Pex`: PEX tubing, ASTM F876 SDR 9 on copper tube ODs
+//! - `Emt`: electrical metallic tubing, ANSI C80.3
+//!
+//! Every size also carries the centreline radius its bends use: long
+//! radius (1.5 × nominal) for steel pipe, but at least 1 × OD at 1/2"
+//! and below so the bend clears its own wall; wrought short radius
+//! (1 × OD) for copper, the 6 × OD minimum for PEX, and the NEC
+//! Chapter 9 Table 2 one-shot radius for EMT.
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+use thiserror::Error;
+
+use crate::core::objex::geospec::profile::Profile;
+use crate::core::objex::geospec::sweep::PathError;
+use crate::core::objex::matcat::materials::MatCatId;
+
+const INCH: f64 = 0.0254;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PipeError {
+    #[error("no {system:?} size `{nominal}`")]
+    UnknownSize { system: PipeSystem, nominal: String },
+
+    #[error("a {branch} branch is larger than its {run} run")]
+    BranchTooLarge { run: String, branch: String },
+
+    #[error("elbow angle {0}° must be above 0° and below 180°")]
+    Angle(f64),
+
+    #[error("length {0} m must be positive and finite")]
+    Length(f64),
+
+    #[error("bend radius {radius} m must be finite and more than the pipe's outer radius {min} m")]
+    BendRadius { radius: f64, min: f64 },
+
+    #[error("a {from} to {to} reducer has no solid outline")]
+    Reducer { from: String, to: String },
+
+    #[error(transparent)]
+    Path(#[from] PathError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipeSystem {
+    NpsSch40,
+    NpsSch80,
+    CopperK,
+    CopperL,
+    CopperM,
+    Pex,
+    Emt,
+}
+
+impl PipeSystem {
+    pub const ALL: [PipeSystem; 7] = [
+        PipeSystem::NpsSch40,
+        PipeSystem::NpsSch80,
+        PipeSystem::CopperK,
+        PipeSystem::CopperL,
+        PipeSystem::CopperM,
+        PipeSystem::Pex,
+        PipeSystem::Emt,
+    ];
+
+    /// What the pipe is made of: carbon steel for NPS and EMT,
+    /// phosphor-deoxidized copper (C122) for CTS tube, cross-linked
+    /// polyethylene (the measured PE-X record) for PEX.
+    pub fn material(self) -> MatCatId {
+        match self {
+            PipeSystem::NpsSch40 | PipeSystem::NpsSch80 | PipeSystem::Emt => MatCatId::steel_lowcarbon(),
+            PipeSystem::CopperK | PipeSystem::CopperL | PipeSystem::CopperM => MatCatId::new(1, 3, 2),
+            PipeSystem::Pex => MatCatId::new(2, 2, 1),
+        }
+    }
+
+    /// Every size in the system, smallest first.
+    pub fn sizes(self) -> Vec<PipeSize> {
+        let table: &[Row] = match self {
+            PipeSystem::NpsSch40 | PipeSystem::NpsSch80 => NPS,
+            PipeSystem::CopperK | PipeSystem::CopperL | PipeSystem::CopperM | PipeSystem::Pex => CTS,
+            PipeSystem::Emt => EMT,
+        };
+        table.iter().filter_map(|row| self.size_from(row)).collect()
+    }
+
+    /// Size by its nominal name (`"1/2"`, `"1-1/4"`, `"2"`).
+    pub fn size(self, nominal: &str) -> Result<PipeSize, PipeError> {
+        self.sizes()
+            .into_iter()
+            .find(|s| s.nominal == nominal.trim().trim_end_matches('"'))
+            .ok_or_else(|| PipeError::UnknownSize { system: self, nominal: nominal.to_string() })
+    }
+
+    fn size_from(self, row: &Row) -> Option<PipeSize> {
+        let &(nominal, inches, od, [a, b, c]) = row;
+        let wall = match self {
+            PipeSystem::NpsSch40 | PipeSystem::CopperK | PipeSystem::Emt => a,
+            PipeSystem::NpsSch80 | PipeSystem::CopperL => b,
+            PipeSystem::CopperM => c,
+            // SDR 9, with a 0.070" minimum wall
+            PipeSystem::Pex => (3.0 / 8.0..=2.0).contains(&inches).then_some((od / 9.0).max(0.070))?,
+        };
+        if wall <= 0.0 {
+            return None;
+        }
+        let bend = match self {
+            PipeSystem::NpsSch40 | PipeSystem::NpsSch80 => (1.5 * inches).max(od),
+            PipeSystem::CopperK | PipeSystem::CopperL | PipeSystem::CopperM => od,
+            PipeSystem::Pex => 6.0 * od,
+            PipeSystem::Emt => c,
+        };
+        Some(PipeSize {
+            system: self,
+            nominal,
+            outer_diameter: od * INCH,
+            wall: wall * INCH,
+            bend_radius: bend * INCH,
+        })
+    }
+}
+
+/// One catalog size. Serialized in full; read back from just `system`
+/// and `nominal`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PipeSize {
+    system: PipeSystem,
+    nominal: &'static str,
+    outer_diameter: f64,
+    wall: f64,
+    bend_radius: f64,
+}
+
+/// Serialized reference to a catalog size.
+#[derive(Deserialize)]
+struct PipeSizeRef {
+    system: PipeSystem,
+    nominal: String,
+}
+
+// By hand: `nominal` borrows from the table, not the input
+impl<'de> Deserialize<'de> for PipeSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let r = PipeSizeRef::deserialize(deserializer)?;
+        r.system.size(&r.nominal).map_err(de::Error::custom)
+    }
+}
+
+impl PipeSize {
+    pub fn system(&self) -> PipeSystem {
+        self.system
+    }
+
+    pub fn nominal(&self) -> &'static str {
+        self.nominal
+    }
+
+    /// m
+    pub fn outer_diameter(&self) -> f64 {
+        self.outer_diameter
+    }
+
+    /// m
+    pub fn inner_diameter(&self) -> f64 {
+        self.outer_diameter - 2.0 * self.wall
+    }
+
+    /// m
+    pub fn wall(&self) -> f64 {
+        self.wall
+    }
+
+    /// Centreline radius of this size's elbows and bends, m.
+    pub fn bend_radius(&self) -> f64 {
+        self.bend_radius
+    }
+
+    pub fn material(&self) -> MatCatId {
+        self.system.material()
+    }
+
+    /// The wall's cross-section.
+    pub fn profile(&self) -> Profile {
+        Profile::Circle {
+            outer_radius: self.outer_diameter / 2.0,
+            inner_radius: Some(self.inner_diameter() / 2.0),
+        }
+    }
+
+    /// Flow area, m².
+    pub fn bore_area(&self) -> f64 {
+        std::f64::consts::PI * (self.inner_diameter() / 2.0).powi(2)
+    }
+}
+
+// ----------------------------------------------------------------
+// Tables (inches)
+// ----------------------------------------------------------------
+
+/// Nominal name, nominal size, OD, and three walls whose meaning
+/// depends on the table.
+type Row = (&'static str, f64, f64, [f64; 3]);
+
+/// OD, [schedule 40, schedule 80, unused]
+const NPS: &[Row] = &[
+    ("1/8", 0.125, 0.405, [0.068, 0.095, 0.0]),
+    ("1/4", 0.25, 0.540, [0.088, 0.119, 0.0]),
+    ("3/8", 0.375, 0.675, [0.091, 0.126, 0.0]),
+    ("1/2", 0.5, 0.840, [0.109, 0.147, 0.0]),
+    ("3/4", 0.75, 1.050, [0.113, 0.154, 0.0]),
+    ("1", 1.0, 1.315, [0.133, 0.179, 0.0]),
+    ("1-1/4", 1.25, 1.660, [0.140, 0.191, 0.0]),
+    ("1-1/2", 1.5, 1.900, [0.145, 0.200, 0.0]),
+    ("2", 2.0, 2.375, [0.154, 0.218, 0.0]),
+    ("2-1/2", 2.5, 2.875, [0.203, 0.276, 0.0]),
+    ("3", 3.0, 3.500, [0.216, 0.300, 0.0]),
+    ("4", 4.0, 4.500, [0.237, 0.337, 0.0]),
+    ("5", 5.0, 5.563, [0.258, 0.375, 0.0]),
+    ("6", 6.0, 6.625, [0.280, 0.432, 0.0]),
+    ("8", 8.0, 8.625, [0.322, 0.500, 0.0]),
+    ("10", 10.0, 10.750, [0.365, 0.594, 0.0]),
+    ("12", 12.0, 12.750, [0.406, 0.688, 0.0]),
+];
+
+/// OD, [type K, type L, type M]; 0 where the type is not made. PEX
+/// shares the ODs.
+const CTS: &[Row] = &[
+    ("1/4", 0.25, 0.375, [0.035, 0.030, 0.0]),
+    ("3/8", 0.375, 0.500, [0.049, 0.035, 0.025]),
+    ("1/2", 0.5, 0.625, [0.049, 0.040, 0.028]),
+    ("5/8", 0.625, 0.750, [0.049, 0.042, 0.0]),
+    ("3/4", 0.75, 0.875, [0.065, 0.045, 0.032]),
+    ("1", 1.0, 1.125, [0.065, 0.050, 0.035]),
+    ("1-1/4", 1.25, 1.375, [0.065, 0.055, 0.042]),
+    ("1-1/2", 1.5, 1.625, [0.072, 0.060, 0.049]),
+    ("2", 2.0, 2.125, [0.083, 0.070, 0.058]),
+    ("2-1/2", 2.5, 2.625, [0.095, 0.080, 0.065]),
+    ("3", 3.0, 3.125, [0.109, 0.090, 0.072]),
+    ("4", 4.0, 4.125, [0.134, 0.110, 0.095]),
+];
+
+/// OD, [wall, unused, one-shot bend radius]
+const EMT: &[Row] = &[
+    ("1/2", 0.5, 0.706, [0.042, 0.0, 4.0]),
+    ("3/4", 0.75, 0.922, [0.049, 0.0, 4.5]),
+    ("1", 1.0, 1.163, [0.057, 0.0, 5.75]),
+    ("1-1/4", 1.25, 1.510, [0.065, 0.0, 7.25]),
+    ("1-1/2", 1.5, 1.740, [0.065, 0.0, 8.25]),
+    ("2", 2.0, 2.197, [0.065, 0.0, 9.5]),
+    ("2-1/2", 2.5, 2.875, [0.072, 0.0, 10.5]),
+    ("3", 3.0, 3.500, [0.072, 0.0, 13.0]),
+    ("3-1/2", 3.5, 4.000, [0.083, 0.0, 15.0]),
+    ("4", 4.0, 4.500, [0.083, 0.0, 16.0]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::matcat::materials::{resolve_props, PropsSource};
+
+    #[test]
+    fn materials_come_from_measured_records() {
+        for system in PipeSystem::ALL {
+            let resolved = resolve_props(&system.material());
+            assert!(matches!(resolved.source, PropsSource::Measured { .. }), "{system:?}: {:?}", resolved.source);
+        }
+
+        // PEX is a polymer: light, soft, insulating and low-melting
+        let pex = resolve_props(&PipeSystem::Pex.material()).props;
+        assert!((900.0..1000.0).contains(&pex.density), "{}", pex.density);
+        assert!(pex.elastic_modulus < 2e9 && pex.thermal_conductivity < 1.0);
+        assert!(pex.melting_point < 200.0 && pex.specific_heat > 1500.0);
+        let steel = resolve_props(&PipeSystem::NpsSch40.material()).props;
+        assert!(steel.density > 7000.0);
+    }
+
+    #[test]
+    fn every_catalog_size_is_a_buildable_pipe() {
+        for system in PipeSystem::ALL {
+            let sizes = system.sizes();
+            assert!(!sizes.is_empty(), "{system:?}");
+            for pair in sizes.windows(2) {
+                assert!(pair[0].outer_diameter() < pair[1].outer_diameter(), "{system:?} out of order");
+            }
+            for size in sizes {
+                assert!(size.wall() > 0.0 && size.inner_diameter() > 0.0, "{system:?} {}", size.nominal());
+                // Elbows need a centreline outside the pipe
+                assert!(size.bend_radius() > size.outer_diameter() / 2.0, "{system:?} {}", size.nominal());
+                assert_eq!(system.size(size.nominal()), Ok(size));
+            }
+        }
+    }
+
+    #[test]
+    fn sizes_are_looked_up_by_nominal_name() {
+        let half = PipeSystem::CopperL.size("1/2").unwrap();
+        assert_eq!(PipeSystem::CopperL.size(" 1/2\" "), Ok(half));
+        assert_eq!(
+            PipeSystem::CopperL.size("7/8"),
+            Err(PipeError::UnknownSize { system: PipeSystem::CopperL, nominal: "7/8".into() })
+        );
+
+        // Type M is not made at 1/4"; PEX only from 3/8" to 2"
+        assert!(PipeSystem::CopperM.size("1/4").is_err());
+        assert!(PipeSystem::Pex.size("1/4").is_err() && PipeSystem::Pex.size("2-1/2").is_err());
+        assert!(PipeSystem::Pex.size("2").is_ok());
+        // SDR 9 walls, never below 0.070"
+        let pex = PipeSystem::Pex.size("3/8").unwrap();
+        assert!((pex.wall() - 0.070 * INCH).abs() < 1e-12);
+
+        // Schedule 80 is the same OD with a thicker wall
+        let (s40, s80) = (PipeSystem::NpsSch40.size("4").unwrap(), PipeSystem::NpsSch80.size("4").unwrap());
+        assert_eq!(s40.outer_diameter(), s80.outer_diameter());
+        assert!(s80.inner_diameter() < s40.inner_diameter());
+
+        // Loaded from system and nominal alone; unknown sizes fail to load
+        let loaded: PipeSize = serde_json::from_str(r#"{"system":"emt","nominal":"1"}"#).unwrap();
+        assert_eq!(loaded, PipeSystem::Emt.size("1").unwrap());
+        assert!(serde_json::from_str::<PipeSize>(r#"{"system":"emt","nominal":"5"}"#).is_err());
+        assert!(serde_json::from_str::<PipeSize>(r#"{"system":"cast_iron","nominal":"1"}"#).is_err());
+    }
+}
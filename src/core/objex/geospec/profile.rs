@@ -18,6 +18,33 @@ pub enum Profile {
         web_height: f64,
         web_thickness: f64,
     },
+    /// C section: web along y, flanges running off toward +x.
+    Channel {
+        flange_width: f64,
+        flange_thickness: f64,
+        web_height: f64,
+        web_thickness: f64,
+    },
+    /// L section: one leg along x (`width`), one along y (`height`).
+    Angle {
+        width: f64,
+        height: f64,
+        thickness: f64,
+    },
+    /// T section: flange on top (+y), stem below.
+    Tee {
+        flange_width: f64,
+        flange_thickness: f64,
+        stem_height: f64,
+        stem_thickness: f64,
+    },
+    /// Any closed polygon (the last point joins the first), with
+    /// optional holes. Winding does not matter.
+    Polyline {
+        points: Vec<[f64; 2]>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        holes: Vec<Vec<[f64; 2]>>,
+    },
 }
 
 pub trait ProfileArea {
@@ -61,6 +88,8 @@ impl ProfileArea for Profile {
                 let web_area = web_thickness * web_height;
                 flange_area + web_area
             }
+
+            _ => Moments::of(&self.outlines()).area,
         }
     }
 }
@@ -82,8 +111,9 @@ impl ProfilePerimeter for Profile {
                     Some(w) => {
                         let inner_w = width - 2.0 * w;
                         let inner_h = height - 2.0 * w;
-                        2.0 * (width + height)
-                            + 2.0 * (inner_w.max(0.0) + inner_h.max(0.0))
+                        // A wall past the middle leaves no hole to line
+                        let hole = if inner_w > 0.0 && inner_h > 0.0 { 2.0 * (inner_w + inner_h) } else { 0.0 };
+                        2.0 * (width + height) + hole
                     }
                 }
             }
@@ -94,15 +124,422 @@ impl ProfilePerimeter for Profile {
                 web_height,
                 web_thickness,
             } => {
-                // Outline perimeter only (correct for corrosion / exposure)
-                let total_height = 2.0 * flange_thickness + web_height;
+                // Outline perimeter only (correct for corrosion / exposure):
+                // both flange faces, four flange tips, and the two web faces
+                // less where the web meets the flanges
+                4.0 * flange_width + 4.0 * flange_thickness + 2.0 * web_height - 2.0 * web_thickness
+            }
+
+            _ => self.outlines().iter().map(|outline| loop_length(outline)).sum(),
+        }
+    }
+}
+
+/// Section properties about the centroid, which is the profile origin.
+///
+/// `ix` resists bending about the x axis (load along y), `iy` about y.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SectionProperties {
+    /// m²
+    pub area: f64,
+    /// Second moments of area ∫y² dA and ∫x² dA, m⁴
+    pub ix: f64,
+    pub iy: f64,
+    /// Product of area ∫xy dA, m⁴; zero when either axis is an axis of
+    /// symmetry
+    pub ixy: f64,
+    /// Elastic section moduli, I over the extreme fibre distance, m³
+    pub sx: f64,
+    pub sy: f64,
+    /// Radii of gyration √(I/A), m
+    pub rx: f64,
+    pub ry: f64,
+}
+
+impl SectionProperties {
+    /// Polar second moment ix + iy. Only for circular sections is this
+    /// also the torsion constant.
+    pub fn polar(&self) -> f64 {
+        self.ix + self.iy
+    }
+
+    /// Principal second moments (major, minor). An unequal or equal
+    /// angle buckles about the minor axis, not x or y.
+    pub fn principal(&self) -> (f64, f64) {
+        let mean = (self.ix + self.iy) / 2.0;
+        let radius = (((self.ix - self.iy) / 2.0).powi(2) + self.ixy.powi(2)).sqrt();
+        (mean + radius, mean - radius)
+    }
+}
+
+/// Segments used when a circle is turned into a polygon outline.
+pub(crate) const CIRCLE_SEGMENTS: usize = 128;
+
+/// Sections sit with their centroid on the origin of the xy plane; for
+/// the symmetric ones that is also their middle. An I-beam's flanges run
+/// along x. Polylines are shifted to their centroid too.
+impl Profile {
+    /// Whether (x, y) lies in the section.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        match self {
+            Profile::Circle { outer_radius, inner_radius } => {
+                let r2 = x * x + y * y;
+                r2 <= outer_radius.powi(2) && inner_radius.is_none_or(|r| r2 >= r.powi(2))
+            }
+
+            Profile::Rect { width, height, wall } => {
+                let (hx, hy) = (width / 2.0, height / 2.0);
+                let solid = x.abs() <= hx && y.abs() <= hy;
+                let hollow = wall.is_some_and(|w| x.abs() < hx - w && y.abs() < hy - w);
+                solid && !hollow
+            }
+
+            Profile::IBeam { flange_width, flange_thickness, web_height, web_thickness } => {
+                let half_height = web_height / 2.0 + flange_thickness;
+                x.abs() <= flange_width / 2.0
+                    && y.abs() <= half_height
+                    && (y.abs() >= web_height / 2.0 || x.abs() <= web_thickness / 2.0)
+            }
+
+            // Even-odd over every loop, holes included
+            _ => self.outlines().iter().filter(|outline| crosses(outline, x, y)).count() % 2 == 1,
+        }
+    }
+
+    /// Half extents (x, y) of a rectangle centred on the origin that
+    /// holds the section. Sections that are not symmetric (channels,
+    /// angles, tees, polylines) get the larger reach either side.
+    pub fn half_extents(&self) -> (f64, f64) {
+        match self {
+            Profile::Circle { outer_radius, .. } => (*outer_radius, *outer_radius),
+            Profile::Rect { width, height, .. } => (width / 2.0, height / 2.0),
+            Profile::IBeam { flange_width, flange_thickness, web_height, .. } => {
+                (flange_width / 2.0, web_height / 2.0 + flange_thickness)
+            }
+            _ => self
+                .outlines()
+                .first()
+                .into_iter()
+                .flatten()
+                .fold((0.0, 0.0), |(hx, hy), [x, y]| (f64::max(hx, x.abs()), f64::max(hy, y.abs()))),
+        }
+    }
+
+    /// Closed outlines: the outer boundary counter-clockwise, then any
+    /// holes clockwise. Circles are approximated by polygons.
+    pub fn outlines(&self) -> Vec<Vec<[f64; 2]>> {
+        let circle = |r: f64, ccw: bool| -> Vec<[f64; 2]> {
+            (0..CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let t = i as f64 / CIRCLE_SEGMENTS as f64 * std::f64::consts::TAU;
+                    let t = if ccw { t } else { -t };
+                    [r * t.cos(), r * t.sin()]
+                })
+                .collect()
+        };
+
+        match self {
+            Profile::Circle { outer_radius, inner_radius } => {
+                let mut loops = vec![circle(*outer_radius, true)];
+                if let Some(r) = inner_radius.filter(|r| *r > 0.0) {
+                    loops.push(circle(r, false));
+                }
+                loops
+            }
+
+            Profile::Rect { width, height, wall } => {
+                let (hx, hy) = (width / 2.0, height / 2.0);
+                let mut loops = vec![vec![[-hx, -hy], [hx, -hy], [hx, hy], [-hx, hy]]];
+                if let Some(w) = wall {
+                    let (ix, iy) = (hx - w, hy - w);
+                    if ix > 0.0 && iy > 0.0 {
+                        loops.push(vec![[-ix, -iy], [-ix, iy], [ix, iy], [ix, -iy]]);
+                    }
+                }
+                loops
+            }
+
+            Profile::IBeam { flange_width, flange_thickness, web_height, web_thickness } => {
+                let (fx, wx) = (flange_width / 2.0, web_thickness / 2.0);
+                let (wy, hy) = (web_height / 2.0, web_height / 2.0 + flange_thickness);
+                vec![vec![
+                    [-fx, -hy], [fx, -hy], [fx, -wy], [wx, -wy],
+                    [wx, wy], [fx, wy], [fx, hy], [-fx, hy],
+                    [-fx, wy], [-wx, wy], [-wx, -wy], [-fx, -wy],
+                ]]
+            }
+
+            // Drawn from a corner, then moved onto the centroid
+            Profile::Channel { flange_width, flange_thickness, web_height, web_thickness } => {
+                let (bf, tf, tw) = (*flange_width, *flange_thickness, *web_thickness);
+                let d = web_height + 2.0 * tf;
+                centred(vec![vec![
+                    [0.0, 0.0], [bf, 0.0], [bf, tf], [tw, tf],
+                    [tw, d - tf], [bf, d - tf], [bf, d], [0.0, d],
+                ]])
+            }
+
+            Profile::Angle { width, height, thickness } => {
+                let t = *thickness;
+                centred(vec![vec![
+                    [0.0, 0.0], [*width, 0.0], [*width, t], [t, t], [t, *height], [0.0, *height],
+                ]])
+            }
+
+            Profile::Tee { flange_width, flange_thickness, stem_height, stem_thickness } => {
+                let (fx, sx) = (flange_width / 2.0, stem_thickness / 2.0);
+                let (h, top) = (*stem_height, stem_height + flange_thickness);
+                centred(vec![vec![
+                    [-sx, 0.0], [sx, 0.0], [sx, h], [fx, h],
+                    [fx, top], [-fx, top], [-fx, h], [-sx, h],
+                ]])
+            }
 
-                2.0 * (
-                    flange_width
-                    + total_height
-                    + web_thickness
-                )
+            Profile::Polyline { points, holes } => {
+                let mut loops = vec![wound(points.clone(), true)];
+                loops.extend(holes.iter().map(|hole| wound(hole.clone(), false)));
+                centred(loops)
             }
         }
     }
+
+    /// Area, second moments and section moduli about the centroid.
+    /// Exact for circles and for every polygonal section.
+    pub fn section(&self) -> SectionProperties {
+        let (area, ix, iy, ixy, (cx, cy)) = match self {
+            Profile::Circle { outer_radius, inner_radius } => {
+                let r = inner_radius.unwrap_or(0.0);
+                let i = std::f64::consts::FRAC_PI_4 * (outer_radius.powi(4) - r.powi(4));
+                (self.area(), i, i, 0.0, (*outer_radius, *outer_radius))
+            }
+            _ => {
+                let outlines = self.outlines();
+                let m = Moments::of(&outlines);
+                let reach = outlines
+                    .first()
+                    .into_iter()
+                    .flatten()
+                    .fold((0.0, 0.0), |(cx, cy), [x, y]| (f64::max(cx, x.abs()), f64::max(cy, y.abs())));
+                (m.area, m.ixx, m.iyy, m.ixy, reach)
+            }
+        };
+
+        let ratio = |a: f64, b: f64| if b > 0.0 { a / b } else { 0.0 };
+        SectionProperties {
+            area,
+            ix,
+            iy,
+            ixy,
+            sx: ratio(ix, cy),
+            sy: ratio(iy, cx),
+            rx: ratio(ix, area).sqrt(),
+            ry: ratio(iy, area).sqrt(),
+        }
+    }
+
+    /// Centroid of the outline (not the area): where the perimeter is
+    /// balanced. Sweeps need it for the side area around bends.
+    pub(crate) fn outline_centroid(&self) -> [f64; 2] {
+        if let Profile::Circle { .. } = self {
+            return [0.0, 0.0];
+        }
+        let (mut length, mut sum) = (0.0, [0.0, 0.0]);
+        for outline in self.outlines() {
+            for (i, a) in outline.iter().enumerate() {
+                let b = outline[(i + 1) % outline.len()];
+                let l = ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt();
+                length += l;
+                sum[0] += l * (a[0] + b[0]) / 2.0;
+                sum[1] += l * (a[1] + b[1]) / 2.0;
+            }
+        }
+        if length > 0.0 { [sum[0] / length, sum[1] / length] } else { [0.0, 0.0] }
+    }
+}
+
+// ----------------------------------------------------------------
+// Polygon helpers
+// ----------------------------------------------------------------
+
+/// Area integrals of oriented loops (outer counter-clockwise, holes
+/// clockwise) by Green's theorem, about the origin.
+struct Moments {
+    area: f64,
+    /// ∫x dA, ∫y dA
+    first: [f64; 2],
+    ixx: f64,
+    iyy: f64,
+    ixy: f64,
+}
+
+impl Moments {
+    fn of(loops: &[Vec<[f64; 2]>]) -> Self {
+        let mut m = Moments { area: 0.0, first: [0.0, 0.0], ixx: 0.0, iyy: 0.0, ixy: 0.0 };
+        for outline in loops {
+            for (i, &[x0, y0]) in outline.iter().enumerate() {
+                let [x1, y1] = outline[(i + 1) % outline.len()];
+                let c = x0 * y1 - x1 * y0;
+                m.area += c / 2.0;
+                m.first[0] += (x0 + x1) * c / 6.0;
+                m.first[1] += (y0 + y1) * c / 6.0;
+                m.ixx += (y0 * y0 + y0 * y1 + y1 * y1) * c / 12.0;
+                m.iyy += (x0 * x0 + x0 * x1 + x1 * x1) * c / 12.0;
+                m.ixy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * c / 24.0;
+            }
+        }
+        m
+    }
+}
+
+fn signed_area(outline: &[[f64; 2]]) -> f64 {
+    Moments::of(&[outline.to_vec()]).area
+}
+
+fn loop_length(outline: &[[f64; 2]]) -> f64 {
+    (0..outline.len())
+        .map(|i| {
+            let (a, b) = (outline[i], outline[(i + 1) % outline.len()]);
+            ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt()
+        })
+        .sum()
+}
+
+/// `outline` running counter-clockwise (`ccw`) or clockwise.
+fn wound(mut outline: Vec<[f64; 2]>, ccw: bool) -> Vec<[f64; 2]> {
+    if (signed_area(&outline) > 0.0) != ccw {
+        outline.reverse();
+    }
+    outline
+}
+
+/// Loops shifted so their area centroid is the origin.
+fn centred(mut loops: Vec<Vec<[f64; 2]>>) -> Vec<Vec<[f64; 2]>> {
+    let m = Moments::of(&loops);
+    if m.area != 0.0 {
+        let (cx, cy) = (m.first[0] / m.area, m.first[1] / m.area);
+        loops.iter_mut().flatten().for_each(|p| *p = [p[0] - cx, p[1] - cy]);
+    }
+    loops
+}
+
+/// Whether a ray from (x, y) toward +x crosses the loop an odd number
+/// of times.
+fn crosses(outline: &[[f64; 2]], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    for (i, &[x0, y0]) in outline.iter().enumerate() {
+        let [x1, y1] = outline[(i + 1) % outline.len()];
+        if (y0 > y) != (y1 > y) && x < x0 + (y - y0) * (x1 - x0) / (y1 - y0) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * a.abs().max(b.abs()).max(1e-12)
+    }
+
+    #[test]
+    fn section_properties_match_handbook_formulas() {
+        // W-shape: flange outer faces, tips and inner faces, web faces
+        let (bf, tf, hw, tw) = (0.2, 0.02, 0.3, 0.01);
+        let beam = Profile::IBeam { flange_width: bf, flange_thickness: tf, web_height: hw, web_thickness: tw };
+        let depth = hw + 2.0 * tf;
+        let s = beam.section();
+        assert!(close(s.area, beam.area()));
+        assert!(close(s.ix, (bf * depth.powi(3) - (bf - tw) * hw.powi(3)) / 12.0));
+        assert!(close(s.sx, s.ix / (depth / 2.0)));
+        assert!(close(beam.perimeter(), beam.outlines().iter().map(|o| loop_length(o)).sum()));
+
+        // A channel is an I-beam about x, with its centroid pulled toward the web
+        let channel = Profile::Channel { flange_width: bf, flange_thickness: tf, web_height: hw, web_thickness: tw };
+        let c = channel.section();
+        assert!(close(c.ix, s.ix));
+        assert!(c.ixy.abs() < 1e-15);
+        let xbar = (2.0 * bf * tf * bf / 2.0 + hw * tw * tw / 2.0) / c.area;
+        let iy = 2.0 * (tf * bf.powi(3) / 12.0 + bf * tf * (bf / 2.0 - xbar).powi(2))
+            + hw * tw.powi(3) / 12.0
+            + hw * tw * (tw / 2.0 - xbar).powi(2);
+        assert!(close(c.iy, iy));
+        assert!(close(c.sy, iy / (bf - xbar)));
+        assert!(channel.contains(-xbar + tw / 2.0, 0.0) && !channel.contains(0.0, 0.0));
+
+        // Equal angle: ix = iy, principal axes at 45°
+        let (w, t) = (0.1, 0.01);
+        let angle = Profile::Angle { width: w, height: w, thickness: t };
+        let a = angle.section();
+        let ybar = (w * t * t / 2.0 + (w - t) * t * (t + (w - t) / 2.0)) / a.area;
+        let ix = w * t.powi(3) / 12.0
+            + w * t * (t / 2.0 - ybar).powi(2)
+            + t * (w - t).powi(3) / 12.0
+            + t * (w - t) * (t + (w - t) / 2.0 - ybar).powi(2);
+        assert!(close(a.ix, ix) && close(a.iy, ix));
+        assert!(a.ixy < 0.0);
+        let (major, minor) = a.principal();
+        assert!(close(major - minor, -2.0 * a.ixy));
+
+        // Tee: flange and stem about the composite centroid
+        let tee = Profile::Tee { flange_width: 0.1, flange_thickness: 0.01, stem_height: 0.09, stem_thickness: 0.01 };
+        let (flange, stem): (f64, f64) = (0.1 * 0.01, 0.09 * 0.01);
+        let ybar = (flange * 0.095 + stem * 0.045) / (flange + stem);
+        let ix = 0.1 * 0.01f64.powi(3) / 12.0 + flange * (0.095 - ybar).powi(2)
+            + 0.01 * 0.09f64.powi(3) / 12.0 + stem * (0.045 - ybar).powi(2);
+        assert!(close(tee.section().ix, ix));
+        assert!(close(tee.section().sx, ix / ybar));
+
+        // A clockwise, off-centre polyline square with a hole is a hollow rect
+        let poly = Profile::Polyline {
+            points: vec![[1.0, 1.0], [1.0, 1.3], [1.2, 1.3], [1.2, 1.0]],
+            holes: vec![vec![[1.01, 1.01], [1.19, 1.01], [1.19, 1.29], [1.01, 1.29]]],
+        };
+        let rect = Profile::Rect { width: 0.2, height: 0.3, wall: Some(0.01) };
+        let (p, r) = (poly.section(), rect.section());
+        assert!(close(p.area, r.area) && close(p.ix, r.ix) && close(p.sy, r.sy));
+        assert!(close(poly.perimeter(), rect.perimeter()));
+        assert!(poly.contains(0.095, 0.0) && !poly.contains(0.0, 0.0));
+
+        // Hollow round, analytic rather than from the polygon
+        let pipe = Profile::Circle { outer_radius: 0.05, inner_radius: Some(0.04) };
+        let i = std::f64::consts::FRAC_PI_4 * (0.05f64.powi(4) - 0.04f64.powi(4));
+        assert!(close(pipe.section().ix, i) && close(pipe.section().sx, i / 0.05));
+    }
+
+    #[test]
+    fn degenerate_sections_stay_finite_and_consistent() {
+        // Walls past the middle close the hole
+        for wall in [0.1, 0.15, 0.5] {
+            let rect = Profile::Rect { width: 1.0, height: 0.2, wall: Some(wall) };
+            let solid = Profile::Rect { width: 1.0, height: 0.2, wall: None };
+            assert_eq!(rect.outlines().len(), 1);
+            assert!(close(rect.area(), solid.area()) && close(rect.perimeter(), solid.perimeter()), "{wall}");
+            assert!(close(rect.section().ix, solid.section().ix));
+        }
+        let thin = Profile::Rect { width: 1.0, height: 0.2, wall: Some(0.01) };
+        assert!(close(thin.perimeter(), thin.outlines().iter().map(|o| loop_length(o)).sum()));
+
+        let ring = Profile::Circle { outer_radius: 1.0, inner_radius: Some(0.0) };
+        assert_eq!(ring.outlines().len(), 1);
+        assert!(close(ring.area(), std::f64::consts::PI));
+
+        // Too few or collinear points enclose nothing
+        for points in [vec![], vec![[0.0, 0.0], [1.0, 1.0]], vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]] {
+            let flat = Profile::Polyline { points, holes: vec![] };
+            let s = flat.section();
+            assert_eq!(flat.area(), 0.0);
+            assert!([s.ix, s.iy, s.sx, s.sy, s.rx, s.ry].iter().all(|v| v.is_finite()), "{s:?}");
+            assert!(!flat.contains(0.5, 0.5));
+            assert!(flat.outline_centroid().iter().all(|v| v.is_finite()));
+        }
+
+        // Winding does not matter
+        let square = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]];
+        let ccw = Profile::Polyline { points: square.clone(), holes: vec![] }.section();
+        let cw = Profile::Polyline { points: square.into_iter().rev().collect(), holes: vec![] }.section();
+        assert_eq!(ccw, cw);
+        assert!(close(ccw.area, 2.0) && close(ccw.ix, 2.0 / 12.0) && ccw.ixy.abs() < 1e-15);
+    }
 }
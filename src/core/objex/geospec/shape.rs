@@ -3,6 +3,7 @@ use serde_json::json;
 
 use crate::core::objex::geospec::traits::*;
 use crate::core::objex::geospec::primitives::*;
+use crate::core::objex::geospec::mesh::Mesh;
 use crate::core::objex::geospec::traits::Dimensions;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +15,7 @@ pub enum Shape {
     Box(BoxShape),
     Cylinder(Cylinder),
     Cone(Cone),
+    Mesh(Mesh),
 }
 
 impl Default for Shape {
@@ -113,6 +115,7 @@ impl SurfaceArea for Shape {
             Shape::Cylinder(c) => c.surface_area(),
             Shape::Cone(c) => c.surface_area(),
             Shape::Plane(p) => p.surface_area(),
+            Shape::Mesh(m) => m.surface_area(),
             _ => 0.0,
         }
     }
@@ -125,6 +128,7 @@ impl Volume for Shape {
             Shape::Box(b) => b.volume(),
             Shape::Cylinder(c) => c.volume(),
             Shape::Cone(c) => c.volume(),
+            Shape::Mesh(m) => m.volume(),
             _ => 0.0,
         }
     }
@@ -140,6 +144,14 @@ impl Dimensions for Shape {
             Shape::Box(b) => json!({ "type": "box", "length": b.length, "width": b.width, "height": b.height }),
             Shape::Cylinder(c) => json!({ "type": "cylinder", "radius": c.radius, "height": c.height }),
             Shape::Cone(c) => json!({ "type": "cone", "radius": c.radius, "height": c.height }),
+            Shape::Mesh(m) => json!({
+                "type": "mesh",
+                "vertices": m.vertices().len(),
+                "triangles": m.triangles().len(),
+                "watertight": m.is_watertight(),
+                "volume": m.volume(),
+                "surface_area": m.surface_area(),
+            }),
         }
     }
 }
@@ -169,6 +181,7 @@ impl Shape {
 
             Shape::Line(l) => l.length / 2.0,
             Shape::Point(_) => 0.0,
+            Shape::Mesh(m) => m.bounding_radius(),
         };
 
         (r_meters * 1_000_000.0) as i64
@@ -3,6 +3,7 @@ use uuid::Uuid;
 use crate::core::objex::geospec::GeoSpec;
 
 
+#[derive(Default)]
 pub struct GeoSpecStore {
     inner: HashMap<Uuid, GeoSpec>,
 }
@@ -0,0 +1,523 @@
+//! Sweeps: a profile carried along a path.
+//!
+//! A `SweepPath` is a polyline in the shape's own frame. Its corners are
+//! rounded with `bend_radius` (pipe elbows, bent gutters), or mitred when
+//! the radius is zero. The section stays square to the path with its
+//! centroid on it. Its x and y axes start where the shortest rotation
+//! from +z onto the first segment puts them and after that turn only
+//! with the bends, never twisting about the path.
+//!
+//! Volume is the section area times the path length (Pappus). Side area
+//! is the perimeter times the length, corrected at each bend or mitre
+//! for how far the outline's centroid sits off the path. Both are exact
+//! as long as the sweep does not run into itself: the bend radius must
+//! reach past the section on the inside of the bend, and segments must
+//! be long enough for their mitres.
+
+use glam::{DQuat, DVec3};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::objex::geospec::profile::{Profile, ProfileArea, ProfilePerimeter, CIRCLE_SEGMENTS};
+
+/// Turns smaller than this (radians) are treated as straight.
+const STRAIGHT: f64 = 1e-12;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PathError {
+    #[error("a sweep path needs at least two points")]
+    TooShort,
+
+    #[error("point {point} is not finite")]
+    NonFinite { point: usize },
+
+    #[error("segment {segment} has zero length")]
+    ZeroLength { segment: usize },
+
+    #[error("the path turns back on itself at point {point}")]
+    Reversal { point: usize },
+
+    #[error("segment {segment} is too short for bends of radius {radius}")]
+    TooTight { segment: usize, radius: f64 },
+
+    #[error("bend radius {0} must be zero or positive and finite")]
+    Radius(f64),
+
+    #[error("arc of {0}° must be finite and at most 360° either way")]
+    Angle(f64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SweepPathSpec", into = "SweepPathSpec")]
+pub struct SweepPath {
+    points: Vec<[f64; 3]>,
+    bend_radius: f64,
+}
+
+/// Serialized form, validated on load.
+#[derive(Serialize, Deserialize)]
+struct SweepPathSpec {
+    points: Vec<[f64; 3]>,
+    #[serde(default)]
+    bend_radius: f64,
+}
+
+impl TryFrom<SweepPathSpec> for SweepPath {
+    type Error = PathError;
+
+    fn try_from(spec: SweepPathSpec) -> Result<Self, Self::Error> {
+        SweepPath::new(spec.points, spec.bend_radius)
+    }
+}
+
+impl From<SweepPath> for SweepPathSpec {
+    fn from(path: SweepPath) -> Self {
+        Self { points: path.points, bend_radius: path.bend_radius }
+    }
+}
+
+impl SweepPath {
+    /// Corner points, with every interior corner rounded to
+    /// `bend_radius` (0 for mitred corners).
+    pub fn new(points: Vec<[f64; 3]>, bend_radius: f64) -> Result<Self, PathError> {
+        if !(bend_radius.is_finite() && bend_radius >= 0.0) {
+            return Err(PathError::Radius(bend_radius));
+        }
+        if points.len() < 2 {
+            return Err(PathError::TooShort);
+        }
+        if let Some(point) = points.iter().position(|p| !p.iter().all(|c| c.is_finite())) {
+            return Err(PathError::NonFinite { point });
+        }
+
+        let path = Self { points, bend_radius };
+        let lengths: Vec<f64> = path.segments().map(|(a, b)| a.distance(b)).collect();
+        if let Some(segment) = lengths.iter().position(|&l| l == 0.0) {
+            return Err(PathError::ZeroLength { segment });
+        }
+
+        let turns = path.turns();
+        if let Some(corner) = turns.iter().position(|t| t.cos() <= -1.0 + 1e-12) {
+            return Err(PathError::Reversal { point: corner + 1 });
+        }
+        // Tangent length taken off each end of a segment by its bends
+        let tangent = |corner: Option<&f64>| corner.map_or(0.0, |t| bend_radius * (t / 2.0).tan());
+        for (segment, length) in lengths.iter().enumerate() {
+            let before = segment.checked_sub(1).and_then(|c| turns.get(c));
+            let taken = tangent(before) + tangent(turns.get(segment));
+            if taken > length * (1.0 + 1e-9) {
+                return Err(PathError::TooTight { segment, radius: bend_radius });
+            }
+        }
+        Ok(path)
+    }
+
+    /// Straight run from the origin along +z.
+    pub fn straight(length: f64) -> Result<Self, PathError> {
+        Self::new(vec![[0.0; 3], [0.0, 0.0, length]], 0.0)
+    }
+
+    /// Circular arc of `radius` through `degrees` (at most 360), from
+    /// the origin heading along +z and curving toward +x (toward -x for
+    /// negative `degrees`).
+    pub fn arc(radius: f64, degrees: f64) -> Result<Self, PathError> {
+        if !degrees.is_finite() || degrees.abs() > 360.0 {
+            return Err(PathError::Angle(degrees));
+        }
+        // Corners of a circumscribed polygon, each rounded by a quarter
+        // turn or less, so the fillets join into one arc
+        let corners = (degrees.abs() / 90.0).ceil().max(1.0) as usize;
+        let step = degrees.to_radians() / corners as f64;
+        let tangent = radius * (step.abs() / 2.0).tan();
+
+        let mut points = vec![[0.0; 3]];
+        let (mut at, mut heading) = (DVec3::ZERO, 0.0f64);
+        for i in 0..=corners {
+            let direction = DVec3::new(heading.sin(), 0.0, heading.cos());
+            at += direction * if i == 0 || i == corners { tangent } else { 2.0 * tangent };
+            points.push(at.to_array());
+            heading += step;
+        }
+        Self::new(points, radius)
+    }
+
+    pub fn points(&self) -> &[[f64; 3]] {
+        &self.points
+    }
+
+    pub fn bend_radius(&self) -> f64 {
+        self.bend_radius
+    }
+
+    /// Centreline length, bends included.
+    pub fn length(&self) -> f64 {
+        self.pieces()
+            .iter()
+            .map(|piece| match piece {
+                Piece::Straight { length, .. } => *length,
+                Piece::Bend { radius, angle, .. } => radius * angle,
+            })
+            .sum()
+    }
+
+    /// Volume swept by `profile`.
+    pub fn swept_volume(&self, profile: &Profile) -> f64 {
+        profile.area() * self.length()
+    }
+
+    /// Side area swept by `profile`'s outline (end caps not included).
+    pub fn swept_area(&self, profile: &Profile) -> f64 {
+        let [cx, cy] = profile.outline_centroid();
+        let perimeter = profile.perimeter();
+        self.pieces()
+            .iter()
+            .map(|piece| match *piece {
+                Piece::Straight { length, cut_start, cut_end, .. } => {
+                    let (x0, y0) = (cut_start.x / cut_start.z, cut_start.y / cut_start.z);
+                    let (x1, y1) = (cut_end.x / cut_end.z, cut_end.y / cut_end.z);
+                    perimeter * (length + (x0 - x1) * cx + (y0 - y1) * cy)
+                }
+                Piece::Bend { radius, angle, inward, .. } => {
+                    perimeter * angle * (radius - inward.x * cx - inward.y * cy)
+                }
+            })
+            .sum()
+    }
+
+    /// Where the section sits at the start and end of the path.
+    pub(crate) fn ends(&self) -> (Station, Station) {
+        let pieces = self.pieces();
+        let start = match pieces[0] {
+            Piece::Straight { start, .. } | Piece::Bend { start, .. } => start,
+        };
+        let end = match pieces[pieces.len() - 1] {
+            Piece::Straight { start, length, .. } => Station { at: start.at + start.forward() * length, rot: start.rot },
+            bend @ Piece::Bend { angle, .. } => bend.station(angle),
+        };
+        (start, end)
+    }
+
+    /// The path cut into straight runs and bends.
+    pub(crate) fn pieces(&self) -> Vec<Piece> {
+        let points: Vec<DVec3> = self.points.iter().map(|p| DVec3::from(*p)).collect();
+        let directions: Vec<DVec3> = self.segments().map(|(a, b)| (b - a).normalize()).collect();
+        let radius = self.bend_radius;
+
+        let mut pieces = Vec::new();
+        let mut at = points[0];
+        let mut rot = DQuat::from_rotation_arc(DVec3::Z, directions[0]);
+        let mut cut_start = DVec3::Z;
+
+        for (i, &t) in directions.iter().enumerate() {
+            let corner = points[i + 1];
+            let Some(&next) = directions.get(i + 1) else {
+                pieces.push(Piece::Straight {
+                    start: Station { at, rot },
+                    length: (corner - at).dot(t),
+                    cut_start,
+                    cut_end: DVec3::Z,
+                });
+                break;
+            };
+
+            let turn = t.angle_between(next);
+            let onward = DQuat::from_rotation_arc(t, next);
+            if turn < STRAIGHT {
+                pieces.push(Piece::Straight { start: Station { at, rot }, length: (corner - at).dot(t), cut_start, cut_end: DVec3::Z });
+                (at, cut_start) = (corner, DVec3::Z);
+            } else if radius == 0.0 {
+                // Mitre on the plane bisecting the corner
+                let mitre = (t + next).normalize();
+                pieces.push(Piece::Straight {
+                    start: Station { at, rot },
+                    length: (corner - at).dot(t),
+                    cut_start,
+                    cut_end: rot.inverse() * mitre,
+                });
+                rot = onward * rot;
+                (at, cut_start) = (corner, rot.inverse() * mitre);
+            } else {
+                let tangent = radius * (turn / 2.0).tan();
+                let entry = corner - t * tangent;
+                pieces.push(Piece::Straight {
+                    start: Station { at, rot },
+                    length: (entry - at).dot(t),
+                    cut_start,
+                    cut_end: DVec3::Z,
+                });
+                let inward = (next - t * t.dot(next)).normalize();
+                pieces.push(Piece::Bend {
+                    start: Station { at: entry, rot },
+                    radius,
+                    angle: turn,
+                    inward: rot.inverse() * inward,
+                });
+                rot = onward * rot;
+                (at, cut_start) = (corner + next * tangent, DVec3::Z);
+            }
+        }
+        pieces.retain(|p| !matches!(p, Piece::Straight { length, .. } if *length <= 0.0));
+        pieces
+    }
+
+    fn segments(&self) -> impl Iterator<Item = (DVec3, DVec3)> + '_ {
+        self.points.windows(2).map(|w| (DVec3::from(w[0]), DVec3::from(w[1])))
+    }
+
+    /// Turning angle at each interior point.
+    fn turns(&self) -> Vec<f64> {
+        let directions: Vec<DVec3> = self.segments().map(|(a, b)| (b - a).normalize_or_zero()).collect();
+        directions.windows(2).map(|w| w[0].angle_between(w[1])).collect()
+    }
+}
+
+// ----------------------------------------------------------------
+// Pieces
+// ----------------------------------------------------------------
+
+/// A section's position: where it crosses the path and the rotation
+/// taking profile x, y and +z (forward) into the shape's frame.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Station {
+    pub at: DVec3,
+    pub rot: DQuat,
+}
+
+impl Station {
+    pub fn forward(&self) -> DVec3 {
+        self.rot * DVec3::Z
+    }
+
+    /// Section point (x, y) at this station.
+    pub fn place(&self, x: f64, y: f64) -> DVec3 {
+        self.at + self.rot * DVec3::new(x, y, 0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Piece {
+    /// Runs `length` along the start station's +z. The ends are cut by
+    /// planes through the path with normals `cut_start` and `cut_end`,
+    /// given in the station's frame (+z for square ends).
+    Straight { start: Station, length: f64, cut_start: DVec3, cut_end: DVec3 },
+    /// Turns through `angle` about a centre `radius` away in the local
+    /// direction `inward` (in the section plane).
+    Bend { start: Station, radius: f64, angle: f64, inward: DVec3 },
+}
+
+impl Piece {
+    /// Extent along the run of the fibre through section point (x, y).
+    pub fn fibre(&self, x: f64, y: f64) -> (f64, f64) {
+        match *self {
+            Piece::Straight { length, cut_start: s, cut_end: e, .. } => {
+                (-(s.x * x + s.y * y) / s.z, length - (e.x * x + e.y * y) / e.z)
+            }
+            Piece::Bend { angle, .. } => (0.0, angle),
+        }
+    }
+
+    /// Station `s` along the piece: a distance for straight runs, an
+    /// angle for bends.
+    pub fn station(&self, s: f64) -> Station {
+        match *self {
+            Piece::Straight { start, .. } => Station { at: start.at + start.forward() * s, rot: start.rot },
+            Piece::Bend { start, radius, inward, .. } => {
+                let centre = start.at + start.rot * (inward * radius);
+                let turn = DQuat::from_axis_angle(start.rot * DVec3::Z.cross(inward), s);
+                Station { at: centre + turn * (start.at - centre), rot: turn * start.rot }
+            }
+        }
+    }
+
+    /// Point on the fibre through section point (x, y), `s` along it.
+    pub fn point(&self, x: f64, y: f64, s: f64) -> DVec3 {
+        match *self {
+            Piece::Straight { start, .. } => start.at + start.rot * DVec3::new(x, y, s),
+            Piece::Bend { .. } => self.station(s).place(x, y),
+        }
+    }
+
+    /// Fibre length per unit of `s` at section point (x, y).
+    pub fn stretch(&self, x: f64, y: f64) -> f64 {
+        match *self {
+            Piece::Straight { .. } => 1.0,
+            Piece::Bend { radius, inward, .. } => radius - inward.x * x - inward.y * y,
+        }
+    }
+
+    /// Whether `p` lies in the piece swept by `profile`.
+    pub fn contains(&self, profile: &Profile, p: DVec3) -> bool {
+        match *self {
+            Piece::Straight { start, .. } => {
+                let local = start.rot.inverse() * (p - start.at);
+                let (from, to) = self.fibre(local.x, local.y);
+                local.z >= from && local.z <= to && profile.contains(local.x, local.y)
+            }
+            Piece::Bend { start, radius, angle, inward } => {
+                let axis = DVec3::Z.cross(inward);
+                let local = start.rot.inverse() * (p - start.at) - inward * radius;
+                let across = local.dot(axis);
+                let in_plane = local - axis * across;
+                // Angle swept from the start, seen from the centre
+                let swept = in_plane.dot(DVec3::Z).atan2(-in_plane.dot(inward));
+                if !(0.0..=angle).contains(&swept) {
+                    return false;
+                }
+                let toward = radius - in_plane.length();
+                let section = inward * toward + axis * across;
+                profile.contains(section.x, section.y)
+            }
+        }
+    }
+}
+
+/// Whether `p` lies in `profile` swept along `path`.
+pub(crate) fn contains(profile: &Profile, path: &SweepPath, p: DVec3) -> bool {
+    path.pieces().iter().any(|piece| piece.contains(profile, p))
+}
+
+/// Points on the outer outline at every piece end and through each
+/// bend, and how far the solid can bulge past them.
+pub(crate) fn hull(profile: &Profile, path: &SweepPath) -> (Vec<DVec3>, f64) {
+    const BEND_STEPS: usize = 16;
+    let outline = profile.outlines().into_iter().next().unwrap_or_default();
+    let reach = outline.iter().map(|[x, y]| x.hypot(*y)).fold(0.0, f64::max);
+
+    // A circle's polygon sits inside the circle, and each bend's arcs
+    // bulge past their chords
+    let polygon = match profile {
+        Profile::Circle { outer_radius, .. } => outer_radius * (1.0 - (std::f64::consts::PI / CIRCLE_SEGMENTS as f64).cos()),
+        _ => 0.0,
+    };
+    let mut bulge: f64 = 0.0;
+    let mut points = Vec::new();
+    for piece in path.pieces() {
+        let stations: Vec<f64> = match piece {
+            Piece::Straight { .. } => Vec::new(),
+            Piece::Bend { radius, angle, .. } => {
+                let step = angle / BEND_STEPS as f64;
+                bulge = bulge.max((radius + reach) * (1.0 - (step / 2.0).cos()));
+                (0..=BEND_STEPS).map(|k| k as f64 * step).collect()
+            }
+        };
+        for &[x, y] in &outline {
+            match piece {
+                Piece::Straight { .. } => {
+                    let (from, to) = piece.fibre(x, y);
+                    points.extend([piece.point(x, y, from), piece.point(x, y, to)]);
+                }
+                Piece::Bend { .. } => points.extend(stations.iter().map(|&s| piece.point(x, y, s))),
+            }
+        }
+    }
+    (points, polygon + bulge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::geospec::authoring::AuthoringShape;
+    use crate::core::objex::geospec::csg::Placed;
+    use crate::core::objex::geospec::mesh::Mesh;
+    use crate::core::objex::geospec::primitives::BoxShape;
+    use crate::core::objex::geospec::traits::{SurfaceArea, Volume};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn sweeps_measure_exactly_and_agree_with_their_tessellation() {
+        // Pipe with one elbow
+        let pipe = Profile::Circle { outer_radius: 0.05, inner_radius: Some(0.04) };
+        let path = SweepPath::new(vec![[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 1.0]], 0.2).unwrap();
+        let length = 2.0 - 2.0 * 0.2 + 0.2 * PI / 2.0;
+        assert!((path.length() - length).abs() < 1e-12);
+
+        let elbow = AuthoringShape::Sweep { profile: pipe.clone(), path: path.clone(), capped: true };
+        let spec = elbow.compile();
+        assert!((spec.volume - pipe.area() * length).abs() < 1e-12);
+        assert!((spec.surface_area - (pipe.perimeter() * length + 2.0 * pipe.area())).abs() < 1e-12);
+        assert!((spec.bounds.max[0] - 1.0).abs() < 1e-3 && (spec.bounds.max[2] - 1.05).abs() < 1e-3);
+
+        let mesh = Mesh::from_shape(&elbow, 128).unwrap();
+        assert!(mesh.is_watertight());
+        assert!((mesh.volume() / spec.volume - 1.0).abs() < 2e-3);
+
+        // Mitred angle iron: planar faces, so the mesh is exact and checks
+        // the off-centre outline correction
+        let angle = Profile::Angle { width: 0.08, height: 0.05, thickness: 0.006 };
+        let zigzag = SweepPath::new(vec![[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.7, 0.4, 1.6], [0.7, 0.4, 2.5]], 0.0).unwrap();
+        let iron = AuthoringShape::Sweep { profile: angle.clone(), path: zigzag, capped: true };
+        let mesh = Mesh::from_shape(&iron, 0).unwrap();
+        assert!(mesh.is_watertight());
+        assert!((mesh.volume() - iron.volume()).abs() < 1e-12);
+        assert!((mesh.surface_area() - iron.surface_area()).abs() < 1e-12);
+
+        // Filleted, off-centre: the mesh converges on the exact area
+        let gutter = AuthoringShape::Sweep { profile: angle, path: SweepPath::arc(0.5, 270.0).unwrap(), capped: false };
+        let mesh = Mesh::from_shape(&gutter, 512).unwrap();
+        assert!((mesh.surface_area() / gutter.surface_area() - 1.0).abs() < 1e-4);
+
+        // A box through the first straight run takes its share of the pipe
+        let duct = Profile::Rect { width: 0.1, height: 0.1, wall: None };
+        let run = AuthoringShape::Sweep { profile: duct, path, capped: true };
+        let block = BoxShape { length: 0.3, width: 0.3, height: 0.2 };
+        let union = AuthoringShape::Union {
+            children: vec![run.clone().into(), Placed::new(AuthoringShape::Box(block)).at(0.0, 0.0, 0.3)],
+        }
+        .compile();
+        let exact = run.volume() + 0.3 * 0.3 * 0.2 - 0.1 * 0.1 * 0.2;
+        assert!((union.volume - exact).abs() <= union.volume_error);
+
+        assert_eq!(SweepPath::new(vec![[0.0; 3]], 0.0), Err(PathError::TooShort));
+        assert_eq!(
+            SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 1.0], [0.0, 0.0, 0.5]], 0.0),
+            Err(PathError::Reversal { point: 1 })
+        );
+        assert_eq!(
+            SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 0.1], [1.0, 0.0, 0.1]], 0.2),
+            Err(PathError::TooTight { segment: 0, radius: 0.2 })
+        );
+    }
+
+    #[test]
+    fn degenerate_paths_are_rejected() {
+        assert_eq!(SweepPath::straight(0.0), Err(PathError::ZeroLength { segment: 0 }));
+        assert_eq!(SweepPath::straight(f64::NAN), Err(PathError::NonFinite { point: 1 }));
+        assert_eq!(SweepPath::straight(f64::INFINITY), Err(PathError::NonFinite { point: 1 }));
+        assert_eq!(
+            SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]], 0.0),
+            Err(PathError::ZeroLength { segment: 1 })
+        );
+        assert!(matches!(SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 1.0]], -0.1), Err(PathError::Radius(_))));
+        assert!(matches!(SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 1.0]], f64::NAN), Err(PathError::Radius(_))));
+
+        assert!(matches!(SweepPath::arc(0.0, 90.0), Err(PathError::ZeroLength { .. })));
+        assert!(matches!(SweepPath::arc(1.0, 0.0), Err(PathError::ZeroLength { .. })));
+        assert!(matches!(SweepPath::arc(-1.0, 90.0), Err(PathError::Radius(_))));
+        for degrees in [360.5, -361.0, 1e18, f64::INFINITY, f64::NAN] {
+            assert!(matches!(SweepPath::arc(1.0, degrees), Err(PathError::Angle(_))), "{degrees}");
+        }
+        assert!(SweepPath::arc(1.0, -360.0).is_ok());
+
+        // Loading validates like `new`
+        assert!(serde_json::from_str::<SweepPath>(r#"{"points":[[0,0,0]]}"#).is_err());
+        assert!(serde_json::from_str::<SweepPath>(r#"{"points":[[0,0,0],[0,0,1]],"bend_radius":-1}"#).is_err());
+    }
+
+    #[test]
+    fn arcs_and_exact_fillets_measure_their_length() {
+        for (radius, degrees) in [(1.0, 360.0), (0.5, 45.0), (2.0, -90.0), (1.0, 180.0)] {
+            let arc = SweepPath::arc(radius, degrees).unwrap();
+            assert!((arc.length() - radius * f64::to_radians(degrees).abs()).abs() < 1e-12, "{degrees}");
+        }
+        // Curving the other way mirrors the path in x
+        let (left, right) = (SweepPath::arc(1.0, 90.0).unwrap(), SweepPath::arc(1.0, -90.0).unwrap());
+        let end = |p: &SweepPath| *p.points().last().unwrap();
+        assert!((end(&left)[0] + end(&right)[0]).abs() < 1e-12 && (end(&left)[2] - end(&right)[2]).abs() < 1e-12);
+
+        // Bends that use a whole segment leave no straight between them
+        let tight = SweepPath::new(vec![[0.0; 3], [0.0, 0.0, 0.2], [0.4, 0.0, 0.2], [0.4, 0.0, 0.4]], 0.2).unwrap();
+        assert!((tight.length() - 0.2 * PI).abs() < 1e-12);
+        let profile = Profile::Circle { outer_radius: 0.05, inner_radius: None };
+        assert!((tight.swept_volume(&profile) - profile.area() * 0.2 * PI).abs() < 1e-12);
+    }
+}
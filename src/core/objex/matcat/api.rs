@@ -3,9 +3,14 @@ use serde::Serialize;
 use crate::core::objex::matcat::{
     categories::{CategoryId, CATEGORY_MAP},
     variants::{VariantId, VARIANT_MAP},
-    grades::{GradeId, GRADE_MAP},
-    materials::{MatCatId, props_for},
+    grades::GRADE_MAP,
+    materials::{MatCatId, PropsSource, resolve_props},
+    measured::{MeasuredMaterial, measured_for},
     properties::MatProps,
+    search::{
+        nearest, substitutes, Constraint, MaterialMatch, PropertyWeights, SearchError,
+        SimilarityQuery, SubstituteQuery,
+    },
 };
 
 /// Generic id + name pair for UI dropdowns
@@ -29,12 +34,15 @@ pub struct ResolveResponse {
 pub struct PreviewResponse {
     pub matcat_id: String,
     pub properties: MatProps,
+    /// How `properties` were resolved (measured vs procedural).
+    pub source: PropsSource,
+    /// Reference values, ranges and citations, when measured.
+    pub measured: Option<&'static MeasuredMaterial>,
 }
 
 /// -------------------------
 /// Categories
 /// -------------------------
-
 pub fn get_categories() -> Vec<IdName> {
     CATEGORY_MAP
         .iter()
@@ -48,7 +56,6 @@ pub fn get_categories() -> Vec<IdName> {
 /// -------------------------
 /// Variants
 /// -------------------------
-
 pub fn get_variants(category: u8) -> Vec<IdName> {
     let cat = CategoryId(category);
 
@@ -65,7 +72,6 @@ pub fn get_variants(category: u8) -> Vec<IdName> {
 /// -------------------------
 /// Grades
 /// -------------------------
-
 pub fn get_grades(category: u8, variant: u16) -> Vec<IdName> {
     let cat = CategoryId(category);
     let var = VariantId(variant);
@@ -83,7 +89,6 @@ pub fn get_grades(category: u8, variant: u16) -> Vec<IdName> {
 /// -------------------------
 /// Resolve
 /// -------------------------
-
 pub fn resolve_material(
     category: u8,
     variant: Option<u16>,
@@ -106,7 +111,6 @@ pub fn resolve_material(
 /// -------------------------
 /// Preview
 /// -------------------------
-
 pub fn preview_material(
     category: u8,
     variant: Option<u16>,
@@ -118,15 +122,59 @@ pub fn preview_material(
         grade.unwrap_or(0),
     );
 
-    let props = props_for(&matcat);
+    let resolved = resolve_props(&matcat);
 
     PreviewResponse {
-        matcat_id: format!(
-            "{:02}-{:04}-{:04}",
-            category,
-            variant.unwrap_or(0),
-            grade.unwrap_or(0)
-        ),
-        properties: props,
+        matcat_id: matcat.code(),
+        properties: resolved.props,
+        source: resolved.source,
+        measured: measured_for(&matcat),
+    }
+}
+
+// -------------------------
+// Similar / substitutes
+// -------------------------
+
+/// The `k` materials most like the given one. `focus` picks the property
+/// subset (`thermal`, `mechanical`, ...; empty = all), `categories`
+/// restricts the search space (empty = all).
+pub fn find_similar(
+    category: u8,
+    variant: Option<u16>,
+    grade: Option<u16>,
+    focus: &str,
+    categories: &[u8],
+    k: usize,
+) -> Result<Vec<MaterialMatch>, SearchError> {
+    let matcat = MatCatId::new(category, variant.unwrap_or(0), grade.unwrap_or(0));
+    let categories: Vec<CategoryId> = categories.iter().map(|c| CategoryId(*c)).collect();
+
+    let query = SimilarityQuery::like(&matcat, k)
+        .weights(PropertyWeights::focus(focus)?)
+        .in_categories(&categories);
+    Ok(nearest(&query))
+}
+
+/// Substitutes for the given material meeting every constraint
+/// (`tensile_strength >= 400`, `cost <= 5`, ...), closest first.
+pub fn suggest_substitutes(
+    category: u8,
+    variant: Option<u16>,
+    grade: Option<u16>,
+    constraints: &[String],
+    focus: &str,
+    categories: &[u8],
+    k: usize,
+) -> Result<Vec<MaterialMatch>, SearchError> {
+    let matcat = MatCatId::new(category, variant.unwrap_or(0), grade.unwrap_or(0));
+    let categories: Vec<CategoryId> = categories.iter().map(|c| CategoryId(*c)).collect();
+
+    let mut query = SubstituteQuery::new(matcat, k)
+        .weights(PropertyWeights::focus(focus)?)
+        .in_categories(&categories);
+    for constraint in constraints {
+        query = query.require(constraint.parse::<Constraint>()?);
     }
+    Ok(substitutes(&query))
 }
@@ -555,7 +555,7 @@ pub fn generate_props_from_category(cat: u8, rng: &mut impl Rng) -> Option<MatPr
 }
 
 pub fn generate_props_for_material(cat: u8, variant: u16, grade: u16) -> MatProps {
-    let mut rng = rand::thread_rng();
+    let mut rng = rand::rng();
 
     // Step 1: Base range by category
     let base = get_category_ranges(cat).expect("invalid material category");
@@ -0,0 +1,380 @@
+//! Environment-dependent material properties.
+//!
+//! `MatProps` holds values at reference conditions (20 °C, dry air, no
+//! chloride). A `PropertyCurve` tabulates one property against one
+//! environment input, either as absolute values (in the `MatProps` unit)
+//! or as a factor of the reference value; tables are piecewise linear and
+//! clamped at their ends.
+//!
+//! Curves come from two places, later ones replacing earlier ones for the
+//! same (property, input):
+//!  1. category defaults (`default_curves`),
+//!  2. the measured table (variant-level record, then the exact grade).
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::core::env::FieldSample;
+use crate::core::objex::matcat::materials::{MatCatId, props_for};
+use crate::core::objex::matcat::measured::{canonical_unit, get_property, set_property, MEASURED_DB};
+use crate::core::objex::matcat::properties::MatProps;
+
+/// Temperature `MatProps` values refer to.
+pub const REFERENCE_TEMPERATURE_K: f64 = 293.15;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CurveError {
+    #[error("curve has no points")]
+    Empty,
+
+    #[error("curve points must be finite")]
+    NonFinite,
+
+    #[error("curve inputs must be strictly increasing")]
+    Unsorted,
+
+    #[error("unknown property `{0}`")]
+    UnknownProperty(String),
+}
+
+/// Environment input a curve is tabulated against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvVar {
+    /// K. Non-positive samples (no temperature field) read as the
+    /// reference temperature.
+    Temperature,
+    /// 0–1.
+    RelativeHumidity,
+    /// Chloride deposition, mg/(m²·d).
+    Chloride,
+}
+
+impl EnvVar {
+    /// Unit the curve's inputs are stored in.
+    pub fn unit(self) -> &'static str {
+        match self {
+            EnvVar::Temperature => "K",
+            EnvVar::RelativeHumidity => "1",
+            EnvVar::Chloride => "mg/(m²·d)",
+        }
+    }
+
+    pub fn of(self, env: &FieldSample) -> f64 {
+        match self {
+            EnvVar::Temperature if env.temperature > 0.0 => env.temperature,
+            EnvVar::Temperature => REFERENCE_TEMPERATURE_K,
+            EnvVar::RelativeHumidity => env.relative_humidity,
+            EnvVar::Chloride => env.chloride,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveMode {
+    /// Values replace the property (in its `MatProps` unit).
+    Absolute,
+    /// Values multiply the reference value.
+    Factor,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyCurve {
+    /// `MatProps` field name.
+    pub property: String,
+    pub input: EnvVar,
+    pub mode: CurveMode,
+    /// (input, value), strictly increasing in input.
+    pub points: Vec<(f64, f64)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl PropertyCurve {
+    pub fn new(
+        property: &str,
+        input: EnvVar,
+        mode: CurveMode,
+        points: Vec<(f64, f64)>,
+    ) -> Result<Self, CurveError> {
+        if canonical_unit(property).is_none() {
+            return Err(CurveError::UnknownProperty(property.to_string()));
+        }
+        if points.is_empty() {
+            return Err(CurveError::Empty);
+        }
+        if points.iter().any(|(x, y)| !x.is_finite() || !y.is_finite()) {
+            return Err(CurveError::NonFinite);
+        }
+        if points.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err(CurveError::Unsorted);
+        }
+        Ok(Self { property: property.to_string(), input, mode, points, source: None })
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Linear interpolation, clamped outside the table.
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self.points.partition_point(|(px, _)| *px <= x);
+        match i {
+            0 => self.points[0].1,
+            n if n == self.points.len() => self.points[n - 1].1,
+            _ => {
+                let ((x0, y0), (x1, y1)) = (self.points[i - 1], self.points[i]);
+                y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+            }
+        }
+    }
+
+    pub fn at(&self, env: &FieldSample) -> f64 {
+        self.eval(self.input.of(env))
+    }
+}
+
+/// The curves that apply to one material.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaterialCurves {
+    pub curves: Vec<PropertyCurve>,
+}
+
+impl MaterialCurves {
+    /// Add `curve`, replacing any curve for the same property and input.
+    pub fn set(&mut self, curve: PropertyCurve) {
+        self.curves.retain(|c| !(c.property == curve.property && c.input == curve.input));
+        self.curves.push(curve);
+    }
+
+    /// `other`'s curves take precedence.
+    pub fn overlay(&mut self, other: &MaterialCurves) {
+        for curve in &other.curves {
+            self.set(curve.clone());
+        }
+    }
+
+    pub fn for_property<'a>(&'a self, property: &'a str) -> impl Iterator<Item = &'a PropertyCurve> {
+        self.curves.iter().filter(move |c| c.property == property)
+    }
+
+    /// `reference` evaluated at `env`: absolute curves first, then factors.
+    pub fn apply(&self, reference: &MatProps, env: &FieldSample) -> MatProps {
+        let mut props = *reference;
+
+        for mode in [CurveMode::Absolute, CurveMode::Factor] {
+            for curve in self.curves.iter().filter(|c| c.mode == mode) {
+                let value = match mode {
+                    CurveMode::Absolute => curve.at(env),
+                    CurveMode::Factor => {
+                        let Some(base) = get_property(&props, &curve.property) else { continue };
+                        base as f64 * curve.at(env)
+                    }
+                };
+                set_property(&mut props, &curve.property, value as f32);
+            }
+        }
+        props
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Category defaults
+// ---------------------------------------------------------------------------
+
+const EN_1993_1_2: &str = "EN 1993-1-2, Eurocode 3: Design of steel structures, Part 1-2: Structural fire design";
+const ISO_9223: &str = "ISO 9223:2012, Corrosivity of atmospheres (humidity and chloride deposition classes)";
+
+/// Celsius table to Kelvin.
+fn celsius(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    points.iter().map(|&(c, y)| (c + 273.15, y)).collect()
+}
+
+/// Generic metal curves, from carbon steel (specific heat smoothed over
+/// the 735 °C phase change). Used until a measured curve replaces them.
+fn metal_curves() -> MaterialCurves {
+    let factor = |property: &str, input: EnvVar, points: Vec<(f64, f64)>, source: &str| {
+        PropertyCurve::new(property, input, CurveMode::Factor, points)
+            .expect("built-in curve is valid")
+            .with_source(source)
+    };
+
+    let modulus = celsius(&[
+        (20.0, 1.0), (100.0, 1.0), (200.0, 0.9), (300.0, 0.8), (400.0, 0.7), (500.0, 0.6),
+        (600.0, 0.31), (700.0, 0.13), (800.0, 0.09), (900.0, 0.0675), (1000.0, 0.045),
+        (1100.0, 0.0225), (1200.0, 0.0),
+    ]);
+    let strength = celsius(&[
+        (20.0, 1.0), (400.0, 1.0), (500.0, 0.78), (600.0, 0.47), (700.0, 0.23), (800.0, 0.11),
+        (900.0, 0.06), (1000.0, 0.04), (1100.0, 0.02), (1200.0, 0.0),
+    ]);
+    // λ = 54 − 0.0333 θ up to 800 °C, then 27.3 W/(m·K)
+    let conductivity = celsius(&[
+        (20.0, 1.0), (200.0, 0.8876), (400.0, 0.7627), (600.0, 0.6379), (800.0, 0.5119),
+    ]);
+    let specific_heat = celsius(&[
+        (20.0, 1.0), (200.0, 1.205), (400.0, 1.378), (600.0, 1.728), (900.0, 1.478),
+    ]);
+
+    let mut curves = MaterialCurves::default();
+    curves.set(factor("elastic_modulus", EnvVar::Temperature, modulus, EN_1993_1_2));
+    curves.set(factor("tensile_strength", EnvVar::Temperature, strength.clone(), EN_1993_1_2));
+    curves.set(factor("compressive_strength", EnvVar::Temperature, strength, EN_1993_1_2));
+    curves.set(factor("thermal_conductivity", EnvVar::Temperature, conductivity, EN_1993_1_2));
+    curves.set(factor("specific_heat", EnvVar::Temperature, specific_heat, EN_1993_1_2));
+
+    // Little corrosion below the critical humidity; chloride class
+    // boundaries S0–S3 at 3, 60, 300 and 1500 mg/(m²·d).
+    curves.set(factor(
+        "corrosion_resistance",
+        EnvVar::RelativeHumidity,
+        vec![(0.0, 1.0), (0.6, 1.0), (0.8, 0.8), (1.0, 0.6)],
+        ISO_9223,
+    ));
+    curves.set(factor(
+        "corrosion_resistance",
+        EnvVar::Chloride,
+        vec![(0.0, 1.0), (3.0, 1.0), (60.0, 0.85), (300.0, 0.7), (1500.0, 0.5)],
+        ISO_9223,
+    ));
+    curves
+}
+
+/// Category defaults (metals only for now; the "Alloy" category's
+/// variants are plastics).
+static DEFAULT_CURVES: Lazy<HashMap<u8, MaterialCurves>> = Lazy::new(|| {
+    let mut m = HashMap::new();
+    m.insert(0x01, metal_curves());
+    m
+});
+
+pub fn default_curves(category: u8) -> MaterialCurves {
+    DEFAULT_CURVES.get(&category).cloned().unwrap_or_default()
+}
+
+/// Curves for `id`: category defaults, overlaid by the measured variant
+/// record's curves, then the exact grade's.
+pub fn curves_for(id: &MatCatId) -> MaterialCurves {
+    let mut curves = default_curves(id.category.0);
+    if id.grade.is_some()
+        && let Some(variant) = MEASURED_DB.get(&MatCatId { grade: None, ..*id })
+    {
+        curves.overlay(&variant.curves);
+    }
+    if let Some(exact) = MEASURED_DB.get(id) {
+        curves.overlay(&exact.curves);
+    }
+    curves
+}
+
+/// Material properties of `id` under the conditions in `env`.
+pub fn props_at(id: &MatCatId, env: &FieldSample) -> MatProps {
+    curves_for(id).apply(&props_for(id), env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(temperature: f64, relative_humidity: f64, chloride: f64) -> FieldSample {
+        FieldSample { temperature, relative_humidity, chloride, ..Default::default() }
+    }
+
+    #[test]
+    fn curves_follow_the_environment() {
+        let steel = MatCatId::steel_lowcarbon();
+        let reference = props_for(&steel);
+
+        // Reference conditions (and a missing temperature) change nothing
+        assert_eq!(props_at(&steel, &at(REFERENCE_TEMPERATURE_K, 0.0, 0.0)), reference);
+        assert_eq!(props_at(&steel, &FieldSample::default()), reference);
+
+        let hot = props_at(&steel, &at(673.15 + 50.0, 0.0, 0.0));
+        assert!((hot.elastic_modulus / reference.elastic_modulus - 0.65).abs() < 1e-4);
+        assert!(hot.thermal_conductivity < reference.thermal_conductivity);
+        assert!(hot.specific_heat > reference.specific_heat);
+
+        let coastal = props_at(&steel, &at(REFERENCE_TEMPERATURE_K, 0.9, 300.0));
+        let expected = reference.corrosion_resistance * 0.7 * 0.7;
+        assert!((coastal.corrosion_resistance - expected).abs() < 1e-5);
+
+        // Measured absolute table for 304 replaces the default factor
+        let stainless = MatCatId::new(1, 1, 2);
+        let k = props_at(&stainless, &at(600.0, 0.0, 0.0)).thermal_conductivity;
+        assert!((k - 19.8).abs() < 1e-4);
+        // ... and agrees with the measured value at the reference temperature
+        let room = props_at(&stainless, &at(REFERENCE_TEMPERATURE_K, 0.0, 0.0));
+        assert_eq!(room.thermal_conductivity, props_for(&stainless).thermal_conductivity);
+        assert_eq!(room.specific_heat, props_for(&stainless).specific_heat);
+
+        // Copper's measured factor table is inherited by its grades
+        let copper = curves_for(&MatCatId::metal_cu());
+        let conductivity: Vec<_> = copper.for_property("thermal_conductivity").collect();
+        assert_eq!(conductivity.len(), 1);
+        assert!(conductivity[0].source.as_deref().unwrap().contains("CRC"));
+
+        assert!(curves_for(&MatCatId::new(27, 1, 1)).curves.is_empty());
+        // Category 2 holds plastics, which steel's fire and corrosion curves don't fit
+        assert!(curves_for(&MatCatId::new(2, 1, 1)).curves.is_empty());
+        assert_eq!(
+            PropertyCurve::new("density", EnvVar::Temperature, CurveMode::Factor, vec![(2.0, 1.0), (1.0, 1.0)]),
+            Err(CurveError::Unsorted)
+        );
+    }
+
+    #[test]
+    fn eval_clamps_outside_the_table_and_hits_points_exactly() {
+        let curve = PropertyCurve::new(
+            "density",
+            EnvVar::Temperature,
+            CurveMode::Factor,
+            vec![(300.0, 1.0), (400.0, 0.5), (500.0, 0.25)],
+        )
+        .unwrap();
+
+        assert_eq!(curve.eval(-1e9), 1.0);
+        assert_eq!(curve.eval(299.999), 1.0);
+        assert_eq!(curve.eval(1e9), 0.25);
+        assert_eq!(curve.eval(f64::INFINITY), 0.25);
+        assert_eq!(curve.eval(400.0), 0.5);
+        assert_eq!(curve.eval(500.0), 0.25);
+        assert!((curve.eval(350.0) - 0.75).abs() < 1e-12);
+
+        let flat = PropertyCurve::new("density", EnvVar::Chloride, CurveMode::Absolute, vec![(10.0, 7.0)]).unwrap();
+        assert_eq!((flat.eval(0.0), flat.eval(10.0), flat.eval(1e6)), (7.0, 7.0, 7.0));
+
+        // Missing temperature reads as the reference, i.e. the first point here
+        assert_eq!(curve.at(&FieldSample::default()), 1.0);
+    }
+
+    #[test]
+    fn invalid_tables_are_rejected() {
+        let new = |property: &str, points: Vec<(f64, f64)>| {
+            PropertyCurve::new(property, EnvVar::Temperature, CurveMode::Factor, points)
+        };
+        assert_eq!(new("density", vec![]), Err(CurveError::Empty));
+        assert_eq!(new("density", vec![(1.0, f64::NAN)]), Err(CurveError::NonFinite));
+        assert_eq!(new("density", vec![(f64::INFINITY, 1.0)]), Err(CurveError::NonFinite));
+        assert_eq!(new("density", vec![(1.0, 1.0), (1.0, 2.0)]), Err(CurveError::Unsorted));
+        assert_eq!(new("colour", vec![(1.0, 1.0)]), Err(CurveError::UnknownProperty("colour".into())));
+    }
+
+    #[test]
+    fn absolute_curves_apply_before_factors() {
+        let reference = props_for(&MatCatId::steel_lowcarbon());
+        let mut curves = MaterialCurves::default();
+        curves.set(PropertyCurve::new("density", EnvVar::Temperature, CurveMode::Factor, vec![(0.0, 0.5)]).unwrap());
+        curves.set(PropertyCurve::new("density", EnvVar::Chloride, CurveMode::Absolute, vec![(0.0, 1000.0)]).unwrap());
+        assert_eq!(curves.apply(&reference, &FieldSample::default()).density, 500.0);
+
+        // Same property and input replaces; a different input adds
+        curves.set(PropertyCurve::new("density", EnvVar::Temperature, CurveMode::Factor, vec![(0.0, 2.0)]).unwrap());
+        assert_eq!(curves.for_property("density").count(), 2);
+        assert_eq!(curves.apply(&reference, &FieldSample::default()).density, 2000.0);
+        assert_eq!(MaterialCurves::default().apply(&reference, &FieldSample::default()), reference);
+    }
+}
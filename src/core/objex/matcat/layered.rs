@@ -0,0 +1,287 @@
+//! Layered (laminate) materials.
+//!
+//! A `LayeredMaterial` is a stack of catalog materials with thicknesses,
+//! listed from the outside in: drywall + insulation + sheathing, zinc
+//! over steel, paint over wood. Its effective `MatProps` treat the stack
+//! as a flat laminate:
+//!
+//! - through the thickness, in series: thermal conductivity (from the
+//!   summed thermal resistance t/k, i.e. the R-value);
+//! - along the layers, thickness-weighted (rule of mixtures): density,
+//!   modulus, strengths, toughness, electrical conductivity, ...;
+//! - per unit mass: specific heat; per unit stiffness: thermal expansion;
+//! - weakest layer: melting point, permeability (a barrier blocks);
+//!   all layers: transparency (product);
+//! - outermost layer: whatever the surface decides (corrosion, UV,
+//!   hardness, optics, flammability, solubility).
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::env::FieldSample;
+use crate::core::objex::matcat::curves::props_at;
+use crate::core::objex::matcat::materials::{props_for, MatCatId};
+use crate::core::objex::matcat::properties::MatProps;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LayerError {
+    #[error("a layered material needs at least one layer")]
+    Empty,
+
+    #[error("layer {index} has thickness {thickness_m} m; thicknesses must be positive and finite")]
+    Thickness { index: usize, thickness_m: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Layer {
+    pub material: MatCatId,
+    pub thickness_m: f64,
+}
+
+impl Layer {
+    pub fn new(material: MatCatId, thickness_m: f64) -> Self {
+        Self { material, thickness_m }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "LayeredSpec", into = "LayeredSpec")]
+pub struct LayeredMaterial {
+    name: Option<String>,
+    /// Outermost first.
+    layers: Vec<Layer>,
+}
+
+/// Serialized form, validated on load.
+#[derive(Serialize, Deserialize)]
+struct LayeredSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    layers: Vec<Layer>,
+}
+
+impl TryFrom<LayeredSpec> for LayeredMaterial {
+    type Error = LayerError;
+
+    fn try_from(spec: LayeredSpec) -> Result<Self, Self::Error> {
+        let mut layered = Self::new(spec.layers)?;
+        layered.name = spec.name;
+        Ok(layered)
+    }
+}
+
+impl From<LayeredMaterial> for LayeredSpec {
+    fn from(layered: LayeredMaterial) -> Self {
+        Self { name: layered.name, layers: layered.layers }
+    }
+}
+
+impl LayeredMaterial {
+    /// `layers` outermost first.
+    pub fn new(layers: Vec<Layer>) -> Result<Self, LayerError> {
+        if layers.is_empty() {
+            return Err(LayerError::Empty);
+        }
+        if let Some((index, layer)) = layers
+            .iter()
+            .enumerate()
+            .find(|(_, l)| !(l.thickness_m.is_finite() && l.thickness_m > 0.0))
+        {
+            return Err(LayerError::Thickness { index, thickness_m: layer.thickness_m });
+        }
+        Ok(Self { name: None, layers })
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// The exposed surface material.
+    pub fn outermost(&self) -> MatCatId {
+        self.layers[0].material
+    }
+
+    pub fn total_thickness(&self) -> f64 {
+        self.layers.iter().map(|l| l.thickness_m).sum()
+    }
+
+    /// kg/m² of laminate.
+    pub fn areal_mass(&self) -> f64 {
+        self.layers.iter().map(|l| props_for(&l.material).density as f64 * l.thickness_m).sum()
+    }
+
+    /// Thermal resistance through the stack, m²·K/W (SI R-value).
+    pub fn r_value(&self) -> f64 {
+        self.r_value_with(props_for)
+    }
+
+    /// R-value with each layer evaluated under `env`.
+    pub fn r_value_at(&self, env: &FieldSample) -> f64 {
+        self.r_value_with(|id| props_at(id, env))
+    }
+
+    /// Thermal transmittance, W/(m²·K).
+    pub fn u_value(&self) -> f64 {
+        1.0 / self.r_value()
+    }
+
+    pub fn effective_props(&self) -> MatProps {
+        self.combine(props_for)
+    }
+
+    /// Effective properties with each layer evaluated under `env`.
+    pub fn effective_props_at(&self, env: &FieldSample) -> MatProps {
+        self.combine(|id| props_at(id, env))
+    }
+
+    fn r_value_with(&self, props_of: impl Fn(&MatCatId) -> MatProps) -> f64 {
+        self.layers
+            .iter()
+            .map(|l| {
+                let k = props_of(&l.material).thermal_conductivity as f64;
+                if k > 0.0 { l.thickness_m / k } else { f64::INFINITY }
+            })
+            .sum()
+    }
+
+    fn combine(&self, props_of: impl Fn(&MatCatId) -> MatProps) -> MatProps {
+        let layers: Vec<(MatProps, f64)> =
+            self.layers.iter().map(|l| (props_of(&l.material), l.thickness_m)).collect();
+        let total = self.total_thickness();
+
+        // Thickness-weighted mean (rule of mixtures)
+        let mix = |f: fn(&MatProps) -> f32| {
+            (layers.iter().map(|(p, t)| f(p) as f64 * t).sum::<f64>() / total) as f32
+        };
+        // Mean weighted by w(props) * thickness
+        let weighted = |f: fn(&MatProps) -> f32, w: fn(&MatProps) -> f32| {
+            let den: f64 = layers.iter().map(|(p, t)| w(p) as f64 * t).sum();
+            if den > 0.0 {
+                (layers.iter().map(|(p, t)| f(p) as f64 * w(p) as f64 * t).sum::<f64>() / den) as f32
+            } else {
+                mix(f)
+            }
+        };
+        let min = |f: fn(&MatProps) -> f32| layers.iter().map(|(p, _)| f(p)).fold(f32::INFINITY, f32::min);
+
+        let resistance = self.r_value_with(&props_of);
+        let surface = layers[0].0;
+
+        MatProps {
+            density: mix(|p| p.density),
+            elastic_modulus: mix(|p| p.elastic_modulus),
+            tensile_strength: mix(|p| p.tensile_strength),
+            compressive_strength: mix(|p| p.compressive_strength),
+            hardness: surface.hardness,
+            fracture_toughness: mix(|p| p.fracture_toughness),
+            fatigue_resistance: mix(|p| p.fatigue_resistance),
+
+            thermal_conductivity: if resistance.is_finite() { (total / resistance) as f32 } else { 0.0 },
+            thermal_expansion: weighted(|p| p.thermal_expansion, |p| p.elastic_modulus),
+            melting_point: min(|p| p.melting_point),
+            specific_heat: weighted(|p| p.specific_heat, |p| p.density),
+
+            corrosion_resistance: surface.corrosion_resistance,
+            solubility: surface.solubility,
+            permeability: min(|p| p.permeability),
+            flammability: surface.flammability,
+
+            electrical_conductivity: mix(|p| p.electrical_conductivity),
+            magnetic_permeability: mix(|p| p.magnetic_permeability),
+
+            refractive_index: surface.refractive_index,
+            transparency: layers.iter().map(|(p, _)| p.transparency).product(),
+            reflectivity: surface.reflectivity,
+            absorption: surface.absorption,
+
+            uv_resistance: surface.uv_resistance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn laminate_mixes_series_and_surface_properties() {
+        let steel = MatCatId::steel_lowcarbon();
+        let aluminium = MatCatId::new(1, 2, 1);
+        let (s, a) = (props_for(&steel), props_for(&aluminium));
+
+        let plate = LayeredMaterial::new(vec![Layer::new(steel, 0.002), Layer::new(aluminium, 0.010)])
+            .unwrap()
+            .named("clad plate");
+        let eff = plate.effective_props();
+
+        let density = (s.density as f64 * 0.002 + a.density as f64 * 0.010) / 0.012;
+        assert!((eff.density as f64 - density).abs() < 1e-2);
+
+        let r = 0.002 / s.thermal_conductivity as f64 + 0.010 / a.thermal_conductivity as f64;
+        assert!((plate.r_value() - r).abs() < 1e-12);
+        assert!((eff.thermal_conductivity as f64 - 0.012 / r).abs() < 1e-3);
+        assert!((plate.u_value() * plate.r_value() - 1.0).abs() < 1e-12);
+
+        assert_eq!(eff.corrosion_resistance, s.corrosion_resistance);
+        assert_eq!(eff.uv_resistance, s.uv_resistance);
+        assert_eq!(eff.melting_point, s.melting_point.min(a.melting_point));
+        assert_eq!(plate.outermost(), steel);
+
+        // Single layer is the material itself (up to rounding)
+        let single = LayeredMaterial::new(vec![Layer::new(aluminium, 0.01)]).unwrap().effective_props();
+        assert!((single.thermal_conductivity - a.thermal_conductivity).abs() < 1e-3);
+
+        let json = serde_json::to_string(&plate).unwrap();
+        assert_eq!(serde_json::from_str::<LayeredMaterial>(&json).unwrap(), plate);
+        assert!(serde_json::from_str::<LayeredMaterial>(r#"{"layers":[]}"#).is_err());
+        assert_eq!(
+            LayeredMaterial::new(vec![Layer::new(steel, 0.0)]),
+            Err(LayerError::Thickness { index: 0, thickness_m: 0.0 })
+        );
+    }
+
+    #[test]
+    fn degenerate_stacks_are_rejected() {
+        let steel = MatCatId::steel_lowcarbon();
+        let ok = Layer::new(steel, 0.001);
+
+        assert_eq!(LayeredMaterial::new(vec![]), Err(LayerError::Empty));
+        for bad in [0.0, -0.001, f64::NAN, f64::INFINITY] {
+            let err = LayeredMaterial::new(vec![ok, ok, Layer::new(steel, bad)]).unwrap_err();
+            assert!(matches!(err, LayerError::Thickness { index: 2, .. }), "{bad}");
+        }
+
+        // Loading validates too, zero thickness anywhere in the stack
+        let mut json = serde_json::to_value(LayeredMaterial::new(vec![ok, ok]).unwrap()).unwrap();
+        json["layers"][1]["thickness_m"] = 0.0.into();
+        let err = serde_json::from_value::<LayeredMaterial>(json).unwrap_err();
+        assert!(err.to_string().contains("layer 1"), "{err}");
+    }
+
+    #[test]
+    fn splitting_a_layer_changes_nothing() {
+        let steel = MatCatId::steel_lowcarbon();
+        let whole = LayeredMaterial::new(vec![Layer::new(steel, 0.004)]).unwrap();
+        let halves = LayeredMaterial::new(vec![Layer::new(steel, 0.001), Layer::new(steel, 0.003)]).unwrap();
+
+        assert!((whole.r_value() - halves.r_value()).abs() < 1e-15);
+        assert!((whole.areal_mass() - halves.areal_mass()).abs() < 1e-9);
+        let (w, h) = (whole.effective_props(), halves.effective_props());
+        assert!((w.density - h.density).abs() < 1e-2);
+        assert!((w.specific_heat - h.specific_heat).abs() < 1e-3);
+        assert_eq!(w.melting_point, h.melting_point);
+
+        // Reference conditions match the static properties
+        assert_eq!(halves.effective_props_at(&FieldSample::default()), h);
+        assert!((halves.r_value_at(&FieldSample::default()) - halves.r_value()).abs() < 1e-15);
+    }
+}
@@ -8,6 +8,7 @@ use crate::core::objex::matcat::variants::{VariantId, VARIANT_MAP};
 use crate::core::objex::matcat::grades::{GradeId, GRADE_MAP};
 use crate::core::objex::matcat::category_ranges::generate_props_from_category;
 use crate::core::objex::matcat::properties::MatProps;
+use crate::core::objex::matcat::measured::{measured_for, MATPROPS_UNITS};
 
 /// Material Categorization ID (5-byte logical identity)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -42,6 +43,28 @@ impl MatCatId {
         (cat << 32) | (var << 16) | grd
     }
 
+    /// Fixed-width code `CC-VVVV-GGGG` (missing parts are zero).
+    pub fn code(&self) -> String {
+        format!(
+            "{:02}-{:04}-{:04}",
+            self.category.0,
+            self.variant.map(|v| v.0).unwrap_or(0),
+            self.grade.map(|g| g.0).unwrap_or(0)
+        )
+    }
+
+    /// Parse a code produced by `code()`.
+    pub fn parse_code(code: &str) -> Option<Self> {
+        let mut parts = code.trim().split('-');
+        let category = parts.next()?.parse().ok()?;
+        let variant = parts.next()?.parse().ok()?;
+        let grade = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self::new(category, variant, grade))
+    }
+
     /// Human-readable name with graceful fallback
     pub fn name(&self) -> String {
         let cat = CATEGORY_MAP
@@ -86,7 +109,8 @@ impl MatCatId {
         Self::new(26, 1, 1)
     }
 
-    pub fn from_str(name: &str) -> Option<Self> {
+    /// Look up a material by common name ("steel", "water", ...).
+    pub fn from_common_name(name: &str) -> Option<Self> {
         match name.to_lowercase().as_str() {
             "steel" => Some(Self::steel_lowcarbon()),
             "copper" => Some(Self::metal_cu()),
@@ -108,22 +132,77 @@ fn lcg(mut seed: u64) -> impl FnMut() -> f32 {
     }
 }
 
+/// Where resolved properties came from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PropsSource {
+    /// Measured reference data (see `measured`). `entry` is the record
+    /// used, which is the variant-level one when the grade has none;
+    /// `generated` fields were filled procedurally.
+    Measured {
+        entry: MatCatId,
+        name: String,
+        measured: Vec<String>,
+        generated: Vec<String>,
+    },
+    /// Hard-coded physics override (plasma).
+    Override,
+    /// Drawn from the category's property ranges.
+    Procedural,
+    /// Unknown category; drawn from generic ranges.
+    Fallback,
+}
+
+/// Material properties together with their resolution path.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResolvedProps {
+    pub props: MatProps,
+    pub source: PropsSource,
+}
+
 /// Cached resolved material properties
-static MATPROPS_CACHE: Lazy<Mutex<HashMap<MatCatId, MatProps>>> =
+static MATPROPS_CACHE: Lazy<Mutex<HashMap<MatCatId, ResolvedProps>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
 /// Resolve material properties for a given MatCatId
 pub fn props_for(id: &MatCatId) -> MatProps {
+    resolve_props(id).props
+}
+
+/// Resolve material properties and report how they were obtained.
+/// Measured data wins; fields it lacks are generated procedurally.
+pub fn resolve_props(id: &MatCatId) -> ResolvedProps {
     // Cache hit
-    if let Some(props) = MATPROPS_CACHE.lock().unwrap().get(id).copied() {
-        return props;
+    if let Some(resolved) = MATPROPS_CACHE.lock().unwrap().get(id).cloned() {
+        return resolved;
     }
 
+    let (mut props, source) = procedural_props(id);
+    let source = match measured_for(id) {
+        Some(record) => {
+            let measured = record.apply(&mut props);
+            let generated = MATPROPS_UNITS
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .filter(|name| !measured.contains(name))
+                .collect();
+            PropsSource::Measured { entry: record.id, name: record.name.clone(), measured, generated }
+        }
+        None => source,
+    };
+
+    let resolved = ResolvedProps { props, source };
+    MATPROPS_CACHE.lock().unwrap().insert(*id, resolved.clone());
+    resolved
+}
+
+/// Deterministic procedural properties for `id`, ignoring measured data.
+pub fn procedural_props(id: &MatCatId) -> (MatProps, PropsSource) {
     let mut rng = StdRng::seed_from_u64(id.seed());
 
     // Special-case plasma (explicit physics override)
-    let props = if id.category.0 == 26 {
-        MatProps {
+    if id.category.0 == 26 {
+        let props = MatProps {
             density: 0.0002,
             elastic_modulus: 0.0,
             tensile_strength: 0.0,
@@ -151,15 +230,16 @@ pub fn props_for(id: &MatCatId) -> MatProps {
             absorption: 1.0,
 
             uv_resistance: 1.0,
-        }
+        };
+        (props, PropsSource::Override)
     } else if let Some(props) =
         generate_props_from_category(id.category.0, &mut rng)
     {
-        props
+        (props, PropsSource::Procedural)
     } else {
         // Absolute fallback (should almost never happen)
         let mut r = lcg(id.seed());
-        MatProps {
+        let props = MatProps {
             density: 500.0 + r() * 20000.0,
             elastic_modulus: r() * 4e11,
             tensile_strength: r() * 2000.0,
@@ -187,9 +267,7 @@ pub fn props_for(id: &MatCatId) -> MatProps {
             absorption: r(),
 
             uv_resistance: r(),
-        }
-    };
-
-    MATPROPS_CACHE.lock().unwrap().insert(*id, props);
-    props
+        };
+        (props, PropsSource::Fallback)
+    }
 }
@@ -0,0 +1,538 @@
+//! Measured material data.
+//!
+//! A curated table of reference values keyed by `MatCatId`, bundled from
+//! `data/measured.json`. Each value carries its reported unit, an
+//! uncertainty range and a citation; on load it is converted to the units
+//! `MatProps` uses (the same units as `category_ranges`):
+//!
+//! | property                | unit      |
+//! |-------------------------|-----------|
+//! | density                 | kg/m³     |
+//! | elastic_modulus         | Pa        |
+//! | tensile/compressive     | MPa       |
+//! | fracture_toughness      | MPa·m^0.5 |
+//! | thermal_conductivity    | W/(m·K)   |
+//! | thermal_expansion       | 1/K       |
+//! | melting_point           | °C        |
+//! | specific_heat           | J/(kg·K)  |
+//! | electrical_conductivity | S/m       |
+//! | hardness                | Mohs      |
+//! | everything else         | 1         |
+//!
+//! Properties a record does not list are left to procedural generation.
+
+use std::collections::{BTreeMap, HashMap};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::objex::matcat::curves::{CurveError, CurveMode, EnvVar, MaterialCurves, PropertyCurve};
+use crate::core::objex::matcat::materials::MatCatId;
+use crate::core::objex::matcat::properties::MatProps;
+
+#[derive(Debug, Error)]
+pub enum MeasuredError {
+    #[error("Serde JSON error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+
+    #[error("invalid material id `{0}` (expected CC-VVVV-GGGG)")]
+    InvalidId(String),
+
+    #[error("duplicate material id {0}")]
+    Duplicate(String),
+
+    #[error("{material}: unknown property `{property}`")]
+    UnknownProperty { material: String, property: String },
+
+    #[error("{material}: unit `{unit}` does not fit `{property}`")]
+    UnknownUnit { material: String, property: String, unit: String },
+
+    #[error("{material}: `{property}` value lies outside its range")]
+    OutOfRange { material: String, property: String },
+
+    #[error("{material}: `{property}` cites source {index}, which does not exist")]
+    UnknownSource { material: String, property: String, index: usize },
+
+    #[error("{material}: `{property}` curve: {error}")]
+    Curve { material: String, property: String, error: CurveError },
+}
+
+// ---------------------------------------------------------------------------
+// Units
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dim {
+    Density,
+    Pressure,
+    Toughness,
+    ThermalConductivity,
+    Expansion,
+    Temperature,
+    SpecificHeat,
+    ElectricalConductivity,
+    Hardness,
+    Ratio,
+    Deposition,
+    Cost,
+}
+
+/// (unit, dimension, scale to SI, offset to SI): si = value * scale + offset
+const UNITS: &[(&str, Dim, f64, f64)] = &[
+    ("kg/m3", Dim::Density, 1.0, 0.0),
+    ("kg/m³", Dim::Density, 1.0, 0.0),
+    ("g/cm3", Dim::Density, 1000.0, 0.0),
+    ("g/cm³", Dim::Density, 1000.0, 0.0),
+    ("Pa", Dim::Pressure, 1.0, 0.0),
+    ("kPa", Dim::Pressure, 1e3, 0.0),
+    ("MPa", Dim::Pressure, 1e6, 0.0),
+    ("GPa", Dim::Pressure, 1e9, 0.0),
+    ("psi", Dim::Pressure, 6_894.757, 0.0),
+    ("ksi", Dim::Pressure, 6_894_757.0, 0.0),
+    ("MPa·m^0.5", Dim::Toughness, 1.0, 0.0),
+    ("MPa√m", Dim::Toughness, 1.0, 0.0),
+    ("W/(m·K)", Dim::ThermalConductivity, 1.0, 0.0),
+    ("W/m·K", Dim::ThermalConductivity, 1.0, 0.0),
+    ("W/(m*K)", Dim::ThermalConductivity, 1.0, 0.0),
+    ("1/K", Dim::Expansion, 1.0, 0.0),
+    ("µm/(m·K)", Dim::Expansion, 1e-6, 0.0),
+    ("ppm/K", Dim::Expansion, 1e-6, 0.0),
+    ("K", Dim::Temperature, 1.0, 0.0),
+    ("°C", Dim::Temperature, 1.0, 273.15),
+    ("J/(kg·K)", Dim::SpecificHeat, 1.0, 0.0),
+    ("J/(kg*K)", Dim::SpecificHeat, 1.0, 0.0),
+    ("kJ/(kg·K)", Dim::SpecificHeat, 1e3, 0.0),
+    ("S/m", Dim::ElectricalConductivity, 1.0, 0.0),
+    ("MS/m", Dim::ElectricalConductivity, 1e6, 0.0),
+    // International Annealed Copper Standard: 100 % = 58.0 MS/m
+    ("%IACS", Dim::ElectricalConductivity, 5.8e5, 0.0),
+    ("Mohs", Dim::Hardness, 1.0, 0.0),
+    ("1", Dim::Ratio, 1.0, 0.0),
+    ("%", Dim::Ratio, 0.01, 0.0),
+    ("mg/(m²·d)", Dim::Deposition, 1.0, 0.0),
+    ("mg/(m2·d)", Dim::Deposition, 1.0, 0.0),
+    ("g/(m²·d)", Dim::Deposition, 1e3, 0.0),
+    ("USD/kg", Dim::Cost, 1.0, 0.0),
+    ("USD/t", Dim::Cost, 1e-3, 0.0),
+];
+
+/// Unit of `MeasuredMaterial::cost`.
+pub const COST_UNIT: &str = "USD/kg";
+
+/// `MatProps` field names and the unit each is stored in.
+pub const MATPROPS_UNITS: &[(&str, &str)] = &[
+    ("density", "kg/m3"),
+    ("elastic_modulus", "Pa"),
+    ("tensile_strength", "MPa"),
+    ("compressive_strength", "MPa"),
+    ("hardness", "Mohs"),
+    ("fracture_toughness", "MPa·m^0.5"),
+    ("fatigue_resistance", "1"),
+    ("thermal_conductivity", "W/(m·K)"),
+    ("thermal_expansion", "1/K"),
+    ("melting_point", "°C"),
+    ("specific_heat", "J/(kg·K)"),
+    ("corrosion_resistance", "1"),
+    ("solubility", "1"),
+    ("permeability", "1"),
+    ("flammability", "1"),
+    ("electrical_conductivity", "S/m"),
+    ("magnetic_permeability", "1"),
+    ("refractive_index", "1"),
+    ("transparency", "1"),
+    ("reflectivity", "1"),
+    ("absorption", "1"),
+    ("uv_resistance", "1"),
+];
+
+/// The unit `MatProps` stores a property in.
+pub fn canonical_unit(property: &str) -> Option<&'static str> {
+    MATPROPS_UNITS.iter().find(|(name, _)| *name == property).map(|(_, unit)| *unit)
+}
+
+/// Convert `value` between two units of the same dimension.
+pub fn convert_unit(value: f64, from: &str, to: &str) -> Option<f64> {
+    let unit_of = |u: &str| UNITS.iter().find(|(name, ..)| *name == u.trim()).map(|&(_, d, s, o)| (d, s, o));
+    let (dim, scale, offset) = unit_of(from)?;
+    let (to_dim, to_scale, to_offset) = unit_of(to)?;
+    if dim != to_dim {
+        return None;
+    }
+    Some((value * scale + offset - to_offset) / to_scale)
+}
+
+/// Convert `value` in `unit` to the unit `MatProps` stores `property` in.
+pub fn to_canonical(property: &str, value: f64, unit: &str) -> Option<f64> {
+    convert_unit(value, unit, canonical_unit(property)?)
+}
+
+/// Read one named field of `MatProps`.
+pub fn get_property(props: &MatProps, property: &str) -> Option<f32> {
+    let mut copy = *props;
+    field_mut(&mut copy, property).map(|field| *field)
+}
+
+/// Write one named field of `MatProps`.
+pub fn set_property(props: &mut MatProps, property: &str, value: f32) -> bool {
+    match field_mut(props, property) {
+        Some(field) => {
+            *field = value;
+            true
+        }
+        None => false,
+    }
+}
+
+fn field_mut<'a>(props: &'a mut MatProps, property: &str) -> Option<&'a mut f32> {
+    let field = match property {
+        "density" => &mut props.density,
+        "elastic_modulus" => &mut props.elastic_modulus,
+        "tensile_strength" => &mut props.tensile_strength,
+        "compressive_strength" => &mut props.compressive_strength,
+        "hardness" => &mut props.hardness,
+        "fracture_toughness" => &mut props.fracture_toughness,
+        "fatigue_resistance" => &mut props.fatigue_resistance,
+        "thermal_conductivity" => &mut props.thermal_conductivity,
+        "thermal_expansion" => &mut props.thermal_expansion,
+        "melting_point" => &mut props.melting_point,
+        "specific_heat" => &mut props.specific_heat,
+        "corrosion_resistance" => &mut props.corrosion_resistance,
+        "solubility" => &mut props.solubility,
+        "permeability" => &mut props.permeability,
+        "flammability" => &mut props.flammability,
+        "electrical_conductivity" => &mut props.electrical_conductivity,
+        "magnetic_permeability" => &mut props.magnetic_permeability,
+        "refractive_index" => &mut props.refractive_index,
+        "transparency" => &mut props.transparency,
+        "reflectivity" => &mut props.reflectivity,
+        "absorption" => &mut props.absorption,
+        "uv_resistance" => &mut props.uv_resistance,
+        _ => return None,
+    };
+    Some(field)
+}
+
+// ---------------------------------------------------------------------------
+// Records
+// ---------------------------------------------------------------------------
+
+/// One measured property, converted to the `MatProps` unit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MeasuredValue {
+    pub value: f32,
+    /// Uncertainty / spread across sources, same unit as `value`.
+    pub min: f32,
+    pub max: f32,
+    pub unit: &'static str,
+    /// The value as published, e.g. `"101 %IACS"`.
+    pub reported: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MeasuredMaterial {
+    pub id: MatCatId,
+    pub name: String,
+    pub sources: Vec<String>,
+    pub properties: BTreeMap<String, MeasuredValue>,
+    /// Environment dependence (see `curves`).
+    pub curves: MaterialCurves,
+    /// Indicative price, `COST_UNIT`.
+    pub cost: Option<MeasuredValue>,
+}
+
+impl MeasuredMaterial {
+    /// Overwrite the measured fields of `props`; returns their names.
+    pub fn apply(&self, props: &mut MatProps) -> Vec<String> {
+        self.properties
+            .iter()
+            .filter(|(name, m)| set_property(props, name, m.value))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+// File layout (see data/measured.json); `version` is informational.
+#[derive(Deserialize)]
+struct RawTable {
+    materials: Vec<RawMaterial>,
+}
+
+#[derive(Deserialize)]
+struct RawMaterial {
+    id: String,
+    name: String,
+    #[serde(default)]
+    sources: Vec<String>,
+    properties: BTreeMap<String, RawValue>,
+    #[serde(default)]
+    curves: Vec<RawCurve>,
+    cost: Option<RawValue>,
+}
+
+/// A curve in file units; with `unit` the values are absolute, without
+/// they are factors of the reference value.
+#[derive(Deserialize)]
+struct RawCurve {
+    property: String,
+    input: EnvVar,
+    input_unit: Option<String>,
+    unit: Option<String>,
+    points: Vec<(f64, f64)>,
+    #[serde(default)]
+    source: usize,
+}
+
+#[derive(Deserialize)]
+struct RawValue {
+    value: f64,
+    unit: String,
+    range: Option<(f64, f64)>,
+    #[serde(default)]
+    source: usize,
+}
+
+impl RawMaterial {
+    fn parse(mut self) -> Result<MeasuredMaterial, MeasuredError> {
+        let id = MatCatId::parse_code(&self.id).ok_or_else(|| MeasuredError::InvalidId(self.id.clone()))?;
+        let mut properties = BTreeMap::new();
+
+        for (property, raw) in std::mem::take(&mut self.properties) {
+            let unit = canonical_unit(&property).ok_or_else(|| MeasuredError::UnknownProperty {
+                material: self.name.clone(),
+                property: property.clone(),
+            })?;
+            let measured = self.value(&property, unit, raw)?;
+            properties.insert(property, measured);
+        }
+        let cost = self.cost.take().map(|raw| self.value("cost", COST_UNIT, raw)).transpose()?;
+
+        let mut curves = MaterialCurves::default();
+        for raw in self.curves {
+            let material = self.name.clone();
+            let property = raw.property.clone();
+            let input_unit = raw.input_unit.as_deref().unwrap_or(raw.input.unit());
+            let unit_error = |unit: &str| MeasuredError::UnknownUnit {
+                material: material.clone(),
+                property: property.clone(),
+                unit: unit.to_string(),
+            };
+
+            let mut points = Vec::with_capacity(raw.points.len());
+            for (x, y) in raw.points {
+                let x = convert_unit(x, input_unit, raw.input.unit()).ok_or_else(|| unit_error(input_unit))?;
+                let y = match &raw.unit {
+                    Some(unit) => to_canonical(&property, y, unit).ok_or_else(|| unit_error(unit))?,
+                    None => y,
+                };
+                points.push((x, y));
+            }
+
+            let mode = if raw.unit.is_some() { CurveMode::Absolute } else { CurveMode::Factor };
+            let curve = PropertyCurve::new(&property, raw.input, mode, points)
+                .map_err(|error| MeasuredError::Curve { material: material.clone(), property: property.clone(), error })?;
+            let Some(source) = self.sources.get(raw.source) else {
+                return Err(MeasuredError::UnknownSource { material, property, index: raw.source });
+            };
+            curves.set(curve.with_source(source.clone()));
+        }
+
+        Ok(MeasuredMaterial { id, name: self.name, sources: self.sources, properties, curves, cost })
+    }
+
+    /// One value converted to `unit`, checked against its range and source.
+    fn value(&self, property: &str, unit: &'static str, raw: RawValue) -> Result<MeasuredValue, MeasuredError> {
+        let material = self.name.clone();
+        let property = property.to_string();
+        let convert = |v: f64| convert_unit(v, &raw.unit, unit);
+        let (lo, hi) = raw.range.unwrap_or((raw.value, raw.value));
+        let (Some(value), Some(a), Some(b)) = (convert(raw.value), convert(lo), convert(hi)) else {
+            return Err(MeasuredError::UnknownUnit { material, property, unit: raw.unit });
+        };
+        let (min, max) = (a.min(b), a.max(b));
+        if !(min..=max).contains(&value) {
+            return Err(MeasuredError::OutOfRange { material, property });
+        }
+        let Some(source) = self.sources.get(raw.source) else {
+            return Err(MeasuredError::UnknownSource { material, property, index: raw.source });
+        };
+
+        Ok(MeasuredValue {
+            value: value as f32,
+            min: min as f32,
+            max: max as f32,
+            unit,
+            reported: format!("{} {}", raw.value, raw.unit),
+            source: source.clone(),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Database
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct MeasuredDb {
+    materials: HashMap<MatCatId, MeasuredMaterial>,
+}
+
+impl MeasuredDb {
+    pub fn from_json(json: &str) -> Result<Self, MeasuredError> {
+        let table: RawTable = serde_json::from_str(json)?;
+        let mut materials = HashMap::new();
+        for raw in table.materials {
+            let record = raw.parse()?;
+            if materials.contains_key(&record.id) {
+                return Err(MeasuredError::Duplicate(record.id.code()));
+            }
+            materials.insert(record.id, record);
+        }
+        Ok(Self { materials })
+    }
+
+    /// The record for exactly this id.
+    pub fn get(&self, id: &MatCatId) -> Option<&MeasuredMaterial> {
+        self.materials.get(id)
+    }
+
+    /// The record for this id, else the variant-level record (grade 0)
+    /// it belongs to.
+    pub fn lookup(&self, id: &MatCatId) -> Option<&MeasuredMaterial> {
+        self.get(id).or_else(|| {
+            id.grade?;
+            self.get(&MatCatId { grade: None, ..*id })
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MeasuredMaterial> {
+        self.materials.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.materials.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.materials.is_empty()
+    }
+}
+
+/// The bundled reference table.
+pub static MEASURED_DB: Lazy<MeasuredDb> = Lazy::new(|| {
+    MeasuredDb::from_json(include_str!("data/measured.json"))
+        .expect("bundled data/measured.json is valid")
+});
+
+/// Measured record used for `id`, if any (see `MeasuredDb::lookup`).
+pub fn measured_for(id: &MatCatId) -> Option<&'static MeasuredMaterial> {
+    MEASURED_DB.lookup(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::matcat::materials::{resolve_props, PropsSource};
+
+    #[test]
+    fn measured_values_win_over_procedural() {
+        assert!(!MEASURED_DB.is_empty());
+
+        let copper = measured_for(&MatCatId::metal_cu()).unwrap();
+        let conductivity = &copper.properties["electrical_conductivity"];
+        assert!((conductivity.value - 5.858e7).abs() < 1e3);
+        assert_eq!(conductivity.unit, "S/m");
+        assert!(conductivity.min <= conductivity.value && conductivity.value <= conductivity.max);
+
+        let resolved = resolve_props(&MatCatId::metal_cu());
+        assert!((resolved.props.density - 8890.0).abs() < 1e-3);
+        assert!((resolved.props.elastic_modulus - 115e9).abs() < 1e3);
+        match &resolved.source {
+            PropsSource::Measured { entry, measured, generated, .. } => {
+                assert_eq!(*entry, MatCatId::metal_cu());
+                assert!(measured.contains(&"density".to_string()));
+                assert!(generated.contains(&"hardness".to_string()));
+            }
+            other => panic!("expected measured, got {other:?}"),
+        }
+
+        // Unknown grade falls back to the variant-level record
+        let resolved = resolve_props(&MatCatId::new(1, 3, 9));
+        assert!(matches!(&resolved.source, PropsSource::Measured { entry, .. } if *entry == MatCatId::new(1, 3, 0)));
+
+        let water = measured_for(&MatCatId::liquid_water()).unwrap();
+        assert!(water.properties["melting_point"].value.abs() < 1e-3);
+
+        assert_eq!(resolve_props(&MatCatId::new(2, 1, 1)).source, PropsSource::Procedural);
+        assert_eq!(resolve_props(&MatCatId::plasma_stellar()).source, PropsSource::Override);
+
+        let bad = r#"{"version":1,"materials":[{"id":"01-0001-0001","name":"x","sources":["s"],
+            "properties":{"density":{"value":1,"unit":"MPa"}}}]}"#;
+        assert!(matches!(MeasuredDb::from_json(bad), Err(MeasuredError::UnknownUnit { .. })));
+    }
+
+    #[test]
+    fn measured_curves_are_converted_and_checked() {
+        let table = |curve: &str| {
+            format!(
+                r#"{{"version":1,"materials":[{{"id":"01-0001-0001","name":"x","sources":["s"],
+                    "properties":{{}},"curves":[{curve}]}}]}}"#
+            )
+        };
+
+        let db = MeasuredDb::from_json(&table(
+            r#"{"property":"thermal_conductivity","input":"temperature","input_unit":"°C",
+                "unit":"W/(m·K)","points":[[0,50],[100,40]]}"#,
+        ))
+        .unwrap();
+        let curve = &db.get(&MatCatId::new(1, 1, 1)).unwrap().curves.curves[0];
+        assert_eq!(curve.mode, CurveMode::Absolute);
+        assert_eq!(curve.points, vec![(273.15, 50.0), (373.15, 40.0)]);
+        assert_eq!(curve.source.as_deref(), Some("s"));
+
+        let error = |curve: &str| MeasuredDb::from_json(&table(curve)).unwrap_err();
+        assert!(matches!(
+            error(r#"{"property":"density","input":"temperature","points":[]}"#),
+            MeasuredError::Curve { error: CurveError::Empty, .. }
+        ));
+        assert!(matches!(
+            error(r#"{"property":"density","input":"temperature","input_unit":"°C","points":[[10,1],[-263.15,1]]}"#),
+            MeasuredError::Curve { error: CurveError::Unsorted, .. }
+        ));
+        assert!(matches!(
+            error(r#"{"property":"density","input":"chloride","input_unit":"K","points":[[1,1]]}"#),
+            MeasuredError::UnknownUnit { unit, .. } if unit == "K"
+        ));
+        assert!(matches!(
+            error(r#"{"property":"density","input":"chloride","points":[[1,1]],"source":3}"#),
+            MeasuredError::UnknownSource { index: 3, .. }
+        ));
+    }
+
+    #[test]
+    fn costs_convert_and_respect_their_range() {
+        let table = |cost: &str| {
+            format!(
+                r#"{{"version":1,"materials":[{{"id":"01-0001-0001","name":"x","sources":["s"],
+                    "properties":{{}},"cost":{cost}}}]}}"#
+            )
+        };
+
+        let db = MeasuredDb::from_json(&table(r#"{"value":2500,"unit":"USD/t","range":[2000,3000]}"#)).unwrap();
+        let cost = db.get(&MatCatId::new(1, 1, 1)).unwrap().cost.as_ref().unwrap();
+        assert_eq!((cost.value, cost.min, cost.max), (2.5, 2.0, 3.0));
+        assert_eq!((cost.unit, cost.reported.as_str()), (COST_UNIT, "2500 USD/t"));
+
+        assert!(matches!(
+            MeasuredDb::from_json(&table(r#"{"value":9,"unit":"USD/kg","range":[1,2]}"#)),
+            Err(MeasuredError::OutOfRange { property, .. }) if property == "cost"
+        ));
+        assert!(matches!(
+            MeasuredDb::from_json(&table(r#"{"value":9,"unit":"MPa"}"#)),
+            Err(MeasuredError::UnknownUnit { .. })
+        ));
+        let without = MeasuredDb::from_json(&table("null")).unwrap();
+        assert_eq!(without.get(&MatCatId::new(1, 1, 1)).unwrap().cost, None);
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! A compact material catalog system.
 //!
-//! Materials are identified by a 5-byte code (`MatCatId`) and deterministically
-//! expanded into full property sets (`MatProps`) using procedural generation.
+//! Materials are identified by a 5-byte code (`MatCatId`) and expanded into
+//! full property sets (`MatProps`): measured reference data where the
+//! bundled table has it, deterministic procedural generation otherwise.
 //!
 //! Public API is kept flat: just `use matcat::...`.
 use crate::core::objex::core::MaterialName;
@@ -14,6 +15,10 @@ pub mod category_ranges;
 pub mod api;
 pub mod grades;
 pub mod properties;
+pub mod measured;
+pub mod curves;
+pub mod search;
+pub mod layered;
 pub use categories::{
     CategoryId,
 };
@@ -25,6 +30,10 @@ pub use grades::{
 };
 pub use properties::*;
 pub use materials::*;
+pub use curves::{CurveMode, EnvVar, MaterialCurves, PropertyCurve, curves_for, props_at};
+pub use search::{Constraint, MaterialMatch, PropertyWeights, SearchError, SimilarityQuery, SubstituteQuery, nearest, substitutes};
+pub use layered::{Layer, LayerError, LayeredMaterial};
+pub use measured::{MeasuredDb, MeasuredError, MeasuredMaterial, MeasuredValue, MEASURED_DB, measured_for};
 
 pub use category_ranges::*;
 /*
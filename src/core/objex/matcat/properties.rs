@@ -2,11 +2,11 @@ use serde::{Serialize, Deserialize};
 
 /// Canonical material property set.
 /// Physics consumes this. Identity does not.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MatProps {
     // --- Mechanical ---
     pub density: f32,              // kg/m³
-    pub elastic_modulus: f32,      // GPa
+    pub elastic_modulus: f32,      // Pa
     pub tensile_strength: f32,     // MPa
     pub compressive_strength: f32, // MPa
     pub hardness: f32,             // Mohs-like 0–10
@@ -26,7 +26,7 @@ pub struct MatProps {
     pub flammability: f32,         // 0.0–1.0
 
     // --- Electrical / Magnetic ---
-    pub electrical_conductivity: f32, // 0.0–1.0
+    pub electrical_conductivity: f32, // S/m
     pub magnetic_permeability: f32,   // relative μ
 
     // --- Optical ---
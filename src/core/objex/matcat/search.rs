@@ -0,0 +1,453 @@
+//! Similarity search and substitution over the material catalog.
+//!
+//! Distances are scale-free: each property contributes its relative
+//! difference `|a − b| / max(|a|, |b|)`, and the weighted RMS of those
+//! is the distance (0 = identical, 1 = nothing in common). Unlike
+//! `properties::distance` this keeps elastic modulus (~1e11 Pa) from
+//! drowning out everything else.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::objex::matcat::categories::CategoryId;
+use crate::core::objex::matcat::grades::GRADE_MAP;
+use crate::core::objex::matcat::materials::{resolve_props, MatCatId, PropsSource};
+use crate::core::objex::matcat::measured::{canonical_unit, get_property, measured_for, MATPROPS_UNITS, MEASURED_DB};
+use crate::core::objex::matcat::properties::MatProps;
+use crate::core::objex::matcat::variants::VARIANT_MAP;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SearchError {
+    #[error("unknown property `{0}`")]
+    UnknownProperty(String),
+
+    #[error("unknown focus `{0}` (all, mechanical, thermal, chemical, electrical, optical)")]
+    UnknownFocus(String),
+
+    #[error("invalid constraint `{0}` (expected e.g. `tensile_strength >= 400` or `cost <= 5`)")]
+    InvalidConstraint(String),
+
+    #[error("weight {weight} for `{property}` must be zero or positive and finite")]
+    InvalidWeight { property: String, weight: f32 },
+}
+
+// ---------------------------------------------------------------------------
+// Weights
+// ---------------------------------------------------------------------------
+
+/// Relative importance of each `MatProps` field; absent fields are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyWeights(pub BTreeMap<String, f32>);
+
+impl PropertyWeights {
+    fn of(names: &[&str]) -> Self {
+        Self(names.iter().map(|n| (n.to_string(), 1.0)).collect())
+    }
+
+    pub fn all() -> Self {
+        Self(MATPROPS_UNITS.iter().map(|(n, _)| (n.to_string(), 1.0)).collect())
+    }
+
+    pub fn mechanical() -> Self {
+        Self::of(&[
+            "density",
+            "elastic_modulus",
+            "tensile_strength",
+            "compressive_strength",
+            "hardness",
+            "fracture_toughness",
+            "fatigue_resistance",
+        ])
+    }
+
+    pub fn thermal() -> Self {
+        Self::of(&["thermal_conductivity", "thermal_expansion", "melting_point", "specific_heat"])
+    }
+
+    pub fn chemical() -> Self {
+        Self::of(&["corrosion_resistance", "solubility", "permeability", "flammability"])
+    }
+
+    pub fn electrical() -> Self {
+        Self::of(&["electrical_conductivity", "magnetic_permeability"])
+    }
+
+    pub fn optical() -> Self {
+        Self::of(&["refractive_index", "transparency", "reflectivity", "absorption"])
+    }
+
+    /// A preset by name (`thermal`, `mechanical`, ...).
+    pub fn focus(name: &str) -> Result<Self, SearchError> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "all" => Ok(Self::all()),
+            "mechanical" => Ok(Self::mechanical()),
+            "thermal" => Ok(Self::thermal()),
+            "chemical" => Ok(Self::chemical()),
+            "electrical" => Ok(Self::electrical()),
+            "optical" => Ok(Self::optical()),
+            other => Err(SearchError::UnknownFocus(other.to_string())),
+        }
+    }
+
+    pub fn with(mut self, property: &str, weight: f32) -> Result<Self, SearchError> {
+        if canonical_unit(property).is_none() {
+            return Err(SearchError::UnknownProperty(property.to_string()));
+        }
+        if !(weight.is_finite() && weight >= 0.0) {
+            return Err(SearchError::InvalidWeight { property: property.to_string(), weight });
+        }
+        self.0.insert(property.to_string(), weight);
+        Ok(self)
+    }
+}
+
+impl Default for PropertyWeights {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Weighted, scale-free distance in [0, 1]. Each property differs by
+/// |a - b| / max(|a|, |b|), capped at 1 so values of opposite sign (a
+/// gas's melting point in °C) count as wholly unlike. Weights that are
+/// not positive and finite are skipped.
+pub fn weighted_distance(a: &MatProps, b: &MatProps, weights: &PropertyWeights) -> f32 {
+    let (mut sum, mut total) = (0.0f64, 0.0f64);
+    for (name, &w) in &weights.0 {
+        let (Some(x), Some(y)) = (get_property(a, name), get_property(b, name)) else { continue };
+        if !(w.is_finite() && w > 0.0) {
+            continue;
+        }
+        let scale = x.abs().max(y.abs()) as f64;
+        let d = if scale > 0.0 { ((x - y).abs() as f64 / scale).min(1.0) } else { 0.0 };
+        sum += w as f64 * d * d;
+        total += w as f64;
+    }
+    if total > 0.0 { (sum / total).sqrt() as f32 } else { 0.0 }
+}
+
+// ---------------------------------------------------------------------------
+// Catalog
+// ---------------------------------------------------------------------------
+
+/// Every addressable material: named variants, named grades and
+/// measured records, in code order.
+pub fn catalog_ids() -> Vec<MatCatId> {
+    let mut ids: Vec<MatCatId> = VARIANT_MAP
+        .keys()
+        .map(|(c, v)| MatCatId::new(c.0, v.0, 0))
+        .chain(GRADE_MAP.keys().map(|(c, v, g)| MatCatId::new(c.0, v.0, g.0)))
+        .chain(MEASURED_DB.iter().map(|m| m.id))
+        .collect();
+    ids.sort_by_key(MatCatId::seed);
+    ids.dedup();
+    ids
+}
+
+/// Indicative price of `id` in USD/kg, from the measured table.
+pub fn cost_per_kg(id: &MatCatId) -> Option<f32> {
+    measured_for(id)?.cost.as_ref().map(|c| c.value)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MaterialMatch {
+    pub id: MatCatId,
+    pub code: String,
+    pub name: String,
+    pub distance: f32,
+    /// Whether the properties come (partly) from measured data.
+    pub measured: bool,
+    pub cost_per_kg: Option<f32>,
+    pub props: MatProps,
+}
+
+impl MaterialMatch {
+    fn new(id: MatCatId, props: MatProps, measured: bool, distance: f32) -> Self {
+        Self {
+            id,
+            code: id.code(),
+            name: id.name(),
+            distance,
+            measured,
+            cost_per_kg: cost_per_kg(&id),
+            props,
+        }
+    }
+}
+
+/// Candidates passing the category filter, with resolved properties.
+fn candidates(categories: &[CategoryId]) -> impl Iterator<Item = (MatCatId, MatProps, bool)> + '_ {
+    catalog_ids()
+        .into_iter()
+        .filter(move |id| categories.is_empty() || categories.contains(&id.category))
+        .map(|id| {
+            let resolved = resolve_props(&id);
+            let measured = matches!(resolved.source, PropsSource::Measured { .. });
+            (id, resolved.props, measured)
+        })
+}
+
+/// Sort by distance, ties by code, and keep `k`.
+fn rank(mut matches: Vec<MaterialMatch>, k: usize) -> Vec<MaterialMatch> {
+    matches.sort_by(|a, b| a.distance.total_cmp(&b.distance).then_with(|| a.id.seed().cmp(&b.id.seed())));
+    matches.truncate(k);
+    matches
+}
+
+// ---------------------------------------------------------------------------
+// Nearest neighbours
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarityQuery {
+    pub target: MatProps,
+    #[serde(default)]
+    pub weights: PropertyWeights,
+    /// Only these categories (empty = all).
+    #[serde(default)]
+    pub categories: Vec<CategoryId>,
+    #[serde(default)]
+    pub exclude: Vec<MatCatId>,
+    pub k: usize,
+}
+
+impl SimilarityQuery {
+    pub fn new(target: MatProps, k: usize) -> Self {
+        Self { target, weights: PropertyWeights::all(), categories: Vec::new(), exclude: Vec::new(), k }
+    }
+
+    /// Materials like `id` (excluding `id` itself).
+    pub fn like(id: &MatCatId, k: usize) -> Self {
+        Self { exclude: vec![*id], ..Self::new(resolve_props(id).props, k) }
+    }
+
+    pub fn weights(mut self, weights: PropertyWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn in_categories(mut self, categories: &[CategoryId]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+}
+
+/// The `k` catalog materials closest to `query.target`.
+pub fn nearest(query: &SimilarityQuery) -> Vec<MaterialMatch> {
+    let matches = candidates(&query.categories)
+        .filter(|(id, ..)| !query.exclude.contains(id))
+        .map(|(id, props, measured)| {
+            let d = weighted_distance(&query.target, &props, &query.weights);
+            MaterialMatch::new(id, props, measured, d)
+        })
+        .collect();
+    rank(matches, query.k)
+}
+
+// ---------------------------------------------------------------------------
+// Substitution
+// ---------------------------------------------------------------------------
+
+/// A requirement a substitute must meet. Properties are in `MatProps`
+/// units, cost in USD/kg (materials without a known cost fail it).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Constraint {
+    AtLeast { property: String, value: f32 },
+    AtMost { property: String, value: f32 },
+    MaxCost { usd_per_kg: f32 },
+}
+
+impl Constraint {
+    pub fn at_least(property: &str, value: f32) -> Self {
+        Constraint::AtLeast { property: property.to_string(), value }
+    }
+
+    pub fn at_most(property: &str, value: f32) -> Self {
+        Constraint::AtMost { property: property.to_string(), value }
+    }
+
+    pub fn is_met(&self, id: &MatCatId, props: &MatProps) -> bool {
+        match self {
+            Constraint::AtLeast { property, value } => get_property(props, property).is_some_and(|v| v >= *value),
+            Constraint::AtMost { property, value } => get_property(props, property).is_some_and(|v| v <= *value),
+            Constraint::MaxCost { usd_per_kg } => cost_per_kg(id).is_some_and(|c| c <= *usd_per_kg),
+        }
+    }
+}
+
+/// `tensile_strength >= 400`, `density <= 3000`, `cost <= 5`.
+impl FromStr for Constraint {
+    type Err = SearchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SearchError::InvalidConstraint(s.to_string());
+        let (name, op, value) = [">=", "<="]
+            .iter()
+            .find_map(|op| s.split_once(op).map(|(l, r)| (l.trim(), *op, r.trim())))
+            .ok_or_else(invalid)?;
+        let value: f32 = value.parse().map_err(|_| invalid())?;
+
+        match (name, op) {
+            ("cost", "<=") => Ok(Constraint::MaxCost { usd_per_kg: value }),
+            ("cost", _) => Err(invalid()),
+            (property, _) if canonical_unit(property).is_none() => {
+                Err(SearchError::UnknownProperty(property.to_string()))
+            }
+            (property, ">=") => Ok(Constraint::at_least(property, value)),
+            (property, _) => Ok(Constraint::at_most(property, value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubstituteQuery {
+    pub original: MatCatId,
+    #[serde(default)]
+    pub constraints: Vec<Constraint>,
+    #[serde(default)]
+    pub weights: PropertyWeights,
+    #[serde(default)]
+    pub categories: Vec<CategoryId>,
+    pub k: usize,
+}
+
+impl SubstituteQuery {
+    pub fn new(original: MatCatId, k: usize) -> Self {
+        Self { original, constraints: Vec::new(), weights: PropertyWeights::all(), categories: Vec::new(), k }
+    }
+
+    pub fn require(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn weights(mut self, weights: PropertyWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn in_categories(mut self, categories: &[CategoryId]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+}
+
+/// Materials meeting every constraint, closest to the original first.
+pub fn substitutes(query: &SubstituteQuery) -> Vec<MaterialMatch> {
+    let original = resolve_props(&query.original).props;
+    let matches = candidates(&query.categories)
+        .filter(|(id, ..)| *id != query.original)
+        .filter(|(id, props, _)| query.constraints.iter().all(|c| c.is_met(id, props)))
+        .map(|(id, props, measured)| {
+            let d = weighted_distance(&original, &props, &query.weights);
+            MaterialMatch::new(id, props, measured, d)
+        })
+        .collect();
+    rank(matches, query.k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::objex::matcat::measured::set_property;
+
+    #[test]
+    fn nearest_and_substitutes() {
+        let al6061 = MatCatId::new(1, 2, 1);
+        let al7075 = MatCatId::new(1, 2, 2);
+
+        let props = resolve_props(&al6061).props;
+        assert_eq!(weighted_distance(&props, &props, &PropertyWeights::all()), 0.0);
+
+        let similar = nearest(&SimilarityQuery::like(&al6061, 3).weights(PropertyWeights::thermal()));
+        assert_eq!(similar.len(), 3);
+        assert!(similar.iter().all(|m| m.id != al6061));
+        assert!(similar.windows(2).all(|w| w[0].distance <= w[1].distance));
+        assert!(similar.iter().any(|m| m.id == al7075 || m.id == MatCatId::new(1, 2, 3)));
+
+        let water_only = nearest(&SimilarityQuery::new(props, 10).in_categories(&[CategoryId(27)]));
+        assert!(water_only.iter().all(|m| m.id.category == CategoryId(27)));
+
+        // Stronger than 6061 and cheaper than 5 USD/kg, mechanically alike
+        let query = SubstituteQuery::new(al6061, 5)
+            .require("tensile_strength >= 400".parse().unwrap())
+            .require("cost <= 5".parse().unwrap())
+            .weights(PropertyWeights::mechanical());
+        let found = substitutes(&query);
+        assert!(!found.is_empty());
+        for m in &found {
+            assert!(m.props.tensile_strength >= 400.0);
+            assert!(m.cost_per_kg.unwrap() <= 5.0);
+        }
+        assert!(found.iter().all(|m| m.id != al7075)); // too expensive
+
+        assert!(matches!("hardness > 3".parse::<Constraint>(), Err(SearchError::InvalidConstraint(_))));
+        assert!(matches!("shininess >= 3".parse::<Constraint>(), Err(SearchError::UnknownProperty(_))));
+    }
+
+    #[test]
+    fn unsatisfiable_queries_return_nothing() {
+        let al6061 = MatCatId::new(1, 2, 1);
+
+        let impossible = SubstituteQuery::new(al6061, 5).require(Constraint::at_least("tensile_strength", 1e9));
+        assert!(substitutes(&impossible).is_empty());
+        let free = SubstituteQuery::new(al6061, 5).require(Constraint::MaxCost { usd_per_kg: 0.0 });
+        assert!(substitutes(&free).is_empty());
+        let contradictory = SubstituteQuery::new(al6061, 5)
+            .require("density >= 3000".parse().unwrap())
+            .require("density <= 2000".parse().unwrap());
+        assert!(substitutes(&contradictory).is_empty());
+
+        assert!(nearest(&SimilarityQuery::like(&al6061, 0)).is_empty());
+        assert!(nearest(&SimilarityQuery::like(&al6061, 5).in_categories(&[CategoryId(250)])).is_empty());
+        assert_eq!(nearest(&SimilarityQuery::like(&al6061, usize::MAX)).len(), catalog_ids().len() - 1);
+    }
+
+    #[test]
+    fn distances_are_bounded_and_ignore_zero_weights() {
+        let a = resolve_props(&MatCatId::new(1, 2, 1)).props;
+        let b = resolve_props(&MatCatId::liquid_water()).props;
+
+        let d = weighted_distance(&a, &b, &PropertyWeights::all());
+        assert!(d > 0.0 && d <= 1.0);
+        assert_eq!(d, weighted_distance(&b, &a, &PropertyWeights::all()));
+
+        let none = PropertyWeights(BTreeMap::new());
+        assert_eq!(weighted_distance(&a, &b, &none), 0.0);
+        let zeroed = PropertyWeights::of(&[]).with("density", 0.0).unwrap();
+        assert_eq!(weighted_distance(&a, &b, &zeroed), 0.0);
+        let mut zero = a;
+        for (name, _) in MATPROPS_UNITS {
+            set_property(&mut zero, name, 0.0);
+        }
+        assert_eq!(weighted_distance(&zero, &zero, &PropertyWeights::all()), 0.0);
+        assert_eq!(weighted_distance(&zero, &a, &PropertyWeights::all()), 1.0);
+
+        // Opposite signs are as far apart as it gets, not twice as far
+        let melting = PropertyWeights::of(&["melting_point"]);
+        let (mut warm, mut cold) = (a, a);
+        set_property(&mut warm, "melting_point", 100.0);
+        set_property(&mut cold, "melting_point", -100.0);
+        assert_eq!(weighted_distance(&warm, &cold, &melting), 1.0);
+        let mut both = melting.clone();
+        both.0.insert("density".into(), f32::NAN);
+        both.0.insert("hardness".into(), f32::INFINITY);
+        assert_eq!(weighted_distance(&warm, &cold, &both), 1.0);
+        for weight in [f32::NAN, f32::INFINITY, -1.0] {
+            assert!(matches!(melting.clone().with("density", weight), Err(SearchError::InvalidWeight { .. })));
+        }
+
+        assert_eq!(PropertyWeights::focus(" Thermal ").unwrap(), PropertyWeights::thermal());
+        assert_eq!(PropertyWeights::focus("").unwrap(), PropertyWeights::all());
+        assert_eq!(PropertyWeights::focus("taste"), Err(SearchError::UnknownFocus("taste".into())));
+        assert_eq!(PropertyWeights::all().with("shine", 1.0), Err(SearchError::UnknownProperty("shine".into())));
+
+        assert!(matches!("cost >= 5".parse::<Constraint>(), Err(SearchError::InvalidConstraint(_))));
+        assert!(matches!("density <= heavy".parse::<Constraint>(), Err(SearchError::InvalidConstraint(_))));
+        assert_eq!("cost<=2.5".parse::<Constraint>(), Ok(Constraint::MaxCost { usd_per_kg: 2.5 }));
+    }
+}
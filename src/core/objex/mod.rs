@@ -30,6 +30,7 @@ pub use templates::{
 */
 pub use geospec::*;
 pub use matcat::*;
+pub use matcat::api; // both submodules have an `api`; the materials picker owns the name
 
 pub mod store;
 pub use store::*;
\ No newline at end of file
@@ -8,6 +8,8 @@ use crate::core::physics::environmental_snapshot::{
     sample_environmental_snapshot,
 };
 use crate::core::physics::tides::AnchorError;
+use crate::core::id::EntityId;
+use crate::core::spatial::{SpatialIndex, UvoxRegionId};
 
 #[derive(Debug, Clone)]
 pub struct Observer {
@@ -34,4 +36,69 @@ impl Observer {
             env,
         )
     }
+
+    /// Entities within `radius_m` of the observer, nearest first, with
+    /// their distances in metres. `index` must be this observer's world's.
+    pub fn nearby(&self, index: &SpatialIndex, radius_m: f64) -> Vec<(EntityId, f64)> {
+        index
+            .within_radius(&self.uvox, radius_m * 1e6)
+            .into_iter()
+            .map(|(id, um)| (id, um * 1e-6))
+            .collect()
+    }
+
+    /// The `k` entities closest to the observer, nearest first, in metres.
+    pub fn nearest(&self, index: &SpatialIndex, k: usize) -> Vec<(EntityId, f64)> {
+        index
+            .nearest(&self.uvox, k)
+            .into_iter()
+            .map(|(id, um)| (id, um * 1e-6))
+            .collect()
+    }
+
+    /// Entities inside `region`, with their distances from the observer
+    /// in metres, nearest first.
+    pub fn in_region(&self, index: &SpatialIndex, region: &UvoxRegionId) -> Vec<(EntityId, f64)> {
+        let mut found: Vec<(EntityId, f64)> = index
+            .within_region(region)
+            .into_iter()
+            .filter_map(|id| Some((id, self.uvox.distance_um(&index.position(id)?) * 1e-6)))
+            .collect();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::uvoxid::{LatCode, LonCode, RUm, EARTH_RADIUS_UM};
+
+    fn at(lat: f64, lon: f64) -> UvoxId {
+        UvoxId::new(RUm(EARTH_RADIUS_UM), LatCode::from_degrees(lat), LonCode::from_degrees(lon))
+    }
+
+    #[test]
+    fn spatial_queries_are_relative_to_the_observer() {
+        let observer = Observer { id: ObserverId(1), world: WorldId(1), uvox: at(27.7, -82.4), created_at: SimTime(0) };
+        let (near, far, other) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let mut index = SpatialIndex::new();
+        index.insert(near, at(27.7001, -82.4));
+        index.insert(far, at(27.71, -82.4));
+        index.insert(other, at(-45.0, 0.0));
+
+        let nearby = observer.nearby(&index, 100.0);
+        assert_eq!(nearby.len(), 1);
+        assert_eq!(nearby[0].0, near);
+        assert!((nearby[0].1 - 11.1).abs() < 0.5, "{}", nearby[0].1);
+        assert!(observer.nearby(&index, 0.0).is_empty());
+
+        let order: Vec<EntityId> = observer.nearest(&index, 5).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(order, vec![near, far, other]);
+
+        let region = UvoxRegionId::new(at(27.705, -82.5), at(27.8, -82.3));
+        let inside = observer.in_region(&index, &region);
+        assert_eq!(inside.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![far]);
+        assert!(inside[0].1 > 1_000.0);
+    }
 }
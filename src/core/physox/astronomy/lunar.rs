@@ -30,7 +30,7 @@ fn norm360(x: f64) -> f64 {
 /// Meeus algorithms 47–49
 /// ------------------------------------------------------------
 pub fn lunar_ra_dec(t: SimTime) -> (f64, f64, f64) {
-    let (_, jd) = crate::core::physox::astronomy::julian::simtime_to_julian(t);
+    let jd = crate::core::physox::astronomy::julian::simtime_to_jde(t);
 
 
     let d = jd - 2451545.0;       // days since J2000
@@ -54,7 +54,7 @@ fn norm360(x: f64) -> f64 {
 /// Meeus "low precision" (error < 1′)
 /// ------------------------------------------------------------
 pub fn solar_ra_dec(t: SimTime) -> (f64, f64, f64) {
-    let (_, jd) = crate::core::physox::astronomy::julian::simtime_to_julian(t);
+    let jd = crate::core::physox::astronomy::julian::simtime_to_jde(t);
 
 
     let n = jd - 2451545.0;      // days since J2000
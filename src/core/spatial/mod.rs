@@ -7,4 +7,6 @@ pub use location::*;
 pub mod uvox_region_id;
 pub use uvox_region_id::UvoxRegionId;
 pub mod spatial_horizon;
-pub use spatial_horizon::SpatialHorizon;
\ No newline at end of file
+pub use spatial_horizon::SpatialHorizon;
+pub mod spatial_index;
+pub use spatial_index::SpatialIndex;
@@ -41,8 +41,9 @@ impl SpatialHorizon {
 }
 
 impl SpatialHorizon {
+    /// Straight-line distance from `center` is within `radius_um`.
     pub fn contains(&self, point: &UvoxId) -> bool {
-        self.center.approx_distance_um(point) <= self.radius_um
+        self.center.distance_um(point) <= self.radius_um as f64
     }
 }
 
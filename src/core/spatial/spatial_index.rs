@@ -0,0 +1,502 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::core::entity::entity_store::EntityStore;
+use crate::core::id::EntityId;
+use crate::core::spatial::spatial_horizon::SpatialHorizon;
+use crate::core::spatial::uvox_region_id::UvoxRegionId;
+use crate::core::uvoxid::{RUm, UvoxCellId, UvoxId};
+
+/// Leaf cells at level 16 are ~0.003° (~300 m at the equator) on a side.
+pub const DEFAULT_LEAF_LEVEL: u8 = 16;
+pub const MAX_LEAF_LEVEL: u8 = 28;
+
+/// Bounds are shaved by this much (µm) so float error can never prune
+/// a cell that holds a match.
+const BOUND_SLACK_UM: f64 = 1_000.0;
+
+#[derive(Debug, Clone)]
+struct Node {
+    count: usize,
+    /// Radius envelope of everything ever inserted below this node.
+    /// Never shrinks on removal, which keeps it a valid bound.
+    r_min: i64,
+    r_max: i64,
+    /// Only filled at the leaf level.
+    entities: Vec<EntityId>,
+}
+
+// ------------------------------------------------------------
+// Index
+// ------------------------------------------------------------
+
+/// -------------------------------------------------------------------
+/// SpatialIndex — hierarchical lat/lon cell index over entity positions
+/// -------------------------------------------------------------------
+///
+/// Entities live in leaf cells; every ancestor cell keeps a count and a
+/// radius envelope. Queries descend from the two root cells and prune by
+/// the exact minimum straight-line distance from the query point to a
+/// cell, so results match `UvoxId::distance_um` without scanning every
+/// entity. Longitude wrap and the poles need no special cases.
+///
+/// Distances are chord distances in micrometers, as in
+/// `SpatialHorizon::contains`.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    leaf_level: u8,
+    positions: HashMap<EntityId, UvoxId>,
+    nodes: HashMap<UvoxCellId, Node>,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::with_leaf_level(DEFAULT_LEAF_LEVEL)
+    }
+}
+
+impl SpatialIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finer leaves mean smaller buckets but deeper trees.
+    pub fn with_leaf_level(level: u8) -> Self {
+        Self {
+            leaf_level: level.min(MAX_LEAF_LEVEL),
+            positions: HashMap::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Index every `Position` in `store`.
+    pub fn from_store(store: &EntityStore) -> Self {
+        let mut index = Self::new();
+        index.sync(store);
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn position(&self, entity: EntityId) -> Option<UvoxId> {
+        self.positions.get(&entity).copied()
+    }
+
+    // ------------------------------------------------------------
+    // Updates
+    // ------------------------------------------------------------
+
+    /// Add an entity or move it. Returns `false` if it was already there.
+    pub fn insert(&mut self, entity: EntityId, pos: UvoxId) -> bool {
+        match self.positions.get(&entity) {
+            Some(old) if *old == pos => return false,
+            Some(_) => {
+                self.remove(entity);
+            }
+            None => {}
+        }
+        self.positions.insert(entity, pos);
+
+        let leaf = pos.cell(self.leaf_level);
+        for key in leaf.lineage() {
+            let node = self.nodes.entry(key).or_insert(Node {
+                count: 0,
+                r_min: pos.r_um.0,
+                r_max: pos.r_um.0,
+                entities: Vec::new(),
+            });
+            node.count += 1;
+            node.r_min = node.r_min.min(pos.r_um.0);
+            node.r_max = node.r_max.max(pos.r_um.0);
+            if key == leaf {
+                node.entities.push(entity);
+            }
+        }
+        true
+    }
+
+    pub fn remove(&mut self, entity: EntityId) -> Option<UvoxId> {
+        let pos = self.positions.remove(&entity)?;
+
+        let leaf = pos.cell(self.leaf_level);
+        for key in leaf.lineage() {
+            let Some(node) = self.nodes.get_mut(&key) else { continue };
+            node.count -= 1;
+            if key == leaf {
+                node.entities.retain(|e| *e != entity);
+            }
+            if node.count == 0 {
+                self.nodes.remove(&key);
+            }
+        }
+        Some(pos)
+    }
+
+    /// Bring the index in line with the store's `Position` components:
+    /// moved and new entities are re-indexed, vanished ones dropped.
+    /// Returns how many entries changed.
+    pub fn sync(&mut self, store: &EntityStore) -> usize {
+        let stale: Vec<EntityId> = self
+            .positions
+            .keys()
+            .filter(|id| !store.positions.contains_key(id))
+            .copied()
+            .collect();
+
+        let mut changed = stale.len();
+        for id in stale {
+            self.remove(id);
+        }
+        for (id, pos) in &store.positions {
+            changed += self.insert(*id, pos.0) as usize;
+        }
+        changed
+    }
+
+    // ------------------------------------------------------------
+    // Queries
+    // ------------------------------------------------------------
+
+    /// Entities within `radius_um` of `center`, nearest first.
+    pub fn within_radius(&self, center: &UvoxId, radius_um: f64) -> Vec<(EntityId, f64)> {
+        let mut found = Vec::new();
+        let mut stack: Vec<UvoxCellId> = UvoxCellId::ROOTS.to_vec();
+
+        while let Some(key) = stack.pop() {
+            let Some(node) = self.nodes.get(&key) else { continue };
+            if min_distance_um(center, key, node) > radius_um {
+                continue;
+            }
+
+            if key.level() == self.leaf_level {
+                for &entity in &node.entities {
+                    let d = center.distance_um(&self.positions[&entity]);
+                    if d <= radius_um {
+                        found.push((entity, d));
+                    }
+                }
+            } else {
+                stack.extend(key.children());
+            }
+        }
+
+        found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        found
+    }
+
+    /// Entities inside a horizon, nearest first.
+    pub fn within_horizon(&self, horizon: &SpatialHorizon) -> Vec<EntityId> {
+        self.within_radius(&horizon.center, horizon.radius_um as f64)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Entities for which `region.contains` holds, in id order.
+    pub fn within_region(&self, region: &UvoxRegionId) -> Vec<EntityId> {
+        let mut found = Vec::new();
+        let mut stack: Vec<UvoxCellId> = UvoxCellId::ROOTS.to_vec();
+
+        while let Some(key) = stack.pop() {
+            let Some(node) = self.nodes.get(&key) else { continue };
+            if !region_may_overlap(region, key, node) {
+                continue;
+            }
+
+            if key.level() == self.leaf_level {
+                found.extend(
+                    node.entities
+                        .iter()
+                        .filter(|e| region.contains(self.positions[e])),
+                );
+            } else {
+                stack.extend(key.children());
+            }
+        }
+
+        found.sort();
+        found
+    }
+
+    /// The `k` entities closest to `center`, nearest first (ties by id).
+    pub fn nearest(&self, center: &UvoxId, k: usize) -> Vec<(EntityId, f64)> {
+        let mut found = Vec::with_capacity(k);
+        let mut heap = BinaryHeap::new();
+
+        for key in UvoxCellId::ROOTS {
+            if let Some(node) = self.nodes.get(&key) {
+                heap.push(Candidate { distance: min_distance_um(center, key, node), item: Item::Cell(key) });
+            }
+        }
+
+        while found.len() < k {
+            let Some(Candidate { distance, item }) = heap.pop() else { break };
+            match item {
+                Item::Entity(entity) => found.push((entity, distance)),
+                Item::Cell(key) => {
+                    let node = &self.nodes[&key];
+                    if key.level() == self.leaf_level {
+                        for &entity in &node.entities {
+                            let distance = center.distance_um(&self.positions[&entity]);
+                            heap.push(Candidate { distance, item: Item::Entity(entity) });
+                        }
+                    } else {
+                        for child in key.children() {
+                            if let Some(node) = self.nodes.get(&child) {
+                                let distance = min_distance_um(center, child, node);
+                                heap.push(Candidate { distance, item: Item::Cell(child) });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        found
+    }
+}
+
+// ------------------------------------------------------------
+// Best-first search queue
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Item {
+    // Cells sort first so an entity is only emitted once no cell can
+    // still hold something at the same distance.
+    Cell(UvoxCellId),
+    Entity(EntityId),
+}
+
+#[derive(Debug)]
+struct Candidate {
+    distance: f64,
+    item: Item,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    /// Reversed, so `BinaryHeap` pops the smallest distance first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.item.cmp(&self.item))
+    }
+}
+
+// ------------------------------------------------------------
+// Geometry
+// ------------------------------------------------------------
+
+/// Smallest straight-line distance (µm) from `q` to any point of the
+/// cell's lat/lon span at a radius within the node's envelope.
+fn min_distance_um(q: &UvoxId, key: UvoxCellId, node: &Node) -> f64 {
+    let h = min_haversine(q, key);
+
+    // Chord² between radii rq, r at central angle θ, with h = sin²(θ/2):
+    // (rq - r)² + 4·rq·r·h, minimised over r in the envelope.
+    let rq = q.r_um.0 as f64;
+    let r = (rq * (1.0 - 2.0 * h)).clamp(node.r_min as f64, node.r_max as f64);
+    let d2 = (rq - r).powi(2) + 4.0 * rq * r * h;
+
+    (d2.max(0.0).sqrt() - BOUND_SLACK_UM).max(0.0)
+}
+
+/// Minimum of sin²(θ/2) over the cell, θ the central angle to `q`.
+fn min_haversine(q: &UvoxId, key: UvoxCellId) -> f64 {
+    let phi_q = q.lat_code.radians();
+    let lam_q = q.lon_code.radians();
+
+    let (lat_lo, lat_hi) = key.lat_range();
+    let (lon_lo, lon_hi) = key.lon_range();
+    let (phi_1, phi_2) = (lat_lo.radians(), lat_hi.radians());
+    let (lam_1, lam_2) = (lon_lo.radians(), lon_hi.radians());
+
+    // For any latitude the nearest meridian of the cell is the same:
+    // q's own if it is inside the span, else the closer edge.
+    let wrap = |d: f64| {
+        let tau = std::f64::consts::TAU;
+        ((d + std::f64::consts::PI).rem_euclid(tau) - std::f64::consts::PI).abs()
+    };
+    let d_lam = if (lam_1..=lam_2).contains(&lam_q) {
+        0.0
+    } else {
+        wrap(lam_q - lam_1).min(wrap(lam_q - lam_2))
+    };
+
+    // Along that meridian, cos θ = A·sin φ + B·cos φ peaks at atan2(A, B).
+    let peak = phi_q.sin().atan2(phi_q.cos() * d_lam.cos());
+    let haversine = |phi: f64| {
+        ((phi - phi_q) / 2.0).sin().powi(2) + phi.cos() * phi_q.cos() * (d_lam / 2.0).sin().powi(2)
+    };
+
+    let mut best = haversine(phi_1).min(haversine(phi_2));
+    if (phi_1..=phi_2).contains(&peak) {
+        best = best.min(haversine(peak));
+    }
+    best.clamp(0.0, 1.0)
+}
+
+/// Overlap test between a region and the points a cell can hold.
+fn region_may_overlap(region: &UvoxRegionId, key: UvoxCellId, node: &Node) -> bool {
+    region.intersects(&UvoxRegionId::from_cell(key, RUm(node.r_min), RUm(node.r_max)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::uvoxid::{LatCode, LonCode, RUm, EARTH_RADIUS_UM};
+
+    fn at(lat: f64, lon: f64, up_m: f64) -> UvoxId {
+        UvoxId::new(
+            RUm(EARTH_RADIUS_UM + (up_m * 1e6) as i64),
+            LatCode::from_degrees(lat),
+            LonCode::from_degrees(lon),
+        )
+    }
+
+    /// Deterministic scatter, dense near a few hot spots including the
+    /// antimeridian and the north pole.
+    fn scatter() -> Vec<(EntityId, UvoxId)> {
+        let hot = [(27.7, -82.4), (0.0, 179.999), (0.0, -179.999), (89.99, 10.0), (-45.0, 0.0)];
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        (0..2_000)
+            .map(|i| {
+                let (lat, lon) = hot[i % hot.len()];
+                let pos = at(
+                    (lat + (next() - 0.5) * 0.02).clamp(-90.0, 90.0),
+                    lon + (next() - 0.5) * 0.02,
+                    next() * 50.0,
+                );
+                (EntityId(uuid::Uuid::from_u128(i as u128 + 1)), pos)
+            })
+            .collect()
+    }
+
+    fn index(points: &[(EntityId, UvoxId)]) -> SpatialIndex {
+        let mut index = SpatialIndex::new();
+        for (id, pos) in points {
+            index.insert(*id, *pos);
+        }
+        index
+    }
+
+    fn brute_radius(points: &[(EntityId, UvoxId)], center: &UvoxId, radius: f64) -> Vec<EntityId> {
+        let mut hits: Vec<(EntityId, f64)> = points
+            .iter()
+            .map(|(id, p)| (*id, center.distance_um(p)))
+            .filter(|(_, d)| *d <= radius)
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        hits.into_iter().map(|(id, _)| id).collect()
+    }
+
+    #[test]
+    fn radius_and_knn_match_brute_force() {
+        let points = scatter();
+        let index = index(&points);
+
+        let queries = [at(27.7, -82.4, 0.0), at(0.0, 180.0, 10.0), at(90.0, 0.0, 0.0), at(-45.0, 0.005, 0.0)];
+        for center in &queries {
+            for radius_m in [5.0, 300.0, 1_500.0] {
+                let got: Vec<EntityId> = index
+                    .within_radius(center, radius_m * 1e6)
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect();
+                assert_eq!(got, brute_radius(&points, center, radius_m * 1e6));
+            }
+
+            let knn: Vec<EntityId> = index.nearest(center, 25).into_iter().map(|(id, _)| id).collect();
+            assert_eq!(knn, brute_radius(&points, center, f64::INFINITY)[..25].to_vec());
+        }
+    }
+
+    #[test]
+    fn region_query_matches_contains() {
+        let points = scatter();
+        let index = index(&points);
+        let region = UvoxRegionId::new(at(27.695, -82.41, 0.0), at(27.705, -82.39, 30.0));
+
+        let mut expected: Vec<EntityId> = points
+            .iter()
+            .filter(|(_, p)| region.contains(*p))
+            .map(|(id, _)| *id)
+            .collect();
+        expected.sort();
+
+        assert!(!expected.is_empty());
+        assert_eq!(index.within_region(&region), expected);
+    }
+
+    #[test]
+    fn region_query_wraps_antimeridian_and_pole() {
+        let points = scatter();
+        let index = index(&points);
+        let regions = [
+            UvoxRegionId::new(at(-0.005, 179.995, 0.0), at(0.005, -179.995, 50.0)),
+            UvoxRegionId::north_cap(RUm(0), RUm(i64::MAX), LatCode::from_degrees(89.995)),
+        ];
+
+        for region in &regions {
+            let mut expected: Vec<EntityId> = points
+                .iter()
+                .filter(|(_, p)| region.contains(*p))
+                .map(|(id, _)| *id)
+                .collect();
+            expected.sort();
+
+            assert!(!expected.is_empty());
+            assert_eq!(index.within_region(region), expected);
+        }
+    }
+
+    #[test]
+    fn sync_tracks_moves_and_removals() {
+        let points = scatter();
+        let mut store = EntityStore::new();
+        for (id, pos) in &points[..10] {
+            store.add_position(*id, crate::core::components::position::Position(*pos));
+        }
+
+        let mut index = SpatialIndex::from_store(&store);
+        assert_eq!(index.len(), 10);
+
+        let (moved, _) = points[0];
+        let target = at(-10.0, 120.0, 0.0);
+        store.add_position(moved, crate::core::components::position::Position(target));
+        store.positions.remove(&points[1].0);
+
+        assert_eq!(index.sync(&store), 2);
+        assert_eq!(index.len(), 9);
+        assert_eq!(index.nearest(&target, 1)[0].0, moved);
+        assert_eq!(index.sync(&store), 0);
+    }
+}
@@ -1,6 +1,20 @@
 use serde::{Serialize, Deserialize};
-use crate::core::uvoxid::{UvoxId, RUm, LatCode, LonCode};
+use crate::core::uvoxid::{UvoxId, UvoxCellId, RUm, LatCode, LonCode, ANG_SCALE, MAX_CELL_LEVEL};
 
+const FULL_TURN: i128 = 360 * ANG_SCALE;
+const NORTH_POLE: i64 = (90 * ANG_SCALE) as i64;
+const SOUTH_POLE: i64 = -NORTH_POLE;
+
+/// ------------------------------------------------------------
+/// A lat/lon/radius box on the sphere
+/// ------------------------------------------------------------
+///
+/// Radius and latitude are plain closed ranges. Longitude runs *east*
+/// from `min.lon_code` to `max.lon_code`, so a region with
+/// `min.lon > max.lon` spans the antimeridian, and one from -180° to
+/// +180° covers every longitude. Points exactly on a pole have no
+/// meaningful longitude, so a region reaching that pole contains them
+/// whatever its longitude range.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct UvoxRegionId {
     pub min: UvoxId,
@@ -8,22 +22,327 @@ pub struct UvoxRegionId {
 }
 
 impl UvoxRegionId {
+    /// Region between two corners. Radius and latitude are put in
+    /// order; longitude is kept as given (west edge first).
     pub fn new(min: UvoxId, max: UvoxId) -> Self {
-        Self { min, max }
+        let (r_lo, r_hi) = ordered(min.r_um.0, max.r_um.0);
+        let (lat_lo, lat_hi) = ordered(min.lat_code.0, max.lat_code.0);
+        Self {
+            min: UvoxId { r_um: RUm(r_lo), lat_code: LatCode(lat_lo), lon_code: min.lon_code },
+            max: UvoxId { r_um: RUm(r_hi), lat_code: LatCode(lat_hi), lon_code: max.lon_code },
+        }
+    }
+
+    /// Every longitude between two latitudes.
+    pub fn latitude_band(r_min: RUm, r_max: RUm, lat_min: LatCode, lat_max: LatCode) -> Self {
+        let mut region = Self::new(
+            UvoxId::new(r_min, lat_min, LonCode(0)),
+            UvoxId::new(r_max, lat_max, LonCode(0)),
+        );
+        region.min.lon_code = LonCode(-(FULL_TURN / 2) as i64);
+        region.max.lon_code = LonCode((FULL_TURN / 2) as i64);
+        region
+    }
+
+    /// Everything north of `edge`.
+    pub fn north_cap(r_min: RUm, r_max: RUm, edge: LatCode) -> Self {
+        Self::latitude_band(r_min, r_max, edge, LatCode(NORTH_POLE))
+    }
+
+    /// Everything south of `edge`.
+    pub fn south_cap(r_min: RUm, r_max: RUm, edge: LatCode) -> Self {
+        Self::latitude_band(r_min, r_max, LatCode(SOUTH_POLE), edge)
+    }
+
+    /// Exactly the points `cell` holds, between two radii. Codes are
+    /// integers, so the half-open cell is the closed box one code short
+    /// of its north and east edges; the north pole belongs to the top row.
+    pub fn from_cell(cell: UvoxCellId, r_min: RUm, r_max: RUm) -> Self {
+        let (south, north) = cell.lat_range();
+        let (west, east) = cell.lon_range();
+        let north = if north.0 < NORTH_POLE { north.0 - 1 } else { north.0 };
+        Self {
+            min: UvoxId { r_um: r_min, lat_code: south, lon_code: west },
+            max: UvoxId { r_um: r_max, lat_code: LatCode(north), lon_code: LonCode(east.0 - 1) },
+        }
+    }
+
+    // ------------------------------------------------------------
+    // Longitude arc
+    // ------------------------------------------------------------
+
+    /// Eastward extent in angle codes, `0..=360°`.
+    pub fn lon_span(&self) -> i128 {
+        let span = self.max.lon_code.0 as i128 - self.min.lon_code.0 as i128;
+        if span < 0 { span + FULL_TURN } else { span.min(FULL_TURN) }
+    }
+
+    pub fn is_full_longitude(&self) -> bool {
+        self.lon_span() >= FULL_TURN
     }
 
+    pub fn crosses_antimeridian(&self) -> bool {
+        !self.is_full_longitude() && self.min.lon_code.0 > self.max.lon_code.0
+    }
+
+    pub fn touches_north_pole(&self) -> bool {
+        self.max.lat_code.0 >= NORTH_POLE
+    }
+
+    pub fn touches_south_pole(&self) -> bool {
+        self.min.lat_code.0 <= SOUTH_POLE
+    }
+
+    fn arc(&self) -> Arc {
+        Arc { start: self.min.lon_code.0 as i128, span: self.lon_span() }
+    }
+
+    // ------------------------------------------------------------
+    // Predicates
+    // ------------------------------------------------------------
+
     /// Check if a coordinate lies within the region
     pub fn contains(&self, id: UvoxId) -> bool {
-        id.r_um.0     >= self.min.r_um.0     && id.r_um.0     <= self.max.r_um.0 &&
-        id.lat_code.0 >= self.min.lat_code.0 && id.lat_code.0 <= self.max.lat_code.0 &&
-        id.lon_code.0 >= self.min.lon_code.0 && id.lon_code.0 <= self.max.lon_code.0
+        let lat = id.lat_code.0;
+        if id.r_um.0 < self.min.r_um.0 || id.r_um.0 > self.max.r_um.0 {
+            return false;
+        }
+        if lat < self.min.lat_code.0 || lat > self.max.lat_code.0 {
+            return false;
+        }
+        lat.abs() >= NORTH_POLE || self.arc().contains(id.lon_code.0 as i128)
     }
 
-    /// Check region overlap (AABB-style checks)
+    /// Check whether the two regions share at least one point.
     pub fn intersects(&self, other: &Self) -> bool {
-        !(other.max.r_um.0     < self.min.r_um.0     || other.min.r_um.0     > self.max.r_um.0 ||
-          other.max.lat_code.0 < self.min.lat_code.0 || other.min.lat_code.0 > self.max.lat_code.0 ||
-          other.max.lon_code.0 < self.min.lon_code.0 || other.min.lon_code.0 > self.max.lon_code.0)
+        let Some((lat_lo, lat_hi)) = self.shared_shell(other) else {
+            return false;
+        };
+        lat_hi >= NORTH_POLE || lat_lo <= SOUTH_POLE || self.arc().overlaps(&other.arc())
+    }
+
+    /// Check whether every point of `other` is inside this region.
+    pub fn contains_region(&self, other: &Self) -> bool {
+        let shell = self.min.r_um.0 <= other.min.r_um.0
+            && other.max.r_um.0 <= self.max.r_um.0
+            && self.min.lat_code.0 <= other.min.lat_code.0
+            && other.max.lat_code.0 <= self.max.lat_code.0;
+
+        // A pole-only region has no longitude extent to check.
+        let pole_only = other.min.lat_code.0 == other.max.lat_code.0
+            && other.min.lat_code.0.abs() >= NORTH_POLE;
+
+        shell && (pole_only || self.arc().covers(&other.arc()))
+    }
+
+    /// Radius and latitude overlap, if any (the latitude range is returned).
+    fn shared_shell(&self, other: &Self) -> Option<(i64, i64)> {
+        let r_lo = self.min.r_um.0.max(other.min.r_um.0);
+        let r_hi = self.max.r_um.0.min(other.max.r_um.0);
+        let lat_lo = self.min.lat_code.0.max(other.min.lat_code.0);
+        let lat_hi = self.max.lat_code.0.min(other.max.lat_code.0);
+        (r_lo <= r_hi && lat_lo <= lat_hi).then_some((lat_lo, lat_hi))
+    }
+
+    // ------------------------------------------------------------
+    // Set operations
+    // ------------------------------------------------------------
+
+    /// The common part of two regions as disjoint regions. Two
+    /// longitude arcs can overlap at both ends, giving two pieces. Arcs
+    /// that only meet at a shared pole give a zero-area pole region.
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        let Some((lat_lo, lat_hi)) = self.shared_shell(other) else {
+            return Vec::new();
+        };
+        let r_lo = RUm(self.min.r_um.0.max(other.min.r_um.0));
+        let r_hi = RUm(self.max.r_um.0.min(other.max.r_um.0));
+
+        let piece = |lat_lo: i64, lat_hi: i64, arc: Arc| {
+            let (west, east) = arc.codes();
+            Self {
+                min: UvoxId { r_um: r_lo, lat_code: LatCode(lat_lo), lon_code: LonCode(west) },
+                max: UvoxId { r_um: r_hi, lat_code: LatCode(lat_hi), lon_code: LonCode(east) },
+            }
+        };
+
+        let arcs = self.arc().intersection(&other.arc());
+        if !arcs.is_empty() {
+            return arcs.into_iter().map(|arc| piece(lat_lo, lat_hi, arc)).collect();
+        }
+
+        let mut poles = Vec::new();
+        if lat_lo <= SOUTH_POLE {
+            poles.push(piece(SOUTH_POLE, SOUTH_POLE, Arc::FULL));
+        }
+        if lat_hi >= NORTH_POLE {
+            poles.push(piece(NORTH_POLE, NORTH_POLE, Arc::FULL));
+        }
+        poles
+    }
+
+    /// Smallest region containing both. Longitude takes the shorter of
+    /// the two ways round that covers both arcs.
+    pub fn bounding_union(&self, other: &Self) -> Self {
+        let (west, east) = self.arc().cover(&other.arc()).codes();
+        Self {
+            min: UvoxId {
+                r_um: RUm(self.min.r_um.0.min(other.min.r_um.0)),
+                lat_code: LatCode(self.min.lat_code.0.min(other.min.lat_code.0)),
+                lon_code: LonCode(west),
+            },
+            max: UvoxId {
+                r_um: RUm(self.max.r_um.0.max(other.max.r_um.0)),
+                lat_code: LatCode(self.max.lat_code.0.max(other.max.lat_code.0)),
+                lon_code: LonCode(east),
+            },
+        }
+    }
+
+    // ------------------------------------------------------------
+    // Areas (m²)
+    // ------------------------------------------------------------
+
+    /// Footprint area on the sphere through the region's floor
+    /// (`min.r_um`): R² · Δλ · (sin φ_max − sin φ_min).
+    pub fn area_m2(&self) -> f64 {
+        let r = self.min.r_um.meters();
+        let d_lon = (self.lon_span() as f64 / ANG_SCALE as f64).to_radians();
+        r * r * d_lon * (self.max.lat_code.radians().sin() - self.min.lat_code.radians().sin())
+    }
+
+    pub fn intersection_area_m2(&self, other: &Self) -> f64 {
+        self.intersection(other).iter().map(Self::area_m2).sum()
+    }
+
+    /// Area covered by either region, by inclusion–exclusion.
+    pub fn union_area_m2(&self, other: &Self) -> f64 {
+        self.area_m2() + other.area_m2() - self.intersection_area_m2(other)
+    }
+}
+
+// ------------------------------------------------------------
+// Cell coverage
+// ------------------------------------------------------------
+
+impl UvoxRegionId {
+    /// Every cell at `level` sharing area with the region's footprint,
+    /// in token order. The count grows 4× per level, so pick a level
+    /// that suits the region's size.
+    pub fn covering_cells(&self, level: u8) -> Vec<UvoxCellId> {
+        self.cover(level.min(MAX_CELL_LEVEL), false)
+    }
+
+    /// Like `covering_cells`, but a cell wholly inside the region is
+    /// returned as is instead of being split down to `max_level`.
+    pub fn covering_cells_compact(&self, max_level: u8) -> Vec<UvoxCellId> {
+        self.cover(max_level.min(MAX_CELL_LEVEL), true)
+    }
+
+    fn cover(&self, max_level: u8, compact: bool) -> Vec<UvoxCellId> {
+        // Footprint only: compare everything at radius zero.
+        let mut footprint = *self;
+        footprint.min.r_um = RUm(0);
+        footprint.max.r_um = RUm(0);
+
+        let mut found = Vec::new();
+        let mut stack: Vec<UvoxCellId> = UvoxCellId::ROOTS.iter().rev().copied().collect();
+        while let Some(cell) = stack.pop() {
+            let bounds = Self::from_cell(cell, RUm(0), RUm(0));
+            if !footprint.intersects(&bounds) {
+                continue;
+            }
+            if cell.level() == max_level || (compact && footprint.contains_region(&bounds)) {
+                found.push(cell);
+            } else {
+                stack.extend(cell.children().iter().rev());
+            }
+        }
+        found
+    }
+}
+
+fn ordered(a: i64, b: i64) -> (i64, i64) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+// ------------------------------------------------------------
+// Closed longitude arc: east from `start` for `span` codes
+// ------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Arc {
+    start: i128,
+    span: i128,
+}
+
+impl Arc {
+    const FULL: Arc = Arc { start: -FULL_TURN / 2, span: FULL_TURN };
+
+    fn is_full(&self) -> bool {
+        self.span >= FULL_TURN
+    }
+
+    /// Eastward distance from the arc's start to `lon`.
+    fn offset(&self, lon: i128) -> i128 {
+        (lon - self.start).rem_euclid(FULL_TURN)
+    }
+
+    fn contains(&self, lon: i128) -> bool {
+        self.is_full() || self.offset(lon) <= self.span
+    }
+
+    fn covers(&self, other: &Arc) -> bool {
+        self.is_full() || (!other.is_full() && self.offset(other.start) + other.span <= self.span)
+    }
+
+    fn overlaps(&self, other: &Arc) -> bool {
+        self.contains(other.start) || other.contains(self.start)
+    }
+
+    fn intersection(&self, other: &Arc) -> Vec<Arc> {
+        if self.is_full() {
+            return vec![*other];
+        }
+        if other.is_full() {
+            return vec![*self];
+        }
+
+        let mut arcs = Vec::with_capacity(2);
+        for (a, b) in [(self, other), (other, self)] {
+            // `b` starts inside `a`: overlap runs from b's start until
+            // whichever arc ends first.
+            let d = a.offset(b.start);
+            if d <= a.span {
+                let arc = Arc { start: b.start, span: b.span.min(a.span - d) };
+                if !arcs.contains(&arc) {
+                    arcs.push(arc);
+                }
+            }
+        }
+        arcs
+    }
+
+    /// Shortest arc covering both, starting from one of their starts.
+    fn cover(&self, other: &Arc) -> Arc {
+        let from_self = self.span.max(self.offset(other.start) + other.span);
+        let from_other = other.span.max(other.offset(self.start) + self.span);
+
+        let best = if from_self <= from_other {
+            Arc { start: self.start, span: from_self }
+        } else {
+            Arc { start: other.start, span: from_other }
+        };
+        if best.is_full() { Arc::FULL } else { best }
+    }
+
+    /// West and east edges as lon codes: west wrapped into
+    /// [-180°, 180°), east = west + span (wrapped unless full).
+    fn codes(&self) -> (i64, i64) {
+        if self.is_full() {
+            return ((-FULL_TURN / 2) as i64, (FULL_TURN / 2) as i64);
+        }
+        let wrap = |v: i128| ((v + FULL_TURN / 2).rem_euclid(FULL_TURN) - FULL_TURN / 2) as i64;
+        (wrap(self.start), wrap(self.start + self.span))
     }
 }
 
@@ -40,32 +359,188 @@ impl Default for UvoxRegionId {
 }
 
 impl UvoxRegionId {
-    /// Convert region to a compact string based on min corner.
+    /// Convert region to a compact string holding both corners:
+    /// "r_lat_lon_r_lat_lon" (min corner first).
     pub fn to_compact_string(&self) -> String {
         format!(
-            "{}_{}_{}",
+            "{}_{}_{}_{}_{}_{}",
             self.min.r_um.0,
             self.min.lat_code.0,
-            self.min.lon_code.0
+            self.min.lon_code.0,
+            self.max.r_um.0,
+            self.max.lat_code.0,
+            self.max.lon_code.0
         )
     }
 
-    /// Parse region from compact string: "r_um_lat_lon"
+    /// Parse region from compact string: "r_lat_lon_r_lat_lon".
+    /// The older single-corner form "r_lat_lon" is read as a point region.
     pub fn from_compact(s: &str) -> Result<Self, anyhow::Error> {
-        let parts: Vec<&str> = s.split('_').collect();
-        if parts.len() != 3 {
-            return Err(anyhow::anyhow!("Invalid compact region '{}'", s));
+        let parts = s
+            .split('_')
+            .map(|p| p.parse::<i64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Invalid compact region '{}': {}", s, e))?;
+
+        let (min, max) = match *parts.as_slice() {
+            [r, lat, lon] => ((r, lat, lon), (r, lat, lon)),
+            [r0, lat0, lon0, r1, lat1, lon1] => ((r0, lat0, lon0), (r1, lat1, lon1)),
+            _ => return Err(anyhow::anyhow!("Invalid compact region '{}'", s)),
+        };
+
+        for (_, lat, lon) in [min, max] {
+            if !(SOUTH_POLE..=NORTH_POLE).contains(&lat) {
+                return Err(anyhow::anyhow!("Latitude code {} out of range in '{}'", lat, s));
+            }
+            if !(-(FULL_TURN / 2)..=FULL_TURN / 2).contains(&(lon as i128)) {
+                return Err(anyhow::anyhow!("Longitude code {} out of range in '{}'", lon, s));
+            }
+        }
+
+        let corner = |(r, lat, lon): (i64, i64, i64)| UvoxId {
+            r_um: RUm(r),
+            lat_code: LatCode(lat),
+            lon_code: LonCode(lon),
+        };
+        Ok(Self::new(corner(min), corner(max)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::uvoxid::EARTH_RADIUS_UM;
+
+    fn at(lat: f64, lon: f64) -> UvoxId {
+        UvoxId::earth_surface(LatCode::from_degrees(lat), LonCode::from_degrees(lon))
+    }
+
+    fn region(lat: (f64, f64), lon: (f64, f64)) -> UvoxRegionId {
+        UvoxRegionId::new(at(lat.0, lon.0), at(lat.1, lon.1))
+    }
+
+    const R: RUm = RUm(EARTH_RADIUS_UM);
+
+    #[test]
+    fn antimeridian_and_poles_are_contained() {
+        let pacific = region((-10.0, 10.0), (170.0, -170.0));
+        assert!(pacific.crosses_antimeridian());
+        assert!(pacific.contains(at(0.0, 179.5)));
+        assert!(pacific.contains(at(0.0, -180.0)));
+        assert!(pacific.contains(at(5.0, -175.0)));
+        assert!(!pacific.contains(at(0.0, 0.0)));
+        assert!(!pacific.contains(at(0.0, -160.0)));
+
+        let arctic = UvoxRegionId::north_cap(R, R, LatCode::from_degrees(66.5));
+        assert!(arctic.is_full_longitude());
+        assert!(arctic.contains(at(80.0, -179.0)));
+        assert!(arctic.contains(at(90.0, 123.0)));
+        assert!(!arctic.contains(at(60.0, 0.0)));
+
+        // A narrow wedge touching the pole still holds the pole itself.
+        let wedge = region((85.0, 90.0), (10.0, 20.0));
+        assert!(wedge.contains(at(90.0, -100.0)));
+        assert!(!wedge.contains(at(89.0, -100.0)));
+    }
+
+    #[test]
+    fn intersection_handles_wrapping_arcs() {
+        let a = region((0.0, 10.0), (170.0, 10.0)); // 200° wide, across ±180
+        let b = region((5.0, 20.0), (0.0, 179.0));
+        let pieces = a.intersection(&b);
+        assert_eq!(pieces.len(), 2);
+        assert!(a.intersects(&b));
+        for p in &pieces {
+            assert_eq!(p.min.lat_code, LatCode::from_degrees(5.0));
+            assert!(a.contains(p.min) && b.contains(p.min));
         }
 
-        let r_um     = RUm(parts[0].parse::<i64>()?);
-        let lat_code = LatCode(parts[1].parse::<i64>()?);
-        let lon_code = LonCode(parts[2].parse::<i64>()?);
+        let east = region((0.0, 1.0), (10.0, 20.0));
+        let west = region((0.0, 1.0), (-20.0, -10.0));
+        assert!(!east.intersects(&west));
+        assert!(east.intersection(&west).is_empty());
+
+        // Disjoint wedges meet only at the north pole.
+        let w1 = region((80.0, 90.0), (0.0, 10.0));
+        let w2 = region((80.0, 90.0), (100.0, 110.0));
+        assert!(w1.intersects(&w2));
+        let pole = w1.intersection(&w2);
+        assert_eq!(pole.len(), 1);
+        assert_eq!(pole[0].area_m2(), 0.0);
+        assert!(pole[0].contains(at(90.0, 0.0)));
+    }
+
+    #[test]
+    fn areas_and_union_add_up() {
+        let r_m = EARTH_RADIUS_UM as f64 * 1e-6;
+        let sphere = 4.0 * std::f64::consts::PI * r_m * r_m;
+
+        let north = UvoxRegionId::north_cap(R, R, LatCode(0));
+        let south = UvoxRegionId::south_cap(R, R, LatCode(0));
+        assert!((north.area_m2() - sphere / 2.0).abs() / sphere < 1e-12);
+        assert!((north.union_area_m2(&south) - sphere).abs() / sphere < 1e-12);
+
+        let a = region((0.0, 10.0), (175.0, -175.0));
+        let b = region((0.0, 10.0), (-180.0, -170.0));
+        let union = a.bounding_union(&b);
+        assert!(union.crosses_antimeridian());
+        assert_eq!(union.lon_span(), 15 * ANG_SCALE);
+
+        let expected = a.area_m2() + b.area_m2() - a.intersection_area_m2(&b);
+        assert!((a.union_area_m2(&b) - expected).abs() < 1e-3);
+        assert!((a.union_area_m2(&b) - union.area_m2()).abs() / union.area_m2() < 1e-9);
+    }
+
+    #[test]
+    fn cell_cover_matches_cell_membership() {
+        let pacific = region((-1.0, 1.0), (179.0, -179.0));
+        let cells = pacific.covering_cells(6);
+        assert!(!cells.is_empty());
+        assert!(cells.windows(2).all(|w| w[0].to_token() < w[1].to_token()));
+
+        // Every sampled point's cell is in the cover; no cell is spare.
+        for i in 0..=40 {
+            for j in 0..=40 {
+                let p = at(-1.0 + i as f64 * 0.05, 179.0 + j as f64 * 0.05);
+                assert!(cells.contains(&p.cell(6)), "{p}");
+            }
+        }
+        for cell in &cells {
+            assert!(pacific.intersects(&UvoxRegionId::from_cell(*cell, R, R)));
+        }
+
+        // A region that is exactly one cell covers just that cell.
+        let cell = at(10.0, 20.0).cell(8);
+        let exact = UvoxRegionId::from_cell(cell, R, R);
+        assert_eq!(exact.covering_cells(8), vec![cell]);
+        assert_eq!(exact.covering_cells_compact(12), vec![cell]);
+
+        // Compact covers use big cells inside and small ones on the edge.
+        let arctic = UvoxRegionId::north_cap(R, R, LatCode::from_degrees(80.0));
+        let compact = arctic.covering_cells_compact(6);
+        assert!(compact.iter().any(|c| c.level() < 6));
+        let area: f64 = compact
+            .iter()
+            .map(|c| UvoxRegionId::from_cell(*c, R, R).area_m2())
+            .sum();
+        assert!(area >= arctic.area_m2() * (1.0 - 1e-9));
+    }
+
+    #[test]
+    fn compact_string_round_trips_both_corners() {
+        let pacific = region((-10.0, 10.0), (170.0, -170.0));
+        let back = UvoxRegionId::from_compact(&pacific.to_compact_string()).unwrap();
+        assert_eq!(back, pacific);
 
-        let min = UvoxId::new(r_um, lat_code, lon_code);
+        let cap = UvoxRegionId::north_cap(R, RUm(EARTH_RADIUS_UM + 1), LatCode::from_degrees(60.0));
+        let back = UvoxRegionId::from_compact(&cap.to_compact_string()).unwrap();
+        assert!(back.is_full_longitude());
+        assert_eq!(back, cap);
 
-        // For now, define max = min (simulation-specific placeholder)
-        let max = min;
+        let point = UvoxRegionId::from_compact("6371000000000_0_0").unwrap();
+        assert_eq!(point.min, point.max);
 
-        Ok(UvoxRegionId { min, max })
+        assert!(UvoxRegionId::from_compact("1_2").is_err());
+        assert!(UvoxRegionId::from_compact("1_9100000000000_0_1_0_0").is_err());
     }
 }
@@ -7,6 +7,10 @@ pub mod sim_display;
 pub mod sim_aggregate;
 pub mod sim_julian;
 pub mod sim_clock;
+pub mod sim_gregorian;
+pub mod sim_zone;
+pub mod sim_scale;
+pub mod sim_schedule;
 
 pub use time_delta::TimeDelta;
 pub use sim_time::SimTime;
@@ -16,4 +20,8 @@ pub use sim_date::SimDate;
 pub use sim_display::*;
 pub use sim_aggregate::*;
 pub use sim_julian::*;
-pub use sim_clock::SimClock;
\ No newline at end of file
+pub use sim_clock::SimClock;
+pub use sim_gregorian::{CalendarMode, CivilDateTime, CivilError};
+pub use sim_zone::FixedZone;
+pub use sim_scale::TimeScale;
+pub use sim_schedule::{CalendarRule, Frequency, Recurrence, Schedule};
\ No newline at end of file
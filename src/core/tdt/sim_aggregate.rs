@@ -1,37 +1,89 @@
 // src/core/tdt/sim_aggregate.rs
 //
 // Deterministic time-bucket aggregation for ChronoEvents.
-// Works with the fixed simulation calendar and the extended formats
-// defined in sim_display.rs.
+// Works with the fixed simulation calendar, calendar-aligned Gregorian
+// years/quarters and the extended formats defined in sim_display.rs.
+//
+// `aggregate_events` groups events as JSON; `aggregate` / `aggregate_rolling`
+// return typed per-bucket statistics of a numeric field.
 
-use crate::core::chronovox::ChronoEvent;
+use crate::core::chronovox::{ChronoEvent, EventKind};
+use crate::core::id::EntityId;
 use crate::core::tdt::sim_time::SimTime;
+use crate::core::tdt::sim_duration::SimDuration;
 use crate::core::tdt::sim_display::{TimeFormat, format_simtime};
+use crate::core::tdt::sim_gregorian::CivilDateTime;
+use crate::core::tdt::sim_zone::FixedZone;
 use crate::core::tdt::sim_calendar::*;
 
-use serde_json::json;
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
 use std::collections::BTreeMap;
+use thiserror::Error;
 
 /// ---------------------------------------------------------------------------
 /// Resolution modes (bucket sizes)
 /// ---------------------------------------------------------------------------
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AggregateResolution {
     RawNs,       // never aggregate, full fidelity
     SimMonth,    // bucket by SimMonth index
     SimWeek,     // bucket by SimWeek index
     SimDay,      // bucket by SimDay index
     SimHour,     // bucket by SimHour index
+    Year,        // Gregorian calendar year (UTC)
+    Quarter,     // Gregorian calendar quarter (UTC), year * 4 + q
 }
 
-/// Compute which bucket this timestamp belongs to.
+/// Compute which bucket this timestamp belongs to. Sim buckets are
+/// floored, so times before the epoch get negative indices.
 fn bucket_index(t: SimTime, res: AggregateResolution) -> i64 {
+    let ns = t.as_ns();
     match res {
-        AggregateResolution::RawNs => t.as_ns() as i64,
-        AggregateResolution::SimMonth => (t.as_ns() / NANOS_PER_MONTH) as i64,
-        AggregateResolution::SimWeek  => (t.as_ns() / NANOS_PER_WEEK) as i64,
-        AggregateResolution::SimDay   => (t.as_ns() / NANOS_PER_DAY) as i64,
-        AggregateResolution::SimHour  => (t.as_ns() / NANOS_PER_HOUR) as i64,
+        AggregateResolution::RawNs => ns as i64,
+        AggregateResolution::SimMonth => ns.div_euclid(NANOS_PER_MONTH) as i64,
+        AggregateResolution::SimWeek  => ns.div_euclid(NANOS_PER_WEEK) as i64,
+        AggregateResolution::SimDay   => ns.div_euclid(NANOS_PER_DAY) as i64,
+        AggregateResolution::SimHour  => ns.div_euclid(NANOS_PER_HOUR) as i64,
+        AggregateResolution::Year => t.to_civil(FixedZone::UTC).year as i64,
+        AggregateResolution::Quarter => {
+            let civil = t.to_civil(FixedZone::UTC);
+            (civil.year * 4 + (civil.month as i128 - 1) / 3) as i64
+        }
+    }
+}
+
+/// `[start, end)` of a bucket and its display label.
+fn bucket_span(index: i64, res: AggregateResolution) -> (SimTime, SimTime, String) {
+    let fixed = |unit: i128, fmt: TimeFormat| {
+        let start = SimTime(index as i128 * unit);
+        (start, start.add_ns(unit), format_simtime(start, fmt))
+    };
+    let civil_start = |year: i128, month: u8| {
+        CivilDateTime::from_ymd(year, month, 1)
+            .and_then(|c| c.to_sim_time())
+            .unwrap_or(SimTime(i128::MAX))
+    };
+
+    match res {
+        AggregateResolution::RawNs => fixed(1, TimeFormat::RawNs),
+        AggregateResolution::SimMonth => fixed(NANOS_PER_MONTH, TimeFormat::SimMonth),
+        AggregateResolution::SimWeek => fixed(NANOS_PER_WEEK, TimeFormat::SimWeek),
+        AggregateResolution::SimDay => fixed(NANOS_PER_DAY, TimeFormat::SimDay),
+        AggregateResolution::SimHour => fixed(NANOS_PER_HOUR, TimeFormat::Rfc3339),
+        AggregateResolution::Year => {
+            let year = index as i128;
+            (civil_start(year, 1), civil_start(year + 1, 1), year.to_string())
+        }
+        AggregateResolution::Quarter => {
+            let (year, q) = ((index as i128).div_euclid(4), index.rem_euclid(4) as u8);
+            let (next_year, next_q) = if q == 3 { (year + 1, 0) } else { (year, q + 1) };
+            (
+                civil_start(year, q * 3 + 1),
+                civil_start(next_year, next_q * 3 + 1),
+                format!("{year}-Q{}", q + 1),
+            )
+        }
     }
 }
 
@@ -43,6 +95,7 @@ fn bucket_display_format(res: AggregateResolution) -> TimeFormat {
         AggregateResolution::SimWeek  => TimeFormat::SimWeek,
         AggregateResolution::SimDay   => TimeFormat::SimDay,
         AggregateResolution::SimHour  => TimeFormat::Rfc3339, // hour-level readable
+        AggregateResolution::Year | AggregateResolution::Quarter => TimeFormat::Rfc3339,
     }
 }
 
@@ -72,11 +125,14 @@ pub fn aggregate_events(
     // 2. Convert into aggregate JSON blocks
     let mut out = Vec::new();
     for (bucket_id, evs) in buckets.iter() {
-        // bucket timestamp reused from the first event in the bucket
-        let display_time = if let Some(e0) = evs.first() {
-            format_simtime(e0.t, fmt)
-        } else {
-            "<empty>".to_string()
+        // bucket timestamp reused from the first event in the bucket;
+        // calendar buckets are shown by name ("2025-Q1")
+        let display_time = match (resolution, evs.first()) {
+            (AggregateResolution::Year | AggregateResolution::Quarter, _) => {
+                bucket_span(*bucket_id, resolution).2
+            }
+            (_, Some(e0)) => format_simtime(e0.t, fmt),
+            (_, None) => "<empty>".to_string(),
         };
 
         let events_json: Vec<_> = evs
@@ -123,3 +179,434 @@ pub fn aggregate_events_json(
     let blocks = aggregate_events(events, resolution);
     serde_json::to_string(&blocks).unwrap()
 }
+
+// ---------------------------------------------------------------------------
+// Typed aggregation
+// ---------------------------------------------------------------------------
+
+/// How events are split within each time bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GroupBy {
+    /// One series for all events.
+    #[default]
+    None,
+    Entity,
+    Kind,
+}
+
+/// A series within a bucket. Kinds are named by variant (`Degrade`), custom
+/// events as `Custom:<label>`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum GroupKey {
+    All,
+    Entity(EntityId),
+    Kind(String),
+}
+
+/// What to aggregate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregateQuery {
+    pub resolution: AggregateResolution,
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// Only events of this kind name (see `GroupKey`).
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Numeric field, as a dotted path into the payload (`"details.loss"`)
+    /// or a field of the kind itself (`"rate"` of `Degrade`).
+    #[serde(default)]
+    pub field: Option<String>,
+    /// Percentiles (0–100) reported in each bucket's `Stats`.
+    #[serde(default)]
+    pub percentiles: Vec<f64>,
+}
+
+impl AggregateQuery {
+    pub fn new(resolution: AggregateResolution) -> Self {
+        Self { resolution, group_by: GroupBy::None, kind: None, field: None, percentiles: Vec::new() }
+    }
+
+    pub fn group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = group_by;
+        self
+    }
+
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    pub fn field(mut self, path: impl Into<String>) -> Self {
+        self.field = Some(path.into());
+        self
+    }
+
+    pub fn percentiles(mut self, ps: &[f64]) -> Self {
+        self.percentiles = ps.to_vec();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Percentile {
+    pub p: f64,
+    pub value: f64,
+}
+
+/// Summary of the numeric values found in one bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub percentiles: Vec<Percentile>,
+}
+
+impl Stats {
+    /// `None` when there are no finite values. Percentiles interpolate
+    /// linearly between ranks (the usual spreadsheet definition).
+    pub fn from_values(values: &[f64], percentiles: &[f64]) -> Option<Self> {
+        let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let sum: f64 = sorted.iter().sum();
+        let at = |p: f64| {
+            let rank = (p.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64;
+            let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+            sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+        };
+
+        Some(Self {
+            count: sorted.len(),
+            sum,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean: sum / sorted.len() as f64,
+            percentiles: percentiles.iter().map(|&p| Percentile { p, value: at(p) }).collect(),
+        })
+    }
+
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        self.percentiles.iter().find(|x| x.p == p).map(|x| x.value)
+    }
+}
+
+/// One series in one time bucket (or rolling window).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregateBucket {
+    pub bucket: i64,
+    pub label: String,
+    /// Inclusive start, exclusive end (rolling windows: exclusive start,
+    /// inclusive end).
+    pub start: SimTime,
+    pub end: SimTime,
+    pub group: GroupKey,
+    /// Matching events, whether or not they carried the field.
+    pub events: usize,
+    /// Statistics of `field`; `None` without a field or values.
+    pub stats: Option<Stats>,
+}
+
+/// Rolling windows of `width`, ending every `step` from the first event
+/// until one covers the last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollingWindow {
+    pub width: SimDuration,
+    pub step: SimDuration,
+}
+
+/// Most windows `aggregate_rolling` will evaluate for one query.
+pub const MAX_ROLLING_WINDOWS: usize = 100_000;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AggregateError {
+    #[error("rolling window width and step must be positive")]
+    NonPositiveWindow,
+
+    #[error("rolling aggregation needs {windows} windows (at most {max})")]
+    TooManyWindows { windows: i128, max: usize },
+}
+
+pub fn kind_name(kind: &EventKind) -> String {
+    match kind {
+        EventKind::Custom(label) => format!("Custom:{label}"),
+        other => match serde_json::to_value(other) {
+            Ok(Value::String(name)) => name,
+            Ok(Value::Object(map)) => map.keys().next().cloned().unwrap_or_default(),
+            _ => String::new(),
+        },
+    }
+}
+
+/// The numeric value at `path` in the payload, else in the kind's fields.
+pub fn event_field(event: &ChronoEvent, path: &str) -> Option<f64> {
+    let lookup = |root: &Value| {
+        path.split('.')
+            .try_fold(root, |v, key| v.get(key))
+            .and_then(Value::as_f64)
+    };
+
+    event.payload.as_ref().and_then(lookup).or_else(|| {
+        let kind = serde_json::to_value(&event.kind).ok()?;
+        let fields = kind.as_object()?.values().next()?;
+        lookup(fields)
+    })
+}
+
+fn group_key(event: &ChronoEvent, group_by: GroupBy) -> GroupKey {
+    match group_by {
+        GroupBy::None => GroupKey::All,
+        GroupBy::Entity => GroupKey::Entity(event.entity_id),
+        GroupBy::Kind => GroupKey::Kind(kind_name(&event.kind)),
+    }
+}
+
+fn matching<'a>(events: &'a [ChronoEvent], query: &'a AggregateQuery) -> impl Iterator<Item = &'a ChronoEvent> {
+    events
+        .iter()
+        .filter(move |e| query.kind.as_ref().is_none_or(|k| kind_name(&e.kind) == *k))
+}
+
+#[derive(Default)]
+struct Accumulator {
+    events: usize,
+    values: Vec<f64>,
+}
+
+impl Accumulator {
+    fn add(&mut self, event: &ChronoEvent, query: &AggregateQuery) {
+        self.events += 1;
+        if let Some(v) = query.field.as_deref().and_then(|f| event_field(event, f)) {
+            self.values.push(v);
+        }
+    }
+
+    fn stats(&self, query: &AggregateQuery) -> Option<Stats> {
+        Stats::from_values(&self.values, &query.percentiles)
+    }
+}
+
+/// Typed per-bucket, per-group statistics, ordered by bucket then group.
+pub fn aggregate(events: &[ChronoEvent], query: &AggregateQuery) -> Vec<AggregateBucket> {
+    let mut cells: BTreeMap<(i64, GroupKey), Accumulator> = BTreeMap::new();
+    for e in matching(events, query) {
+        let key = (bucket_index(e.t, query.resolution), group_key(e, query.group_by));
+        cells.entry(key).or_default().add(e, query);
+    }
+
+    cells
+        .into_iter()
+        .map(|((bucket, group), acc)| {
+            let (start, end, label) = bucket_span(bucket, query.resolution);
+            AggregateBucket { bucket, label, start, end, group, events: acc.events, stats: acc.stats(query) }
+        })
+        .collect()
+}
+
+/// Statistics over rolling windows `(end - width, end]`; `query.resolution`
+/// is not used. Windows with no matching events are omitted. Fails rather
+/// than evaluate more than `MAX_ROLLING_WINDOWS` windows.
+pub fn aggregate_rolling(
+    events: &[ChronoEvent],
+    query: &AggregateQuery,
+    window: RollingWindow,
+) -> Result<Vec<AggregateBucket>, AggregateError> {
+    let step = window.step.as_ns();
+    if step <= 0 || window.width.as_ns() <= 0 {
+        return Err(AggregateError::NonPositiveWindow);
+    }
+    let mut matched: Vec<&ChronoEvent> = matching(events, query).collect();
+    if matched.is_empty() {
+        return Ok(Vec::new());
+    }
+    matched.sort_by_key(|e| e.t);
+    let (first, last) = (matched[0].t, matched[matched.len() - 1].t);
+
+    let span = last.as_ns().saturating_sub(first.as_ns());
+    let windows = (span / step).saturating_add(i128::from(span % step != 0)).saturating_add(1);
+    if windows > MAX_ROLLING_WINDOWS as i128 {
+        return Err(AggregateError::TooManyWindows { windows, max: MAX_ROLLING_WINDOWS });
+    }
+
+    let mut out = Vec::new();
+    let mut end = first;
+    let mut bucket = 0;
+    loop {
+        let start = end - window.width;
+        let lo = matched.partition_point(|e| e.t <= start);
+        let hi = matched.partition_point(|e| e.t <= end);
+
+        let mut cells: BTreeMap<GroupKey, Accumulator> = BTreeMap::new();
+        for e in &matched[lo..hi] {
+            cells.entry(group_key(e, query.group_by)).or_default().add(e, query);
+        }
+        for (group, acc) in cells {
+            out.push(AggregateBucket {
+                bucket,
+                label: end.format_rfc3339(),
+                start,
+                end,
+                group,
+                events: acc.events,
+                stats: acc.stats(query),
+            });
+        }
+
+        if end >= last {
+            break;
+        }
+        end = end + window.step;
+        bucket += 1;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::id::WorldId;
+    use serde_json::json;
+
+    fn degrade(entity: EntityId, t: &str, rate: f64) -> ChronoEvent {
+        ChronoEvent::new(entity, WorldId(1), t.parse().unwrap(), EventKind::Degrade { rate })
+            .with_payload(json!({ "detail": { "integrity": 1.0 - rate } }))
+    }
+
+    #[test]
+    fn typed_stats_per_quarter_entity_and_window() {
+        let (a, b) = (EntityId::new(), EntityId::new());
+        let events = vec![
+            degrade(a, "2025-01-10T00:00:00Z", 0.1),
+            degrade(a, "2025-03-31T23:59:59Z", 0.3),
+            degrade(b, "2025-02-01T00:00:00Z", 0.2),
+            degrade(a, "2025-04-01T00:00:00Z", 0.5),
+            ChronoEvent::custom(a, WorldId(1), "2025-02-01T00:00:00Z".parse().unwrap(), "maintenance"),
+        ];
+
+        let query = AggregateQuery::new(AggregateResolution::Quarter)
+            .kind("Degrade")
+            .field("rate")
+            .percentiles(&[50.0, 90.0]);
+        let buckets = aggregate(&events, &query);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].label, "2025-Q1");
+        assert_eq!(buckets[0].start, "2025-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(buckets[0].end, "2025-04-01T00:00:00Z".parse().unwrap());
+        let q1 = buckets[0].stats.as_ref().unwrap();
+        assert_eq!((q1.count, q1.min, q1.max), (3, 0.1, 0.3));
+        assert!((q1.mean - 0.2).abs() < 1e-12);
+        assert!((q1.percentile(90.0).unwrap() - 0.28).abs() < 1e-12);
+
+        let per_entity = aggregate(&events, &query.clone().group_by(GroupBy::Entity).field("detail.integrity"));
+        assert_eq!(per_entity.iter().filter(|b| b.bucket == buckets[0].bucket).count(), 2);
+
+        let by_kind = aggregate(&events, &AggregateQuery::new(AggregateResolution::Year).group_by(GroupBy::Kind));
+        let names: Vec<&GroupKey> = by_kind.iter().map(|b| &b.group).collect();
+        assert_eq!(names, vec![&GroupKey::Kind("Custom:maintenance".into()), &GroupKey::Kind("Degrade".into())]);
+
+        let window = RollingWindow { width: SimDuration::days(90), step: SimDuration::days(30) };
+        let rolling = aggregate_rolling(&events, &query, window).unwrap();
+        assert_eq!(rolling.first().unwrap().events, 1);
+        assert!(rolling.iter().any(|b| b.events == 3));
+        assert!(rolling.last().unwrap().end >= "2025-04-01T00:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn sim_buckets_floor_before_the_epoch() {
+        let a = EntityId::new();
+        let at = |ns: i128| ChronoEvent::new(a, WorldId(1), SimTime(ns), EventKind::Degrade { rate: 0.1 });
+        let events = vec![at(-1), at(-NANOS_PER_DAY), at(-NANOS_PER_DAY - 1), at(0)];
+
+        let buckets = aggregate(&events, &AggregateQuery::new(AggregateResolution::SimDay));
+        let spans: Vec<(i64, i128, i128, usize)> = buckets
+            .iter()
+            .map(|b| (b.bucket, b.start.as_ns(), b.end.as_ns(), b.events))
+            .collect();
+        assert_eq!(spans, vec![
+            (-2, -2 * NANOS_PER_DAY, -NANOS_PER_DAY, 1),
+            (-1, -NANOS_PER_DAY, 0, 2),
+            (0, 0, NANOS_PER_DAY, 1),
+        ]);
+    }
+
+    #[test]
+    fn calendar_buckets_split_exactly_at_quarter_and_year_boundaries() {
+        let a = EntityId::new();
+        let events = vec![
+            degrade(a, "2024-12-31T23:59:59.999999999Z", 0.1),
+            degrade(a, "2025-01-01T00:00:00Z", 0.2),
+            degrade(a, "2025-06-30T23:59:59.999999999Z", 0.3),
+            degrade(a, "2025-07-01T00:00:00Z", 0.4),
+        ];
+
+        let quarters = aggregate(&events, &AggregateQuery::new(AggregateResolution::Quarter));
+        let labels: Vec<&str> = quarters.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, vec!["2024-Q4", "2025-Q1", "2025-Q2", "2025-Q3"]);
+        assert_eq!(quarters[0].end, quarters[1].start);
+        assert_eq!(quarters[2].end, "2025-07-01T00:00:00Z".parse().unwrap());
+        assert!(quarters.iter().all(|b| b.events == 1));
+
+        let years = aggregate(&events, &AggregateQuery::new(AggregateResolution::Year));
+        let counts: Vec<(&str, usize)> = years.iter().map(|b| (b.label.as_str(), b.events)).collect();
+        assert_eq!(counts, vec![("2024", 1), ("2025", 3)]);
+        assert_eq!(years[1].start, "2025-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(years[1].end, "2026-01-01T00:00:00Z".parse().unwrap());
+
+        // Quarters of years before 0 still run Q1..Q4 forward in time.
+        let early = vec![degrade(a, "-0001-12-31T00:00:00Z", 0.1), degrade(a, "0000-01-01T00:00:00Z", 0.1)];
+        let labels: Vec<String> = aggregate(&early, &AggregateQuery::new(AggregateResolution::Quarter))
+            .into_iter()
+            .map(|b| b.label)
+            .collect();
+        assert_eq!(labels, vec!["-1-Q4", "0-Q1"]);
+    }
+
+    #[test]
+    fn missing_or_non_numeric_fields_count_events_without_stats() {
+        let a = EntityId::new();
+        let t: SimTime = "2025-01-01T00:00:00Z".parse().unwrap();
+        let events = vec![
+            ChronoEvent::new(a, WorldId(1), t, EventKind::Degrade { rate: f64::NAN }),
+            ChronoEvent::custom(a, WorldId(1), t, "note").with_payload(json!({ "loss": "heavy" })),
+            ChronoEvent::custom(a, WorldId(1), t, "note").with_payload(json!({ "loss": null })),
+        ];
+        assert!(aggregate(&[], &AggregateQuery::new(AggregateResolution::SimDay)).is_empty());
+
+        for field in ["rate", "loss", "absent.path"] {
+            let buckets = aggregate(&events, &AggregateQuery::new(AggregateResolution::SimDay).field(field));
+            assert_eq!(buckets.len(), 1);
+            assert_eq!(buckets[0].events, 3);
+            assert_eq!(buckets[0].stats, None, "{field}");
+        }
+        assert_eq!(Stats::from_values(&[f64::NAN, f64::INFINITY], &[50.0]), None);
+    }
+
+    #[test]
+    fn rolling_rejects_unbounded_window_counts() {
+        let a = EntityId::new();
+        let events = vec![
+            degrade(a, "2020-01-01T00:00:00Z", 0.1),
+            degrade(a, "2025-01-01T00:00:00Z", 0.2),
+        ];
+        let query = AggregateQuery::new(AggregateResolution::RawNs).field("rate");
+
+        let tiny = RollingWindow { width: SimDuration::days(1), step: SimDuration::from_ns(1) };
+        assert!(matches!(
+            aggregate_rolling(&events, &query, tiny),
+            Err(AggregateError::TooManyWindows { max: MAX_ROLLING_WINDOWS, .. })
+        ));
+        let zero = RollingWindow { width: SimDuration::days(1), step: SimDuration::from_ns(0) };
+        assert_eq!(aggregate_rolling(&events, &query, zero), Err(AggregateError::NonPositiveWindow));
+        assert_eq!(aggregate_rolling(&[], &query, tiny), Ok(Vec::new()));
+
+        let daily = RollingWindow { width: SimDuration::days(1), step: SimDuration::days(1) };
+        let out = aggregate_rolling(&events, &query, daily).unwrap();
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[1].end, events[1].t);
+    }
+}
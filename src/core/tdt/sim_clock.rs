@@ -1,5 +1,8 @@
 // src/sim/time/clock.rs
+use std::collections::BTreeSet;
+
 use chrono::{DateTime, Duration, Utc};
+use crate::core::chronovox::Timeline;
 use crate::core::tdt::sim_time::SimTime;
 use crate::core::tdt::sim_duration::SimDuration;
 
@@ -14,8 +17,20 @@ pub struct SimClock {
     /// Current simulation time
     pub current: SimTime,
 
-    /// Fixed step size per tick
+    /// Step size per tick; in event-driven use, the largest jump
     pub step: SimDuration,
+
+    /// Floor for `request_step`, so a system can't stall the run.
+    pub min_step: SimDuration,
+
+    /// Smallest step requested for the next advance.
+    requested: Option<SimDuration>,
+
+    /// Instants the clock must stop at exactly.
+    wakeups: BTreeSet<SimTime>,
+
+    /// Where the last advance started.
+    previous: SimTime,
 }
 
 impl SimClock {
@@ -24,6 +39,31 @@ impl SimClock {
     // Constructors
     // ------------------------------------------------------------
 
+    /// Stepped clock at `start`.
+    pub fn new(start: SimTime, end: SimTime, step: SimDuration) -> Self {
+        Self {
+            start,
+            end,
+            current: start,
+            step,
+            min_step: SimDuration(1),
+            requested: None,
+            wakeups: BTreeSet::new(),
+            previous: start,
+        }
+    }
+
+    /// Discrete-event clock: each advance jumps straight to the next
+    /// wake-up (see `wake_at`, `wake_for_next_event`), or to `end`.
+    pub fn event_driven(start: SimTime, end: SimTime) -> Self {
+        Self::new(start, end, end - start)
+    }
+
+    pub fn with_min_step(mut self, min_step: SimDuration) -> Self {
+        self.min_step = min_step;
+        self
+    }
+
     /// Create a clock from real-world datetimes + chrono::Duration step
     /// (useful for scratch files + quick simulation setups)
     pub fn from_wall_dates(start: DateTime<Utc>, end: DateTime<Utc>, step: Duration) -> Self {
@@ -31,23 +71,13 @@ impl SimClock {
             .expect("step duration out of range")
             as i128;
 
-        Self {
-            start: SimTime::from_datetime(start),
-            current: SimTime::from_datetime(start),
-            end:   SimTime::from_datetime(end),
-            step:  SimDuration(step_ns),
-        }
+        Self::new(SimTime::from_datetime(start), SimTime::from_datetime(end), SimDuration(step_ns))
     }
 
     /// Create a clock directly from nanosecond values
     /// (useful if simulation steps are precomputed)
     pub fn from_utc_range(start: DateTime<Utc>, end: DateTime<Utc>, step_ns: i128) -> Self {
-        Self {
-            start:   SimTime::from_datetime(start),
-            current: SimTime::from_datetime(start),
-            end:     SimTime::from_datetime(end),
-            step:    SimDuration(step_ns),
-        }
+        Self::new(SimTime::from_datetime(start), SimTime::from_datetime(end), SimDuration(step_ns))
     }
 
     // ------------------------------------------------------------
@@ -78,18 +108,70 @@ impl SimClock {
         self.step.as_ns() as f64 / 1e9
     }
 
+    /// Length of the step the last `advance` took (zero before the first).
+    #[inline]
+    pub fn last_step(&self) -> SimDuration {
+        self.current - self.previous
+    }
+
+    // ------------------------------------------------------------
+    // Step control
+    // ------------------------------------------------------------
+
+    /// Ask for the next step to be at most `dt` (never below
+    /// `min_step`). Requests are combined by minimum and used once.
+    pub fn request_step(&mut self, dt: SimDuration) {
+        let dt = dt.max(self.min_step);
+        self.requested = Some(self.requested.map_or(dt, |r| r.min(dt)));
+    }
+
+    /// Make the clock stop exactly at `t`, if it lies ahead.
+    pub fn wake_at(&mut self, t: SimTime) {
+        if t > self.current && t <= self.end {
+            self.wakeups.insert(t);
+        }
+    }
+
+    /// Wake at the next event recorded in `timeline` after `current`.
+    pub fn wake_for_next_event(&mut self, timeline: &Timeline) -> Option<SimTime> {
+        let t = timeline.next_after(self.current)?.t;
+        self.wake_at(t);
+        Some(t)
+    }
+
+    /// Where the next `advance` will land, or `None` when finished.
+    pub fn next_target(&self) -> Option<SimTime> {
+        if self.is_finished() {
+            return None;
+        }
+
+        let mut target = self.end.min(self.current.add(self.step));
+        if let Some(requested) = self.requested {
+            target = target.min(self.current.add(requested));
+        }
+        if let Some(&wakeup) = self.wakeups.range(self.current.add_ns(1)..).next() {
+            target = target.min(wakeup);
+        }
+        Some(target)
+    }
+
     // ------------------------------------------------------------
     // Time progression
     // ------------------------------------------------------------
 
-    /// Advance by exactly one simulation step.
+    /// Advance by one simulation step of `step`, cut short by a pending
+    /// `request_step`, the next wake-up and `end`, so the clock lands
+    /// exactly on each of them.
     /// Returns `true` if we advanced, `false` if simulation is finished.
     pub fn advance(&mut self) -> bool {
-        if self.current.as_ns() >= self.end.as_ns() {
+        let Some(target) = self.next_target() else {
             return false;
-        }
+        };
 
-        self.current = self.current.add(self.step);
+        self.previous = self.current;
+        self.current = target;
+        self.requested = None;
+        self.wakeups = self.wakeups.split_off(&target.add_ns(1));
         true
     }
 
@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 use std::fmt;
 use crate::core::tdt::sim_time::SimTime;
 use crate::core::tdt::sim_time;
+use crate::core::tdt::sim_gregorian::{CalendarMode, CivilError};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimDate {
@@ -23,6 +24,12 @@ impl SimDate {
         SimTime::from_sim_date(*self)
 
     }
+
+    /// Convert to SimTime in the chosen calendar; Gregorian dates must
+    /// exist (no 2025-02-30).
+    pub fn to_sim_time_in(&self, mode: CalendarMode) -> Result<SimTime, CivilError> {
+        SimTime::from_sim_date_in(*self, mode)
+    }
 }
 
 impl fmt::Display for SimDate {
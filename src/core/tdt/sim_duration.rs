@@ -216,3 +216,44 @@ impl SimDuration {
         Self::from_seconds_f64(days * 86_400.0)
     }
 }
+
+// -------------------------------------------------------------
+// Parsing
+// -------------------------------------------------------------
+
+/// Parses a whole number with a unit suffix, e.g. `"30d"`, `"15m"`,
+/// `"1y"`. Units: `ns`, `us`, `ms`, `s`, `m`, `h`, `d`, `w`, `mo`, `y`
+/// (sim-months and sim-years, as in `sim_calendar`).
+impl std::str::FromStr for SimDuration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '-'))
+            .ok_or_else(|| format!("duration `{s}` has no unit"))?;
+        let (n, unit) = s.split_at(split);
+
+        let n: i128 = n
+            .parse()
+            .map_err(|_| format!("invalid duration `{s}`"))?;
+
+        let scale = match unit.trim() {
+            "ns" => 1,
+            "us" | "µs" => NANOS_PER_MICROSECOND,
+            "ms" => NANOS_PER_MILLISECOND,
+            "s" => NANOS_PER_SECOND,
+            "m" | "min" => NANOS_PER_MINUTE,
+            "h" => NANOS_PER_HOUR,
+            "d" => NANOS_PER_DAY,
+            "w" => NANOS_PER_WEEK,
+            "mo" => NANOS_PER_MONTH,
+            "y" => NANOS_PER_YEAR,
+            other => return Err(format!("unknown duration unit `{other}` in `{s}`")),
+        };
+
+        n.checked_mul(scale)
+            .map(Self)
+            .ok_or_else(|| format!("duration `{s}` is out of range"))
+    }
+}
@@ -0,0 +1,415 @@
+//! Proleptic Gregorian calendar for SimTime.
+//!
+//! The fixed calendar in `sim_calendar` stays the simulation default.
+//! This is the opt-in alternative for dates that must agree with the
+//! real world (and with chrono): true month lengths, leap years, and
+//! wall-clock time in a `FixedZone`. Days are 86 400 s, as in POSIX time;
+//! leap seconds live in `sim_scale`.
+//!
+//! Years are i128, so every SimTime has a civil date and nothing here
+//! panics. Day counts use Howard Hinnant's `days_from_civil` /
+//! `civil_from_days` algorithms.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::tdt::sim_calendar::*;
+use crate::core::tdt::sim_date::SimDate;
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::tdt::sim_zone::FixedZone;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CivilError {
+    #[error("month {0} is out of range 1..=12")]
+    InvalidMonth(u8),
+
+    #[error("day {day} does not exist in {year:04}-{month:02}")]
+    InvalidDay { year: i128, month: u8, day: u8 },
+
+    #[error("time {hour:02}:{minute:02}:{second:02}.{nanosecond:09} is invalid")]
+    InvalidTime { hour: u8, minute: u8, second: u8, nanosecond: u32 },
+
+    #[error("unknown time zone `{0}` (only fixed offsets are supported)")]
+    UnknownZone(String),
+
+    #[error("UTC offset of {0} s is outside ±24 h")]
+    OffsetOutOfRange(i32),
+
+    #[error("date is outside the SimTime range")]
+    OutOfRange,
+
+    #[error("invalid civil timestamp `{0}`")]
+    Parse(String),
+}
+
+/// Which calendar `SimDate` conversions use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CalendarMode {
+    /// 365.25-day years of twelve equal months (`sim_calendar`).
+    #[default]
+    Fixed,
+    /// Proleptic Gregorian dates at UTC midnight.
+    Gregorian,
+}
+
+// ------------------------------------------------------------
+// Day arithmetic
+// ------------------------------------------------------------
+
+pub fn is_leap_year(year: i128) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Length of `month` (1–12) in `year`; 0 for an invalid month.
+pub fn days_in_month(year: i128, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since 1970-01-01. `None` only for years far beyond SimTime.
+pub fn days_from_civil(year: i128, month: u8, day: u8) -> Option<i128> {
+    let y = if month <= 2 { year.checked_sub(1)? } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (month as i128 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i128 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era.checked_mul(146_097)?.checked_add(doe - 719_468)
+}
+
+/// Inverse of `days_from_civil`: `(year, month, day)`.
+pub fn civil_from_days(days: i128) -> (i128, u8, u8) {
+    // Shift to 0000-03-01 so leap days fall at the end of the cycle;
+    // splitting first keeps the addition from overflowing.
+    let (days_era, days_rem) = (days.div_euclid(146_097), days.rem_euclid(146_097));
+    let z = days_rem + 719_468;
+    let era = days_era + z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+
+    let year = era * 400 + yoe + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// ------------------------------------------------------------
+// CivilDateTime
+// ------------------------------------------------------------
+
+/// A Gregorian wall-clock time in a fixed-offset zone.
+///
+/// `second` is 60 only for a leap second, which `from_tai` produces and
+/// `to_sim_time` folds onto the following second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CivilDateTime {
+    pub year: i128,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    pub zone: FixedZone,
+}
+
+impl CivilDateTime {
+    /// Midnight UTC on the given date.
+    pub fn from_ymd(year: i128, month: u8, day: u8) -> Result<Self, CivilError> {
+        if !(1..=12).contains(&month) {
+            return Err(CivilError::InvalidMonth(month));
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(CivilError::InvalidDay { year, month, day });
+        }
+        Ok(Self { year, month, day, hour: 0, minute: 0, second: 0, nanosecond: 0, zone: FixedZone::UTC })
+    }
+
+    pub fn and_hms(self, hour: u8, minute: u8, second: u8) -> Result<Self, CivilError> {
+        self.and_hms_nano(hour, minute, second, 0)
+    }
+
+    pub fn and_hms_nano(self, hour: u8, minute: u8, second: u8, nanosecond: u32) -> Result<Self, CivilError> {
+        if hour > 23 || minute > 59 || second > 60 || nanosecond as i128 >= NANOS_PER_SECOND {
+            return Err(CivilError::InvalidTime { hour, minute, second, nanosecond });
+        }
+        Ok(Self { hour, minute, second, nanosecond, ..self })
+    }
+
+    /// Same wall-clock reading, interpreted in another zone.
+    pub fn with_zone(self, zone: FixedZone) -> Self {
+        Self { zone, ..self }
+    }
+
+    /// The civil reading of `t` in `zone`. Total over every SimTime.
+    pub fn from_sim_time(t: SimTime, zone: FixedZone) -> Self {
+        // Shift by the offset without overflowing at the i128 edges.
+        let (days, ns_of_day) = split_days(t.as_ns());
+        let (days, ns_of_day) = match ns_of_day + zone.offset_ns() {
+            ns if ns < 0 => (days - 1, ns + NANOS_PER_DAY),
+            ns if ns >= NANOS_PER_DAY => (days + 1, ns - NANOS_PER_DAY),
+            ns => (days, ns),
+        };
+
+        let (year, month, day) = civil_from_days(days);
+        let secs = ns_of_day / NANOS_PER_SECOND;
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs / 3600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+            nanosecond: (ns_of_day % NANOS_PER_SECOND) as u32,
+            zone,
+        }
+    }
+
+    /// The instant this reading names, or `OutOfRange` past the i128
+    /// limits.
+    pub fn to_sim_time(&self) -> Result<SimTime, CivilError> {
+        let days = days_from_civil(self.year, self.month, self.day).ok_or(CivilError::OutOfRange)?;
+        let ns_of_day = self.hour as i128 * NANOS_PER_HOUR
+            + self.minute as i128 * NANOS_PER_MINUTE
+            + self.second as i128 * NANOS_PER_SECOND
+            + self.nanosecond as i128
+            - self.zone.offset_ns();
+
+        // Near the i128 edges `days * NANOS_PER_DAY` alone can overflow
+        // while the instant itself fits, so try neighbouring day splits.
+        (-2..=2)
+            .find_map(|k: i128| {
+                days.checked_add(k)?
+                    .checked_mul(NANOS_PER_DAY)?
+                    .checked_add(ns_of_day - k * NANOS_PER_DAY)
+            })
+            .map(SimTime)
+            .ok_or(CivilError::OutOfRange)
+    }
+
+    /// The date part as a `SimDate`, if the year fits in an i32.
+    pub fn date(&self) -> Option<SimDate> {
+        let year = i32::try_from(self.year).ok()?;
+        Some(SimDate { year, month: self.month, day: self.day })
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday. The date is valid, so days exist.
+        let days = days_from_civil(self.year, self.month, self.day).unwrap_or(0);
+        match (days + 3).rem_euclid(7) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+
+    /// Calendar months later (or earlier), keeping the time of day and
+    /// clamping the day to the target month: Jan 31 + 1 → Feb 28/29.
+    pub fn add_months(self, n: i128) -> Self {
+        let index = self.year * 12 + (self.month as i128 - 1) + n;
+        let (year, month) = (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8);
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day, ..self }
+    }
+
+    pub fn add_years(self, n: i128) -> Self {
+        self.add_months(n * 12)
+    }
+}
+
+fn split_days(ns: i128) -> (i128, i128) {
+    (ns.div_euclid(NANOS_PER_DAY), ns.rem_euclid(NANOS_PER_DAY))
+}
+
+/// RFC 3339 with nanoseconds, matching chrono for years 0–9999 and using
+/// signed ISO 8601 expanded years outside that.
+impl fmt::Display for CivilDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+            self.month, self.day, self.hour, self.minute, self.second, self.nanosecond
+        )?;
+        if self.zone.is_utc() {
+            f.write_str("Z")
+        } else {
+            write!(f, "{}", self.zone.offset_string())
+        }
+    }
+}
+
+/// Accepts `YYYY-MM-DD`, optionally followed by `T` (or a space) and
+/// `HH:MM[:SS[.fraction]]`, then an optional zone: `Z`, `±HH:MM`, or a
+/// space and a zone name. Years may carry a sign and any number of
+/// digits. Without a zone the reading is UTC.
+impl FromStr for CivilDateTime {
+    type Err = CivilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || CivilError::Parse(s.to_string());
+        let text = s.trim();
+
+        // Date: [sign]year-MM-DD
+        let (sign, rest) = match text.as_bytes().first() {
+            Some(b'+') => (1, &text[1..]),
+            Some(b'-') => (-1, &text[1..]),
+            _ => (1, text),
+        };
+        let year_len = rest.find('-').ok_or_else(bad)?;
+        let year: i128 = digits(&rest[..year_len]).ok_or_else(bad)?;
+        let rest = &rest[year_len + 1..];
+        let month = rest.get(..2).and_then(digits::<u8>).ok_or_else(bad)?;
+        let day = rest.get(3..5).and_then(digits::<u8>).ok_or_else(bad)?;
+        if rest.as_bytes().get(2) != Some(&b'-') {
+            return Err(bad());
+        }
+        let date = Self::from_ymd(sign * year, month, day)?;
+
+        let rest = &rest[5..];
+        let Some(rest) = rest.strip_prefix(['T', 't', ' ']) else {
+            return if rest.is_empty() { Ok(date) } else { Err(bad()) };
+        };
+
+        // Time: HH:MM[:SS[.fraction]]
+        let time_len = rest.find(|c: char| !(c.is_ascii_digit() || c == ':' || c == '.')).unwrap_or(rest.len());
+        let (time, zone) = rest.split_at(time_len);
+        let (hms, fraction) = time.split_once('.').unwrap_or((time, ""));
+        let mut fields = hms.split(':');
+        let hour = fields.next().and_then(digits::<u8>).ok_or_else(bad)?;
+        let minute = fields.next().and_then(digits::<u8>).ok_or_else(bad)?;
+        let second = fields.next().map_or(Some(0), digits::<u8>).ok_or_else(bad)?;
+        if fields.next().is_some() || (!fraction.is_empty() && hms.len() != 8) || fraction.len() > 9 {
+            return Err(bad());
+        }
+        let nanosecond = match fraction {
+            "" => 0,
+            f => digits::<u32>(f).ok_or_else(bad)? * 10u32.pow(9 - f.len() as u32),
+        };
+        let civil = date.and_hms_nano(hour, minute, second, nanosecond)?;
+
+        let zone = zone.trim_start();
+        if zone.is_empty() {
+            return Ok(civil);
+        }
+        Ok(civil.with_zone(zone.parse()?))
+    }
+}
+
+fn digits<T: FromStr>(s: &str) -> Option<T> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+// ------------------------------------------------------------
+// SimTime / SimDate in Gregorian mode
+// ------------------------------------------------------------
+
+impl SimTime {
+    /// Civil reading in `zone`; total, unlike `to_datetime`.
+    pub fn to_civil(self, zone: FixedZone) -> CivilDateTime {
+        CivilDateTime::from_sim_time(self, zone)
+    }
+
+    pub fn from_civil(civil: &CivilDateTime) -> Result<Self, CivilError> {
+        civil.to_sim_time()
+    }
+
+    /// Converts a `SimDate` in the chosen calendar. The Gregorian mode
+    /// rejects dates that do not exist (e.g. 2025-02-30).
+    pub fn from_sim_date_in(date: SimDate, mode: CalendarMode) -> Result<Self, CivilError> {
+        match mode {
+            CalendarMode::Fixed => Ok(Self::from_sim_date(date)),
+            CalendarMode::Gregorian => {
+                CivilDateTime::from_ymd(date.year as i128, date.month, date.day)?.to_sim_time()
+            }
+        }
+    }
+
+    /// The date in the chosen calendar; `None` if a Gregorian year does
+    /// not fit `SimDate`.
+    pub fn to_sim_date_in(self, mode: CalendarMode) -> Option<SimDate> {
+        match mode {
+            CalendarMode::Fixed => Some(self.to_sim_date()),
+            CalendarMode::Gregorian => self.to_civil(FixedZone::UTC).date(),
+        }
+    }
+
+    /// Calendar months in `zone`, with day-of-month clamping. Saturates
+    /// at the ends of the SimTime range.
+    pub fn add_civil_months(self, n: i64, zone: FixedZone) -> Self {
+        let civil = self.to_civil(zone).add_months(n as i128);
+        civil.to_sim_time().unwrap_or(if n < 0 { SimTime(i128::MIN) } else { SimTime(i128::MAX) })
+    }
+
+    pub fn add_civil_years(self, n: i64, zone: FixedZone) -> Self {
+        self.add_civil_months(n.saturating_mul(12), zone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn gregorian_dates_agree_with_chrono() {
+        let date = SimDate::new(2025, 3, 1);
+        let t = SimTime::from_sim_date_in(date, CalendarMode::Gregorian).unwrap();
+        let chrono = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(t, SimTime::from_datetime(chrono));
+        assert_ne!(t, SimTime::from_sim_date_in(date, CalendarMode::Fixed).unwrap());
+        assert_eq!(t.to_sim_date_in(CalendarMode::Gregorian), Some(date));
+
+        for days in [-800_000, -719_469, -1, 0, 59, 11_016, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), Some(days));
+            let t = SimTime(days * NANOS_PER_DAY + 12_345);
+            assert_eq!(t.to_civil(FixedZone::UTC).to_string(), t.format_rfc3339());
+        }
+
+        assert!(SimTime::from_sim_date_in(SimDate::new(2025, 2, 29), CalendarMode::Gregorian).is_err());
+        assert!(SimTime::from_sim_date_in(SimDate::new(2024, 2, 29), CalendarMode::Gregorian).is_ok());
+    }
+
+    #[test]
+    fn zones_months_and_extremes() {
+        let zone: FixedZone = "+05:30".parse().unwrap();
+        let civil: CivilDateTime = "2024-01-31T23:00:00-08:00".parse().unwrap();
+        let t = civil.to_sim_time().unwrap();
+        assert_eq!(t.to_civil(zone).to_string(), "2024-02-01T12:30:00.000000000+05:30");
+        assert_eq!(civil.weekday(), Weekday::Wed);
+
+        let feb = t.add_civil_months(1, civil.zone).to_civil(civil.zone);
+        assert_eq!((feb.month, feb.day, feb.hour), (2, 29, 23));
+
+        for ns in [i128::MIN, i128::MAX, -1] {
+            let civil = SimTime(ns).to_civil(zone);
+            assert_eq!(civil.to_sim_time(), Ok(SimTime(ns)));
+            assert_eq!(civil.to_string().parse::<CivilDateTime>(), Ok(civil));
+        }
+        assert_eq!(SimTime(i128::MAX).add_civil_years(1, zone), SimTime(i128::MAX));
+    }
+}
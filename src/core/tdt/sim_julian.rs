@@ -3,7 +3,10 @@
 //! Formula from US Naval Observatory / Meeus.
 
 use crate::core::tdt::sim_time::SimTime;
-use chrono::{Datelike, Timelike};
+use crate::core::tdt::sim_calendar::NANOS_PER_DAY;
+
+/// Julian Date of 1970-01-01T00:00:00Z.
+pub const JD_UNIX_EPOCH: f64 = 2_440_587.5;
 
 /*
 /// Convert a SimTime (ns since Unix epoch) to Julian Day Number (JDN)
@@ -52,37 +55,11 @@ pub fn simtime_to_julian(t: SimTime) -> (f64, f64) {
 }
 */
 /// Convert SimTime → Julian Date (JD)
-/// Continuous UTC-based time, suitable for astronomy.
+/// Continuous UTC-based time, suitable for astronomy. Computed from the
+/// nanosecond count directly, so it is defined for every SimTime.
 pub fn simtime_to_julian(t: SimTime) -> f64 {
-    let dt = t.to_datetime();
-
-    let year  = dt.year();
-    let month = dt.month() as i32;
-    let day   = dt.day() as i32;
-
-    let hour   = dt.hour() as f64;
-    let minute = dt.minute() as f64;
-    let second = dt.second() as f64;
-    let nanos  = dt.nanosecond() as f64;
-
-    let frac_day =
-        (hour + (minute + (second + nanos / 1e9) / 60.0) / 60.0) / 24.0;
-
-    let (y, m) = if month <= 2 {
-        (year - 1, month + 12)
-    } else {
-        (year, month)
-    };
-
-    let a = (y as f64 / 100.0).floor();
-    let b = 2.0 - a + (a / 4.0).floor();
-
-    let jd = (365.25 * (y as f64 + 4716.0)).floor()
-        + (30.6001 * (m as f64 + 1.0)).floor()
-        + (day as f64)
-        + frac_day
-        + b
-        - 1524.5;
+    let days = t.as_ns().div_euclid(NANOS_PER_DAY);
+    let frac_day = t.as_ns().rem_euclid(NANOS_PER_DAY) as f64 / NANOS_PER_DAY as f64;
 
-    jd
+    JD_UNIX_EPOCH + days as f64 + frac_day
 }
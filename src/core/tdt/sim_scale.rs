@@ -0,0 +1,192 @@
+//! Time scales: UTC, TAI and TT.
+//!
+//! SimTime counts UTC the POSIX way: every day is 86 400 s and leap
+//! seconds are not counted, which is what chrono does too. TAI is
+//! continuous, 10 s ahead of UTC in 1972 and 37 s ahead since 2017.
+//! TT = TAI + 32.184 s is the time argument of the ephemerides in
+//! `physox::astronomy`.
+//!
+//! Before 1972 the offset is held at 10 s; after the last table entry it
+//! stays at its final value until a new leap second is added here.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::tdt::sim_calendar::*;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_gregorian::{days_from_civil, CivilDateTime};
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::tdt::sim_zone::FixedZone;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, leap seconds not counted.
+    Utc,
+    /// International Atomic Time.
+    Tai,
+    /// Terrestrial Time (TAI + 32.184 s).
+    Tt,
+}
+
+pub const TT_MINUS_TAI_NS: i128 = 32_184_000_000;
+
+/// (year, month, TAI − UTC in seconds from the 1st of that month, 00:00 UTC)
+const LEAP_SECONDS: [(i128, u8, i128); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+
+/// (UTC instant the offset starts, previous offset, new offset), in ns.
+fn leap_table() -> impl DoubleEndedIterator<Item = (i128, i128, i128)> {
+    LEAP_SECONDS.iter().enumerate().map(|(i, &(year, month, offset))| {
+        let starts = days_from_civil(year, month, 1).unwrap_or(0) * NANOS_PER_DAY;
+        let previous = if i == 0 { offset } else { LEAP_SECONDS[i - 1].2 };
+        (starts, previous * NANOS_PER_SECOND, offset * NANOS_PER_SECOND)
+    })
+}
+
+/// TAI − UTC in force at the UTC instant `t`.
+pub fn tai_minus_utc(t: SimTime) -> SimDuration {
+    let offset = leap_table()
+        .rev()
+        .find(|&(starts, _, _)| t.as_ns() >= starts)
+        .map_or(LEAP_SECONDS[0].2 * NANOS_PER_SECOND, |(_, _, offset)| offset);
+    SimDuration(offset)
+}
+
+/// Where a TAI instant falls relative to the leap seconds: the offset
+/// to subtract, and the nanoseconds into an inserted leap second if it
+/// lies inside one.
+fn locate_tai(tai: i128) -> (i128, Option<i128>) {
+    for (starts, previous, offset) in leap_table().rev() {
+        if tai >= starts.saturating_add(offset) {
+            return (offset, None);
+        }
+        let leap_begins = starts.saturating_add(previous);
+        if tai >= leap_begins {
+            return (offset, Some(tai - leap_begins));
+        }
+    }
+    (LEAP_SECONDS[0].2 * NANOS_PER_SECOND, None)
+}
+
+pub fn utc_to_tai(t: SimTime) -> SimTime {
+    SimTime(t.as_ns().saturating_add(tai_minus_utc(t).as_ns()))
+}
+
+/// UTC has no reading for an inserted leap second, so instants inside
+/// one map to the end of it (00:00:00 of the next day).
+pub fn tai_to_utc(tai: SimTime) -> SimTime {
+    match locate_tai(tai.as_ns()) {
+        (offset, None) => SimTime(tai.as_ns().saturating_sub(offset)),
+        (offset, Some(into)) => SimTime(tai.as_ns() - into - offset + NANOS_PER_SECOND),
+    }
+}
+
+/// Whether the TAI instant lies inside an inserted leap second
+/// (23:59:60 UTC).
+pub fn is_leap_second(tai: SimTime) -> bool {
+    locate_tai(tai.as_ns()).1.is_some()
+}
+
+impl TimeScale {
+    /// A TAI instant read on this scale.
+    fn reading_of_tai(self, tai: SimTime) -> SimTime {
+        match self {
+            TimeScale::Utc => tai_to_utc(tai),
+            TimeScale::Tai => tai,
+            TimeScale::Tt => SimTime(tai.as_ns().saturating_add(TT_MINUS_TAI_NS)),
+        }
+    }
+
+    /// A reading on this scale as a TAI instant.
+    fn to_tai(self, t: SimTime) -> SimTime {
+        match self {
+            TimeScale::Utc => utc_to_tai(t),
+            TimeScale::Tai => t,
+            TimeScale::Tt => SimTime(t.as_ns().saturating_sub(TT_MINUS_TAI_NS)),
+        }
+    }
+}
+
+impl SimTime {
+    /// Re-express an instant read on scale `from` as a reading on `to`.
+    /// SimTime values default to UTC.
+    pub fn convert_scale(self, from: TimeScale, to: TimeScale) -> SimTime {
+        if from == to {
+            return self;
+        }
+        to.reading_of_tai(from.to_tai(self))
+    }
+}
+
+impl CivilDateTime {
+    /// The UTC civil reading of a TAI instant in `zone`, showing second
+    /// 60 during an inserted leap second.
+    pub fn from_tai(tai: SimTime, zone: FixedZone) -> Self {
+        match locate_tai(tai.as_ns()) {
+            (_, None) => Self::from_sim_time(tai_to_utc(tai), zone),
+            (_, Some(into)) => {
+                let last = Self::from_sim_time(SimTime(tai_to_utc(tai).as_ns() - NANOS_PER_SECOND), zone);
+                Self { second: last.second + 1, nanosecond: into as u32, ..last }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_seconds_and_scales() {
+        let t2020: SimTime = "2020-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(tai_minus_utc(t2020), SimDuration::seconds(37));
+        assert_eq!(
+            t2020.convert_scale(TimeScale::Utc, TimeScale::Tt) - t2020,
+            SimDuration(69_184_000_000)
+        );
+        assert_eq!(t2020.convert_scale(TimeScale::Utc, TimeScale::Tt).convert_scale(TimeScale::Tt, TimeScale::Utc), t2020);
+
+        // 2016-12-31T23:59:60 UTC
+        let midnight: SimTime = "2017-01-01T00:00:00Z".parse().unwrap();
+        let leap = SimTime(midnight.as_ns() + 36 * NANOS_PER_SECOND + 250_000_000);
+        assert!(is_leap_second(leap));
+        assert_eq!(tai_to_utc(leap), midnight);
+        let civil = CivilDateTime::from_tai(leap, FixedZone::UTC);
+        assert_eq!(civil.to_string(), "2016-12-31T23:59:60.250000000Z");
+
+        let after = utc_to_tai(midnight);
+        assert!(!is_leap_second(after));
+        assert_eq!(after - leap, SimDuration(750_000_000));
+        assert_eq!(tai_to_utc(after), midnight);
+
+        assert_eq!(tai_minus_utc(SimTime(0)), SimDuration::seconds(10));
+        assert_eq!(SimTime(i128::MAX).convert_scale(TimeScale::Utc, TimeScale::Tt), SimTime(i128::MAX));
+    }
+}
@@ -0,0 +1,842 @@
+//! Recurrence rules and schedules on SimTime.
+//!
+//! A `Schedule` is a start instant (e.g. an install date) plus a
+//! `Recurrence`, optionally capped by a count or an end time. Rules:
+//!
+//! - `Every` — a fixed `SimDuration`, so `every 6mo` uses the
+//!   deterministic sim calendar;
+//! - `Months` — Gregorian months in a zone (`every 6 months`), with the
+//!   start's day of month clamped to short months;
+//! - `Usage` — a usage counter at a constant rate
+//!   (`every 10000 cycles at 40/d`);
+//! - `Calendar` — an RRULE subset (`FREQ=YEARLY;BYMONTH=1;BYMONTHDAY=15`,
+//!   or `each January 15`);
+//! - `FirstOf` — whichever alternative comes first
+//!   (`every 10000 cycles at 40/d or every 5 years`). All alternatives
+//!   restart from each occurrence, as a maintenance plan does.
+//!
+//! Occurrences are strictly after the start, which is not itself one.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::core::chronovox::{ChronoEvent, EventKey, Timeline};
+use crate::core::id::{EntityId, WorldId};
+use crate::core::tdt::sim_calendar::*;
+use crate::core::tdt::sim_clock::SimClock;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_gregorian::{days_from_civil, days_in_month, civil_from_days, CivilDateTime};
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::tdt::sim_zone::FixedZone;
+
+/// ---------------------------------------------------------------------------
+/// Recurrence
+/// ---------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Recurrence {
+    /// A fixed step.
+    Every(SimDuration),
+
+    /// `n` Gregorian months, counted in `zone`.
+    Months { n: u32, zone: FixedZone },
+
+    /// Every `every` units of usage, accruing `rate` units per `per`.
+    Usage { every: f64, unit: String, rate: f64, per: SimDuration },
+
+    /// Civil dates matching an RRULE subset.
+    Calendar(CalendarRule),
+
+    /// Whichever alternative is due first.
+    FirstOf(Vec<Recurrence>),
+}
+
+impl Recurrence {
+    /// First occurrence strictly after `after` of the series that starts
+    /// at `base`. `None` if there is none (or it is past SimTime's range).
+    pub fn next_occurrence(&self, base: SimTime, after: SimTime) -> Option<SimTime> {
+        match self {
+            Recurrence::Every(step) => next_step(base, after, step.as_ns()),
+            Recurrence::Months { n, zone } => next_months(base, after, *n, *zone),
+            Recurrence::Usage { every, rate, per, .. } => {
+                let step = per.as_ns() as f64 * every / rate;
+                if !(step.is_finite() && step >= 1.0) {
+                    return None;
+                }
+                next_step(base, after, step.round() as i128)
+            }
+            Recurrence::Calendar(rule) => rule.next_after(base, after),
+            Recurrence::FirstOf(rules) => rules
+                .iter()
+                .filter_map(|rule| rule.next_occurrence(base, after))
+                .min(),
+        }
+    }
+
+    /// Whether each occurrence starts the series again.
+    fn restarts(&self) -> bool {
+        matches!(self, Recurrence::FirstOf(_))
+    }
+}
+
+fn next_step(base: SimTime, after: SimTime, step: i128) -> Option<SimTime> {
+    if step <= 0 {
+        return None;
+    }
+    let k = if after < base {
+        1
+    } else {
+        after.as_ns().checked_sub(base.as_ns())? / step + 1
+    };
+    k.checked_mul(step)?.checked_add(base.as_ns()).map(SimTime)
+}
+
+fn next_months(base: SimTime, after: SimTime, n: u32, zone: FixedZone) -> Option<SimTime> {
+    if n == 0 {
+        return None;
+    }
+    let (b, a) = (base.to_civil(zone), after.to_civil(zone));
+    let elapsed = (a.year * 12 + a.month as i128) - (b.year * 12 + b.month as i128);
+
+    // Always counted from the base, so clamped days don't drift
+    // (Jan 31 → Feb 28 → Mar 31).
+    let mut k = (elapsed / n as i128).max(1);
+    loop {
+        let t = b.add_months(k * n as i128).to_sim_time().ok()?;
+        if t > after {
+            return Some(t);
+        }
+        k += 1;
+    }
+}
+
+/// ---------------------------------------------------------------------------
+/// CalendarRule — RRULE subset
+/// ---------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+}
+
+/// FREQ, INTERVAL, BYMONTH, BYMONTHDAY (negative counts from month end)
+/// and BYDAY (plain weekdays) as in RFC 5545. Time of day, and any part
+/// of the date the rule leaves open, come from the series start.
+/// Dates that don't exist (Feb 30) are skipped, not clamped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub by_month: Vec<u8>,
+    pub by_month_day: Vec<i8>,
+    pub by_weekday: Vec<Weekday>,
+    pub zone: FixedZone,
+}
+
+impl CalendarRule {
+    pub fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_weekday: Vec::new(),
+            zone: FixedZone::UTC,
+        }
+    }
+
+    /// Each year on `month`/`day`.
+    pub fn yearly_on(month: u8, day: i8) -> Self {
+        Self { by_month: vec![month], by_month_day: vec![day], ..Self::new(Frequency::Yearly) }
+    }
+
+    /// First match strictly after both `base` and `after`.
+    pub fn next_after(&self, base: SimTime, after: SimTime) -> Option<SimTime> {
+        if self.interval == 0 {
+            return None;
+        }
+        let b = base.to_civil(self.zone);
+        let from = after.max(base).to_civil(self.zone);
+        let b_days = days_from_civil(b.year, b.month, b.day)?;
+
+        let elapsed = match self.freq {
+            Frequency::Yearly => from.year - b.year,
+            Frequency::Monthly => (from.year * 12 + from.month as i128) - (b.year * 12 + b.month as i128),
+            Frequency::Weekly => (week_start(days_from_civil(from.year, from.month, from.day)?) - week_start(b_days)) / 7,
+            Frequency::Daily => days_from_civil(from.year, from.month, from.day)? - b_days,
+        };
+        let interval = self.interval as i128;
+        let first = elapsed.div_euclid(interval) * interval;
+
+        // The Gregorian calendar repeats every 400 years, so one cycle of
+        // periods finds a match if any exists.
+        let cycle = match self.freq {
+            Frequency::Yearly => 400,
+            Frequency::Monthly => 4800,
+            Frequency::Weekly => 20_871,
+            Frequency::Daily => 146_097,
+        };
+
+        for i in 0..=cycle {
+            let period = first + i * interval;
+            let mut hits: Vec<SimTime> = self
+                .dates_in_period(&b, b_days, period)
+                .into_iter()
+                .filter_map(|(y, m, d)| {
+                    let civil = CivilDateTime { year: y, month: m, day: d, ..b };
+                    civil.to_sim_time().ok()
+                })
+                .filter(|&t| t > after && t > base)
+                .collect();
+            hits.sort();
+            if let Some(&t) = hits.first() {
+                return Some(t);
+            }
+        }
+        None
+    }
+
+    fn dates_in_period(&self, b: &CivilDateTime, b_days: i128, period: i128) -> Vec<(i128, u8, u8)> {
+        match self.freq {
+            Frequency::Yearly => {
+                let year = b.year + period;
+                let months: Vec<u8> = if !self.by_month.is_empty() {
+                    self.by_month.clone()
+                } else if self.by_month_day.is_empty() && self.by_weekday.is_empty() {
+                    vec![b.month]
+                } else {
+                    (1..=12).collect()
+                };
+                months
+                    .into_iter()
+                    .flat_map(|m| self.days_in(b, year, m).into_iter().map(move |d| (year, m, d)))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let index = b.year * 12 + (b.month as i128 - 1) + period;
+                let (year, month) = (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8);
+                if !self.month_matches(month) {
+                    return Vec::new();
+                }
+                self.days_in(b, year, month).into_iter().map(|d| (year, month, d)).collect()
+            }
+            Frequency::Weekly => {
+                let monday = week_start(b_days) + period * 7;
+                let weekdays = if self.by_weekday.is_empty() { vec![weekday_of(b_days)] } else { self.by_weekday.clone() };
+                (monday..monday + 7)
+                    .filter(|&days| weekdays.contains(&weekday_of(days)))
+                    .map(civil_from_days)
+                    .filter(|&(y, m, d)| self.month_matches(m) && self.month_day_matches(y, m, d))
+                    .collect()
+            }
+            Frequency::Daily => {
+                let days = b_days + period;
+                let (y, m, d) = civil_from_days(days);
+                let weekday_ok = self.by_weekday.is_empty() || self.by_weekday.contains(&weekday_of(days));
+                if weekday_ok && self.month_matches(m) && self.month_day_matches(y, m, d) {
+                    vec![(y, m, d)]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    /// Days of `year`-`month` selected by BYMONTHDAY / BYDAY, or the
+    /// start's day of month when neither is given.
+    fn days_in(&self, b: &CivilDateTime, year: i128, month: u8) -> Vec<u8> {
+        let len = days_in_month(year, month);
+        let first = days_from_civil(year, month, 1).unwrap_or(0);
+        let weekday_ok = |d: u8| self.by_weekday.is_empty() || self.by_weekday.contains(&weekday_of(first + d as i128 - 1));
+
+        if !self.by_month_day.is_empty() {
+            let mut days: Vec<u8> = self
+                .by_month_day
+                .iter()
+                .filter_map(|&d| resolve_month_day(d, len))
+                .filter(|&d| weekday_ok(d))
+                .collect();
+            days.sort_unstable();
+            days.dedup();
+            days
+        } else if !self.by_weekday.is_empty() {
+            (1..=len).filter(|&d| weekday_ok(d)).collect()
+        } else if b.day <= len {
+            vec![b.day]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn month_matches(&self, month: u8) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&month)
+    }
+
+    fn month_day_matches(&self, year: i128, month: u8, day: u8) -> bool {
+        let len = days_in_month(year, month);
+        self.by_month_day.is_empty()
+            || self.by_month_day.iter().any(|&d| resolve_month_day(d, len) == Some(day))
+    }
+}
+
+fn resolve_month_day(d: i8, len: u8) -> Option<u8> {
+    let day = if d < 0 { len as i16 + 1 + d as i16 } else { d as i16 };
+    (1..=len as i16).contains(&day).then_some(day as u8)
+}
+
+fn weekday_of(days: i128) -> Weekday {
+    // 1970-01-01 was a Thursday.
+    WEEKDAYS[(days + 3).rem_euclid(7) as usize]
+}
+
+/// Day number of the Monday starting the week containing `days`.
+fn week_start(days: i128) -> i128 {
+    days - (days + 3).rem_euclid(7)
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+const RRULE_DAYS: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+const MONTH_NAMES: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june",
+    "july", "august", "september", "october", "november", "december",
+];
+
+/// ---------------------------------------------------------------------------
+/// Schedule
+/// ---------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Series start (e.g. install time); not itself an occurrence.
+    pub start: SimTime,
+    pub rule: Recurrence,
+    /// Stop after this many occurrences.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// Last instant an occurrence may fall on (inclusive).
+    #[serde(default)]
+    pub until: Option<SimTime>,
+}
+
+impl Schedule {
+    pub fn new(start: SimTime, rule: Recurrence) -> Self {
+        Self { start, rule, count: None, until: None }
+    }
+
+    /// `Schedule::new` with the rule given as an expression.
+    pub fn parse(start: SimTime, expr: &str) -> Result<Self, String> {
+        Ok(Self::new(start, expr.parse()?))
+    }
+
+    pub fn with_count(mut self, count: u64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn with_until(mut self, until: SimTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// All occurrences in order.
+    pub fn iter(&self) -> Occurrences<'_> {
+        Occurrences { schedule: self, last: self.start, emitted: 0 }
+    }
+
+    /// First occurrence strictly after `t`.
+    pub fn next_after(&self, t: SimTime) -> Option<SimTime> {
+        if self.is_stateless() {
+            // Stateless rules can jump straight there.
+            let next = self.rule.next_occurrence(self.start, t.max(self.start))?;
+            return self.until.is_none_or(|until| next <= until).then_some(next);
+        }
+        self.iter().find(|&o| o > t)
+    }
+
+    /// Occurrences in `(after, through]` — the span one clock step covers.
+    pub fn due_between(&self, after: SimTime, through: SimTime) -> Vec<SimTime> {
+        if !self.is_stateless() {
+            return self
+                .iter()
+                .skip_while(|&t| t <= after)
+                .take_while(|&t| t <= through)
+                .collect();
+        }
+        let mut due = Vec::new();
+        let mut t = after;
+        while let Some(next) = self.next_after(t).filter(|&next| next <= through) {
+            due.push(next);
+            t = next;
+        }
+        due
+    }
+
+    /// Whether an occurrence can be found without replaying the series
+    /// from the start (no count, no restarting alternatives).
+    fn is_stateless(&self) -> bool {
+        self.count.is_none() && !self.rule.restarts()
+    }
+
+    /// `Custom(label)` events for each occurrence in `(after, through]`,
+    /// with the label and rule in the payload.
+    pub fn events_between(
+        &self,
+        entity_id: EntityId,
+        world_id: WorldId,
+        label: &str,
+        after: SimTime,
+        through: SimTime,
+    ) -> Vec<ChronoEvent> {
+        self.due_between(after, through)
+            .into_iter()
+            .map(|t| {
+                ChronoEvent::custom(entity_id, world_id, t, label)
+                    .with_payload(json!({ "schedule": label, "rule": self.rule.to_string() }))
+            })
+            .collect()
+    }
+
+    /// Records `events_between` into `timeline`, after anything already
+    /// at the same instants.
+    pub fn record_between(
+        &self,
+        timeline: &mut Timeline,
+        entity_id: EntityId,
+        world_id: WorldId,
+        label: &str,
+        after: SimTime,
+        through: SimTime,
+    ) -> Vec<EventKey> {
+        self.events_between(entity_id, world_id, label, after, through)
+            .into_iter()
+            .map(|event| timeline.record(event))
+            .collect()
+    }
+}
+
+/// Iterator over a schedule's occurrences.
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    schedule: &'a Schedule,
+    last: SimTime,
+    emitted: u64,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = SimTime;
+
+    fn next(&mut self) -> Option<SimTime> {
+        let s = self.schedule;
+        if s.count.is_some_and(|count| self.emitted >= count) {
+            return None;
+        }
+        let base = if s.rule.restarts() { self.last } else { s.start };
+        let next = s.rule.next_occurrence(base, self.last)?;
+        if s.until.is_some_and(|until| next > until) {
+            return None;
+        }
+        self.last = next;
+        self.emitted += 1;
+        Some(next)
+    }
+}
+
+impl SimClock {
+    /// `advance`, then the schedule's occurrences in the step just
+    /// taken. `None` once the clock is finished.
+    pub fn advance_due(&mut self, schedule: &Schedule) -> Option<Vec<SimTime>> {
+        let before = self.current;
+        if !self.advance() {
+            return None;
+        }
+        Some(schedule.due_between(before, self.current))
+    }
+}
+
+// ------------------------------------------------------------
+// Expressions
+// ------------------------------------------------------------
+
+/// Largest `SimDuration` unit that divides `ns` exactly, e.g. `6mo`.
+fn duration_token(ns: i128) -> String {
+    let units = [
+        (NANOS_PER_YEAR, "y"),
+        (NANOS_PER_MONTH, "mo"),
+        (NANOS_PER_WEEK, "w"),
+        (NANOS_PER_DAY, "d"),
+        (NANOS_PER_HOUR, "h"),
+        (NANOS_PER_MINUTE, "m"),
+        (NANOS_PER_SECOND, "s"),
+        (NANOS_PER_MILLISECOND, "ms"),
+        (NANOS_PER_MICROSECOND, "us"),
+    ];
+    units
+        .iter()
+        .find(|(unit, _)| ns != 0 && ns % unit == 0)
+        .map_or_else(|| format!("{ns}ns"), |(unit, name)| format!("{}{name}", ns / unit))
+}
+
+fn zone_suffix(zone: FixedZone) -> String {
+    if zone.is_utc() { String::new() } else { format!(" in {zone}") }
+}
+
+impl fmt::Display for CalendarRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let freq = match self.freq {
+            Frequency::Yearly => "YEARLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Daily => "DAILY",
+        };
+        let list = |items: Vec<String>| items.join(",");
+
+        write!(f, "FREQ={freq}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_month.is_empty() {
+            write!(f, ";BYMONTH={}", list(self.by_month.iter().map(u8::to_string).collect()))?;
+        }
+        if !self.by_month_day.is_empty() {
+            write!(f, ";BYMONTHDAY={}", list(self.by_month_day.iter().map(i8::to_string).collect()))?;
+        }
+        if !self.by_weekday.is_empty() {
+            let days = self.by_weekday.iter().map(|d| RRULE_DAYS[d.num_days_from_monday() as usize].to_string());
+            write!(f, ";BYDAY={}", list(days.collect()))?;
+        }
+        if !self.zone.is_utc() {
+            write!(f, ";TZ={}", self.zone)?;
+        }
+        Ok(())
+    }
+}
+
+/// RRULE properties separated by `;`. `TZ=<zone>` is an extension;
+/// COUNT and UNTIL belong on the `Schedule`.
+impl FromStr for CalendarRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+        let mut rule: Option<CalendarRule> = None;
+        let mut props = Vec::new();
+
+        for part in s.split(';').filter(|p| !p.trim().is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("RRULE part `{part}` is not KEY=VALUE"))?;
+            let (key, value) = (key.trim().to_ascii_uppercase(), value.trim());
+            if key == "FREQ" {
+                let freq = match value.to_ascii_uppercase().as_str() {
+                    "YEARLY" => Frequency::Yearly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "WEEKLY" => Frequency::Weekly,
+                    "DAILY" => Frequency::Daily,
+                    other => return Err(format!("unsupported FREQ `{other}`")),
+                };
+                rule = Some(CalendarRule::new(freq));
+            } else {
+                props.push((key, value));
+            }
+        }
+
+        let mut rule = rule.ok_or_else(|| format!("RRULE `{s}` has no FREQ"))?;
+        for (key, value) in props {
+            let bad = || format!("invalid {key} `{value}`");
+            match key.as_str() {
+                "INTERVAL" => rule.interval = value.parse().ok().filter(|&n| n > 0).ok_or_else(bad)?,
+                "BYMONTH" => {
+                    rule.by_month = value
+                        .split(',')
+                        .map(|m| m.trim().parse().ok().filter(|m| (1..=12).contains(m)))
+                        .collect::<Option<_>>()
+                        .ok_or_else(bad)?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = value
+                        .split(',')
+                        .map(|d| d.trim().parse::<i8>().ok().filter(|d| *d != 0 && (-31..=31).contains(d)))
+                        .collect::<Option<_>>()
+                        .ok_or_else(bad)?
+                }
+                "BYDAY" => {
+                    rule.by_weekday = value
+                        .split(',')
+                        .map(|d| {
+                            let d = d.trim().to_ascii_uppercase();
+                            RRULE_DAYS.iter().position(|&name| name == d).map(|i| WEEKDAYS[i])
+                        })
+                        .collect::<Option<_>>()
+                        .ok_or_else(bad)?
+                }
+                "TZ" => rule.zone = value.parse().map_err(|e| format!("{e}"))?,
+                "WKST" if value.eq_ignore_ascii_case("MO") => {}
+                "COUNT" | "UNTIL" => {
+                    return Err(format!("{key} belongs on the Schedule (with_count / with_until)"));
+                }
+                other => return Err(format!("unsupported RRULE part `{other}`")),
+            }
+        }
+        Ok(rule)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recurrence::Every(step) => write!(f, "every {}", duration_token(step.as_ns())),
+            Recurrence::Months { n, zone } => write!(f, "every {n} months{}", zone_suffix(*zone)),
+            Recurrence::Usage { every, unit, rate, per } => {
+                write!(f, "every {every} {unit} at {rate}/{}", duration_token(per.as_ns()))
+            }
+            Recurrence::Calendar(rule) => write!(f, "{rule}"),
+            Recurrence::FirstOf(rules) => {
+                let parts: Vec<String> = rules.iter().map(|r| r.to_string()).collect();
+                f.write_str(&parts.join(" or "))
+            }
+        }
+    }
+}
+
+/// Expressions (case-insensitive keywords):
+///
+/// - `every 6mo`, `every 30d` — `SimDuration` syntax, sim calendar;
+/// - `every 12 hours`, `every 2 weeks` — fixed units in words;
+/// - `every 6 months`, `every 5 years [in <zone>]` — Gregorian months;
+/// - `every 10,000 cycles at 40/d` (or `at 40 per day`) — usage;
+/// - `each January 15`, `each monday`, `each day [in <zone>]`;
+/// - an RRULE such as `FREQ=MONTHLY;BYMONTHDAY=-1`;
+/// - alternatives joined by ` or `, optionally ending in
+///   `, whichever first`.
+impl FromStr for Recurrence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = s.trim().to_string();
+        for tail in [", whichever comes first", ", whichever first", " whichever comes first", " whichever first"] {
+            if text.to_ascii_lowercase().ends_with(tail) {
+                text.truncate(text.len() - tail.len());
+            }
+        }
+
+        let lower = text.to_ascii_lowercase();
+        let mut parts = Vec::new();
+        let mut from = 0;
+        while let Some(i) = lower[from..].find(" or ") {
+            parts.push(&text[from..from + i]);
+            from += i + 4;
+        }
+        parts.push(&text[from..]);
+
+        let mut rules = parts.into_iter().map(parse_single).collect::<Result<Vec<_>, _>>()?;
+        Ok(if rules.len() == 1 { rules.remove(0) } else { Recurrence::FirstOf(rules) })
+    }
+}
+
+fn parse_single(s: &str) -> Result<Recurrence, String> {
+    let s = s.trim();
+    if s.to_ascii_uppercase().starts_with("FREQ=") || s.to_ascii_uppercase().starts_with("RRULE:") {
+        return s.parse().map(Recurrence::Calendar);
+    }
+
+    // Optional trailing zone for civil rules.
+    let lower = s.to_ascii_lowercase();
+    let (body, zone) = match lower.rfind(" in ") {
+        Some(i) => (&s[..i], s[i + 4..].parse::<FixedZone>().map_err(|e| e.to_string())?),
+        None => (s, FixedZone::UTC),
+    };
+    let words: Vec<String> = body.split_whitespace().map(str::to_ascii_lowercase).collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let bad = || format!("unrecognized schedule expression `{s}`");
+
+    match words.as_slice() {
+        ["each", "day"] => Ok(Recurrence::Calendar(CalendarRule { zone, ..CalendarRule::new(Frequency::Daily) })),
+        ["each", day] if RRULE_DAYS.iter().any(|d| day.starts_with(&d.to_ascii_lowercase())) => {
+            let weekday: Weekday = day.parse().map_err(|_| bad())?;
+            Ok(Recurrence::Calendar(CalendarRule {
+                by_weekday: vec![weekday],
+                zone,
+                ..CalendarRule::new(Frequency::Weekly)
+            }))
+        }
+        ["each", month, day] => {
+            let month = MONTH_NAMES
+                .iter()
+                .position(|name| name.starts_with(month) && month.len() >= 3)
+                .ok_or_else(bad)? as u8
+                + 1;
+            let day: i8 = day.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().map_err(|_| bad())?;
+            if resolve_month_day(day, days_in_month(2000, month)).is_none() {
+                return Err(format!("{} has no day {day}", MONTH_NAMES[month as usize - 1]));
+            }
+            Ok(Recurrence::Calendar(CalendarRule { zone, ..CalendarRule::yearly_on(month, day) }))
+        }
+        ["every", token] => {
+            let step: SimDuration = token.parse()?;
+            if step.as_ns() <= 0 {
+                return Err(format!("step in `{s}` must be positive"));
+            }
+            Ok(Recurrence::Every(step))
+        }
+        ["every", n, unit] => {
+            let n: u32 = n.replace(',', "").parse().map_err(|_| bad())?;
+            if n == 0 {
+                return Err(format!("step in `{s}` must be positive"));
+            }
+            match unit.trim_end_matches('s') {
+                "month" => Ok(Recurrence::Months { n, zone }),
+                "year" => n
+                    .checked_mul(12)
+                    .map(|n| Recurrence::Months { n, zone })
+                    .ok_or_else(|| format!("step in `{s}` is too large")),
+                unit => unit_duration(unit)
+                    .map(|d| Recurrence::Every(d * n as i128))
+                    .ok_or_else(|| format!("unknown unit `{unit}` (usage needs `at <rate>/<period>`)")),
+            }
+        }
+        ["every", n, unit, "at", rate @ ..] => {
+            let every: f64 = n.replace(',', "").parse().map_err(|_| bad())?;
+            let (rate, per) = match rate {
+                [rate_per] => rate_per.split_once('/').ok_or_else(bad)?,
+                [rate, "per", per] => (*rate, *per),
+                _ => return Err(bad()),
+            };
+            let rate: f64 = rate.replace(',', "").parse().map_err(|_| bad())?;
+            let per = if per.starts_with(|c: char| c.is_ascii_digit()) {
+                per.parse::<SimDuration>()?
+            } else {
+                unit_duration(per.trim_end_matches('s'))
+                    .or_else(|| format!("1{per}").parse().ok())
+                    .ok_or_else(bad)?
+            };
+            if !(every > 0.0 && rate > 0.0 && per.as_ns() > 0) {
+                return Err(format!("usage in `{s}` must be positive"));
+            }
+            Ok(Recurrence::Usage { every, unit: unit.to_string(), rate, per })
+        }
+        _ => Err(bad()),
+    }
+}
+
+/// Fixed-length units in words (singular).
+fn unit_duration(word: &str) -> Option<SimDuration> {
+    let ns = match word {
+        "second" | "sec" => NANOS_PER_SECOND,
+        "minute" | "min" => NANOS_PER_MINUTE,
+        "hour" => NANOS_PER_HOUR,
+        "day" => NANOS_PER_DAY,
+        "week" => NANOS_PER_WEEK,
+        "sim-month" => NANOS_PER_MONTH,
+        "sim-year" => NANOS_PER_YEAR,
+        _ => return None,
+    };
+    Some(SimDuration(ns))
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(rule: Recurrence) -> Self {
+        rule.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn at(s: &str) -> SimTime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn calendar_and_interval_rules() {
+        let install = at("2024-01-31T09:00:00Z");
+
+        let six_months = Schedule::parse(install, "every 6 months").unwrap();
+        let dates: Vec<String> = six_months.iter().take(3).map(|t| t.format_rfc3339()).collect();
+        assert_eq!(dates[0], "2024-07-31T09:00:00.000000000Z");
+        assert_eq!(dates[2], "2025-07-31T09:00:00.000000000Z");
+
+        let monthly = Schedule::new(install, Recurrence::Months { n: 1, zone: FixedZone::UTC });
+        assert_eq!(monthly.next_after(install).unwrap(), at("2024-02-29T09:00:00Z"));
+        assert_eq!(monthly.next_after(at("2024-02-29T09:00:00Z")).unwrap(), at("2024-03-31T09:00:00Z"));
+
+        let jan15 = Schedule::parse(install, "each January 15").unwrap().with_count(2);
+        assert_eq!(jan15.iter().collect::<Vec<_>>(), vec![at("2025-01-15T09:00:00Z"), at("2026-01-15T09:00:00Z")]);
+
+        let sim = Schedule::parse(SimTime(0), "every 6mo").unwrap();
+        assert_eq!(sim.next_after(SimTime(0)), Some(SimTime(6 * NANOS_PER_MONTH)));
+
+        // Last weekday-of-month style: Fridays that are the 13th.
+        let rule: CalendarRule = "FREQ=MONTHLY;BYMONTHDAY=13;BYDAY=FR".parse().unwrap();
+        let fri13 = Schedule::new(at("2024-01-01T00:00:00Z"), Recurrence::Calendar(rule.clone()));
+        assert_eq!(fri13.next_after(fri13.start), Some(at("2024-09-13T00:00:00Z")));
+        assert_eq!(rule.to_string().parse::<CalendarRule>().unwrap(), rule);
+
+        let never: CalendarRule = "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30".parse().unwrap();
+        assert_eq!(never.next_after(install, install), None);
+
+        // Year counts that overflow the month count are errors, not wraps
+        let _ = Schedule::parse(install, "every 357913941 years").unwrap().next_after(install);
+        assert_eq!(
+            Schedule::parse(install, "every 357913942 years").unwrap_err(),
+            "step in `every 357913942 years` is too large"
+        );
+    }
+
+    #[test]
+    fn whichever_first_restarts_and_emits_events() {
+        let install = at("2025-01-01T00:00:00Z");
+        let expr = "every 10,000 cycles at 40/d or every 5 years, whichever first";
+        let schedule = Schedule::parse(install, expr).unwrap();
+        let Recurrence::FirstOf(rules) = &schedule.rule else { panic!("expected alternatives") };
+        assert_eq!(rules.len(), 2);
+        assert_eq!(schedule.rule.to_string().parse::<Recurrence>().unwrap(), schedule.rule);
+
+        // 10 000 cycles at 40/day is 250 days, well inside 5 years.
+        let first = schedule.next_after(install).unwrap();
+        assert_eq!(first - install, SimDuration::days(250));
+        assert_eq!(schedule.iter().nth(1).unwrap() - first, SimDuration::days(250));
+
+        let slow = Schedule::parse(install, "every 10000 cycles at 1 per day or every 5 years").unwrap();
+        assert_eq!(slow.next_after(install), Some(at("2030-01-01T00:00:00Z")));
+
+        let mut timeline = Timeline::new();
+        let (entity, world) = (EntityId(Uuid::new_v4()), WorldId(1));
+        let end = at("2027-01-01T00:00:00Z");
+        let keys = schedule.record_between(&mut timeline, entity, world, "maintenance", install, end);
+        assert_eq!(keys.len(), 2);
+        assert!(timeline.iter().all(|e| e.kind == crate::core::chronovox::EventKind::Custom("maintenance".into())));
+
+        let mut clock = SimClock::new(install, end, SimDuration::days(200));
+        let mut due = Vec::new();
+        while let Some(step_due) = clock.advance_due(&schedule) {
+            due.extend(step_due);
+        }
+        assert_eq!(due, schedule.due_between(install, end));
+    }
+}
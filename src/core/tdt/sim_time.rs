@@ -1,15 +1,29 @@
 use crate::core::tdt::sim_calendar::*;
 use crate::core::tdt::sim_date::SimDate;
-use chrono::{DateTime, Utc, TimeZone, SecondsFormat};
+use chrono::{DateTime, Utc, TimeZone};
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use serde::de::Error;
 use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_gregorian::CivilDateTime;
+use crate::core::tdt::sim_zone::FixedZone;
 //use crate::core::tdt::sim_julian::{simtime_to_julian, julian_to_simtime};
 /// Absolute simulation time: nanoseconds since Unix epoch.
 /// Deterministic, monotonic, integer-based.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SimTime(pub i128);
 
+/// Serialized as a decimal string of nanoseconds, since i128 does not fit
+/// a JSON number. Deserializing accepts that or RFC 3339 (see `FromStr`),
+/// so `serialize_simtime` output reads back too.
+impl Serialize for SimTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
 
 impl<'de> Deserialize<'de> for SimTime {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -17,8 +31,7 @@ impl<'de> Deserialize<'de> for SimTime {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let ns: i128 = s.parse().map_err(serde::de::Error::custom)?;
-        Ok(SimTime(ns))
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -65,11 +78,10 @@ impl SimTime {
     ///
     /// * `dt` - A `DateTime<Utc>` instance
     ///
-    /// # Panics
-    ///
-    /// Panics if the datetime cannot be converted to nanoseconds.
+    /// Every chrono datetime fits, including those outside the i64
+    /// nanosecond range.
     pub fn from_datetime(dt: DateTime<Utc>) -> Self {
-        SimTime(dt.timestamp_nanos_opt().unwrap() as i128)
+        SimTime(dt.timestamp() as i128 * NANOS_PER_SECOND + dt.timestamp_subsec_nanos() as i128)
     }
 
     /// Creates a new `SimTime` from a `SimDate`.
@@ -108,15 +120,23 @@ impl SimTime {
     ///
     /// # Returns
     ///
-    /// A `DateTime<Utc>` instance
-    ///
-    /// # Panics
-    ///
-    /// Panics if the timestamp is out of range for `DateTime<Utc>`.
+    /// A `DateTime<Utc>` instance. Times outside chrono's range (about
+    /// ±262 000 years) clamp to its first or last representable instant;
+    /// use `try_to_datetime` or `to_civil` to tell.
     pub fn to_datetime(self) -> DateTime<Utc> {
-        let secs = (self.0 / 1_000_000_000) as i64;
-        let nanos = (self.0 % 1_000_000_000) as u32;
-        Utc.timestamp_opt(secs, nanos).unwrap()
+        self.try_to_datetime().unwrap_or(if self.0 < 0 {
+            DateTime::<Utc>::MIN_UTC
+        } else {
+            DateTime::<Utc>::MAX_UTC
+        })
+    }
+
+    /// Converts the `SimTime` to a UTC datetime, or `None` outside
+    /// chrono's range.
+    pub fn try_to_datetime(self) -> Option<DateTime<Utc>> {
+        let secs = i64::try_from(self.0.div_euclid(NANOS_PER_SECOND)).ok()?;
+        let nanos = self.0.rem_euclid(NANOS_PER_SECOND) as u32;
+        Utc.timestamp_opt(secs, nanos).single()
     }
 
     /// Converts the `SimTime` to a `SimDate`.
@@ -161,10 +181,10 @@ impl SimTime {
     ///
     /// # Returns
     ///
-    /// A `String` in RFC 3339 format (e.g., "2024-01-01T00:00:00.000000000Z")
+    /// A `String` in RFC 3339 format (e.g., "2024-01-01T00:00:00.000000000Z").
+    /// Years outside 0–9999 use the signed expanded form ("+12345-…").
     pub fn format_rfc3339(&self) -> String {
-        self.to_datetime()
-            .to_rfc3339_opts(SecondsFormat::Nanos, true)
+        self.to_civil(FixedZone::UTC).to_string()
     }
 
     // ----------------------------------------------------
@@ -314,8 +334,7 @@ pub fn serialize_simtime<S>(t: &SimTime, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    let dt = t.to_datetime();
-    s.serialize_str(&dt.to_rfc3339_opts(SecondsFormat::Nanos, true))
+    s.serialize_str(&t.format_rfc3339())
 }
 
 
@@ -326,8 +345,7 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(d)?;
-    let ns = s.parse::<i128>().map_err(D::Error::custom)?;
-    Ok(SimTime(ns))
+    s.parse().map_err(D::Error::custom)
 }
 
 pub fn deserialize_simtime_opt<'de, D>(
@@ -338,14 +356,38 @@ where
 {
     let opt = Option::<String>::deserialize(d)?;
     match opt {
-        Some(s) => {
-            let ns = s.parse::<i128>().map_err(serde::de::Error::custom)?;
-            Ok(Some(SimTime(ns)))
-        }
+        Some(s) => s.parse().map(Some).map_err(serde::de::Error::custom),
         None => Ok(None),
     }
 }
 
+// ------------------------------------------------------------
+// Parsing
+// ------------------------------------------------------------
+
+/// Parses either raw nanoseconds (`"1704067200000000000"`) or an
+/// RFC 3339 timestamp (`"2024-01-01T00:00:00Z"`). Timestamps beyond
+/// chrono's range fall back to the Gregorian `CivilDateTime` syntax,
+/// so every `format_rfc3339` output reads back.
+impl std::str::FromStr for SimTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(ns) = s.parse::<i128>() {
+            return Ok(SimTime(ns));
+        }
+
+        match DateTime::parse_from_rfc3339(s) {
+            Ok(dt) => Ok(SimTime::from_datetime(dt.with_timezone(&Utc))),
+            Err(e) => s
+                .parse::<CivilDateTime>()
+                .and_then(|civil| civil.to_sim_time())
+                .map_err(|_| format!("invalid SimTime `{s}`: {e}")),
+        }
+    }
+}
+
 // ------------------------------------------------------------
 // Now function
 // ------------------------------------------------------------
@@ -0,0 +1,180 @@
+//! Fixed-offset time zones for the Gregorian calendar mode.
+//!
+//! Only constant offsets are modelled, so results never depend on a
+//! tz database. Names follow the IANA spellings that denote a fixed
+//! offset: `UTC`, `GMT`, `Etc/UTC`, `Etc/GMT±N` (POSIX sign: `Etc/GMT+5`
+//! is five hours *behind* UTC), plus `Z`, `±HH[:MM[:SS]]`, `±HHMM` and
+//! `UTC±H[:MM]`. Region zones such as `Europe/Paris` are rejected.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::tdt::sim_calendar::NANOS_PER_SECOND;
+use crate::core::tdt::sim_gregorian::CivilError;
+
+/// Offsets must stay strictly inside one day.
+const MAX_OFFSET_S: i32 = 86_399;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FixedZone {
+    offset_s: i32,
+}
+
+impl FixedZone {
+    pub const UTC: Self = Self { offset_s: 0 };
+
+    /// `seconds` east of Greenwich (local = UTC + offset).
+    pub fn east(seconds: i32) -> Result<Self, CivilError> {
+        if !(-MAX_OFFSET_S..=MAX_OFFSET_S).contains(&seconds) {
+            return Err(CivilError::OffsetOutOfRange(seconds));
+        }
+        Ok(Self { offset_s: seconds })
+    }
+
+    /// `seconds` west of Greenwich (local = UTC − offset).
+    pub fn west(seconds: i32) -> Result<Self, CivilError> {
+        Self::east(seconds.checked_neg().ok_or(CivilError::OffsetOutOfRange(seconds))?)
+    }
+
+    pub fn offset_seconds(&self) -> i32 {
+        self.offset_s
+    }
+
+    pub fn offset_ns(&self) -> i128 {
+        self.offset_s as i128 * NANOS_PER_SECOND
+    }
+
+    pub fn is_utc(&self) -> bool {
+        self.offset_s == 0
+    }
+
+    /// `±HH:MM`, with `:SS` only when the offset has seconds.
+    pub fn offset_string(&self) -> String {
+        let sign = if self.offset_s < 0 { '-' } else { '+' };
+        let abs = self.offset_s.unsigned_abs();
+        let (h, m, s) = (abs / 3600, abs / 60 % 60, abs % 60);
+        if s == 0 {
+            format!("{sign}{h:02}:{m:02}")
+        } else {
+            format!("{sign}{h:02}:{m:02}:{s:02}")
+        }
+    }
+}
+
+/// `UTC` for zero, otherwise the offset.
+impl fmt::Display for FixedZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_utc() {
+            f.write_str("UTC")
+        } else {
+            f.write_str(&self.offset_string())
+        }
+    }
+}
+
+impl FromStr for FixedZone {
+    type Err = CivilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || CivilError::UnknownZone(s.to_string());
+        let name = s.trim();
+
+        match name {
+            "Z" | "z" | "UTC" | "GMT" | "UT" | "Etc/UTC" | "Etc/GMT" | "Etc/UCT" | "Etc/Zulu" => {
+                return Ok(Self::UTC);
+            }
+            _ => {}
+        }
+
+        // Etc/GMT+5 means UTC-5.
+        if let Some(offset) = name.strip_prefix("Etc/GMT") {
+            let seconds = parse_offset(offset, true).ok_or_else(unknown)?;
+            return Self::west(seconds);
+        }
+
+        let offset = ["UTC", "GMT"]
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name);
+        Self::east(parse_offset(offset, false).ok_or_else(unknown)?)
+    }
+}
+
+/// `±HH[:MM[:SS]]` or `±HHMM`; `hours_only` is the `Etc/GMT±N` form.
+/// Hours may be one digit.
+fn parse_offset(s: &str, hours_only: bool) -> Option<i32> {
+    let (sign, body) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    if body.is_empty() || !body.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+
+    let parts: Vec<&str> = if body.contains(':') {
+        body.split(':').collect()
+    } else if body.len() == 4 {
+        vec![&body[..2], &body[2..]]
+    } else {
+        vec![body]
+    };
+    if parts.len() > 3 || (hours_only && parts.len() > 1) || parts.iter().any(|p| p.is_empty() || p.len() > 2) {
+        return None;
+    }
+
+    let mut fields = parts.iter().map(|p| p.parse::<i32>().ok());
+    let h = fields.next()??;
+    let m = fields.next().unwrap_or(Some(0))?;
+    let sec = fields.next().unwrap_or(Some(0))?;
+    if h > 23 || m > 59 || sec > 59 {
+        return None;
+    }
+    Some(sign * (h * 3600 + m * 60 + sec))
+}
+
+impl TryFrom<String> for FixedZone {
+    type Error = CivilError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<FixedZone> for String {
+    fn from(zone: FixedZone) -> Self {
+        zone.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fixed_offset_names() {
+        let cases = [
+            ("UTC", 0),
+            ("Etc/UTC", 0),
+            ("Z", 0),
+            ("+05:30", 19_800),
+            ("-0800", -28_800),
+            ("UTC+9", 32_400),
+            ("GMT-03:30", -12_600),
+            ("Etc/GMT+5", -18_000),
+            ("Etc/GMT-14", 50_400),
+        ];
+        for (name, offset) in cases {
+            let zone: FixedZone = name.parse().unwrap();
+            assert_eq!(zone.offset_seconds(), offset, "{name}");
+            assert_eq!(zone.to_string().parse::<FixedZone>(), Ok(zone));
+        }
+
+        for name in ["Europe/Paris", "+24:00", "UTC+", "Etc/GMT+5:30", "+5:60"] {
+            assert!(name.parse::<FixedZone>().is_err(), "{name}");
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::core::uvoxid::{UvoxId, RUm, LatCode, LonCode, ANG_SCALE};
+
+/// Deepest level: cells are ~1.7e-7° (≈ 2 cm at the equator) on a side.
+pub const MAX_CELL_LEVEL: u8 = 30;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum UvoxCellError {
+    #[error("empty cell token")]
+    Empty,
+
+    #[error("invalid character {0:?} in cell token")]
+    InvalidDigit(char),
+
+    #[error("cell level {0} is deeper than {MAX_CELL_LEVEL}")]
+    TooDeep(usize),
+}
+
+/// ---------------------------------------------------------------------------
+/// UvoxCellId — hierarchical lat/lon tile
+/// ---------------------------------------------------------------------------
+///
+/// Level `l` splits latitude into `2^l` rows and longitude into
+/// `2^(l+1)` columns, so cells are square at the equator and every cell
+/// has four children. Level 0 is the western and eastern hemisphere.
+///
+/// The compact token is the root digit (`0` west, `1` east) followed by
+/// one digit `0..=3` per level (`2·north + east` within the parent).
+/// A cell's token is a prefix of all its descendants' tokens, and
+/// sorting tokens keeps nearby cells together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UvoxCellId {
+    level: u8,
+    lat: u32,
+    lon: u32,
+}
+
+impl UvoxCellId {
+    pub const ROOTS: [UvoxCellId; 2] = [
+        UvoxCellId { level: 0, lat: 0, lon: 0 },
+        UvoxCellId { level: 0, lat: 0, lon: 1 },
+    ];
+
+    /// Cell by row (`lat`, counted from the south pole) and column
+    /// (`lon`, counted east from -180°). `None` if out of range.
+    pub fn new(level: u8, lat: u32, lon: u32) -> Option<Self> {
+        let cell = Self { level, lat, lon };
+        (level <= MAX_CELL_LEVEL && (lat as u64) < cell.rows() && (lon as u64) < cell.cols()).then_some(cell)
+    }
+
+    /// The cell at `level` holding `pos`. Points on the north pole and
+    /// on +180° fall in the last row / column.
+    pub fn containing(pos: &UvoxId, level: u8) -> Self {
+        let level = level.min(MAX_CELL_LEVEL);
+        let rows = 1i128 << level;
+        let cols = rows * 2;
+        let lat = (pos.lat_code.0 as i128 + 90 * ANG_SCALE) * rows / (180 * ANG_SCALE);
+        let lon = (pos.lon_code.0 as i128 + 180 * ANG_SCALE) * cols / (360 * ANG_SCALE);
+
+        Self {
+            level,
+            lat: lat.clamp(0, rows - 1) as u32,
+            lon: lon.clamp(0, cols - 1) as u32,
+        }
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn lat_index(&self) -> u32 {
+        self.lat
+    }
+
+    pub fn lon_index(&self) -> u32 {
+        self.lon
+    }
+
+    fn rows(&self) -> u64 {
+        1 << self.level
+    }
+
+    fn cols(&self) -> u64 {
+        2 << self.level
+    }
+
+    // ------------------------------------------------------------
+    // Hierarchy
+    // ------------------------------------------------------------
+
+    pub fn parent(&self) -> Option<Self> {
+        (self.level > 0).then(|| self.ancestor(self.level - 1))
+    }
+
+    /// The cell at `level` containing this one (itself if `level` is
+    /// not above it).
+    pub fn ancestor(&self, level: u8) -> Self {
+        let level = level.min(self.level);
+        let shift = self.level - level;
+        Self { level, lat: self.lat >> shift, lon: self.lon >> shift }
+    }
+
+    /// This cell and all its ancestors, deepest first.
+    pub fn lineage(self) -> impl Iterator<Item = UvoxCellId> {
+        (0..=self.level).rev().map(move |level| self.ancestor(level))
+    }
+
+    /// The four cells one level down, in token digit order
+    /// (south-west, south-east, north-west, north-east).
+    pub fn children(&self) -> [Self; 4] {
+        let (level, lat, lon) = (self.level + 1, self.lat * 2, self.lon * 2);
+        [
+            Self { level, lat, lon },
+            Self { level, lat, lon: lon + 1 },
+            Self { level, lat: lat + 1, lon },
+            Self { level, lat: lat + 1, lon: lon + 1 },
+        ]
+    }
+
+    pub fn contains_cell(&self, other: &Self) -> bool {
+        other.level >= self.level && other.ancestor(self.level) == *self
+    }
+
+    pub fn contains(&self, pos: &UvoxId) -> bool {
+        Self::containing(pos, self.level) == *self
+    }
+
+    // ------------------------------------------------------------
+    // Neighbours
+    // ------------------------------------------------------------
+
+    /// Cell `d_lat` rows north and `d_lon` columns east at the same
+    /// level. Longitude wraps; stepping past a pole gives `None`.
+    pub fn neighbour(&self, d_lat: i64, d_lon: i64) -> Option<Self> {
+        let lat = self.lat as i64 + d_lat;
+        if lat < 0 || lat >= self.rows() as i64 {
+            return None;
+        }
+        let lon = (self.lon as i64 + d_lon).rem_euclid(self.cols() as i64);
+        Some(Self { level: self.level, lat: lat as u32, lon: lon as u32 })
+    }
+
+    /// Edge and corner neighbours, without duplicates. Cells that only
+    /// share a pole point with this one are not included.
+    pub fn neighbours(&self) -> Vec<Self> {
+        let mut found = Vec::with_capacity(8);
+        for d_lat in -1..=1 {
+            for d_lon in -1..=1 {
+                if let Some(cell) = self.neighbour(d_lat, d_lon)
+                    && cell != *self
+                    && !found.contains(&cell)
+                {
+                    found.push(cell);
+                }
+            }
+        }
+        found
+    }
+
+    // ------------------------------------------------------------
+    // Bounds
+    // ------------------------------------------------------------
+
+    /// South and north edges (the cell is half-open: south inclusive).
+    pub fn lat_range(&self) -> (LatCode, LatCode) {
+        let h = 180 * ANG_SCALE;
+        let rows = self.rows() as i128;
+        (
+            LatCode((-90 * ANG_SCALE + self.lat as i128 * h / rows) as i64),
+            LatCode((-90 * ANG_SCALE + (self.lat as i128 + 1) * h / rows) as i64),
+        )
+    }
+
+    /// West and east edges. The last column's east edge is +180°,
+    /// left unwrapped so the range stays increasing.
+    pub fn lon_range(&self) -> (LonCode, LonCode) {
+        let w = 360 * ANG_SCALE;
+        let cols = self.cols() as i128;
+        (
+            LonCode((-180 * ANG_SCALE + self.lon as i128 * w / cols) as i64),
+            LonCode((-180 * ANG_SCALE + (self.lon as i128 + 1) * w / cols) as i64),
+        )
+    }
+
+    /// The cell's centre point at radius `r`.
+    pub fn center(&self, r: RUm) -> UvoxId {
+        let (s, n) = self.lat_range();
+        let (w, e) = self.lon_range();
+        UvoxId::new(r, LatCode(s.0 + (n.0 - s.0) / 2), LonCode(w.0 + (e.0 - w.0) / 2))
+    }
+
+    // ------------------------------------------------------------
+    // Token
+    // ------------------------------------------------------------
+
+    pub fn to_token(&self) -> String {
+        let root = self.ancestor(0).lon;
+        let mut token = String::with_capacity(self.level as usize + 1);
+        token.push(char::from(b'0' + root as u8));
+
+        for depth in 1..=self.level {
+            let shift = self.level - depth;
+            let north = (self.lat >> shift) & 1;
+            let east = (self.lon >> shift) & 1;
+            token.push(char::from(b'0' + (2 * north + east) as u8));
+        }
+        token
+    }
+
+    pub fn from_token(token: &str) -> Result<Self, UvoxCellError> {
+        let mut digits = token.chars();
+        let root = match digits.next() {
+            None => return Err(UvoxCellError::Empty),
+            Some(c @ ('0' | '1')) => c as u32 - '0' as u32,
+            Some(c) => return Err(UvoxCellError::InvalidDigit(c)),
+        };
+
+        let depth = token.len() - 1;
+        if depth > MAX_CELL_LEVEL as usize {
+            return Err(UvoxCellError::TooDeep(depth));
+        }
+
+        let mut cell = Self { level: 0, lat: 0, lon: root };
+        for c in digits {
+            let digit = match c {
+                '0'..='3' => c as usize - '0' as usize,
+                _ => return Err(UvoxCellError::InvalidDigit(c)),
+            };
+            cell = cell.children()[digit];
+        }
+        Ok(cell)
+    }
+}
+
+impl UvoxId {
+    /// The cell at `level` holding this point.
+    pub fn cell(&self, level: u8) -> UvoxCellId {
+        UvoxCellId::containing(self, level)
+    }
+}
+
+impl fmt::Display for UvoxCellId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_token())
+    }
+}
+
+impl FromStr for UvoxCellId {
+    type Err = UvoxCellError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_token(s)
+    }
+}
+
+/// Serialized as its token, so cells can be map keys in JSON.
+impl Serialize for UvoxCellId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_token())
+    }
+}
+
+impl<'de> Deserialize<'de> for UvoxCellId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let token = String::deserialize(deserializer)?;
+        Self::from_token(&token).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::uvoxid::EARTH_RADIUS_UM;
+
+    fn at(lat: f64, lon: f64) -> UvoxId {
+        UvoxId::earth_surface(LatCode::from_degrees(lat), LonCode::from_degrees(lon))
+    }
+
+    #[test]
+    fn hierarchy_and_tokens_round_trip() {
+        let tampa = at(27.95, -82.46);
+        let leaf = tampa.cell(20);
+
+        assert!(leaf.contains(&tampa));
+        for cell in leaf.lineage() {
+            assert!(cell.contains(&tampa));
+            assert!(cell.contains_cell(&leaf));
+            assert!(leaf.to_token().starts_with(&cell.to_token()));
+            assert_eq!(UvoxCellId::from_token(&cell.to_token()), Ok(cell));
+        }
+
+        let parent = leaf.parent().unwrap();
+        assert!(parent.children().contains(&leaf));
+        assert_eq!(UvoxCellId::ROOTS[0].parent(), None);
+        assert_eq!(leaf.to_token().len(), 21);
+
+        let json = serde_json::to_string(&leaf).unwrap();
+        assert_eq!(json, format!("\"{}\"", leaf.to_token()));
+        assert_eq!(serde_json::from_str::<UvoxCellId>(&json).unwrap(), leaf);
+
+        assert_eq!(UvoxCellId::from_token(""), Err(UvoxCellError::Empty));
+        assert_eq!(UvoxCellId::from_token("2"), Err(UvoxCellError::InvalidDigit('2')));
+        assert_eq!(UvoxCellId::from_token("04"), Err(UvoxCellError::InvalidDigit('4')));
+        assert_eq!(UvoxCellId::from_token(&"0".repeat(40)), Err(UvoxCellError::TooDeep(39)));
+    }
+
+    #[test]
+    fn neighbours_wrap_longitude_and_stop_at_poles() {
+        let east_edge = at(0.5, 179.999).cell(4);
+        let west_edge = at(0.5, -179.999).cell(4);
+        assert!(east_edge.neighbours().contains(&west_edge));
+        assert_eq!(east_edge.neighbour(0, 1), Some(west_edge));
+        assert_eq!(east_edge.neighbours().len(), 8);
+
+        let polar = at(89.9, 10.0).cell(4);
+        assert_eq!(polar.neighbour(1, 0), None);
+        assert_eq!(polar.neighbours().len(), 5);
+
+        // Level 0 has only two cells, each the other's only neighbour.
+        assert_eq!(UvoxCellId::ROOTS[0].neighbours(), vec![UvoxCellId::ROOTS[1]]);
+    }
+
+    #[test]
+    fn bounds_hold_their_points() {
+        for (lat, lon) in [(90.0, 0.0), (-90.0, -180.0), (0.0, 179.999_999), (-33.9, 151.2)] {
+            let p = at(lat, lon);
+            let cell = p.cell(MAX_CELL_LEVEL);
+            let (s, n) = cell.lat_range();
+            let (w, e) = cell.lon_range();
+            assert!(s.0 <= p.lat_code.0 && p.lat_code.0 <= n.0);
+            assert!(w.0 <= p.lon_code.0 && p.lon_code.0 <= e.0);
+            assert!(cell.contains(&cell.center(RUm(EARTH_RADIUS_UM))));
+        }
+    }
+}
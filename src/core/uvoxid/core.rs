@@ -288,24 +288,6 @@ impl UvoxId {
     }
 
 }
-use std::str::FromStr;
-
-impl FromStr for UvoxId {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // 1. Try hex
-        if let Some(id) = UvoxId::from_hex(s) {
-            return Ok(id);
-        }
-
-        // 2. Try other syntaxes later
-        // if let Some(id) = parse_lat_lon(s) { ... }
-
-        Err(format!("Invalid UvoxId string: {}", s))
-    }
-}
-
 // ------------------------------------------------------------
 // Operator overloads for delta math
 // ------------------------------------------------------------
@@ -407,4 +389,13 @@ impl UvoxId {
 
         dr + (r * dlat / 1_000_000) + (r * dlon / 1_000_000)
     }
+
+    /// Straight-line (chord) distance in micrometers. Exact at any
+    /// scale, including across the antimeridian and near the poles.
+    pub fn distance_um(&self, other: &UvoxId) -> f64 {
+        let (x1, y1, z1) = self.to_cartesian();
+        let (x2, y2, z2) = other.to_cartesian();
+        let (dx, dy, dz) = (x1 - x2, y1 - y2, z1 - z2);
+        (dx * dx + dy * dy + dz * dz).sqrt() * 1e6
+    }
 }
@@ -1,5 +1,8 @@
 use crate::core::uvoxid::{UvoxId, RUm, LatCode, LonCode};
 
+/// Spherical placement: `elevation_m` above a 6371 km sphere, with
+/// `lat` read as geocentric. Use `UvoxId::from_geodetic` for WGS84
+/// (GPS) coordinates.
 pub fn from_lat_lon(lat: f64, lon: f64, elevation_m: f64) -> UvoxId {
     const EARTH_RADIUS_M: f64 = 6_371_000.0;
 
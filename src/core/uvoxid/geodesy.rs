@@ -0,0 +1,511 @@
+//! ---------------------------------------------------------------------------
+//! Geodesy — WGS84 and great-circle maths on `UvoxId`
+//! ---------------------------------------------------------------------------
+//!
+//! A `UvoxId` is read as *geocentric* spherical coordinates of an
+//! Earth-centred, Earth-fixed point: `r_um` from the centre, latitude
+//! measured from the equatorial plane. Geodetic (GPS-style) latitude
+//! and height above the ellipsoid are a different thing, so a point on
+//! the 6371 km reference sphere sits ~7 km below the WGS84 equator.
+//! Every conversion here goes through ECEF metres and rounds to the
+//! nearest code, which keeps round trips within a micrometre.
+
+use std::f64::consts::PI;
+use std::ops::{Add, AddAssign};
+
+use serde::{Serialize, Deserialize};
+use thiserror::Error;
+
+use crate::core::uvoxid::{UvoxId, RUm, LatCode, LonCode, ANG_SCALE};
+
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum GeodesyError {
+    #[error("geodesic did not converge (points are nearly antipodal)")]
+    NoConvergence,
+
+    #[error("{what} must be finite, got {value}")]
+    NonFinite { what: &'static str, value: f64 },
+}
+
+// ------------------------------------------------------------
+// Ellipsoid
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ellipsoid {
+    /// Semi-major axis (m)
+    pub a: f64,
+    /// Flattening
+    pub f: f64,
+}
+
+impl Ellipsoid {
+    pub const WGS84: Ellipsoid = Ellipsoid { a: 6_378_137.0, f: 1.0 / 298.257_223_563 };
+
+    /// Semi-minor axis (m)
+    pub fn b(&self) -> f64 {
+        self.a * (1.0 - self.f)
+    }
+
+    /// First eccentricity squared
+    pub fn e2(&self) -> f64 {
+        self.f * (2.0 - self.f)
+    }
+
+    /// Geodetic → ECEF metres.
+    pub fn to_ecef(&self, p: &Geodetic) -> [f64; 3] {
+        let (lat, lon) = (p.lat_deg.to_radians(), p.lon_deg.to_radians());
+        let n = self.a / (1.0 - self.e2() * lat.sin().powi(2)).sqrt();
+
+        [
+            (n + p.height_m) * lat.cos() * lon.cos(),
+            (n + p.height_m) * lat.cos() * lon.sin(),
+            (n * (1.0 - self.e2()) + p.height_m) * lat.sin(),
+        ]
+    }
+
+    /// ECEF metres → geodetic. Fixed-point iteration on latitude; the
+    /// height formula stays well conditioned at the poles.
+    pub fn from_ecef(&self, [x, y, z]: [f64; 3]) -> Geodetic {
+        let e2 = self.e2();
+        let p = x.hypot(y);
+        let lon = y.atan2(x);
+
+        let mut lat = z.atan2(p * (1.0 - e2));
+        for _ in 0..16 {
+            let n = self.a / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+            let next = (z + e2 * n * lat.sin()).atan2(p);
+            let done = (next - lat).abs() < 1e-15;
+            lat = next;
+            if done {
+                break;
+            }
+        }
+
+        let height = p * lat.cos() + z * lat.sin()
+            - self.a * (1.0 - e2 * lat.sin().powi(2)).sqrt();
+
+        Geodetic { lat_deg: lat.to_degrees(), lon_deg: lon.to_degrees(), height_m: height }
+    }
+
+    /// Vincenty's inverse problem: shortest distance and bearings
+    /// between two points on the ellipsoid. Fails only for points
+    /// within a fraction of a degree of antipodal.
+    pub fn inverse(&self, from: &Geodetic, to: &Geodetic) -> Result<Geodesic, GeodesyError> {
+        let (a, b, f) = (self.a, self.b(), self.f);
+
+        let l = wrap_degrees(to.lon_deg - from.lon_deg).to_radians();
+        let u1 = ((1.0 - f) * from.lat_deg.to_radians().tan()).atan();
+        let u2 = ((1.0 - f) * to.lat_deg.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..200 {
+            let (sin_l, cos_l) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_l).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_l).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                return Ok(Geodesic { distance_m: 0.0, initial_bearing_deg: 0.0, final_bearing_deg: 0.0 });
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_l;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_l / sin_sigma;
+            let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+            // Equatorial lines have cos²α = 0.
+            let cos_2sm = if cos2_alpha != 0.0 {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+            } else {
+                0.0
+            };
+
+            let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+            let prev = lambda;
+            lambda = l + (1.0 - c) * f * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sm + c * cos_sigma * (-1.0 + 2.0 * cos_2sm * cos_2sm)));
+
+            if lambda.abs() > PI + 1e-9 {
+                return Err(GeodesyError::NoConvergence);
+            }
+            if (lambda - prev).abs() > 1e-12 {
+                continue;
+            }
+
+            let u_sq = cos2_alpha * (a * a - b * b) / (b * b);
+            let (big_a, big_b) = series(u_sq);
+            let d_sigma = big_b * sin_sigma
+                * (cos_2sm + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sm * cos_2sm)
+                        - big_b / 6.0 * cos_2sm
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sm * cos_2sm)));
+
+            let (sin_l, cos_l) = lambda.sin_cos();
+            let alpha1 = (cos_u2 * sin_l).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_l);
+            let alpha2 = (cos_u1 * sin_l).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_l);
+
+            return Ok(Geodesic {
+                distance_m: b * big_a * (sigma - d_sigma),
+                initial_bearing_deg: normalize_bearing(alpha1.to_degrees()),
+                final_bearing_deg: normalize_bearing(alpha2.to_degrees()),
+            });
+        }
+
+        Err(GeodesyError::NoConvergence)
+    }
+
+    /// Vincenty's direct problem: where you end up after `distance_m`
+    /// along the geodesic leaving `from` at `bearing_deg` (clockwise
+    /// from north). Returns the end point and the bearing on arrival.
+    pub fn direct(&self, from: &Geodetic, bearing_deg: f64, distance_m: f64) -> Result<(Geodetic, f64), GeodesyError> {
+        for (what, value) in [("bearing", bearing_deg), ("distance", distance_m)] {
+            if !value.is_finite() {
+                return Err(GeodesyError::NonFinite { what, value });
+            }
+        }
+        let (a, b, f) = (self.a, self.b(), self.f);
+
+        let (sin_a1, cos_a1) = bearing_deg.to_radians().sin_cos();
+        let u1 = ((1.0 - f) * from.lat_deg.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+
+        let sigma1 = u1.tan().atan2(cos_a1);
+        let sin_alpha = cos_u1 * sin_a1;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+        let u_sq = cos2_alpha * (a * a - b * b) / (b * b);
+        let (big_a, big_b) = series(u_sq);
+
+        let mut sigma = distance_m / (b * big_a);
+        let (mut sin_sigma, mut cos_sigma, mut cos_2sm);
+        let mut converged = false;
+        for _ in 0..200 {
+            cos_2sm = (2.0 * sigma1 + sigma).cos();
+            (sin_sigma, cos_sigma) = sigma.sin_cos();
+            let d_sigma = big_b * sin_sigma
+                * (cos_2sm + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sm * cos_2sm)
+                        - big_b / 6.0 * cos_2sm
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sm * cos_2sm)));
+            let next = distance_m / (b * big_a) + d_sigma;
+            converged = (next - sigma).abs() < 1e-12;
+            sigma = next;
+            if converged {
+                break;
+            }
+        }
+        if !converged {
+            return Err(GeodesyError::NoConvergence);
+        }
+        cos_2sm = (2.0 * sigma1 + sigma).cos();
+        (sin_sigma, cos_sigma) = sigma.sin_cos();
+
+        let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_a1;
+        let lat2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_a1)
+            .atan2((1.0 - f) * (sin_alpha * sin_alpha + tmp * tmp).sqrt());
+        let lambda = (sin_sigma * sin_a1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_a1);
+        let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+        let l = lambda - (1.0 - c) * f * sin_alpha
+            * (sigma + c * sin_sigma * (cos_2sm + c * cos_sigma * (-1.0 + 2.0 * cos_2sm * cos_2sm)));
+        let alpha2 = sin_alpha.atan2(-tmp);
+
+        let end = Geodetic {
+            lat_deg: lat2.to_degrees(),
+            lon_deg: wrap_degrees(from.lon_deg + l.to_degrees()),
+            height_m: from.height_m,
+        };
+        Ok((end, normalize_bearing(alpha2.to_degrees())))
+    }
+}
+
+/// Vincenty's A and B series in u².
+fn series(u_sq: f64) -> (f64, f64) {
+    let a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    (a, b)
+}
+
+fn normalize_bearing(deg: f64) -> f64 {
+    deg.rem_euclid(360.0)
+}
+
+fn wrap_degrees(deg: f64) -> f64 {
+    (deg + 180.0).rem_euclid(360.0) - 180.0
+}
+
+// ------------------------------------------------------------
+// Value types
+// ------------------------------------------------------------
+
+/// Latitude/longitude on an ellipsoid plus height above it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Geodetic {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub height_m: f64,
+}
+
+impl Geodetic {
+    pub fn new(lat_deg: f64, lon_deg: f64, height_m: f64) -> Self {
+        Self { lat_deg, lon_deg, height_m }
+    }
+}
+
+/// Solution of the inverse problem. Bearings are degrees clockwise
+/// from north in `[0, 360)`; the final bearing is the heading on arrival.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Geodesic {
+    pub distance_m: f64,
+    pub initial_bearing_deg: f64,
+    pub final_bearing_deg: f64,
+}
+
+/// Offset in a local east/north/up frame (metres). "Up" is the WGS84
+/// ellipsoid normal at the origin point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Enu {
+    pub east_m: f64,
+    pub north_m: f64,
+    pub up_m: f64,
+}
+
+impl Enu {
+    pub fn new(east_m: f64, north_m: f64, up_m: f64) -> Self {
+        Self { east_m, north_m, up_m }
+    }
+}
+
+// ------------------------------------------------------------
+// UvoxId ↔ ECEF / geodetic
+// ------------------------------------------------------------
+
+impl UvoxId {
+    /// ECEF position in metres (same as `to_cartesian`, as an array).
+    pub fn to_ecef(&self) -> [f64; 3] {
+        let (x, y, z) = self.to_cartesian();
+        [x, y, z]
+    }
+
+    /// Nearest `UvoxId` to an ECEF position in metres.
+    pub fn from_ecef([x, y, z]: [f64; 3]) -> Self {
+        let r_m = (x * x + y * y + z * z).sqrt();
+        let lat = z.atan2(x.hypot(y)).to_degrees();
+        let lon = y.atan2(x).to_degrees();
+
+        UvoxId::new(
+            RUm((r_m * 1e6).round() as i64),
+            LatCode((lat * ANG_SCALE as f64).round() as i64),
+            LonCode((lon * ANG_SCALE as f64).round() as i64),
+        )
+    }
+
+    /// From WGS84 latitude, longitude and ellipsoidal height.
+    pub fn from_geodetic(p: &Geodetic) -> Self {
+        Self::from_ecef(Ellipsoid::WGS84.to_ecef(p))
+    }
+
+    /// WGS84 latitude, longitude and ellipsoidal height.
+    pub fn to_geodetic(&self) -> Geodetic {
+        Ellipsoid::WGS84.from_ecef(self.to_ecef())
+    }
+
+    // --------------------------------------------------------
+    // Great circle (the UvoxId sphere itself)
+    // --------------------------------------------------------
+
+    /// Angle between the two points seen from the centre (radians).
+    /// Uses the atan2 form, which is accurate for both tiny and
+    /// near-antipodal separations.
+    pub fn central_angle(&self, other: &UvoxId) -> f64 {
+        let (phi1, phi2) = (self.lat_code.radians(), other.lat_code.radians());
+        let d_lon = other.lon_code.radians() - self.lon_code.radians();
+
+        let y = ((phi2.cos() * d_lon.sin()).powi(2)
+            + (phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * d_lon.cos()).powi(2))
+        .sqrt();
+        let x = phi1.sin() * phi2.sin() + phi1.cos() * phi2.cos() * d_lon.cos();
+        y.atan2(x)
+    }
+
+    /// Great-circle distance on a sphere of the pair's mean radius (m).
+    pub fn great_circle_m(&self, other: &UvoxId) -> f64 {
+        self.central_angle(other) * (self.radius_m() + other.radius_m()) / 2.0
+    }
+
+    /// Initial great-circle bearing toward `other`, degrees from north.
+    pub fn great_circle_bearing_deg(&self, other: &UvoxId) -> f64 {
+        let (phi1, phi2) = (self.lat_code.radians(), other.lat_code.radians());
+        let d_lon = other.lon_code.radians() - self.lon_code.radians();
+
+        let y = d_lon.sin() * phi2.cos();
+        let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * d_lon.cos();
+        normalize_bearing(y.atan2(x).to_degrees())
+    }
+
+    // --------------------------------------------------------
+    // WGS84 geodesics
+    // --------------------------------------------------------
+
+    /// Ellipsoidal distance and bearings to `other`, measured between
+    /// the two points' footprints on the WGS84 ellipsoid.
+    pub fn geodesic_to(&self, other: &UvoxId) -> Result<Geodesic, GeodesyError> {
+        Ellipsoid::WGS84.inverse(&self.to_geodetic(), &other.to_geodetic())
+    }
+
+    /// Point `distance_m` along the WGS84 geodesic leaving at
+    /// `bearing_deg`, at the same ellipsoidal height.
+    pub fn destination(&self, bearing_deg: f64, distance_m: f64) -> Result<UvoxId, GeodesyError> {
+        let (end, _) = Ellipsoid::WGS84.direct(&self.to_geodetic(), bearing_deg, distance_m)?;
+        Ok(Self::from_geodetic(&end))
+    }
+
+    // --------------------------------------------------------
+    // Local east/north/up frame
+    // --------------------------------------------------------
+
+    /// Rows are the east, north and up unit vectors in ECEF.
+    fn enu_basis(&self) -> [[f64; 3]; 3] {
+        let g = self.to_geodetic();
+        let (sin_lat, cos_lat) = g.lat_deg.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = g.lon_deg.to_radians().sin_cos();
+
+        [
+            [-sin_lon, cos_lon, 0.0],
+            [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+            [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+        ]
+    }
+
+    /// The point reached by moving `offset` in this point's local frame.
+    pub fn offset_enu(&self, offset: Enu) -> UvoxId {
+        let [e, n, u] = self.enu_basis();
+        let p = self.to_ecef();
+        let d = [offset.east_m, offset.north_m, offset.up_m];
+
+        Self::from_ecef(std::array::from_fn(|i| p[i] + d[0] * e[i] + d[1] * n[i] + d[2] * u[i]))
+    }
+
+    /// Where `other` lies in this point's local frame.
+    pub fn enu_to(&self, other: &UvoxId) -> Enu {
+        let basis = self.enu_basis();
+        let (p, q) = (self.to_ecef(), other.to_ecef());
+        let d = [q[0] - p[0], q[1] - p[1], q[2] - p[2]];
+        let dot = |v: [f64; 3]| v[0] * d[0] + v[1] * d[1] + v[2] * d[2];
+
+        Enu { east_m: dot(basis[0]), north_m: dot(basis[1]), up_m: dot(basis[2]) }
+    }
+}
+
+impl Add<Enu> for UvoxId {
+    type Output = UvoxId;
+
+    fn add(self, offset: Enu) -> Self::Output {
+        self.offset_enu(offset)
+    }
+}
+
+impl AddAssign<Enu> for UvoxId {
+    fn add_assign(&mut self, offset: Enu) {
+        *self = self.offset_enu(offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dms(d: f64, m: f64, s: f64) -> f64 {
+        d.signum() * (d.abs() + m / 60.0 + s / 3600.0)
+    }
+
+    #[test]
+    fn vincenty_matches_reference_line() {
+        // Flinders Peak → Buninyong, Vincenty (1975) worked example.
+        let flinders = Geodetic::new(-dms(37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440), 0.0);
+        let buninyong = Geodetic::new(-dms(37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390), 0.0);
+
+        let line = Ellipsoid::WGS84.inverse(&flinders, &buninyong).unwrap();
+        assert!((line.distance_m - 54_972.271).abs() < 1e-3);
+        assert!((line.initial_bearing_deg - dms(306.0, 52.0, 5.37)).abs() < 1e-5);
+        assert!((line.final_bearing_deg - dms(307.0, 10.0, 25.07)).abs() < 1e-5);
+
+        let (end, arrival) = Ellipsoid::WGS84.direct(&flinders, line.initial_bearing_deg, line.distance_m).unwrap();
+        assert!((end.lat_deg - buninyong.lat_deg).abs() < 1e-9);
+        assert!((end.lon_deg - buninyong.lon_deg).abs() < 1e-9);
+        assert!((arrival - line.final_bearing_deg).abs() < 1e-7);
+    }
+
+    #[test]
+    fn geodetic_round_trips_to_the_micrometre() {
+        for p in [
+            Geodetic::new(27.7, -82.4, 12.5),
+            Geodetic::new(-89.999_999, 179.999_999, -30.0),
+            Geodetic::new(90.0, 0.0, 1_000.0),
+            Geodetic::new(0.0, -180.0, 0.0),
+        ] {
+            let id = UvoxId::from_geodetic(&p);
+            let back = UvoxId::from_geodetic(&id.to_geodetic());
+            assert!(id.distance_um(&back) <= 1.0, "{p:?}");
+        }
+
+        // The 6371 km reference sphere is below the WGS84 equator.
+        let g = crate::core::uvoxid::from_lat_lon(0.0, 0.0, 0.0).to_geodetic();
+        assert!((g.height_m - (6_371_000.0 - 6_378_137.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn enu_offsets_round_trip() {
+        let origin = UvoxId::from_geodetic(&Geodetic::new(27.7, -82.4, 3.0));
+        for offset in [
+            Enu::new(1.0, 0.0, 0.0),
+            Enu::new(-12.345_678, 250.0, -1.5),
+            Enu::new(0.000_5, -0.000_5, 0.000_5),
+            Enu::new(3_000.0, -2_000.0, 40.0),
+        ] {
+            let moved = origin + offset;
+            let back = origin.enu_to(&moved);
+            assert!((back.east_m - offset.east_m).abs() < 1e-6, "{offset:?} → {back:?}");
+            assert!((back.north_m - offset.north_m).abs() < 1e-6, "{offset:?} → {back:?}");
+            assert!((back.up_m - offset.up_m).abs() < 1e-6, "{offset:?} → {back:?}");
+        }
+
+        // "North" and "up" are geodetic: moving up keeps the WGS84 lat/lon.
+        let raised = (origin + Enu::new(0.0, 0.0, 100.0)).to_geodetic();
+        assert!((raised.lat_deg - 27.7).abs() < 1e-10);
+        assert!((raised.height_m - 103.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn destination_and_great_circle_agree_with_inverse() {
+        let start = UvoxId::from_geodetic(&Geodetic::new(51.5, -0.12, 0.0));
+        let end = start.destination(45.0, 10_000.0).unwrap();
+        let line = start.geodesic_to(&end).unwrap();
+        assert!((line.distance_m - 10_000.0).abs() < 1e-5);
+        assert!((line.initial_bearing_deg - 45.0).abs() < 1e-7);
+
+        // Across the antimeridian the sphere and ellipsoid agree to ~0.5%.
+        let a = UvoxId::from_geodetic(&Geodetic::new(0.0, 179.9, 0.0));
+        let b = UvoxId::from_geodetic(&Geodetic::new(0.0, -179.9, 0.0));
+        let ellipsoidal = a.geodesic_to(&b).unwrap().distance_m;
+        assert!((a.great_circle_m(&b) / ellipsoidal - 1.0).abs() < 5e-3);
+        assert!((a.great_circle_bearing_deg(&b) - 90.0).abs() < 1e-6);
+
+        // Vincenty's known failure case reports an error instead of looping.
+        let near_antipodal = Ellipsoid::WGS84.inverse(
+            &Geodetic::new(0.0, 0.0, 0.0),
+            &Geodetic::new(0.5, 179.7, 0.0),
+        );
+        assert_eq!(near_antipodal, Err(GeodesyError::NoConvergence));
+
+        // Non-finite input is rejected rather than iterated forever.
+        assert!(matches!(start.destination(45.0, f64::NAN), Err(GeodesyError::NonFinite { what: "distance", .. })));
+        assert!(matches!(start.destination(45.0, f64::INFINITY), Err(GeodesyError::NonFinite { .. })));
+        assert!(matches!(start.destination(f64::NAN, 1.0), Err(GeodesyError::NonFinite { what: "bearing", .. })));
+        assert!(start.destination(30.0, 1e7).is_ok() && start.destination(30.0, -1e7).is_ok());
+        for distance in [1e12, 1e300] {
+            assert!(matches!(start.destination(30.0, distance), Ok(_) | Err(GeodesyError::NoConvergence)));
+        }
+    }
+}
@@ -1,9 +1,15 @@
 mod core;
+mod cell;
 mod delta;
 mod geocode;
+mod geodesy;
+mod parse;
 pub mod units;
 pub use geocode::{from_lat_lon};
 pub use units::*;
 
 pub use core::*;
+pub use cell::{UvoxCellId, UvoxCellError, MAX_CELL_LEVEL};
+pub use geodesy::{Ellipsoid, Enu, Geodesic, Geodetic, GeodesyError};
+pub use parse::{CoordFormat, UvoxDisplay, UvoxParseError, WorldUvoxId};
 pub use delta::{Delta, DRUm, DLat, DLon};
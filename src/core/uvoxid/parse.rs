@@ -0,0 +1,578 @@
+//! ---------------------------------------------------------------------------
+//! Human coordinate syntaxes for `UvoxId`
+//! ---------------------------------------------------------------------------
+//!
+//! `FromStr` accepts:
+//! - the 48-char hex form (`UvoxId::to_hex`)
+//! - decimal degrees with optional elevation: `27.95,-82.46@12m`
+//! - degrees/minutes/seconds: `27°57'N 82°27'36.5"W`, also with `@12m`
+//! - RFC 5870 geo URIs: `geo:27.95,-82.46,12;u=5` (WGS84 crs only)
+//!
+//! Latitude and longitude are the id's own codes and elevation is
+//! measured from the `EARTH_RADIUS_UM` reference sphere, as in
+//! `from_lat_lon`, so decimal text round-trips exactly. Use
+//! `UvoxId::from_geodetic` to place ellipsoidal (survey) coordinates.
+//!
+//! `world:3/27.95,-82.46` names the world too; parse it as a
+//! `WorldUvoxId`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::core::id::WorldId;
+use crate::core::uvoxid::{UvoxId, RUm, LatCode, LonCode, ANG_SCALE, EARTH_RADIUS_UM};
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum UvoxParseError {
+    #[error("empty coordinate string")]
+    Empty,
+
+    #[error("unrecognised coordinate syntax: {0:?}")]
+    UnrecognizedFormat(String),
+
+    #[error("invalid number {0:?}")]
+    InvalidNumber(String),
+
+    #[error("latitude {0:?} is outside ±90°")]
+    LatitudeOutOfRange(String),
+
+    #[error("longitude {0:?} is outside ±180°")]
+    LongitudeOutOfRange(String),
+
+    #[error("{part} {value:?} must be below 60")]
+    DmsOutOfRange { part: &'static str, value: String },
+
+    #[error("coordinate {0:?} needs a hemisphere letter (N, S, E or W)")]
+    MissingHemisphere(String),
+
+    #[error("expected one latitude (N/S) and one longitude (E/W)")]
+    ConflictingHemispheres,
+
+    #[error("unknown elevation unit {0:?}")]
+    UnknownUnit(String),
+
+    #[error("unsupported geo URI crs {0:?} (only wgs84)")]
+    UnsupportedCrs(String),
+
+    #[error("invalid world id {0:?}")]
+    InvalidWorld(String),
+
+    #[error("coordinate is qualified with world {0}; parse it as a WorldUvoxId")]
+    WorldQualified(WorldId),
+}
+
+type Result<T> = std::result::Result<T, UvoxParseError>;
+
+// ------------------------------------------------------------
+// Exact decimals
+// ------------------------------------------------------------
+
+/// Digits after the point we keep; more would overflow the i128 maths.
+const MAX_FRACTION_DIGITS: u32 = 15;
+
+/// `text` as `mantissa / 10^digits`, without going through f64.
+fn parse_decimal(text: &str, allow_sign: bool) -> Result<(i128, u32)> {
+    let invalid = || UvoxParseError::InvalidNumber(text.to_string());
+
+    let (negative, body) = match text.as_bytes().first() {
+        Some(b'-') if allow_sign => (true, &text[1..]),
+        Some(b'+') if allow_sign => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (whole, frac) = body.split_once('.').unwrap_or((body, ""));
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if (whole.is_empty() && frac.is_empty())
+        || !all_digits(whole)
+        || !all_digits(frac)
+        || whole.len() > 20
+        || frac.len() > MAX_FRACTION_DIGITS as usize
+    {
+        return Err(invalid());
+    }
+
+    let digits = format!("{whole}{frac}");
+    let mantissa: i128 = if digits.is_empty() { 0 } else { digits.parse().map_err(|_| invalid())? };
+    Ok((if negative { -mantissa } else { mantissa }, frac.len() as u32))
+}
+
+/// round(numerator / denominator), halves away from zero; `None` on
+/// overflow.
+fn div_round(numerator: i128, denominator: i128) -> Option<i128> {
+    let half = denominator / 2;
+    let rounded = if numerator >= 0 { numerator.checked_add(half)? } else { numerator.checked_sub(half)? };
+    Some(rounded / denominator)
+}
+
+/// `mantissa / 10^digits · scale`, rounded to an integer. Long inputs
+/// can overflow the i128 product; that is an invalid number.
+fn scaled((mantissa, digits): (i128, u32), scale: i128, text: &str) -> Result<i128> {
+    mantissa
+        .checked_mul(scale)
+        .and_then(|product| div_round(product, 10i128.pow(digits)))
+        .ok_or_else(|| UvoxParseError::InvalidNumber(text.to_string()))
+}
+
+/// Exact decimal text for `value / 10^digits`, trailing zeros trimmed.
+fn format_fixed(value: i128, digits: u32) -> String {
+    let unit = 10i128.pow(digits);
+    let sign = if value < 0 { "-" } else { "" };
+    let (whole, frac) = (value.abs() / unit, value.abs() % unit);
+    if frac == 0 {
+        return format!("{sign}{whole}");
+    }
+    let frac = format!("{frac:0width$}", width = digits as usize);
+    format!("{sign}{whole}.{}", frac.trim_end_matches('0'))
+}
+
+const ANG_DIGITS: u32 = 11; // ANG_SCALE = 10^11
+const UM_DIGITS: u32 = 6;
+
+fn lat_code(value: i128, text: &str) -> Result<LatCode> {
+    if value.abs() > 90 * ANG_SCALE {
+        return Err(UvoxParseError::LatitudeOutOfRange(text.to_string()));
+    }
+    Ok(LatCode(value as i64))
+}
+
+fn lon_code(value: i128, text: &str) -> Result<LonCode> {
+    if value.abs() > 180 * ANG_SCALE {
+        return Err(UvoxParseError::LongitudeOutOfRange(text.to_string()));
+    }
+    Ok(LonCode(value as i64))
+}
+
+fn degrees(text: &str) -> Result<i128> {
+    scaled(parse_decimal(text.trim(), true)?, ANG_SCALE, text.trim())
+}
+
+// ------------------------------------------------------------
+// Elevation
+// ------------------------------------------------------------
+
+/// "12", "12m", "-3.5 ft", "250um" → micrometres.
+fn parse_elevation(text: &str) -> Result<i64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+
+    let um_per_unit: i128 = match unit.trim() {
+        "" | "m" => 1_000_000,
+        "km" => 1_000_000_000,
+        "cm" => 10_000,
+        "mm" => 1_000,
+        "um" | "µm" | "μm" => 1,
+        "ft" => 304_800,
+        other => return Err(UvoxParseError::UnknownUnit(other.to_string())),
+    };
+
+    let um = scaled(parse_decimal(number, true)?, um_per_unit, text)?;
+    i64::try_from(um).map_err(|_| UvoxParseError::InvalidNumber(text.to_string()))
+}
+
+fn radius(elevation_um: i64) -> RUm {
+    RUm(EARTH_RADIUS_UM.saturating_add(elevation_um))
+}
+
+fn elevation_m(id: &UvoxId) -> String {
+    format_fixed(id.r_um.0 as i128 - EARTH_RADIUS_UM as i128, UM_DIGITS)
+}
+
+// ------------------------------------------------------------
+// Syntaxes
+// ------------------------------------------------------------
+
+/// `lat,lon` in decimal degrees.
+fn parse_decimal_pair(text: &str) -> Result<(LatCode, LonCode)> {
+    let (lat, lon) = text
+        .split_once(',')
+        .ok_or_else(|| UvoxParseError::UnrecognizedFormat(text.to_string()))?;
+    let (lat, lon) = (lat.trim(), lon.trim());
+    Ok((lat_code(degrees(lat)?, lat)?, lon_code(degrees(lon)?, lon)?))
+}
+
+/// RFC 5870: `geo:lat,lon[,alt][;crs=wgs84][;u=…][;other=…]`.
+fn parse_geo_uri(body: &str) -> Result<UvoxId> {
+    let mut parts = body.split(';');
+    let coords = parts.next().unwrap_or_default();
+
+    for param in parts {
+        let (key, value) = param.split_once('=').unwrap_or((param, ""));
+        if key.trim().eq_ignore_ascii_case("crs") && !value.trim().eq_ignore_ascii_case("wgs84") {
+            return Err(UvoxParseError::UnsupportedCrs(value.trim().to_string()));
+        }
+    }
+
+    let fields: Vec<&str> = coords.split(',').collect();
+    let (lat, lon, alt) = match fields.as_slice() {
+        [lat, lon] => (*lat, *lon, None),
+        [lat, lon, alt] => (*lat, *lon, Some(*alt)),
+        _ => return Err(UvoxParseError::UnrecognizedFormat(format!("geo:{body}"))),
+    };
+
+    let elevation = alt.map(parse_elevation).transpose()?.unwrap_or(0);
+    Ok(UvoxId::new(
+        radius(elevation),
+        lat_code(degrees(lat)?, lat)?,
+        lon_code(degrees(lon)?, lon)?,
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Lat,
+    Lon,
+}
+
+/// One DMS coordinate off the front of `text`: value in angle codes,
+/// its axis, and the rest of the string.
+fn parse_dms_coord(text: &str) -> Result<(i128, Axis, &str)> {
+    let hemisphere = |c: char| match c.to_ascii_uppercase() {
+        'N' => Some((Axis::Lat, 1)),
+        'S' => Some((Axis::Lat, -1)),
+        'E' => Some((Axis::Lon, 1)),
+        'W' => Some((Axis::Lon, -1)),
+        _ => None,
+    };
+    let original = text;
+    let mut rest = text.trim_start();
+
+    let mut hemi = rest.chars().next().and_then(hemisphere);
+    if hemi.is_some() {
+        rest = rest[1..].trim_start();
+    }
+
+    // Up to three numbers, each followed by its symbol.
+    let symbols: [&[&str]; 3] = [&["°", "º", "d"], &["'", "′", "’"], &["\"", "″", "''", "”"]];
+    let mut parts: Vec<(i128, u32)> = Vec::new();
+    for (i, syms) in symbols.iter().enumerate() {
+        let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+        if end == 0 {
+            break;
+        }
+        let (number, after) = rest.split_at(end);
+        let after = after.trim_start();
+
+        let own = syms.iter().find(|s| after.starts_with(**s));
+        let other = symbols.iter().flat_map(|s| s.iter()).any(|s| after.starts_with(*s));
+        if own.is_none() && other {
+            // e.g. the "82°" of the next coordinate: stop here.
+            break;
+        }
+
+        let value = parse_decimal(number, false)?;
+        if i > 0 && value.0 >= 60 * 10i128.pow(value.1) {
+            let part = if i == 1 { "minutes" } else { "seconds" };
+            return Err(UvoxParseError::DmsOutOfRange { part, value: number.to_string() });
+        }
+        parts.push(value);
+
+        // The symbol may be left off the last number ("27.5N").
+        match own {
+            Some(sym) => rest = after[sym.len()..].trim_start(),
+            None => {
+                rest = after;
+                break;
+            }
+        }
+    }
+    if parts.is_empty() {
+        return Err(UvoxParseError::UnrecognizedFormat(original.trim().to_string()));
+    }
+
+    if hemi.is_none() {
+        hemi = rest.chars().next().and_then(hemisphere);
+        if hemi.is_some() {
+            rest = &rest[1..];
+        }
+    }
+    let (axis, sign) = hemi.ok_or_else(|| UvoxParseError::MissingHemisphere(original.trim().to_string()))?;
+
+    // degrees + minutes/60 + seconds/3600 over a common 10^digits.
+    let digits = parts.iter().map(|p| p.1).max().unwrap_or(0);
+    let weights = [3600, 60, 1];
+    let overflow = || UvoxParseError::InvalidNumber(original.trim().to_string());
+    let numerator = parts.iter().zip(weights).try_fold(0i128, |sum, (&(m, d), w)| {
+        m.checked_mul(10i128.pow(digits - d))?.checked_mul(w)?.checked_add(sum)
+    });
+    let code = numerator
+        .and_then(|n| n.checked_mul(ANG_SCALE))
+        .and_then(|n| div_round(n, 3600 * 10i128.pow(digits)))
+        .ok_or_else(overflow)?;
+
+    Ok((sign * code, axis, rest))
+}
+
+fn parse_dms_pair(text: &str) -> Result<(LatCode, LonCode)> {
+    let (first, axis_1, rest) = parse_dms_coord(text)?;
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix(',').unwrap_or(rest);
+    let (second, axis_2, rest) = parse_dms_coord(rest)?;
+    if !rest.trim().is_empty() {
+        return Err(UvoxParseError::UnrecognizedFormat(text.to_string()));
+    }
+
+    let ((lat, lat_text), (lon, lon_text)) = match (axis_1, axis_2) {
+        (Axis::Lat, Axis::Lon) => ((first, text), (second, text)),
+        (Axis::Lon, Axis::Lat) => ((second, text), (first, text)),
+        _ => return Err(UvoxParseError::ConflictingHemispheres),
+    };
+    Ok((lat_code(lat, lat_text)?, lon_code(lon, lon_text)?))
+}
+
+fn looks_like_dms(text: &str) -> bool {
+    text.contains(['°', 'º', '\'', '′', '"', '″'])
+        || text.trim_end().ends_with(|c: char| "NSEWnsew".contains(c))
+        || text.trim_start().starts_with(|c: char| "NSEWnsew".contains(c))
+}
+
+impl FromStr for UvoxId {
+    type Err = UvoxParseError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(UvoxParseError::Empty);
+        }
+
+        if s.get(..6).is_some_and(|p| p.eq_ignore_ascii_case("world:")) {
+            return Err(UvoxParseError::WorldQualified(s.parse::<WorldUvoxId>()?.world_id));
+        }
+        if s.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("geo:")) {
+            return parse_geo_uri(&s[4..]);
+        }
+        if let Some(id) = UvoxId::from_hex(s) {
+            return Ok(id);
+        }
+
+        let (coords, elevation) = match s.rsplit_once('@') {
+            Some((coords, elevation)) => (coords, parse_elevation(elevation)?),
+            None => (s, 0),
+        };
+        let (lat, lon) = if looks_like_dms(coords) {
+            parse_dms_pair(coords)?
+        } else {
+            parse_decimal_pair(coords)?
+        };
+        Ok(UvoxId::new(radius(elevation), lat, lon))
+    }
+}
+
+// ------------------------------------------------------------
+// Display variants
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordFormat {
+    /// `27.95,-82.46@12m` — exact
+    Decimal,
+    /// `27°57'00"N 82°27'36"W@12m` — seconds to 1e-5″ (~0.3 mm)
+    Dms,
+    /// `geo:27.95,-82.46,12` — exact
+    GeoUri,
+    /// `UvoxId::to_hex`
+    Hex,
+}
+
+/// Formats a `UvoxId` in one of the syntaxes `FromStr` reads.
+#[derive(Debug, Clone, Copy)]
+pub struct UvoxDisplay {
+    id: UvoxId,
+    format: CoordFormat,
+}
+
+impl UvoxId {
+    pub fn display(&self, format: CoordFormat) -> UvoxDisplay {
+        UvoxDisplay { id: *self, format }
+    }
+}
+
+fn dms(code: i64, positive: char, negative: char) -> String {
+    const SEC_DIGITS: u32 = 5;
+    let hemisphere = if code < 0 { negative } else { positive };
+
+    // Work in 1e-5 arc-seconds, rounded once.
+    let total = div_round(code.unsigned_abs() as i128 * 3600 * 10i128.pow(SEC_DIGITS), ANG_SCALE)
+        .expect("an i64 code in 1e-5 arc-seconds fits in i128");
+    let per_minute = 60 * 10i128.pow(SEC_DIGITS);
+    let (d, m, s) = (total / (60 * per_minute), total / per_minute % 60, total % per_minute);
+
+    let seconds = format_fixed(s, SEC_DIGITS);
+    let pad = if s < 10 * 10i128.pow(SEC_DIGITS) { "0" } else { "" };
+    format!("{d}°{m:02}'{pad}{seconds}\"{hemisphere}")
+}
+
+impl fmt::Display for UvoxDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = &self.id;
+        let lat = format_fixed(id.lat_code.0 as i128, ANG_DIGITS);
+        let lon = format_fixed(id.lon_code.0 as i128, ANG_DIGITS);
+        let at_sea_level = id.r_um.0 == EARTH_RADIUS_UM;
+
+        match self.format {
+            CoordFormat::Decimal | CoordFormat::Dms => {
+                if self.format == CoordFormat::Decimal {
+                    write!(f, "{lat},{lon}")?;
+                } else {
+                    write!(f, "{} {}", dms(id.lat_code.0, 'N', 'S'), dms(id.lon_code.0, 'E', 'W'))?;
+                }
+                if !at_sea_level {
+                    write!(f, "@{}m", elevation_m(id))?;
+                }
+                Ok(())
+            }
+            CoordFormat::GeoUri => {
+                write!(f, "geo:{lat},{lon}")?;
+                if !at_sea_level {
+                    write!(f, ",{}", elevation_m(id))?;
+                }
+                Ok(())
+            }
+            CoordFormat::Hex => f.write_str(&id.to_hex()),
+        }
+    }
+}
+
+// ------------------------------------------------------------
+// World-qualified form
+// ------------------------------------------------------------
+
+/// A position together with the world it is in:
+/// `world:<id>/<any UvoxId syntax>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorldUvoxId {
+    pub world_id: WorldId,
+    pub id: UvoxId,
+}
+
+impl WorldUvoxId {
+    pub fn new(world_id: WorldId, id: UvoxId) -> Self {
+        Self { world_id, id }
+    }
+}
+
+impl FromStr for WorldUvoxId {
+    type Err = UvoxParseError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let body = s
+            .get(..6)
+            .filter(|p| p.eq_ignore_ascii_case("world:"))
+            .map(|_| &s[6..])
+            .ok_or_else(|| UvoxParseError::UnrecognizedFormat(s.to_string()))?;
+
+        let (world, coords) = body
+            .split_once('/')
+            .ok_or_else(|| UvoxParseError::UnrecognizedFormat(s.to_string()))?;
+        let world_id = world
+            .trim()
+            .parse::<i64>()
+            .map(WorldId)
+            .map_err(|_| UvoxParseError::InvalidWorld(world.to_string()))?;
+
+        Ok(Self { world_id, id: coords.parse()? })
+    }
+}
+
+impl fmt::Display for WorldUvoxId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "world:{}/{}", self.world_id, self.id.display(CoordFormat::Decimal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(deg: &str) -> i64 {
+        degrees(deg).unwrap() as i64
+    }
+
+    #[test]
+    fn decimal_and_geo_forms_are_exact() {
+        let id: UvoxId = "27.95,-82.46@12m".parse().unwrap();
+        assert_eq!(id.lat_code, LatCode(code("27.95")));
+        assert_eq!(id.lon_code, LonCode(code("-82.46")));
+        assert_eq!(id.r_um, RUm(EARTH_RADIUS_UM + 12_000_000));
+
+        assert_eq!(id.display(CoordFormat::Decimal).to_string(), "27.95,-82.46@12m");
+        assert_eq!(id.display(CoordFormat::GeoUri).to_string(), "geo:27.95,-82.46,12");
+        assert_eq!("GEO:27.95,-82.46,12;crs=WGS84;u=35".parse::<UvoxId>(), Ok(id));
+        assert_eq!(" 27.95 , -82.46 @ 39.37007874ft ".parse::<UvoxId>().unwrap().r_um, id.r_um);
+
+        // Every code survives a text round trip.
+        let odd = UvoxId::new(RUm(EARTH_RADIUS_UM - 1), LatCode(-1), LonCode(17_999_999_999_999));
+        for format in [CoordFormat::Decimal, CoordFormat::GeoUri, CoordFormat::Hex] {
+            assert_eq!(odd.display(format).to_string().parse::<UvoxId>(), Ok(odd), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn dms_forms() {
+        let id: UvoxId = "27°57'N 82°27'W".parse().unwrap();
+        assert_eq!(id.lat_code, LatCode(code("27.95")));
+        assert_eq!(id.lon_code, LonCode(code("-82.45")));
+
+        let swapped: UvoxId = "W 82° 27′ 0″, N 27° 57′".parse().unwrap();
+        assert_eq!(swapped, id);
+
+        let precise: UvoxId = "33°51'35.9\"S 151°12'40\"E@3m".parse().unwrap();
+        let text = precise.display(CoordFormat::Dms).to_string();
+        assert_eq!(text, "33°51'35.9\"S 151°12'40\"E@3m");
+        assert_eq!(text.parse::<UvoxId>(), Ok(precise));
+
+        assert_eq!("27.5N 82.25W".parse::<UvoxId>().unwrap().lon_code, LonCode(code("-82.25")));
+    }
+
+    #[test]
+    fn errors_are_specific() {
+        use UvoxParseError::*;
+        let err = |s: &str| s.parse::<UvoxId>().unwrap_err();
+
+        assert_eq!(err("  "), Empty);
+        assert_eq!(err("91,0"), LatitudeOutOfRange("91".into()));
+        assert_eq!(err("0,180.5"), LongitudeOutOfRange("180.5".into()));
+        assert_eq!(err("1e3,0"), InvalidNumber("1e3".into()));
+        assert_eq!(err("0,0@5 parsecs"), UnknownUnit("parsecs".into()));
+        assert_eq!(err("27°61'N 82°W"), DmsOutOfRange { part: "minutes", value: "61".into() });
+        assert_eq!(err("27°N 82°S"), ConflictingHemispheres);
+        assert_eq!(err("27°57' 82°27'W"), MissingHemisphere("27°57' 82°27'W".into()));
+        assert_eq!(err("geo:1,2;crs=nad83"), UnsupportedCrs("nad83".into()));
+        assert_eq!(err("hello"), UnrecognizedFormat("hello".into()));
+        assert_eq!(err("world:4/1,2"), WorldQualified(WorldId(4)));
+
+        // Longest accepted decimals overflow the scaled i128 maths
+        let long = "12345678901234567890.123456789012345";
+        assert_eq!(err(&format!("{long},0")), InvalidNumber(long.into()));
+        assert_eq!(err(&format!("0,{long}")), InvalidNumber(long.into()));
+        assert_eq!(err(&format!("0,0@{long}km")), InvalidNumber(format!("{long}km")));
+        let high = "99999999999999999999.999999999999999";
+        assert_eq!(err(&format!("geo:1,2,{high}")), InvalidNumber(high.into()));
+        let dms = format!("{long}°N 82°W");
+        assert_eq!(err(&dms), InvalidNumber(dms.clone()));
+        // Large but representable elevations still fail as too high, not by wrapping
+        assert_eq!(err("0,0@99999999999999m"), InvalidNumber("99999999999999m".into()));
+
+        // Multi-byte characters where the prefixes are checked
+        assert_eq!("10°2′N 3°4′E".parse::<UvoxId>(), "10°2'N 3°4'E".parse::<UvoxId>());
+        assert!("10°2′N 3°4′E".parse::<UvoxId>().is_ok());
+        assert!("1°°".parse::<UvoxId>().is_err());
+        for text in ["é", "géo:1,2", "wörld:1/2,3", "1°2′", "°°°°°°", "ge°:", "world°"] {
+            assert!(text.parse::<UvoxId>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn world_qualified_round_trip() {
+        let q: WorldUvoxId = "world:4/geo:1.5,2.25,-10".parse().unwrap();
+        assert_eq!(q.world_id, WorldId(4));
+        assert_eq!(q.to_string(), "world:4/1.5,2.25@-10m");
+        assert_eq!(q.to_string().parse::<WorldUvoxId>(), Ok(q));
+        assert_eq!("world:x/1,2".parse::<WorldUvoxId>(), Err(UvoxParseError::InvalidWorld("x".into())));
+    }
+}
@@ -34,5 +34,11 @@ pub fn earth_v0() -> WorldEnvDescriptor {
         pressure: Some(PressureModel {
             derive_from_density: true,
         }),
+
+        // Global mean near-surface humidity; inland chloride (S0/S1)
+        climate: Some(ClimateModel {
+            relative_humidity: 0.75,
+            chloride: 3.0,
+        }),
     }
 }
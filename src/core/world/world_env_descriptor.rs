@@ -83,6 +83,17 @@ pub struct TemperatureModel {
     pub lapse_rate_k_per_m: Option<f64>,
 }
 
+/// Optional surface climate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClimateModel {
+    /// Relative humidity (0–1)
+    pub relative_humidity: f64,
+
+    /// Chloride deposition rate, mg/(m²·d) (ISO 9223 S0 below 3,
+    /// coastal sites 60–300)
+    pub chloride: f64,
+}
+
 /// Optional pressure model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PressureModel {
@@ -111,6 +122,7 @@ pub struct WorldEnvDescriptor {
     pub atmosphere: Option<AtmosphereModel>,
     pub temperature: Option<TemperatureModel>,
     pub pressure: Option<PressureModel>,
+    pub climate: Option<ClimateModel>,
 }
 
 impl WorldSpace {
@@ -3,6 +3,7 @@ use crate::core::tdt::sim_duration::SimDuration;
 use crate::core::env::fields::{Field, FieldSample};
 use crate::core::world::world_env_descriptor::{WorldEnvDescriptor, WorldSpace, LandModel};
 use crate::core::env::pressure::PressureField;
+use crate::core::env::climate::ClimateField;
 use crate::core::env::chemistry::OceanChemistry;
 use std::sync::Arc;
 use crate::core::env::land::models::flat::FlatLand;
@@ -63,6 +64,13 @@ impl WorldEnvironment {
         fields.push(atm.clone());
     }
 
+    // ----------------------------------
+    // Climate (humidity, chloride)
+    // ----------------------------------
+    if let Some(climate) = &desc.climate {
+        fields.push(Arc::new(ClimateField::from_model(climate)));
+    }
+
     // ----------------------------------
     // Pressure
     // ----------------------------------
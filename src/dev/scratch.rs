@@ -40,6 +40,7 @@ fn main() {
             entity_id: entity_a,
             world_id,
             t: base_t,
+            seq: 0,
             kind: EventKind::Custom(format!("Install_M{}", month)),
             payload: Some(json!({ "info": format!("EntityA installed month {}", month) })),
         });
@@ -50,6 +51,7 @@ fn main() {
             entity_id: entity_b,
             world_id,
             t: mid,
+            seq: 0,
             kind: EventKind::Custom(format!("Thermal_M{}", month)),
             payload: Some(json!({ "temp": 20.0 + month as f64 })),
         });
@@ -63,6 +65,7 @@ fn main() {
             entity_id: entity_a,
             world_id,
             t,
+            seq: 0,
             kind: EventKind::Custom(format!("DailyCheck_D{}", day)),
             payload: None,
         });
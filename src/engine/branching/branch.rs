@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+use serde_json::json;
+use thiserror::Error;
+
+use crate::core::chronovox::{ChronoEvent, EventKey, Timeline};
+use crate::core::components::material::Material;
+use crate::core::components::spawned_at::SpawnedAt;
+use crate::core::id::EntityId;
+use crate::core::objex::matcat::materials::MatCatId;
+use crate::core::tdt::sim_clock::SimClock;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_time::SimTime;
+use crate::engine::branching::diff::BranchDiff;
+use crate::engine::systems::{SchedulerError, SystemScheduler};
+use crate::engine::world::state::WorldState;
+
+/// Branch index, as carried by `SimulationConfig::branch`. The trunk is 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BranchId(pub u32);
+
+impl BranchId {
+    pub const TRUNK: BranchId = BranchId(0);
+}
+
+impl fmt::Display for BranchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "branch {}", self.0)
+    }
+}
+
+/// Where a branch split off its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkPoint {
+    pub parent: BranchId,
+    pub at: SimTime,
+}
+
+#[derive(Debug, Error)]
+pub enum BranchError {
+    #[error("{0} does not exist")]
+    UnknownBranch(BranchId),
+
+    #[error("cannot fork {branch} at {at:?}: it is already at {now:?}")]
+    ForkInPast {
+        branch: BranchId,
+        at: SimTime,
+        now: SimTime,
+    },
+
+    #[error("entity {0} does not exist in this branch")]
+    UnknownEntity(EntityId),
+
+    #[error(transparent)]
+    Scheduler(#[from] SchedulerError),
+}
+
+/// -------------------------------------------------------------------
+/// Branch — one line of history: its own world, timeline and systems
+/// -------------------------------------------------------------------
+pub struct Branch {
+    pub id: BranchId,
+    pub fork: Option<ForkPoint>,
+    pub world: WorldState,
+    pub timeline: Timeline,
+    scheduler: SystemScheduler,
+}
+
+impl Branch {
+    pub fn sim_time(&self) -> SimTime {
+        self.world.sim_time
+    }
+
+    /// Run the branch's systems up to `until` in steps of `step`.
+    /// Returns how many events were recorded.
+    pub fn advance(&mut self, until: SimTime, step: SimDuration) -> Result<usize, BranchError> {
+        let now = self.world.sim_time;
+        if until <= now {
+            return Ok(0);
+        }
+
+        let mut clock = SimClock::new(now, until, step);
+        let events = self.scheduler.run(&mut self.world, &mut clock)?;
+
+        let count = events.len();
+        for event in events {
+            self.timeline.record(event);
+        }
+        Ok(count)
+    }
+
+    /// Swap an entity for a new one of another material at the current
+    /// time: the material changes, its age restarts and accumulated
+    /// condition (corrosion, thermal history, degradation) is cleared.
+    pub fn replace_material(&mut self, entity: EntityId, matcat: MatCatId) -> Result<EventKey, BranchError> {
+        let now = self.world.sim_time;
+        let store = &mut self.world.entity_store;
+        if !store.spawned_ats.contains_key(&entity) {
+            return Err(BranchError::UnknownEntity(entity));
+        }
+
+        let previous = store.materials.get(&entity).map(|m| m.0);
+        store.add_material(entity, Material(matcat));
+        store.layered_materials.remove(&entity);
+        store.add_spawned_at(entity, SpawnedAt { time: now });
+        store.corrosions.remove(&entity);
+        store.thermals.remove(&entity);
+        store.thermal_exposures.remove(&entity);
+        store.degradations.remove(&entity);
+
+        let event = ChronoEvent::custom(entity, self.world.meta.id, now, "Replace").with_payload(json!({
+            "from": previous,
+            "to": matcat,
+        }));
+        Ok(self.timeline.record(event))
+    }
+}
+
+/// -------------------------------------------------------------------
+/// BranchSet — a trunk simulation and the what-if forks taken from it
+/// -------------------------------------------------------------------
+///
+/// Every branch steps with the same `step` and gets a fresh scheduler
+/// from the same factory, so differences between branches come only
+/// from what was changed after the fork.
+pub struct BranchSet {
+    step: SimDuration,
+    make_scheduler: Box<dyn Fn() -> SystemScheduler + Send + Sync>,
+    branches: BTreeMap<BranchId, Branch>,
+}
+
+impl BranchSet {
+    /// Trunk running the standard systems.
+    pub fn new(trunk: WorldState, step: SimDuration) -> Self {
+        Self::with_systems(trunk, step, SystemScheduler::standard)
+    }
+
+    pub fn with_systems(
+        trunk: WorldState,
+        step: SimDuration,
+        make_scheduler: impl Fn() -> SystemScheduler + Send + Sync + 'static,
+    ) -> Self {
+        let branch = Branch {
+            id: BranchId::TRUNK,
+            fork: None,
+            world: trunk,
+            timeline: Timeline::new(),
+            scheduler: make_scheduler(),
+        };
+
+        Self {
+            step,
+            make_scheduler: Box::new(make_scheduler),
+            branches: BTreeMap::from([(BranchId::TRUNK, branch)]),
+        }
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = BranchId> + '_ {
+        self.branches.keys().copied()
+    }
+
+    pub fn branch(&self, id: BranchId) -> Result<&Branch, BranchError> {
+        self.branches.get(&id).ok_or(BranchError::UnknownBranch(id))
+    }
+
+    pub fn branch_mut(&mut self, id: BranchId) -> Result<&mut Branch, BranchError> {
+        self.branches.get_mut(&id).ok_or(BranchError::UnknownBranch(id))
+    }
+
+    /// Fork `parent` at `at`, first advancing it there if it is behind.
+    /// The new branch starts as an exact copy of the parent at `at`.
+    pub fn fork(&mut self, parent: BranchId, at: SimTime) -> Result<BranchId, BranchError> {
+        let step = self.step;
+        let source = self.branch_mut(parent)?;
+        if source.sim_time() > at {
+            return Err(BranchError::ForkInPast { branch: parent, at, now: source.sim_time() });
+        }
+        source.advance(at, step)?;
+
+        let world = source.world.clone();
+        let timeline = source.timeline.clone();
+
+        let id = BranchId(self.branches.keys().next_back().map_or(0, |last| last.0 + 1));
+        self.branches.insert(id, Branch {
+            id,
+            fork: Some(ForkPoint { parent, at }),
+            world,
+            timeline,
+            scheduler: (self.make_scheduler)(),
+        });
+        Ok(id)
+    }
+
+    pub fn advance(&mut self, id: BranchId, until: SimTime) -> Result<usize, BranchError> {
+        let step = self.step;
+        self.branch_mut(id)?.advance(until, step)
+    }
+
+    /// Bring every branch up to `until`.
+    pub fn advance_all(&mut self, until: SimTime) -> Result<(), BranchError> {
+        let step = self.step;
+        for branch in self.branches.values_mut() {
+            branch.advance(until, step)?;
+        }
+        Ok(())
+    }
+
+    pub fn diff(&self, left: BranchId, right: BranchId) -> Result<BranchDiff, BranchError> {
+        Ok(BranchDiff::between(self.branch(left)?, self.branch(right)?))
+    }
+}
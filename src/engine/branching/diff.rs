@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::core::chronovox::{ChronoEvent, Timeline};
+use crate::core::entity::entity_store::EntityStore;
+use crate::core::id::EntityId;
+use crate::core::tdt::sim_time::SimTime;
+use crate::engine::branching::branch::{Branch, BranchId};
+
+/// -------------------------------------------------------------------
+/// Structured comparison of two branches
+/// -------------------------------------------------------------------
+#[derive(Debug, Clone, Serialize)]
+pub struct BranchDiff {
+    pub left: BranchId,
+    pub right: BranchId,
+    pub left_time: SimTime,
+    pub right_time: SimTime,
+    /// Entities whose components differ, in id order.
+    pub entities: Vec<EntityDiff>,
+    pub events: EventDiff,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityDiff {
+    pub entity_id: EntityId,
+    /// Differing components, by store field name (`"materials"`, ...).
+    pub changes: Vec<ComponentChange>,
+}
+
+/// One component's value on each side; `None` where it is absent.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentChange {
+    pub component: String,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EventDiff {
+    /// Time of the first event that is not shared by both branches.
+    pub diverged_at: Option<SimTime>,
+    pub only_left: Vec<ChronoEvent>,
+    pub only_right: Vec<ChronoEvent>,
+    /// Same key on both sides, different content.
+    pub changed: Vec<(ChronoEvent, ChronoEvent)>,
+}
+
+impl BranchDiff {
+    pub fn between(left: &Branch, right: &Branch) -> Self {
+        Self {
+            left: left.id,
+            right: right.id,
+            left_time: left.sim_time(),
+            right_time: right.sim_time(),
+            entities: diff_stores(&left.world.entity_store, &right.world.entity_store),
+            events: diff_timelines(&left.timeline, &right.timeline),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+            && self.events.only_left.is_empty()
+            && self.events.only_right.is_empty()
+            && self.events.changed.is_empty()
+    }
+
+    pub fn entity(&self, entity_id: EntityId) -> Option<&EntityDiff> {
+        self.entities.iter().find(|e| e.entity_id == entity_id)
+    }
+}
+
+impl EntityDiff {
+    pub fn component(&self, name: &str) -> Option<&ComponentChange> {
+        self.changes.iter().find(|c| c.component == name)
+    }
+}
+
+/// Compare stores component by component via their serialized form, so
+/// new component maps are picked up without touching this code.
+pub fn diff_stores(left: &EntityStore, right: &EntityStore) -> Vec<EntityDiff> {
+    let left = components_by_entity(left);
+    let right = components_by_entity(right);
+
+    let mut ids: Vec<&String> = left.keys().chain(right.keys()).collect();
+    ids.sort();
+    ids.dedup();
+
+    let empty = BTreeMap::new();
+    let mut diffs = Vec::new();
+
+    for id in ids {
+        let l = left.get(id).unwrap_or(&empty);
+        let r = right.get(id).unwrap_or(&empty);
+
+        let mut names: Vec<&String> = l.keys().chain(r.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let changes: Vec<ComponentChange> = names
+            .into_iter()
+            .filter(|name| l.get(*name) != r.get(*name))
+            .map(|name| ComponentChange {
+                component: name.clone(),
+                left: l.get(name).cloned(),
+                right: r.get(name).cloned(),
+            })
+            .collect();
+
+        if changes.is_empty() {
+            continue;
+        }
+        if let Ok(entity_id) = serde_json::from_value(Value::String(id.clone())) {
+            diffs.push(EntityDiff { entity_id, changes });
+        }
+    }
+
+    diffs
+}
+
+/// entity id → component name → value
+fn components_by_entity(store: &EntityStore) -> BTreeMap<String, BTreeMap<String, Value>> {
+    let mut out: BTreeMap<String, BTreeMap<String, Value>> = BTreeMap::new();
+
+    let Ok(Value::Object(components)) = serde_json::to_value(store) else {
+        return out;
+    };
+
+    for (component, by_entity) in components {
+        let Value::Object(by_entity) = by_entity else { continue };
+        for (entity, value) in by_entity {
+            out.entry(entity).or_default().insert(component.clone(), value);
+        }
+    }
+
+    out
+}
+
+/// Merge-walk two key-ordered timelines.
+pub fn diff_timelines(left: &Timeline, right: &Timeline) -> EventDiff {
+    let mut diff = EventDiff::default();
+    let mut l = left.iter().peekable();
+    let mut r = right.iter().peekable();
+
+    loop {
+        match (l.peek(), r.peek()) {
+            (None, None) => break,
+            (Some(a), None) => {
+                diff.only_left.push((*a).clone());
+                l.next();
+            }
+            (None, Some(b)) => {
+                diff.only_right.push((*b).clone());
+                r.next();
+            }
+            (Some(a), Some(b)) => match a.key().cmp(&b.key()) {
+                std::cmp::Ordering::Less => {
+                    diff.only_left.push((*a).clone());
+                    l.next();
+                }
+                std::cmp::Ordering::Greater => {
+                    diff.only_right.push((*b).clone());
+                    r.next();
+                }
+                std::cmp::Ordering::Equal => {
+                    if !a.is_same_event(b) {
+                        diff.changed.push(((*a).clone(), (*b).clone()));
+                    }
+                    l.next();
+                    r.next();
+                }
+            },
+        }
+    }
+
+    diff.diverged_at = [
+        diff.only_left.first().map(|e| e.t),
+        diff.only_right.first().map(|e| e.t),
+        diff.changed.first().map(|(e, _)| e.t),
+    ]
+    .into_iter()
+    .flatten()
+    .min();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::components::geometry::{Length, Radius};
+    use crate::core::components::material::Material;
+    use crate::core::components::spawned_at::SpawnedAt;
+    use crate::core::objex::matcat::materials::MatCatId;
+    use crate::core::tdt::sim_duration::SimDuration;
+    use crate::engine::branching::BranchSet;
+    use crate::engine::world::state::WorldState;
+
+    fn world_with_pipe() -> (WorldState, EntityId) {
+        let mut world = WorldState::flat();
+
+        let pipe = EntityId::new();
+        let store = &mut world.entity_store;
+        store.add_spawned_at(pipe, SpawnedAt { time: SimTime::from_ns(0) });
+        store.add_material(pipe, Material(MatCatId::steel_lowcarbon()));
+        store.add_radius(pipe, Radius(0.0134));
+        store.add_length(pipe, Length(6.0));
+        (world, pipe)
+    }
+
+    #[test]
+    fn identical_branches_have_no_diff() {
+        let (world, _) = world_with_pipe();
+        let mut set = BranchSet::new(world, SimDuration::days(30));
+        let fork = set.fork(BranchId::TRUNK, SimTime::from_ns(0).add_years(2)).unwrap();
+        set.advance_all(SimTime::from_ns(0).add_years(10)).unwrap();
+
+        assert!(set.diff(BranchId::TRUNK, fork).unwrap().is_empty());
+    }
+
+    #[test]
+    fn replacement_branch_diverges_at_the_fork() {
+        let (world, pipe) = world_with_pipe();
+        let fork_at = SimTime::from_ns(0).add_years(3);
+
+        let mut set = BranchSet::new(world, SimDuration::days(30));
+        let pex = set.fork(BranchId::TRUNK, fork_at).unwrap();
+        set.branch_mut(pex).unwrap().replace_material(pipe, MatCatId::category_only(13)).unwrap();
+        set.advance_all(SimTime::from_ns(0).add_years(20)).unwrap();
+
+        let diff = set.diff(BranchId::TRUNK, pex).unwrap();
+        let pipe_diff = diff.entity(pipe).unwrap();
+        assert!(pipe_diff.component("materials").is_some());
+        assert!(pipe_diff.component("degradations").is_some());
+
+        assert_eq!(diff.events.diverged_at, Some(fork_at));
+        assert!(diff.events.only_right.iter().any(|e| e.t == fork_at));
+
+        let trunk = set.branch(BranchId::TRUNK).unwrap();
+        let replaced = set.branch(pex).unwrap();
+        assert_ne!(
+            replaced.world.entity_store.materials[&pipe].0,
+            trunk.world.entity_store.materials[&pipe].0
+        );
+        assert_eq!(replaced.world.entity_store.spawned_ats[&pipe].time, fork_at);
+    }
+
+    #[test]
+    fn forking_in_the_past_is_rejected() {
+        let (world, _) = world_with_pipe();
+        let mut set = BranchSet::new(world, SimDuration::days(30));
+        set.advance(BranchId::TRUNK, SimTime::from_ns(0).add_years(5)).unwrap();
+
+        assert!(set.fork(BranchId::TRUNK, SimTime::from_ns(0).add_years(1)).is_err());
+        assert!(set.fork(BranchId(7), SimTime::from_ns(0).add_years(6)).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod branch;
+pub mod diff;
+
+pub use branch::{Branch, BranchError, BranchId, BranchSet, ForkPoint};
+pub use diff::{BranchDiff, ComponentChange, EntityDiff, EventDiff};
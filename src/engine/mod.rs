@@ -1,5 +1,7 @@
 pub mod world;
 pub mod systems;
+pub mod scenario;
+pub mod branching;
 pub mod generators;
 pub use world::state::*;
 pub mod entity;
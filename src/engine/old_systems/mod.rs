@@ -1,61 +0,0 @@
-use crate::core::chronovox::ChronoEvent;
-use crate::engine::world::WorldState;
-use crate::engine::simulations::simulation::Simulation;
-
-pub trait System: Send + Sync {
-    fn name(&self) -> &'static str;
-    fn tick(&mut self, world: &mut WorldState) -> Vec<ChronoEvent>;
-}
-pub mod movement_system;
-pub use movement_system::*;
-pub mod movement;
-pub use movement::*;
-pub mod acceleration;
-pub use acceleration::*;
-pub mod collision;
-pub use collision::*;
-pub mod gravity;
-pub use gravity::*;
-
-pub mod fracture;
-pub use fracture::*;
-
-pub mod degradation;
-pub use degradation::*;
-pub mod electrical;
-pub use electrical::*;
-pub mod mass;
-pub use mass::*;
-pub mod mechanical;
-pub use mechanical::*;
-pub mod optical;
-pub use optical::*;
-
-pub mod strength;
-pub use strength::*;
-//pub mod thermal;
-//pub use thermal::*;
-pub mod corrosion;
-pub use corrosion::*;
-pub mod solar_motion;
-pub use solar_motion::*;
-//pub mod solar_raycast;
-//pub use solar_raycast::*;
-pub mod solar_exposure;
-pub use solar_exposure::*;
-//pub mod uv_degradation;
-//pub use uv_degradation::UVDegradationSystem;
-//pub mod solar_sky;
-//pub use solar_sky::*;
-
-pub mod sun_damage;
-pub use sun_damage::*;
-
-//pub mod orbital_motion;
-//pub use orbital_motion::*;
-
-pub mod solar_radation;
-pub use solar_radation::*;
-
-pub mod analytical;
-pub use analytical::*;
\ No newline at end of file
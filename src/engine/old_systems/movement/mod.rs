@@ -1,4 +0,0 @@
-pub mod camera;
-
-pub use camera::*;
-
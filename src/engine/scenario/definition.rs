@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Deserializer};
+
+use crate::core::id::WorldId;
+use crate::core::objex::geospec::mesh::Mesh;
+use crate::core::objex::geospec::shape::Shape;
+use crate::core::objex::matcat::layered::LayeredMaterial;
+use crate::core::objex::matcat::materials::MatCatId;
+use crate::core::tdt::sim_clock::SimClock;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_time::SimTime;
+use crate::core::uvoxid::UvoxId;
+
+/// -------------------------------------------------------------------
+/// Scenario — a reproducible simulation run, loaded from JSON or TOML
+/// -------------------------------------------------------------------
+///
+/// Times accept nanoseconds or RFC 3339 (`"2024-01-01T00:00:00Z"`),
+/// the step a unit suffix (`"30d"`, `"1h"`). `seed` feeds entity id
+/// derivation, so the same file and seed always produce the same run.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Scenario {
+    pub world_id: WorldId,
+
+    #[serde(default)]
+    pub seed: u64,
+
+    #[serde(deserialize_with = "parse_str")]
+    pub start: SimTime,
+
+    #[serde(deserialize_with = "parse_str")]
+    pub end: SimTime,
+
+    #[serde(deserialize_with = "parse_str")]
+    pub step: SimDuration,
+
+    /// Reusable objex definitions, referenced by name from `entities`.
+    #[serde(default)]
+    pub templates: BTreeMap<String, ObjexTemplate>,
+
+    #[serde(default)]
+    pub entities: Vec<ScenarioEntity>,
+}
+
+/// Material + geometry, the two halves of an objex.
+///
+/// The material is either a single `matcat` or a `layers` stack; with
+/// both, the stack wins.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjexTemplate {
+    #[serde(default)]
+    pub matcat: Option<MatCatId>,
+    #[serde(default)]
+    pub layers: Option<LayeredMaterial>,
+    #[serde(default)]
+    pub shape: Shape,
+    /// OBJ or STL file, relative to the scenario file, whose mesh
+    /// replaces `shape` when the scenario is loaded.
+    #[serde(default)]
+    pub mesh_file: Option<PathBuf>,
+}
+
+impl ObjexTemplate {
+    /// Catalog identity of the (outermost) material.
+    pub fn material(&self) -> Option<MatCatId> {
+        self.layers.as_ref().map(|l| l.outermost()).or(self.matcat)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioEntity {
+    pub name: String,
+    pub template: String,
+    pub position: UvoxId,
+
+    /// Defaults to the scenario start.
+    #[serde(default, deserialize_with = "parse_str_opt")]
+    pub spawn_at: Option<SimTime>,
+
+    #[serde(default, deserialize_with = "parse_str_opt")]
+    pub despawn_at: Option<SimTime>,
+}
+
+impl Scenario {
+    /// Load a scenario, picking the format from the file extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("reading scenario {}", path.display()))?;
+
+        let mut scenario = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&raw)?,
+            Some("toml") => Self::from_toml_str(&raw)?,
+            _ => bail!("scenario {} must be .json or .toml", path.display()),
+        };
+
+        scenario.load_meshes(path.parent().unwrap_or(Path::new(".")))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    pub fn from_json_str(raw: &str) -> Result<Self> {
+        Ok(serde_json::from_str(raw)?)
+    }
+
+    pub fn from_toml_str(raw: &str) -> Result<Self> {
+        Ok(toml::from_str(raw)?)
+    }
+
+    /// Read every template's `mesh_file` (relative to `base`) into its
+    /// shape.
+    pub fn load_meshes(&mut self, base: &Path) -> Result<()> {
+        for (name, template) in &mut self.templates {
+            if let Some(file) = &template.mesh_file {
+                let mesh = Mesh::load(base.join(file))
+                    .with_context(|| format!("template `{name}`: loading mesh {}", file.display()))?;
+                template.shape = Shape::Mesh(mesh);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.step.as_ns() <= 0 {
+            bail!("scenario step must be positive");
+        }
+        if self.end < self.start {
+            bail!("scenario ends before it starts");
+        }
+        for (name, template) in &self.templates {
+            if template.material().is_none() {
+                bail!("template `{name}` needs a `matcat` or `layers`");
+            }
+            if template.mesh_file.is_some() && !matches!(template.shape, Shape::Mesh(_)) {
+                bail!("template `{name}` has a mesh file that was not loaded");
+            }
+        }
+        for entity in &self.entities {
+            self.template(&entity.template)?;
+        }
+        Ok(())
+    }
+
+    pub fn template(&self, name: &str) -> Result<&ObjexTemplate> {
+        self.templates
+            .get(name)
+            .ok_or_else(|| anyhow!("unknown objex template `{name}`"))
+    }
+
+    pub fn clock(&self) -> SimClock {
+        SimClock::new(self.start, self.end, self.step)
+    }
+}
+
+// ------------------------------------------------------------
+// Serde helpers
+// ------------------------------------------------------------
+
+fn parse_str<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = String::deserialize(d)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+fn parse_str_opt<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(d)?
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
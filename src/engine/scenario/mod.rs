@@ -0,0 +1,5 @@
+pub mod definition;
+pub mod runner;
+
+pub use definition::{ObjexTemplate, Scenario, ScenarioEntity};
+pub use runner::{run_scenario, scenario_entity_id, ScenarioRun};
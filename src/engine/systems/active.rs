@@ -21,3 +21,10 @@ pub fn update_active_markers(
     }
 
 }
+
+/// Active entities in a stable order, so system output is reproducible.
+pub fn active_entities(store: &EntityStore) -> Vec<EntityId> {
+    let mut ids: Vec<EntityId> = store.actives.keys().copied().collect();
+    ids.sort();
+    ids
+}
@@ -0,0 +1,149 @@
+use crate::core::chronovox::{ChronoEvent, EventKind};
+use crate::core::components::condition::CorrosionData;
+use crate::core::env::FieldSample;
+use crate::core::objex::geospec::traits::SurfaceArea;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::engine::systems::active::active_entities;
+use crate::engine::systems::geometry::shape_of;
+use crate::engine::systems::material::material_props_at;
+use crate::engine::systems::system::System;
+use crate::engine::world::state::WorldState;
+
+use serde_json::json;
+use serde::{Serialize, Deserialize};
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 86_400.0;
+
+/// Used when an entity has no `Thickness` component (1 cm steel).
+const REFERENCE_THICKNESS_M: f64 = 0.01;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct CorrosionSystem;
+
+impl CorrosionSystem {
+    /// Convert meters to millimeters
+    fn m_to_mm(m: f64) -> f64 {
+        m * 1000.0
+    }
+
+    /// Humidity and temperature dependence of atmospheric corrosion, from
+    /// the ISO 9223:2012 dose-response function for carbon steel
+    /// (`exp(0.020·RH + f_st)`, RH in %), relative to the reference
+    /// conditions of `MatProps` (20 °C, dry air). Chloride acts through
+    /// the material's corrosion-resistance curve instead. A missing
+    /// temperature field reads as 20 °C.
+    fn env_factor(env: &FieldSample) -> f64 {
+        let f_st = |t_c: f64| if t_c <= 10.0 { 0.150 * (t_c - 10.0) } else { -0.054 * (t_c - 10.0) };
+        let t_c = if env.temperature > 0.0 { env.temperature - 273.15 } else { 20.0 };
+        let rh_pct = 100.0 * env.relative_humidity.clamp(0.0, 1.0);
+        (0.020 * rh_pct + f_st(t_c) - f_st(20.0)).exp()
+    }
+
+    /// Compute corrosion rate (m/year)
+    fn rate_m_per_year(resistance: f64, env_factor: f64) -> f64 {
+        (1.0 - resistance) * 1e-4 * env_factor
+    }
+}
+
+impl System for CorrosionSystem {
+    fn name(&self) -> &'static str { "CorrosionSystem" }
+
+    fn tick(&mut self, world: &mut WorldState, dt: SimDuration) -> Vec<ChronoEvent> {
+        let mut events = vec![];
+
+        let now = world.sim_time;
+        let world_id = world.meta.id;
+        let dt_years = dt.seconds_f64() / SECONDS_PER_YEAR;
+        let environment = &world.environment;
+        let store = &mut world.entity_store;
+
+        for id in active_entities(store) {
+            //---------------------------------------------------------
+            // Material + geometry come from components; corrosion
+            // resistance depends on humidity and chloride at the position
+            //---------------------------------------------------------
+            let sample = store
+                .positions
+                .get(&id)
+                .map(|p| environment.sample(&p.0, SimDuration::from_ns(now.as_ns())))
+                .unwrap_or_default();
+            let Some(mat_props) = material_props_at(store, id, &sample) else { continue };
+            let Some(shape) = shape_of(store, id) else { continue };
+
+            let reference_thickness = store
+                .thicknesses
+                .get(&id)
+                .map(|t| t.0)
+                .unwrap_or(REFERENCE_THICKNESS_M);
+
+            //---------------------------------------------------------
+            // Compute corrosion rate
+            //---------------------------------------------------------
+            let env_factor = Self::env_factor(&sample);
+            let rate = Self::rate_m_per_year(
+                mat_props.corrosion_resistance as f64,
+                env_factor,
+            );
+
+            //---------------------------------------------------------
+            // Initialize/update component
+            //---------------------------------------------------------
+            let entry = store.corrosions.entry(id).or_insert(CorrosionData {
+                surface_area: shape.surface_area(),
+                thickness_loss: 0.0,
+                rate,
+                environment_factor: env_factor as f32,
+                severity: 0.0,
+            });
+
+            // Already failed: nothing left to report
+            if entry.severity >= 1.0 {
+                continue;
+            }
+
+            entry.rate = rate;
+            entry.environment_factor = env_factor as f32;
+            entry.thickness_loss += rate * dt_years;
+            entry.severity = (entry.thickness_loss / reference_thickness).min(1.0);
+
+            let loss_mm = Self::m_to_mm(entry.thickness_loss);
+
+            //---------------------------------------------------------
+            // Choose event type
+            //---------------------------------------------------------
+            let (event_name, details) = if entry.severity >= 1.0 {
+                (
+                    "CorrosionFailure",
+                    json!({
+                        "rate_m_per_year": entry.rate,
+                        "severity": entry.severity,
+                        "reference_thickness_m": reference_thickness
+                    })
+                )
+            } else {
+                (
+                    "CorrosionProgress",
+                    json!({
+                        "thickness_loss_m": entry.thickness_loss,
+                        "thickness_loss_mm": loss_mm,
+                        "severity": entry.severity
+                    })
+                )
+            };
+
+            //---------------------------------------------------------
+            // Emit event
+            //---------------------------------------------------------
+            events.push(
+                ChronoEvent::new(id, world_id, now, EventKind::Custom(event_name.into()))
+                    .with_payload(json!({
+                        "surface_area_m2": entry.surface_area,
+                        "env_factor": entry.environment_factor,
+                        "details": details
+                    }))
+            );
+        }
+
+        events
+    }
+}
@@ -0,0 +1,109 @@
+use crate::core::chronovox::{ChronoEvent, EventKind};
+use crate::core::components::condition::DegradationData;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::engine::systems::active::active_entities;
+use crate::engine::systems::material::material_props;
+use crate::engine::systems::system::System;
+use crate::engine::world::state::WorldState;
+
+use serde_json::json;
+use serde::{Serialize, Deserialize};
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 86_400.0;
+
+/// Service life of a material with fatigue resistance 1.0.
+const BASE_LIFESPAN_YEARS: f64 = 50.0;
+
+/// Fraction of the melting point where thermal damage begins.
+const THERMAL_ONSET: f64 = 0.4;
+
+/// Folds corrosion, age and overheating into a single integrity value.
+/// Runs after the systems whose components it reads.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct DegradationSystem;
+
+impl System for DegradationSystem {
+    fn name(&self) -> &'static str {
+        "DegradationSystem"
+    }
+
+    fn after(&self) -> &'static [&'static str] {
+        &["CorrosionSystem", "ThermalSystem"]
+    }
+
+    fn tick(&mut self, world: &mut WorldState, dt: SimDuration) -> Vec<ChronoEvent> {
+        let mut events = Vec::new();
+
+        let now = world.sim_time;
+        let world_id = world.meta.id;
+        let dt_years = dt.seconds_f64() / SECONDS_PER_YEAR;
+        let store = &mut world.entity_store;
+
+        for id in active_entities(store) {
+            let Some(mat) = material_props(store, id) else { continue };
+
+            //---------------------------------------------------------
+            // Inputs from other condition components
+            //---------------------------------------------------------
+            let corrosion = store.corrosions.get(&id).map(|c| c.severity).unwrap_or(0.0);
+
+            let age_years = store
+                .spawned_ats
+                .get(&id)
+                .map(|s| (now - s.time).seconds_f64() / SECONDS_PER_YEAR)
+                .unwrap_or(0.0)
+                .max(0.0);
+            let lifespan_years = BASE_LIFESPAN_YEARS * (mat.fatigue_resistance as f64).max(0.01);
+            let fatigue = (age_years / lifespan_years).min(1.0);
+
+            let melting_c = mat.melting_point as f64;
+            let overheat = store
+                .thermals
+                .get(&id)
+                .filter(|_| melting_c > 0.0)
+                .map(|t| {
+                    let onset = THERMAL_ONSET * melting_c;
+                    ((t.temperature_c - onset) / (melting_c - onset)).clamp(0.0, 1.0)
+                })
+                .unwrap_or(0.0);
+
+            //---------------------------------------------------------
+            // Update component (thermal damage never heals)
+            //---------------------------------------------------------
+            let entry = store.degradations.entry(id).or_insert(DegradationData {
+                corrosion: 0.0,
+                fatigue: 0.0,
+                thermal: 0.0,
+                total_integrity: 1.0,
+            });
+
+            let previous = entry.total_integrity;
+            entry.corrosion = corrosion;
+            entry.fatigue = fatigue;
+            entry.thermal = entry.thermal.max(overheat);
+            entry.total_integrity =
+                ((1.0 - entry.corrosion) * (1.0 - entry.fatigue) * (1.0 - entry.thermal))
+                    .clamp(0.0, 1.0);
+
+            let loss = previous - entry.total_integrity;
+            if loss <= 0.0 || dt_years <= 0.0 {
+                continue;
+            }
+
+            //---------------------------------------------------------
+            // Emit event (rate = integrity lost per year)
+            //---------------------------------------------------------
+            events.push(
+                ChronoEvent::new(id, world_id, now, EventKind::Degrade { rate: loss / dt_years })
+                    .with_payload(json!({
+                        "total_integrity": entry.total_integrity,
+                        "corrosion": entry.corrosion,
+                        "fatigue": entry.fatigue,
+                        "thermal": entry.thermal,
+                    }))
+            );
+        }
+
+        events
+    }
+}
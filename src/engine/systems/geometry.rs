@@ -0,0 +1,69 @@
+use crate::core::EntityId;
+use crate::core::components::geometry::{Height, Length, MeshGeometry, Radius, Width};
+use crate::core::objex::geospec::primitives::{BoxShape, Cylinder, Line, Plane, Sphere};
+use crate::core::objex::geospec::shape::Shape;
+use crate::shared::entities::entity_store::EntityStore;
+
+/// Rebuild a primitive shape from an entity's dimension components.
+///
+/// - radius + length (or height) → cylinder
+/// - radius alone                → sphere
+/// - length + width + height     → box (thickness stands in for height)
+/// - length + width              → plane
+/// - length alone                → line
+///
+/// A `MeshGeometry` component takes precedence over dimensions.
+pub fn shape_of(store: &EntityStore, entity: EntityId) -> Option<Shape> {
+    if let Some(mesh) = store.meshes.get(&entity) {
+        return Some(Shape::Mesh(mesh.0.clone()));
+    }
+    let radius = store.radii.get(&entity).map(|r| r.0);
+    let length = store.lengths.get(&entity).map(|l| l.0);
+    let width = store.widths.get(&entity).map(|w| w.0);
+    let height = store
+        .heights
+        .get(&entity)
+        .map(|h| h.0)
+        .or_else(|| store.thicknesses.get(&entity).map(|t| t.0));
+
+    let shape = match (radius, length, width, height) {
+        (Some(radius), Some(height), _, _) | (Some(radius), None, _, Some(height)) => {
+            Shape::Cylinder(Cylinder { radius, height })
+        }
+        (Some(radius), None, _, None) => Shape::Sphere(Sphere { radius }),
+        (None, Some(length), Some(width), Some(height)) => {
+            Shape::Box(BoxShape { length, width, height })
+        }
+        (None, Some(length), Some(width), None) => Shape::Plane(Plane { width, height: length }),
+        (None, Some(length), None, _) => Shape::Line(Line { length }),
+        _ => return None,
+    };
+
+    Some(shape)
+}
+
+/// Write a primitive shape onto an entity as dimension components,
+/// the inverse of [`shape_of`]; meshes are stored whole. Returns
+/// `false` for shapes that have no component form (points and cones).
+pub fn set_shape(store: &mut EntityStore, entity: EntityId, shape: &Shape) -> bool {
+    match shape {
+        Shape::Sphere(s) => store.add_radius(entity, Radius(s.radius)),
+        Shape::Cylinder(c) => {
+            store.add_radius(entity, Radius(c.radius));
+            store.add_length(entity, Length(c.height));
+        }
+        Shape::Box(b) => {
+            store.add_length(entity, Length(b.length));
+            store.add_width(entity, Width(b.width));
+            store.add_height(entity, Height(b.height));
+        }
+        Shape::Plane(p) => {
+            store.add_length(entity, Length(p.height));
+            store.add_width(entity, Width(p.width));
+        }
+        Shape::Line(l) => store.add_length(entity, Length(l.length)),
+        Shape::Mesh(m) => store.add_mesh(entity, MeshGeometry(m.clone())),
+        Shape::Point(_) | Shape::Cone(_) => return false,
+    }
+    true
+}
@@ -0,0 +1,46 @@
+use crate::core::EntityId;
+use crate::core::env::FieldSample;
+use crate::core::objex::matcat::curves::props_at;
+use crate::core::objex::matcat::materials::props_for;
+use crate::core::objex::matcat::properties::MatProps;
+use crate::shared::entities::entity_store::EntityStore;
+
+/// Resolve the material properties of an entity.
+///
+/// Starts from the catalog entry of its `Material` component; explicit
+/// per-entity components (density, conductivity, hardness) win over the
+/// catalog values. Entities with `Layers` use the laminate's effective
+/// properties instead. Entities without a `Material` have no properties.
+pub fn material_props(store: &EntityStore, entity: EntityId) -> Option<MatProps> {
+    let material = store.materials.get(&entity)?;
+    let props = match store.layered_materials.get(&entity) {
+        Some(layers) => layers.0.effective_props(),
+        None => props_for(&material.0),
+    };
+    Some(with_overrides(store, entity, props))
+}
+
+/// Like `material_props`, with the catalog values evaluated under the
+/// conditions in `env` (temperature, humidity, chloride).
+pub fn material_props_at(store: &EntityStore, entity: EntityId, env: &FieldSample) -> Option<MatProps> {
+    let material = store.materials.get(&entity)?;
+    let props = match store.layered_materials.get(&entity) {
+        Some(layers) => layers.0.effective_props_at(env),
+        None => props_at(&material.0, env),
+    };
+    Some(with_overrides(store, entity, props))
+}
+
+fn with_overrides(store: &EntityStore, entity: EntityId, mut props: MatProps) -> MatProps {
+    if let Some(density) = store.densities.get(&entity) {
+        props.density = density.0 as f32;
+    }
+    if let Some(conductivity) = store.conductivities.get(&entity) {
+        props.thermal_conductivity = conductivity.0 as f32;
+    }
+    if let Some(hardness) = store.hardnesses.get(&entity) {
+        props.hardness = hardness.0 as f32;
+    }
+
+    props
+}
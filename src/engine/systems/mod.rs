@@ -1,4 +1,15 @@
 pub mod lifecycle;
 pub mod active;
 pub mod material;
-pub mod geometry;
\ No newline at end of file
+pub mod geometry;
+pub mod system;
+pub mod scheduler;
+pub mod corrosion;
+pub mod thermal;
+pub mod degradation;
+
+pub use system::System;
+pub use scheduler::{SystemScheduler, SchedulerError};
+pub use corrosion::CorrosionSystem;
+pub use thermal::ThermalSystem;
+pub use degradation::DegradationSystem;
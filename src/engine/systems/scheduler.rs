@@ -0,0 +1,533 @@
+use std::collections::{BTreeMap, HashMap};
+
+use thiserror::Error;
+
+use crate::core::chronovox::{ChronoEvent, Timeline};
+use crate::core::tdt::sim_clock::SimClock;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::core::tdt::sim_time::SimTime;
+use crate::engine::systems::active::update_active_markers;
+use crate::engine::systems::system::System;
+use crate::engine::systems::{CorrosionSystem, DegradationSystem, ThermalSystem};
+use crate::engine::world::state::WorldState;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SchedulerError {
+    #[error("system `{0}` is registered more than once")]
+    DuplicateSystem(&'static str),
+
+    #[error("system `{system}` runs after unknown system `{dependency}`")]
+    UnknownDependency {
+        system: &'static str,
+        dependency: &'static str,
+    },
+
+    #[error("dependency cycle between systems: {0:?}")]
+    Cycle(Vec<&'static str>),
+
+    #[error("clock step must be positive")]
+    NonPositiveStep,
+
+    #[error("sub-step set for unknown system `{0}`")]
+    UnknownSystem(&'static str),
+}
+
+/// -------------------------------------------------------------------
+/// Runs registered systems over a `WorldState` in dependency order.
+/// -------------------------------------------------------------------
+///
+/// Each tick advances `world.sim_time` by `dt`, refreshes the lifecycle
+/// `Active` markers and the spatial index, then runs every system once
+/// and re-indexes any positions they moved. The tick's events are
+/// stamped with `seq` in the order they were emitted.
+/// Ties between independent systems keep registration order, so the
+/// schedule (and the event stream) is deterministic.
+///
+/// Systems given a sub-step (`with_substep`) run several times inside a
+/// longer tick, so a fast process can be resolved while the rest of the
+/// world takes long steps.
+#[derive(Default)]
+pub struct SystemScheduler {
+    systems: Vec<Box<dyn System>>,
+    order: Option<Vec<usize>>,
+    substeps: BTreeMap<&'static str, SimDuration>,
+}
+
+impl SystemScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scheduler preloaded with the material condition systems.
+    pub fn standard() -> Self {
+        Self::new()
+            .with_system(CorrosionSystem)
+            .with_system(ThermalSystem)
+            .with_system(DegradationSystem)
+    }
+
+    pub fn with_system(mut self, system: impl System + 'static) -> Self {
+        self.add(Box::new(system));
+        self
+    }
+
+    pub fn add(&mut self, system: Box<dyn System>) {
+        self.systems.push(system);
+        self.order = None;
+    }
+
+    /// Run the system called `name` in steps of at most `dt` within each
+    /// tick.
+    pub fn with_substep(mut self, name: &'static str, dt: SimDuration) -> Self {
+        self.set_substep(name, dt);
+        self
+    }
+
+    pub fn set_substep(&mut self, name: &'static str, dt: SimDuration) {
+        self.substeps.insert(name, dt);
+        self.order = None;
+    }
+
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// System names in the order they will run.
+    pub fn order(&mut self) -> Result<Vec<&'static str>, SchedulerError> {
+        let order = self.resolve()?.to_vec();
+        Ok(order.into_iter().map(|i| self.systems[i].name()).collect())
+    }
+
+    /// Advance the world by one step of length `dt`.
+    pub fn tick(
+        &mut self,
+        world: &mut WorldState,
+        dt: SimDuration,
+    ) -> Result<Vec<ChronoEvent>, SchedulerError> {
+        let order = self.resolve()?.to_vec();
+
+        world.sim_time = world.sim_time + dt;
+        update_active_markers(&mut world.entity_store, world.sim_time);
+        world.refresh_spatial_index();
+
+        let mut events = Vec::new();
+        for i in order {
+            match self.substeps.get(self.systems[i].name()) {
+                Some(&sub) if dt > sub => events.extend(self.substep(i, world, dt, sub)),
+                _ => events.extend(self.systems[i].tick(world, dt)),
+            }
+        }
+
+        // Number this tick's events in run order per instant, so
+        // (t, seq, entity) identifies each one and replays sort them the
+        // same way.
+        let mut next_seq: HashMap<SimTime, u64> = HashMap::new();
+        for event in events.iter_mut() {
+            let seq = next_seq.entry(event.t).or_default();
+            event.seq = *seq;
+            *seq += 1;
+        }
+        events.sort_by_key(|e| e.key());
+
+        world.refresh_spatial_index();
+
+        Ok(events)
+    }
+
+    /// Run system `i` over the tick ending at `world.sim_time` in equal
+    /// pieces no longer than `sub`, with the world clock at the end of
+    /// each piece.
+    fn substep(&mut self, i: usize, world: &mut WorldState, dt: SimDuration, sub: SimDuration) -> Vec<ChronoEvent> {
+        let end = world.sim_time;
+        let start = end - dt;
+        let pieces = (dt.as_ns() + sub.as_ns() - 1) / sub.as_ns();
+
+        let mut events = Vec::new();
+        let mut t = start;
+        for k in 1..=pieces {
+            let next = start.add_ns(dt.as_ns() * k / pieces);
+            world.sim_time = next;
+            events.extend(self.systems[i].tick(world, next - t));
+            t = next;
+        }
+        world.sim_time = end;
+        events
+    }
+
+    /// Limit the clock's next step to what every system accepts.
+    fn request_steps(&self, world: &WorldState, clock: &mut SimClock) {
+        for system in &self.systems {
+            if let Some(dt) = system.max_step(world) {
+                clock.request_step(dt);
+            }
+        }
+    }
+
+    /// Drive the world with `clock` until it is finished.
+    /// The world is first moved to `clock.current`.
+    pub fn run(
+        &mut self,
+        world: &mut WorldState,
+        clock: &mut SimClock,
+    ) -> Result<Vec<ChronoEvent>, SchedulerError> {
+        if clock.step.as_ns() <= 0 {
+            return Err(SchedulerError::NonPositiveStep);
+        }
+
+        world.sim_time = clock.current;
+
+        let mut events = Vec::new();
+        loop {
+            self.request_steps(world, clock);
+            if !clock.advance() {
+                break;
+            }
+            let dt = clock.current - world.sim_time;
+            events.extend(self.tick(world, dt)?);
+        }
+
+        Ok(events)
+    }
+
+    /// Like `run`, but also stops at every event already scheduled in
+    /// `timeline` (e.g. maintenance) and records each tick's events into
+    /// it. With `SimClock::event_driven` the world jumps from one
+    /// scheduled event to the next. Returns how many events were
+    /// recorded.
+    pub fn run_timeline(
+        &mut self,
+        world: &mut WorldState,
+        clock: &mut SimClock,
+        timeline: &mut Timeline,
+    ) -> Result<usize, SchedulerError> {
+        if clock.step.as_ns() <= 0 {
+            return Err(SchedulerError::NonPositiveStep);
+        }
+
+        world.sim_time = clock.current;
+
+        let mut recorded = 0;
+        loop {
+            clock.wake_for_next_event(timeline);
+            self.request_steps(world, clock);
+            if !clock.advance() {
+                break;
+            }
+            let dt = clock.current - world.sim_time;
+            for event in self.tick(world, dt)? {
+                timeline.record(event);
+                recorded += 1;
+            }
+        }
+
+        Ok(recorded)
+    }
+
+    // ------------------------------------------------------------
+    // Ordering
+    // ------------------------------------------------------------
+
+    fn resolve(&mut self) -> Result<&[usize], SchedulerError> {
+        if self.order.is_none() {
+            self.order = Some(self.topological_order()?);
+        }
+        Ok(self.order.as_deref().unwrap_or(&[]))
+    }
+
+    /// Kahn's algorithm; the ready set is always drained lowest index first.
+    fn topological_order(&self) -> Result<Vec<usize>, SchedulerError> {
+        let mut index: HashMap<&'static str, usize> = HashMap::new();
+        for (i, system) in self.systems.iter().enumerate() {
+            if index.insert(system.name(), i).is_some() {
+                return Err(SchedulerError::DuplicateSystem(system.name()));
+            }
+        }
+
+        for (&name, dt) in &self.substeps {
+            if !index.contains_key(name) {
+                return Err(SchedulerError::UnknownSystem(name));
+            }
+            if dt.as_ns() <= 0 {
+                return Err(SchedulerError::NonPositiveStep);
+            }
+        }
+
+        let n = self.systems.len();
+        let mut indegree = vec![0usize; n];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+        for (i, system) in self.systems.iter().enumerate() {
+            for &dependency in system.after() {
+                let &d = index.get(dependency).ok_or(SchedulerError::UnknownDependency {
+                    system: system.name(),
+                    dependency,
+                })?;
+                dependents[d].push(i);
+                indegree[i] += 1;
+            }
+        }
+
+        let mut order = Vec::with_capacity(n);
+        let mut done = vec![false; n];
+
+        while order.len() < n {
+            let Some(next) = (0..n).find(|&i| !done[i] && indegree[i] == 0) else {
+                let stuck = (0..n)
+                    .filter(|&i| !done[i])
+                    .map(|i| self.systems[i].name())
+                    .collect();
+                return Err(SchedulerError::Cycle(stuck));
+            };
+
+            done[next] = true;
+            order.push(next);
+            for &dependent in &dependents[next] {
+                indegree[dependent] -= 1;
+            }
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::chronovox::EventKind;
+    use crate::core::components::condition::{Sunlight, ThermalData};
+    use crate::core::components::geometry::{Length, Radius};
+    use crate::core::components::material::Material;
+    use crate::core::components::spawned_at::SpawnedAt;
+    use crate::core::objex::matcat::materials::MatCatId;
+    use crate::core::tdt::SimTime;
+    use crate::core::{EntityId, WorldId};
+    use super::*;
+    use crate::engine::systems::System;
+    use std::sync::{Arc, Mutex};
+    use crate::core::components::spatial::position::Position;
+    use crate::core::env::{ClimateField, Field, TemperatureField};
+    use crate::core::uvoxid::UvoxId;
+    use crate::core::world::world_env_descriptor::ClimateModel;
+    use crate::engine::systems::corrosion::CorrosionSystem;
+
+    fn spawn_pipe(world: &mut WorldState) -> EntityId {
+        let id = EntityId::new();
+        let store = &mut world.entity_store;
+        store.add_spawned_at(id, SpawnedAt { time: SimTime::from_ns(0) });
+        store.add_material(id, Material(MatCatId::steel_lowcarbon()));
+        store.add_radius(id, Radius(0.01));
+        store.add_length(id, Length(2.0));
+        id
+    }
+
+    fn one_year_clock() -> SimClock {
+        SimClock::new(SimTime::from_ns(0), SimTime::from_ns(0).add_days(360), SimDuration::days(30))
+    }
+
+    struct Named(&'static str, &'static [&'static str]);
+
+    impl System for Named {
+        fn name(&self) -> &'static str { self.0 }
+        fn after(&self) -> &'static [&'static str] { self.1 }
+        fn tick(&mut self, _world: &mut WorldState, _dt: SimDuration) -> Vec<ChronoEvent> { vec![] }
+    }
+
+    #[test]
+    fn dependencies_run_first() {
+        let mut scheduler = SystemScheduler::new()
+            .with_system(Named("c", &["a", "b"]))
+            .with_system(Named("b", &[]))
+            .with_system(Named("a", &["b"]));
+
+        assert_eq!(scheduler.order().unwrap(), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn cycles_and_unknown_dependencies_are_rejected() {
+        let mut cyclic = SystemScheduler::new()
+            .with_system(Named("a", &["b"]))
+            .with_system(Named("b", &["a"]));
+        assert!(matches!(cyclic.order(), Err(SchedulerError::Cycle(_))));
+
+        let mut dangling = SystemScheduler::new().with_system(Named("a", &["missing"]));
+        assert_eq!(
+            dangling.order(),
+            Err(SchedulerError::UnknownDependency { system: "a", dependency: "missing" })
+        );
+    }
+
+    /// Records the instant and length of every tick it gets.
+    struct Probe {
+        name: &'static str,
+        max_step: Option<SimDuration>,
+        ticks: Arc<Mutex<Vec<(SimTime, SimDuration)>>>,
+    }
+
+    impl Probe {
+        fn new(name: &'static str, max_step: Option<SimDuration>) -> (Self, Arc<Mutex<Vec<(SimTime, SimDuration)>>>) {
+            let ticks = Arc::new(Mutex::new(Vec::new()));
+            (Self { name, max_step, ticks: ticks.clone() }, ticks)
+        }
+    }
+
+    impl System for Probe {
+        fn name(&self) -> &'static str { self.name }
+        fn max_step(&self, _world: &WorldState) -> Option<SimDuration> { self.max_step }
+        fn tick(&mut self, world: &mut WorldState, dt: SimDuration) -> Vec<ChronoEvent> {
+            self.ticks.lock().unwrap().push((world.sim_time, dt));
+            vec![]
+        }
+    }
+
+    #[test]
+    fn adaptive_and_multi_rate_steps() {
+        let mut world = WorldState::flat();
+        let (slow, slow_ticks) = Probe::new("slow", None);
+        let (fast, fast_ticks) = Probe::new("fast", None);
+        let mut scheduler = SystemScheduler::new()
+            .with_system(slow)
+            .with_system(fast)
+            .with_substep("fast", SimDuration::days(1));
+
+        scheduler.run(&mut world, &mut one_year_clock()).unwrap();
+        assert_eq!(slow_ticks.lock().unwrap().len(), 12);
+        let fast_ticks = fast_ticks.lock().unwrap();
+        assert_eq!(fast_ticks.len(), 360);
+        assert!(fast_ticks.iter().all(|&(_, dt)| dt == SimDuration::days(1)));
+        assert_eq!(fast_ticks.last().unwrap().0, SimTime::from_ns(0).add_days(360));
+
+        // A system that needs short steps shortens them for everyone.
+        let mut world = WorldState::flat();
+        let (picky, _) = Probe::new("picky", Some(SimDuration::days(10)));
+        let (other, other_ticks) = Probe::new("other", None);
+        let mut scheduler = SystemScheduler::new().with_system(picky).with_system(other);
+        scheduler.run(&mut world, &mut one_year_clock()).unwrap();
+        assert_eq!(other_ticks.lock().unwrap().len(), 36);
+
+        let mut unknown = SystemScheduler::new().with_substep("nobody", SimDuration::days(1));
+        assert_eq!(unknown.order(), Err(SchedulerError::UnknownSystem("nobody")));
+    }
+
+    #[test]
+    fn event_driven_clock_jumps_between_scheduled_events() {
+        let mut world = WorldState::flat();
+        let (probe, ticks) = Probe::new("probe", None);
+        let mut scheduler = SystemScheduler::new().with_system(probe);
+
+        let t0 = SimTime::from_ns(0);
+        let mut timeline = Timeline::new();
+        for day in [10, 100] {
+            timeline.record(ChronoEvent::custom(EntityId::new(), WorldId(1), t0.add_days(day), "maintenance"));
+        }
+
+        let mut clock = SimClock::event_driven(t0, t0.add_days(365));
+        scheduler.run_timeline(&mut world, &mut clock, &mut timeline).unwrap();
+
+        let times: Vec<SimTime> = ticks.lock().unwrap().iter().map(|&(t, _)| t).collect();
+        assert_eq!(times, vec![t0.add_days(10), t0.add_days(100), t0.add_days(365)]);
+        assert_eq!(clock.last_step(), SimDuration::days(265));
+    }
+
+    #[test]
+    fn standard_systems_advance_condition() {
+        let mut world = WorldState::flat();
+        let pipe = spawn_pipe(&mut world);
+        let mut scheduler = SystemScheduler::standard();
+
+        let events = scheduler.run(&mut world, &mut one_year_clock()).unwrap();
+
+        assert_eq!(world.sim_time, SimTime::from_ns(0).add_days(360));
+        assert!(world.entity_store.corrosions[&pipe].thickness_loss > 0.0);
+        assert!(world.entity_store.degradations[&pipe].total_integrity < 1.0);
+        assert!(events.iter().any(|e| matches!(e.kind, EventKind::Degrade { .. })));
+        assert!(events.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    /// Uniform humidity and temperature, on top of the descriptor's fields.
+    struct Climate { relative_humidity: f64, temperature: f64 }
+
+    impl Climate {
+        fn fields(&self) -> [Arc<dyn Field>; 2] {
+            let model = ClimateModel { relative_humidity: self.relative_humidity, chloride: 0.0 };
+            [
+                Arc::new(ClimateField::from_model(&model)),
+                Arc::new(TemperatureField { surface_temp_k: self.temperature, lapse_rate_k_per_m: 0.0, surface_radius_m: 0.0 }),
+            ]
+        }
+    }
+
+    #[test]
+    fn corrosion_follows_the_environment() {
+        let loss_in = |climate: Option<Climate>| {
+            let mut world = WorldState::flat();
+            if let Some(climate) = climate {
+                world.environment.fields.extend(climate.fields());
+            }
+            let pipe = spawn_pipe(&mut world);
+            world.entity_store.add_position(pipe, Position(UvoxId::default()));
+            let mut scheduler = SystemScheduler::new().with_system(CorrosionSystem);
+            scheduler.run(&mut world, &mut one_year_clock()).unwrap();
+            let corrosion = &world.entity_store.corrosions[&pipe];
+            (corrosion.thickness_loss, corrosion.environment_factor)
+        };
+
+        // Dry air at 20 °C is the reference
+        let (dry, factor) = loss_in(Some(Climate { relative_humidity: 0.0, temperature: 293.15 }));
+        assert_eq!(factor, 1.0);
+        assert_eq!(loss_in(None).0, dry);
+
+        let (humid, factor) = loss_in(Some(Climate { relative_humidity: 0.9, temperature: 293.15 }));
+        assert!(humid > 2.0 * dry && factor > 1.0);
+        let (cold, _) = loss_in(Some(Climate { relative_humidity: 0.9, temperature: 268.15 }));
+        assert!(cold < humid);
+    }
+
+    #[test]
+    fn thermal_relaxes_toward_ambient() {
+        let mut world = WorldState::flat();
+        let pipe = spawn_pipe(&mut world);
+        world.entity_store.thermals.insert(pipe, ThermalData {
+            temperature_c: 90.0,
+            heat_capacity_j_per_kg_k: 500.0,
+            absorptivity: 0.7,
+            mass_kg: 5.0,
+        });
+
+        let mut scheduler = SystemScheduler::standard();
+        scheduler.tick(&mut world, SimDuration::hours(1)).unwrap();
+        let after_hour = world.entity_store.thermals[&pipe].temperature_c;
+        scheduler.tick(&mut world, SimDuration::days(30)).unwrap();
+        let after_month = world.entity_store.thermals[&pipe].temperature_c;
+
+        assert!(after_hour < 90.0 && after_hour > after_month);
+        assert!((after_month - 15.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sunlight_settles_where_absorption_balances_losses() {
+        let mut world = WorldState::flat();
+        let pipe = spawn_pipe(&mut world);
+        world.entity_store.thermals.insert(pipe, ThermalData {
+            temperature_c: 15.0,
+            heat_capacity_j_per_kg_k: 500.0,
+            absorptivity: 0.7,
+            mass_kg: 5.0,
+        });
+        world.entity_store.add_sunlight(pipe, Sunlight { irradiance_w_m2: 800.0 });
+
+        let mut scheduler = SystemScheduler::new().with_system(ThermalSystem);
+        for _ in 0..10 {
+            scheduler.tick(&mut world, SimDuration::days(1)).unwrap();
+        }
+
+        // 560 W/m² absorbed = convection + radiation at the settled temperature
+        let t_c = world.entity_store.thermals[&pipe].temperature_c;
+        let (t_k, amb_k) = (t_c + 273.15, 288.15_f64);
+        let losses = 10.0 * (t_c - 15.0) + 0.9 * 5.670_374e-8 * (t_k.powi(4) - amb_k.powi(4));
+        assert!(t_c > 30.0 && (losses - 560.0).abs() < 1e-3);
+        assert!(world.entity_store.thermal_exposures[&pipe].total_energy_j > 0.0);
+    }
+}
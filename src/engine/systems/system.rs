@@ -0,0 +1,29 @@
+use crate::core::chronovox::ChronoEvent;
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::engine::world::state::WorldState;
+
+/// -------------------------------------------------------------------
+/// A unit of simulation logic run once per tick by the scheduler.
+/// -------------------------------------------------------------------
+///
+/// Systems read and write components in `world.entity_store` and report
+/// what happened as `ChronoEvent`s stamped at `world.sim_time`, which is
+/// already the *end* of the step of length `dt` when `tick` is called.
+pub trait System: Send + Sync {
+    /// Unique name, also used by other systems to declare ordering.
+    fn name(&self) -> &'static str;
+
+    /// Names of systems that must run before this one within a tick.
+    fn after(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Longest step this system can take accurately from the world's
+    /// current state. Before each step the scheduler limits the clock to
+    /// the smallest answer; `None` accepts any step.
+    fn max_step(&self, _world: &WorldState) -> Option<SimDuration> {
+        None
+    }
+
+    fn tick(&mut self, world: &mut WorldState, dt: SimDuration) -> Vec<ChronoEvent>;
+}
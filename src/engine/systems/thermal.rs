@@ -0,0 +1,158 @@
+use crate::core::chronovox::{ChronoEvent, EventKind};
+use crate::core::env::FieldSample;
+use crate::core::components::condition::{ThermalData, ThermalExposure};
+use crate::core::objex::geospec::traits::{SurfaceArea, Volume};
+use crate::core::tdt::sim_duration::SimDuration;
+use crate::engine::systems::active::active_entities;
+use crate::engine::systems::geometry::shape_of;
+use crate::engine::systems::material::{material_props, material_props_at};
+use crate::engine::systems::system::System;
+use crate::engine::world::state::WorldState;
+
+use serde_json::json;
+use serde::{Serialize, Deserialize};
+
+/// Convective film coefficient (W/m²·K), still air.
+const H_CONV: f64 = 10.0;
+
+/// Surface emissivity for radiative exchange with the surroundings.
+const EMISSIVITY: f64 = 0.9;
+
+/// Stefan–Boltzmann constant (W/m²·K⁴).
+const STEFAN_BOLTZMANN: f64 = 5.670_374e-8;
+
+/// Ambient used when the world has no temperature model at a position.
+const FALLBACK_AMBIENT_C: f64 = 15.0;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ThermalSystem;
+
+impl System for ThermalSystem {
+    fn name(&self) -> &'static str {
+        "ThermalSystem"
+    }
+
+    fn tick(&mut self, world: &mut WorldState, dt: SimDuration) -> Vec<ChronoEvent> {
+        let mut events = Vec::new();
+
+        let now = world.sim_time;
+        let world_id = world.meta.id;
+        let dt_s = dt.seconds_f64();
+        let environment = &world.environment;
+        let store = &mut world.entity_store;
+
+        for id in active_entities(store) {
+            //---------------------------------------------------------
+            // Material + geometry
+            //---------------------------------------------------------
+            let Some(mat) = material_props(store, id) else { continue };
+            let Some(shape) = shape_of(store, id) else { continue };
+
+            let area = shape.surface_area();
+            let volume = shape.volume();
+            if volume <= 0.0 || area <= 0.0 {
+                continue;
+            }
+
+            //---------------------------------------------------------
+            // Ambient conditions at the entity's position
+            //---------------------------------------------------------
+            let sample = store
+                .positions
+                .get(&id)
+                .map(|p| environment.sample(&p.0, SimDuration::from_ns(now.as_ns())))
+                .unwrap_or_default();
+            let ambient_c = Some(sample.temperature)
+                .filter(|k| *k > 0.0)
+                .map(|k| k - 273.15)
+                .unwrap_or(FALLBACK_AMBIENT_C);
+
+            let irradiance_w_m2 = store
+                .sunlights
+                .get(&id)
+                .map(|s| s.irradiance_w_m2.max(0.0))
+                .unwrap_or(0.0);
+
+            // Specific heat follows the body's own temperature
+            let body_c = store.thermals.get(&id).map(|t| t.temperature_c).unwrap_or(ambient_c);
+            let at_body = FieldSample { temperature: body_c + 273.15, ..sample };
+            let specific_heat = material_props_at(store, id, &at_body)
+                .map(|m| m.specific_heat as f64)
+                .unwrap_or(mat.specific_heat as f64);
+
+            //---------------------------------------------------------
+            // Thermal component (starts in equilibrium with ambient)
+            //---------------------------------------------------------
+            let thermal = store.thermals.entry(id).or_insert(ThermalData {
+                temperature_c: ambient_c,
+                heat_capacity_j_per_kg_k: specific_heat,
+                absorptivity: mat.absorption as f64,
+                mass_kg: (mat.density as f64) * volume,
+            });
+
+            let exposure = store.thermal_exposures.entry(id).or_insert(ThermalExposure {
+                total_energy_j: 0.0,
+                average_temperature_c: thermal.temperature_c,
+                cycles: 0,
+            });
+
+            //---------------------------------------------------------
+            // Absorbed sunlight, convection and radiation toward
+            // ambient. Radiation is linearised about the current
+            // temperature, so the step is still solved exactly and
+            // long steps stay stable.
+            //---------------------------------------------------------
+            thermal.heat_capacity_j_per_kg_k = specific_heat;
+            let heat_capacity = thermal.mass_kg * thermal.heat_capacity_j_per_kg_k;
+            if heat_capacity <= 0.0 {
+                continue;
+            }
+
+            let previous_c = thermal.temperature_c;
+            let (t_k, amb_k) = (previous_c + 273.15, ambient_c + 273.15);
+            let h_rad = EMISSIVITY * STEFAN_BOLTZMANN * (t_k * t_k + amb_k * amb_k) * (t_k + amb_k);
+            let h_total = H_CONV + h_rad;
+
+            let absorbed_w_m2 = thermal.absorptivity * irradiance_w_m2;
+            let equilibrium_c = ambient_c + absorbed_w_m2 / h_total;
+            let decay = (-h_total * area * dt_s / heat_capacity).exp();
+            thermal.temperature_c = equilibrium_c + (previous_c - equilibrium_c) * decay;
+
+            let delta_c = thermal.temperature_c - previous_c;
+            let net_energy = delta_c * heat_capacity;
+
+            //---------------------------------------------------------
+            // Exposure tracking
+            //---------------------------------------------------------
+            exposure.total_energy_j += absorbed_w_m2 * area * dt_s;
+            exposure.cycles += 1;
+            exposure.average_temperature_c +=
+                (thermal.temperature_c - exposure.average_temperature_c)
+                / exposure.cycles as f64;
+
+            if delta_c.abs() < 1e-9 {
+                continue;
+            }
+
+            //---------------------------------------------------------
+            // Events
+            //---------------------------------------------------------
+            events.push(
+                ChronoEvent::new(id, world_id, now, EventKind::TemperatureChange { delta_c })
+                    .with_payload(json!({
+                        "temperature_c": thermal.temperature_c,
+                        "ambient_c": ambient_c,
+                        "irradiance_w_m2": irradiance_w_m2,
+                        "net_energy_j": net_energy,
+                        "mass_kg": thermal.mass_kg,
+                        "heat_capacity_j_per_kg_k": thermal.heat_capacity_j_per_kg_k,
+                        "absorptivity": thermal.absorptivity,
+                        "average_temperature_c": exposure.average_temperature_c,
+                        "cycles": exposure.cycles,
+                    }))
+            );
+        }
+
+        events
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::world::{World, WorldEnvironment};
 use crate::core::tdt::sim_time::SimTime;
 use crate::core::entity::entity_store::EntityStore;
+use crate::core::spatial::SpatialIndex;
 use crate::shared::world_sources::state::source::WorldStateSnapshot;
 /// -------------------------------------------------------------------
 /// ECS-style in-memory simulation state for a running world
@@ -15,8 +16,10 @@ pub struct WorldState {
     // --- ECS ---
     pub entity_store: EntityStore,
 
-    // --- Derived / runtime-only helpers (later) ---
-    // pub spatial_index: SpatialIndex,
+    // --- Derived / runtime-only helpers ---
+    /// Positions of `entity_store`, kept in step by the scheduler
+    /// (or `refresh_spatial_index` after editing positions directly).
+    pub spatial_index: SpatialIndex,
     // pub system_cache: ...
 }
 
@@ -27,6 +30,7 @@ impl WorldState {
             environment,
             sim_time: SimTime::from_ns(0),
             entity_store: EntityStore::default(),
+            spatial_index: SpatialIndex::new(),
         }
     }
 
@@ -39,7 +43,39 @@ impl WorldState {
             meta,
             environment,
             sim_time: snapshot.sim_time,
+            spatial_index: SpatialIndex::from_store(&snapshot.entity_store),
             entity_store: snapshot.entity_store,
         }
     }
+
+    /// Re-index positions that changed outside a scheduler tick.
+    pub fn refresh_spatial_index(&mut self) -> usize {
+        self.spatial_index.sync(&self.entity_store)
+    }
+}
+
+#[cfg(test)]
+impl WorldState {
+    /// World 1, "test": Earth-sized and radial, gas everywhere, with no
+    /// land, atmosphere, temperature or pressure fields.
+    pub(crate) fn flat() -> Self {
+        use crate::core::world::world_env_descriptor::WorldEnvDescriptor;
+        use crate::core::WorldId;
+
+        let desc: WorldEnvDescriptor = serde_json::from_value(serde_json::json!({
+            "space": { "up_model": "Radial", "surface_radius_m": 6371000.0 },
+            "gravity": { "kind": "Radial", "strength": 9.80665 },
+            "medium": { "default": "Gas" },
+            "land": null,
+            "atmosphere": null,
+            "temperature": null,
+            "pressure": null
+        }))
+        .unwrap();
+
+        Self::new(
+            World::new(WorldId(1), Some("test".into()), None, None),
+            WorldEnvironment::from_descriptor(&desc),
+        )
+    }
 }
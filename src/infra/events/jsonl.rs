@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+
+use crate::core::chronovox::ChronoEvent;
+use crate::core::id::{EntityId, WorldId};
+use crate::core::tdt::sim_time::SimTime;
+use crate::infra::inmemory::events::{EventLog, by_world};
+use crate::shared::events::event_source::{EventCursor, EventPage, EventSource};
+
+/// --------------------------------------------------
+/// JSON Lines EventSource
+/// --------------------------------------------------
+///
+/// - One append-only file per world: `world_{id}.events.jsonl`
+/// - One `ChronoEvent` per line, in append order
+/// - Files are indexed in memory the first time a world is touched
+/// - A torn last line (crash mid-write) is dropped on load
+/// - A batch spanning worlds writes one file after another; if a write
+///   fails, files already written are cut back, so the batch leaves
+///   nothing. A crash between files can leave the earlier worlds'
+///   events, which re-appending the batch dedups
+/// --------------------------------------------------
+pub struct JsonlEventSource {
+    root: PathBuf,
+    logs: Mutex<HashMap<WorldId, EventLog>>,
+}
+
+impl JsonlEventSource {
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        fs::create_dir_all(&root)?;
+        Ok(Self {
+            root,
+            logs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn log_path(&self, world_id: WorldId) -> PathBuf {
+        self.root.join(format!("world_{}.events.jsonl", world_id.0))
+    }
+
+    /// Read a world's file into an index, truncating a torn tail.
+    fn load(&self, world_id: WorldId) -> Result<EventLog> {
+        let mut log = EventLog::default();
+        let path = self.log_path(world_id);
+        if !path.exists() {
+            return Ok(log);
+        }
+
+        let mut reader = BufReader::new(File::open(&path)?);
+        let mut line = String::new();
+        let mut good_len = 0u64;
+        let mut line_no = 0;
+
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            line_no += 1;
+
+            if !line.ends_with('\n') {
+                // Only the last line can be incomplete; drop it.
+                OpenOptions::new().write(true).open(&path)?.set_len(good_len)?;
+                break;
+            }
+
+            let event: ChronoEvent = serde_json::from_str(&line)
+                .with_context(|| format!("{}:{line_no}", path.display()))?;
+            let event_ref = [&event];
+            if !log.new_events(&event_ref)?.is_empty() {
+                log.push(event);
+            }
+            good_len += read as u64;
+        }
+
+        Ok(log)
+    }
+
+    fn with_log<T>(
+        &self,
+        logs: &mut HashMap<WorldId, EventLog>,
+        world_id: WorldId,
+        f: impl FnOnce(&mut EventLog) -> Result<T>,
+    ) -> Result<T> {
+        let log = match logs.entry(world_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(self.load(world_id)?),
+        };
+        f(log)
+    }
+}
+
+#[async_trait]
+impl EventSource for JsonlEventSource {
+    async fn append(&self, events: &[ChronoEvent]) -> Result<usize> {
+        let mut logs = self.logs.lock().unwrap();
+        let grouped = by_world(events);
+
+        // Check every world before writing any.
+        let mut fresh = Vec::new();
+        for (world_id, batch) in &grouped {
+            let new = self.with_log(&mut logs, *world_id, |log| log.new_events(batch))?;
+            fresh.push((*world_id, new));
+        }
+
+        // Write every world, then index; on failure undo the writes.
+        let mut written: Vec<(PathBuf, u64)> = Vec::new();
+        let write = |written: &mut Vec<(PathBuf, u64)>| -> Result<()> {
+            for (world_id, events) in fresh.iter().filter(|(_, e)| !e.is_empty()) {
+                let mut buf = Vec::new();
+                for event in events {
+                    serde_json::to_writer(&mut buf, event)?;
+                    buf.push(b'\n');
+                }
+
+                let path = self.log_path(*world_id);
+                let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+                written.push((path, file.metadata()?.len()));
+                file.write_all(&buf)?;
+                file.sync_data()?;
+            }
+            Ok(())
+        };
+        if let Err(e) = write(&mut written) {
+            for (path, len) in written {
+                let _ = OpenOptions::new().write(true).open(&path).and_then(|f| f.set_len(len));
+            }
+            return Err(e);
+        }
+
+        let mut added = 0;
+        for (world_id, events) in fresh {
+            let log = logs.get_mut(&world_id).expect("loaded above");
+            for event in events {
+                log.push(event.clone());
+                added += 1;
+            }
+        }
+
+        Ok(added)
+    }
+
+    async fn range_by_time(
+        &self,
+        world_id: WorldId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>> {
+        let mut logs = self.logs.lock().unwrap();
+        self.with_log(&mut logs, world_id, |log| Ok(log.range_by_time(start, end)))
+    }
+
+    async fn range_by_entity(
+        &self,
+        world_id: WorldId,
+        entity_id: EntityId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>> {
+        let mut logs = self.logs.lock().unwrap();
+        self.with_log(&mut logs, world_id, |log| Ok(log.range_by_entity(entity_id, start, end)))
+    }
+
+    async fn read_after(
+        &self,
+        world_id: WorldId,
+        cursor: EventCursor,
+        limit: usize,
+    ) -> Result<EventPage> {
+        let mut logs = self.logs.lock().unwrap();
+        self.with_log(&mut logs, world_id, |log| Ok(log.read_after(cursor, limit)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::chronovox::{ChronovoxError, EventKind};
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("omnivox-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    /// Floats with full 17-digit mantissas, which must read back exactly.
+    fn events(entity: EntityId) -> Vec<ChronoEvent> {
+        (0..5)
+            .map(|day| {
+                let rate = 97.82034458884425 + day as f64 / 3.0;
+                ChronoEvent::new(entity, WorldId(1), SimTime::from_ns(0).add_days(day), EventKind::Degrade { rate })
+                    .with_payload(serde_json::json!({ "temperature_c": rate.sqrt(), "loss": [0.1 + 0.2, 1e-300 / 3.0] }))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn survives_restart_and_dedups() {
+        let root = temp_root("jsonl-restart");
+        let pipe = EntityId::new();
+
+        let source = JsonlEventSource::new(&root).unwrap();
+        assert_eq!(source.append(&events(pipe)[..3]).await.unwrap(), 3);
+        drop(source);
+
+        let reopened = JsonlEventSource::new(&root).unwrap();
+        assert_eq!(reopened.append(&events(pipe)).await.unwrap(), 2);
+
+        let all = reopened
+            .range_by_entity(WorldId(1), pipe, SimTime::from_ns(0), SimTime::from_ns(0).add_days(10))
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 5);
+        assert!(all.iter().zip(events(pipe)).all(|(read, written)| read.is_same_event(&written)));
+
+        let page = reopened.read_after(WorldId(1), EventCursor(3), 10).await.unwrap();
+        assert_eq!(page.events.len(), 2);
+        assert_eq!(page.next, EventCursor(5));
+        let past_end = reopened.read_after(WorldId(1), EventCursor(9), 10).await.unwrap();
+        assert_eq!((past_end.events.len(), past_end.next), (0, EventCursor(5)));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn conflicting_batch_is_not_written() {
+        let root = temp_root("jsonl-conflict");
+        let pipe = EntityId::new();
+        let source = JsonlEventSource::new(&root).unwrap();
+        source.append(&events(pipe)[..1]).await.unwrap();
+
+        let mut clash = events(pipe);
+        clash[0].kind = EventKind::Degrade { rate: 99.0 };
+        let err = source.append(&clash).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ChronovoxError::ConflictingEvent(_))));
+
+        let reopened = JsonlEventSource::new(&root).unwrap();
+        let page = reopened.read_after(WorldId(1), EventCursor::default(), 10).await.unwrap();
+        assert_eq!(page.events.len(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_write_rolls_back_the_whole_batch() {
+        let root = temp_root("jsonl-rollback");
+        let pipe = EntityId::new();
+        let source = JsonlEventSource::new(&root).unwrap();
+        source.append(&events(pipe)[..1]).await.unwrap();
+        let before = fs::read(source.log_path(WorldId(1))).unwrap();
+
+        // World 2 is indexed (empty), then its file becomes unwritable
+        source.read_after(WorldId(2), EventCursor::default(), 1).await.unwrap();
+        fs::create_dir(source.log_path(WorldId(2))).unwrap();
+
+        let mut batch = events(pipe)[1..3].to_vec();
+        batch[1].world_id = WorldId(2);
+        assert!(source.append(&batch).await.is_err());
+        assert_eq!(fs::read(source.log_path(WorldId(1))).unwrap(), before);
+        let page = source.read_after(WorldId(1), EventCursor::default(), 10).await.unwrap();
+        assert_eq!(page.events.len(), 1);
+
+        // Once writable again the same batch goes through
+        fs::remove_dir(source.log_path(WorldId(2))).unwrap();
+        assert_eq!(source.append(&batch).await.unwrap(), 2);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn torn_tail_is_dropped() {
+        let root = temp_root("jsonl-torn");
+        let pipe = EntityId::new();
+        let source = JsonlEventSource::new(&root).unwrap();
+        source.append(&events(pipe)[..2]).await.unwrap();
+
+        let path = source.log_path(WorldId(1));
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"entity_id\":").unwrap();
+        drop(source);
+
+        let reopened = JsonlEventSource::new(&root).unwrap();
+        assert_eq!(reopened.append(&events(pipe)[2..3]).await.unwrap(), 1);
+        drop(reopened);
+
+        let again = JsonlEventSource::new(&root).unwrap();
+        let page = again.read_after(WorldId(1), EventCursor::default(), 10).await.unwrap();
+        assert_eq!(page.events.len(), 3);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+use crate::core::chronovox::{ChronoEvent, ChronovoxError, EventKey, Timeline};
+use crate::core::id::{EntityId, WorldId};
+use crate::core::tdt::sim_time::SimTime;
+use crate::shared::events::event_source::{EventCursor, EventPage, EventSource};
+
+/// --------------------------------------------------
+/// One world's log: key-ordered index + append order
+/// --------------------------------------------------
+///
+/// Shared by the in-memory and file-backed adapters.
+#[derive(Default)]
+pub(crate) struct EventLog {
+    timeline: Timeline,
+    appended: Vec<EventKey>,
+}
+
+impl EventLog {
+    /// Events from `batch` not yet in the log, in batch order.
+    /// Fails without changing anything if any event conflicts.
+    pub(crate) fn new_events<'a>(&self, batch: &[&'a ChronoEvent]) -> Result<Vec<&'a ChronoEvent>> {
+        let mut pending: BTreeMap<EventKey, &ChronoEvent> = BTreeMap::new();
+        let mut fresh = Vec::new();
+
+        for &event in batch {
+            let key = event.key();
+            let seen = self.timeline.get(&key).or_else(|| pending.get(&key).copied());
+            match seen {
+                Some(existing) if existing.is_same_event(event) => {}
+                Some(_) => bail!(ChronovoxError::ConflictingEvent(key)),
+                None => {
+                    pending.insert(key, event);
+                    fresh.push(event);
+                }
+            }
+        }
+
+        Ok(fresh)
+    }
+
+    /// Record an event already checked by `new_events`.
+    pub(crate) fn push(&mut self, event: ChronoEvent) {
+        self.appended.push(event.key());
+        // Checked by `new_events`, so this cannot conflict.
+        let _ = self.timeline.append(event);
+    }
+
+    pub(crate) fn range_by_time(&self, start: SimTime, end: SimTime) -> Vec<ChronoEvent> {
+        self.timeline
+            .query_time_range(start.as_ns(), end.as_ns())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn range_by_entity(&self, entity_id: EntityId, start: SimTime, end: SimTime) -> Vec<ChronoEvent> {
+        self.timeline
+            .query_time_range(start.as_ns(), end.as_ns())
+            .into_iter()
+            .filter(|e| e.entity_id == entity_id)
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn read_after(&self, cursor: EventCursor, limit: usize) -> EventPage {
+        let from = (cursor.0 as usize).min(self.appended.len());
+        let events: Vec<ChronoEvent> = self.appended[from..]
+            .iter()
+            .take(limit)
+            .filter_map(|key| self.timeline.get(key).cloned())
+            .collect();
+
+        EventPage {
+            next: EventCursor((from + events.len()) as u64),
+            events,
+        }
+    }
+}
+
+/// Group a batch by world, keeping batch order within each world.
+pub(crate) fn by_world(events: &[ChronoEvent]) -> BTreeMap<WorldId, Vec<&ChronoEvent>> {
+    let mut grouped: BTreeMap<WorldId, Vec<&ChronoEvent>> = BTreeMap::new();
+    for event in events {
+        grouped.entry(event.world_id).or_default().push(event);
+    }
+    grouped
+}
+
+/// --------------------------------------------------
+/// In-memory EventSource
+/// --------------------------------------------------
+///
+/// - Keeps every world's log in memory
+/// - No persistence
+/// - Used for dev / tests / short-lived runs
+/// --------------------------------------------------
+#[derive(Default)]
+pub struct InMemoryEventSource {
+    logs: Mutex<HashMap<WorldId, EventLog>>,
+}
+
+impl InMemoryEventSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventSource for InMemoryEventSource {
+    async fn append(&self, events: &[ChronoEvent]) -> Result<usize> {
+        let mut logs = self.logs.lock().unwrap();
+        let grouped = by_world(events);
+
+        // Check every world before writing any, so a conflict leaves
+        // the whole batch unapplied.
+        let mut fresh = Vec::new();
+        for (world_id, batch) in &grouped {
+            let log = logs.entry(*world_id).or_default();
+            fresh.push((*world_id, log.new_events(batch)?));
+        }
+
+        let mut added = 0;
+        for (world_id, events) in fresh {
+            let log = logs.entry(world_id).or_default();
+            for event in events {
+                log.push(event.clone());
+                added += 1;
+            }
+        }
+
+        Ok(added)
+    }
+
+    async fn range_by_time(
+        &self,
+        world_id: WorldId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>> {
+        let logs = self.logs.lock().unwrap();
+        Ok(logs.get(&world_id).map(|log| log.range_by_time(start, end)).unwrap_or_default())
+    }
+
+    async fn range_by_entity(
+        &self,
+        world_id: WorldId,
+        entity_id: EntityId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>> {
+        let logs = self.logs.lock().unwrap();
+        Ok(logs
+            .get(&world_id)
+            .map(|log| log.range_by_entity(entity_id, start, end))
+            .unwrap_or_default())
+    }
+
+    async fn read_after(
+        &self,
+        world_id: WorldId,
+        cursor: EventCursor,
+        limit: usize,
+    ) -> Result<EventPage> {
+        let logs = self.logs.lock().unwrap();
+        Ok(match logs.get(&world_id) {
+            Some(log) => log.read_after(cursor, limit),
+            None => EventPage { events: Vec::new(), next: EventCursor::default() },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::chronovox::EventKind;
+
+    fn degrade(entity: EntityId, world: i64, day: i64, rate: f64) -> ChronoEvent {
+        ChronoEvent::new(entity, WorldId(world), SimTime::from_ns(0).add_days(day), EventKind::Degrade { rate })
+    }
+
+    #[tokio::test]
+    async fn dedups_within_and_across_batches() {
+        let source = InMemoryEventSource::new();
+        let pipe = EntityId::new();
+        let first = degrade(pipe, 1, 0, 0.25);
+
+        assert_eq!(source.append(&[first.clone(), first.clone()]).await.unwrap(), 1);
+        assert_eq!(source.append(&[first.clone(), degrade(pipe, 1, 1, 0.5)]).await.unwrap(), 1);
+
+        let page = source.read_after(WorldId(1), EventCursor::default(), 10).await.unwrap();
+        assert_eq!(page.events.len(), 2);
+        assert_eq!(page.next, EventCursor(2));
+    }
+
+    #[tokio::test]
+    async fn conflict_in_one_world_leaves_every_world_unchanged() {
+        let source = InMemoryEventSource::new();
+        let pipe = EntityId::new();
+        source.append(&[degrade(pipe, 1, 0, 0.25)]).await.unwrap();
+
+        let batch = [degrade(pipe, 2, 0, 0.25), degrade(pipe, 1, 0, 0.75)];
+        let err = source.append(&batch).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ChronovoxError::ConflictingEvent(_))));
+
+        let untouched = source.read_after(WorldId(2), EventCursor::default(), 10).await.unwrap();
+        assert!(untouched.events.is_empty());
+        assert_eq!(untouched.next, EventCursor::default());
+    }
+
+    #[tokio::test]
+    async fn queries_are_inclusive_and_paged_in_append_order() {
+        let source = InMemoryEventSource::new();
+        let (pipe, valve) = (EntityId::new(), EntityId::new());
+        // Appended out of time order
+        let events = [degrade(pipe, 1, 5, 0.1), degrade(valve, 1, 1, 0.2), degrade(pipe, 1, 3, 0.3)];
+        source.append(&events).await.unwrap();
+
+        let day = |d| SimTime::from_ns(0).add_days(d);
+        let in_range = source.range_by_time(WorldId(1), day(1), day(3)).await.unwrap();
+        assert_eq!(in_range.iter().map(|e| e.t).collect::<Vec<_>>(), vec![day(1), day(3)]);
+        assert_eq!(source.range_by_entity(WorldId(1), pipe, day(0), day(10)).await.unwrap().len(), 2);
+        assert!(source.range_by_time(WorldId(9), day(0), day(10)).await.unwrap().is_empty());
+
+        let first = source.read_after(WorldId(1), EventCursor::default(), 2).await.unwrap();
+        assert_eq!(first.events[0].t, day(5));
+        let rest = source.read_after(WorldId(1), first.next, 2).await.unwrap();
+        assert_eq!((rest.events.len(), rest.next), (1, EventCursor(3)));
+        let past_end = source.read_after(WorldId(1), EventCursor(10), 2).await.unwrap();
+        assert_eq!((past_end.events.len(), past_end.next), (0, EventCursor(3)));
+        let no_log = source.read_after(WorldId(9), EventCursor(4), 2).await.unwrap();
+        assert_eq!((no_log.events.len(), no_log.next), (0, EventCursor(0)));
+    }
+}
@@ -7,4 +7,4 @@ pub mod session;
 pub mod users;
 pub mod anon_users;
 pub mod auth;   
-pub mod world_state;
\ No newline at end of file
+pub mod world_state;pub mod events;
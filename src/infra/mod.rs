@@ -17,6 +17,7 @@ pub mod location;
 pub use location::*;
 */
 pub mod world_sources;
+pub mod events;
 
 pub mod inmemory;
 
@@ -15,7 +15,6 @@ use crate::shared::world_sources::catalog::source::WorldCatalog;
 
 /// JSON-backed, read-only world catalog
 pub struct JsonWorldCatalog {
-    root: PathBuf,
     worlds: Vec<WorldDefinition>,
 }
 
@@ -24,10 +23,13 @@ impl JsonWorldCatalog {
         let root = path.into();
         let mut worlds = Vec::new();
 
-        for entry in fs::read_dir(&root)? {
-            let entry = entry?;
-            let path = entry.path();
+        // Sorted so the catalog order doesn't depend on the filesystem.
+        let mut paths = fs::read_dir(&root)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        paths.sort();
 
+        for path in paths {
             if path.extension().and_then(|e| e.to_str()) == Some("json") {
                 let data = fs::read_to_string(&path)?;
                 let mut defs: Vec<WorldDefinition> = serde_json::from_str(&data)?;
@@ -35,15 +37,14 @@ impl JsonWorldCatalog {
             }
         }
 
-        Ok(Self { root, worlds })
+        Ok(Self { worlds })
     }
 
-    //fn find(&self, world_id: WorldId) -> Option<&WorldDefinition> {
-    //    self.worlds.iter().find(|w| w.world_id == world_id)
-    //}
-
-    fn definition_path(&self, world_id: WorldId) -> std::path::PathBuf {
-        self.root.join(format!("world_{}.json", world_id))
+    fn find(&self, world_id: WorldId) -> Result<&WorldDefinition> {
+        self.worlds
+            .iter()
+            .find(|w| w.world_id == world_id)
+            .ok_or_else(|| anyhow::anyhow!("World not found"))
     }
 }
 
@@ -52,24 +53,16 @@ impl WorldCatalog for JsonWorldCatalog {
     async fn list_worlds(&self) -> Result<Vec<WorldSummary>> {
         Ok(self.worlds.iter().map(WorldSummary::from).collect())
     }
-        async fn get_world_definition(
-            &self,
-            world_id: WorldId,
-        ) -> Result<WorldDefinition> {
-            let path = self.definition_path(world_id);
-            let raw = std::fs::read_to_string(path)?;
-            let def: WorldDefinition = serde_json::from_str(&raw)?;
-            Ok(def)
-        }
 
-    async fn get_world(&self, world_id: WorldId) -> Result<WorldSummary> {
-        let def = self
-            .worlds
-            .iter()
-            .find(|w| w.world_id == world_id)
-            .ok_or_else(|| anyhow::anyhow!("World not found"))?;
+    async fn get_world_definition(
+        &self,
+        world_id: WorldId,
+    ) -> Result<WorldDefinition> {
+        Ok(self.find(world_id)?.clone())
+    }
 
-        Ok(WorldSummary::from(def))
+    async fn get_world(&self, world_id: WorldId) -> Result<WorldSummary> {
+        Ok(WorldSummary::from(self.find(world_id)?))
     }
 
 
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::RwLock;
+use crate::core::observer::{Observer, ObserverId};
 use crate::shared::world_sources::catalog::source::WorldCatalog;
 use crate::shared::world_sources::state::source::WorldStateSource;
 use crate::shared::identity::auth_source::AuthSource;
@@ -39,6 +42,10 @@ pub struct AppState {
     pub world_engine: Arc<WorldEngine>,
     pub location_engine: Arc<LocationEngine>,
     pub property_engine: Arc<PropertyEngine>,
+
+    // ---- Runtime ----
+    /// Observers placed through the API, by id
+    pub observers: Arc<RwLock<HashMap<ObserverId, Observer>>>,
 }
 
 
@@ -84,6 +91,8 @@ impl AppState {
             world_engine,
             location_engine,
             property_engine,
+
+            observers: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     pub fn from_env() -> anyhow::Result<Self> {
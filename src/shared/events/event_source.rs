@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+
+use crate::core::chronovox::ChronoEvent;
+use crate::core::id::{EntityId, WorldId};
+use crate::core::tdt::sim_time::SimTime;
+
+/// Position in a world's log, counted in appended events.
+/// `EventCursor::default()` is the start of the log.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EventCursor(pub u64);
+
+/// One page of a cursor read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPage {
+    /// Events in the order they were appended.
+    pub events: Vec<ChronoEvent>,
+    /// Where to continue: just past the last event returned. When caught
+    /// up this is the log's length, so a cursor past the end (or into a
+    /// world with no log) comes back clamped.
+    pub next: EventCursor,
+}
+
+/// Append-only event log, one per world.
+///
+/// Events are identified by their `EventKey`: appending an event that is
+/// already stored is a no-op, appending a different event under a stored
+/// key is an error and nothing from that batch is written. Range queries
+/// return events in key order; cursor reads return them in append order,
+/// so a reader tailing the log sees every event exactly once.
+#[async_trait]
+pub trait EventSource: Send + Sync {
+    // ---- Commands ----
+
+    /// Append events, each to the log of its own `world_id`.
+    /// Returns how many were new. An error leaves every log as it was.
+    async fn append(
+        &self,
+        events: &[ChronoEvent],
+    ) -> Result<usize>;
+
+    // ---- Queries ----
+
+    /// Events with `start <= t <= end`.
+    async fn range_by_time(
+        &self,
+        world_id: WorldId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>>;
+
+    /// Events for one entity with `start <= t <= end`.
+    async fn range_by_entity(
+        &self,
+        world_id: WorldId,
+        entity_id: EntityId,
+        start: SimTime,
+        end: SimTime,
+    ) -> Result<Vec<ChronoEvent>>;
+
+    /// Up to `limit` events appended after `cursor`.
+    async fn read_after(
+        &self,
+        world_id: WorldId,
+        cursor: EventCursor,
+        limit: usize,
+    ) -> Result<EventPage>;
+}
@@ -0,0 +1,2 @@
+pub mod event_source;
+pub use event_source::{EventCursor, EventPage, EventSource};
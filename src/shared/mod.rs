@@ -17,4 +17,6 @@ pub use properties::*;
 pub mod location;
 pub use location::*;
 pub mod entities;
-pub use entities::*;
\ No newline at end of file
+pub use entities::*;
+pub mod events;
+pub use events::*;